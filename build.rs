@@ -0,0 +1,91 @@
+// Precompiles the runtime objects in `src/stdlib` into `$OUT_DIR/stdlib`,
+// where wheelc embeds them (`src/runtime.rs`), so a release binary links
+// `--mode ll` programs without a checkout or a C compiler.
+//
+// The table and the commands are the ones wheelc uses itself
+// (`src/runtime_objects.rs`). An object that does not compile here (SDL2
+// without its headers, say) is written empty and wheelc builds it from
+// source when a program needs it. `$OUT_DIR/runtime_embedded.rs` maps each
+// object to its source and precompiled bytes for `src/runtime.rs`.
+//
+// It also sets `WHEEL_BUILD_ID`, a hash of the compiler's sources and
+// features, which keys the incremental cache (`src/cache.rs`) so a rebuilt
+// wheelc never reuses code generated by another build of the same version.
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+include!("src/runtime_objects.rs");
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    let target = env::var("TARGET").expect("cargo sets TARGET");
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("cargo sets CARGO_MANIFEST_DIR");
+    let dir = Path::new(&out_dir).join("stdlib");
+    fs::create_dir_all(&dir).expect("failed to create $OUT_DIR/stdlib");
+    println!("cargo:rustc-env=WHEEL_STDLIB_TRIPLE={}", target);
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/runtime_objects.rs");
+    println!("cargo:rerun-if-env-changed=CC");
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rustc-env=WHEEL_BUILD_ID={}", build_id(Path::new(&manifest_dir)));
+
+    // only the `wheelc` binary embeds them
+    let native = env::var_os("CARGO_FEATURE_NATIVE").is_some();
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let mut table = String::from("fn embedded(name: &str) -> (&'static str, &'static [u8]) {\n    match name {\n");
+    for object in OBJECTS {
+        let src = Path::new(&manifest_dir).join("src/stdlib").join(object.file);
+        println!("cargo:rerun-if-changed={}", src.display());
+        let out = dir.join(format!("{}.o", object.name));
+        let cmd = object.command(&target, &rustc);
+        let built = native && Command::new(&cmd[0]).args(&cmd[1..]).arg(&src).arg("-o").arg(&out)
+            .output()
+            .is_ok_and(|o| o.status.success());
+        if !built {
+            fs::write(&out, b"").expect("failed to write $OUT_DIR/stdlib");
+        }
+        writeln!(table, "        {:?} => (include_str!({:?}), include_bytes!({:?})),", object.name, src.display().to_string(), out.display().to_string()).unwrap();
+    }
+    table.push_str("        _ => unreachable!(\"not a runtime object\"),\n    }\n}\n");
+    fs::write(Path::new(&out_dir).join("runtime_embedded.rs"), table).expect("failed to write $OUT_DIR/runtime_embedded.rs");
+}
+
+/// FNV-1a over the enabled features and the path and contents of build.rs,
+/// Cargo.toml and every file under `src`, in path order.
+fn build_id(manifest_dir: &Path) -> String {
+    fn files(dir: &Path, out: &mut Vec<std::path::PathBuf>) {
+        for entry in fs::read_dir(dir).expect("failed to read the source tree").flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                files(&path, out);
+            } else {
+                out.push(path);
+            }
+        }
+    }
+    let mut paths = vec![manifest_dir.join("build.rs"), manifest_dir.join("Cargo.toml")];
+    files(&manifest_dir.join("src"), &mut paths);
+    paths[2..].sort();
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut update = |bytes: &[u8]| {
+        for b in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            hash ^= *b as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    };
+    let mut features: Vec<String> = env::vars().map(|(k, _)| k).filter(|k| k.starts_with("CARGO_FEATURE_")).collect();
+    features.sort();
+    for feature in &features {
+        update(feature.as_bytes());
+    }
+    for path in &paths {
+        update(path.strip_prefix(manifest_dir).unwrap_or(path).to_string_lossy().as_bytes());
+        update(&fs::read(path).expect("failed to read the source tree"));
+    }
+    format!("{:016x}", hash)
+}
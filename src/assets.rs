@@ -0,0 +1,172 @@
+// Static assets baked into the binary.
+//
+// `embed("sprites.bin")` evaluates to a pointer to the file's bytes in
+// `.rodata` and `embed_len("sprites.bin")` to their length. The argument is a
+// name from the `[assets]` table of `wheel.toml` (paths relative to the project
+// root) or a path relative to the input file. This pass runs after imports are
+// merged and rewrites every argument to the canonical path, so the backends
+// only ever see absolute paths that are known to exist.
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use crate::ast::{Expr, Program, Stmt};
+
+/// Builtins taking an asset as their only argument.
+pub const EMBED_BUILTINS: &[&str] = &["embed", "embed_len"];
+
+pub struct AssetResolver<'a> {
+    /// Directory of the root input; plain paths are relative to it
+    pub base_dir: &'a Path,
+    /// Project root; `[assets]` paths are relative to it
+    pub root: &'a Path,
+    pub table: &'a BTreeMap<String, String>,
+}
+
+impl AssetResolver<'_> {
+    fn locate(&self, name: &str) -> Result<PathBuf, String> {
+        let path = match self.table.get(name) {
+            Some(p) => self.root.join(p),
+            None => self.base_dir.join(name),
+        };
+        path.canonicalize()
+            .map_err(|e| format!("cannot embed `{}` ({}): {}", name, path.display(), e))
+    }
+
+    /// Rewrite every `embed`/`embed_len` argument in `prog` to a canonical path.
+    /// Returns the distinct files embedded, in first-use order.
+    pub fn resolve(&self, prog: &mut Program) -> Result<Vec<PathBuf>, String> {
+        let mut files = Vec::new();
+        self.stmts(&mut prog.items, &mut files)?;
+        Ok(files)
+    }
+
+    fn stmts(&self, stmts: &mut [Stmt], files: &mut Vec<PathBuf>) -> Result<(), String> {
+        for stmt in stmts {
+            match stmt {
+                Stmt::Expr(e) | Stmt::Let { value: e, .. } | Stmt::Assign { value: e, .. } | Stmt::Return(Some(e)) => self.expr(e, files)?,
+                Stmt::ArrayAssign { index, value, .. } => {
+                    self.expr(index, files)?;
+                    self.expr(value, files)?;
+                }
+                Stmt::Func { body, .. } => self.stmts(body, files)?,
+                Stmt::If { cond, then_body, else_body } => {
+                    self.expr(cond, files)?;
+                    self.stmts(then_body, files)?;
+                    if let Some(eb) = else_body {
+                        self.stmts(eb, files)?;
+                    }
+                }
+                Stmt::While { cond, body } => {
+                    self.expr(cond, files)?;
+                    self.stmts(body, files)?;
+                }
+                Stmt::ForRange { start, end, body, .. } => {
+                    self.expr(start, files)?;
+                    self.expr(end, files)?;
+                    self.stmts(body, files)?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn expr(&self, e: &mut Expr, files: &mut Vec<PathBuf>) -> Result<(), String> {
+        match e {
+            Expr::Call { name, args } if EMBED_BUILTINS.contains(&name.as_str()) => {
+                let [Expr::Str(asset)] = args.as_mut_slice() else {
+                    return Err(format!("`{}` takes a single string literal", name));
+                };
+                let path = self.locate(asset)?;
+                *asset = path.to_string_lossy().into_owned();
+                if !files.contains(&path) {
+                    files.push(path);
+                }
+            }
+            Expr::Call { args, .. } | Expr::ArrayLiteral(args) => {
+                for a in args {
+                    self.expr(a, files)?;
+                }
+            }
+            Expr::BinaryOp { left, right, .. } => {
+                self.expr(left, files)?;
+                self.expr(right, files)?;
+            }
+            Expr::ArrayAccess { array, index } => {
+                self.expr(array, files)?;
+                self.expr(index, files)?;
+            }
+            Expr::Int(_) | Expr::Str(_) | Expr::Ident(_) => {}
+        }
+        Ok(())
+    }
+}
+
+/// Canonical paths of every asset referenced by an already-resolved program, in
+/// first-use order; used by the backends to lay out `.rodata`.
+pub fn embedded_files(prog: &Program) -> Vec<String> {
+    fn walk_expr(e: &Expr, out: &mut Vec<String>) {
+        match e {
+            Expr::Call { name, args } if EMBED_BUILTINS.contains(&name.as_str()) => {
+                if let [Expr::Str(p)] = args.as_slice() {
+                    if !out.contains(p) {
+                        out.push(p.clone());
+                    }
+                }
+            }
+            Expr::Call { args, .. } | Expr::ArrayLiteral(args) => args.iter().for_each(|a| walk_expr(a, out)),
+            Expr::BinaryOp { left, right, .. } => {
+                walk_expr(left, out);
+                walk_expr(right, out);
+            }
+            Expr::ArrayAccess { array, index } => {
+                walk_expr(array, out);
+                walk_expr(index, out);
+            }
+            Expr::Int(_) | Expr::Str(_) | Expr::Ident(_) => {}
+        }
+    }
+    fn walk(stmts: &[Stmt], out: &mut Vec<String>) {
+        for stmt in stmts {
+            match stmt {
+                Stmt::Expr(e) | Stmt::Let { value: e, .. } | Stmt::Assign { value: e, .. } | Stmt::Return(Some(e)) => walk_expr(e, out),
+                Stmt::ArrayAssign { index, value, .. } => {
+                    walk_expr(index, out);
+                    walk_expr(value, out);
+                }
+                Stmt::Func { body, .. } => walk(body, out),
+                Stmt::If { cond, then_body, else_body } => {
+                    walk_expr(cond, out);
+                    walk(then_body, out);
+                    if let Some(eb) = else_body {
+                        walk(eb, out);
+                    }
+                }
+                Stmt::While { cond, body } => {
+                    walk_expr(cond, out);
+                    walk(body, out);
+                }
+                Stmt::ForRange { start, end, body, .. } => {
+                    walk_expr(start, out);
+                    walk_expr(end, out);
+                    walk(body, out);
+                }
+                _ => {}
+            }
+        }
+    }
+    let mut out = Vec::new();
+    walk(&prog.items, &mut out);
+    out
+}
+
+/// Assembler label of an embedded file's data.
+pub fn label(path: &str) -> String {
+    let mut h = crate::cache::Fingerprint::new();
+    h.update(path.as_bytes());
+    format!("Lembed_{}", h.hex())
+}
+
+/// Size of an embedded file; 0 if it vanished after resolution.
+pub fn embedded_len(path: &str) -> u64 {
+    std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
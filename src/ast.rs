@@ -1,4 +1,8 @@
+use std::path::PathBuf;
+use crate::diagnostic::Span;
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
     Int,
     Str,
@@ -7,6 +11,7 @@ pub enum Type {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Expr {
     Int(i64),
     Str(String),
@@ -18,18 +23,32 @@ pub enum Expr {
 }
 
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BinOp { 
     Add, Sub, Mul, Div,
     Lt, Gt, LtEq, GtEq, EqEq, NotEq,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Stmt {
     Expr(Expr),
     Let { name: String, ty: Option<Type>, value: Expr },
     Assign { name: String, value: Expr },
     ArrayAssign { array: String, index: Expr, value: Expr },
-    Func { name: String, params: Vec<String>, body: Vec<Stmt> },
+    /// `export` is set for `pub extern func`, which is callable from C;
+    /// `comptime` for `comptime func`, whose calls on constants are
+    /// evaluated during compilation (see [`crate::comptime`])
+    Func {
+        name: String,
+        params: Vec<String>,
+        body: Vec<Stmt>,
+        export: Option<CSignature>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        comptime: bool,
+    },
+    /// `extern func puts(s: str) -> int;`: a foreign symbol with a C signature
+    Extern { name: String, params: Vec<(String, Type)>, ret: Option<Type> },
     Return(Option<Expr>),
     Import { path: String },
     Use { lib: String },
@@ -37,7 +56,46 @@ pub enum Stmt {
     While { cond: Expr, body: Vec<Stmt> },
     ForRange { var: String, start: Expr, end: Expr, body: Vec<Stmt> },
     StructDef { name: String, fields: Vec<(String, Type)> },
+    /// `@if COND { ... } else { ... }`, resolved against `-D` defines before codegen
+    CfgIf { cond: Expr, then_body: Vec<Stmt>, else_body: Option<Vec<Stmt>> },
+    /// `@asm("...")`/`@llvm("...")`: text copied as it is into the output of
+    /// the backend it is written for
+    Inline { target: InlineTarget, code: String },
+}
+
+/// Backend whose output an [`Stmt::Inline`] block goes into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InlineTarget {
+    /// `@asm`: x86_64 assembly (Intel syntax) for `--mode ge`/`gb`, at the
+    /// point of the block
+    Asm,
+    /// `@llvm`: top-level LLVM IR definitions for `--mode ll`
+    Llvm,
 }
 
+/// Parameter and return types of a function exported to C: the symbol keeps
+/// its Wheel name and uses the C calling convention.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CSignature { pub params: Vec<Type>, pub ret: Option<Type> }
+
 #[derive(Debug, Clone)]
-pub struct Program { pub items: Vec<Stmt> }
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Program {
+    pub items: Vec<Stmt>,
+    /// Where each statement came from, in pre-order (a statement, then the
+    /// statements nested in it). Empty when unknown, e.g. for generated code;
+    /// see [`crate::sourcemap`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub locs: Vec<Loc>,
+}
+
+/// Source location of a statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Loc {
+    /// `None` until the front end knows which file was parsed
+    pub file: Option<PathBuf>,
+    pub span: Span,
+}
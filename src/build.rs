@@ -0,0 +1,204 @@
+// Compiling Wheel modules from a Cargo build script.
+//
+//     // build.rs
+//     fn main() {
+//         wheel::build::compile("src/logic.wheel").unwrap();
+//     }
+//
+// The module goes through the LLVM backend (the `llvm` feature of the `wheel`
+// build-dependency) into a static library `lib<stem>.a` in `OUT_DIR`, next to
+// a `<stem>.h` for bindgen, and the cargo directives that link it and rerun
+// the script when the module or one of its imports changes are printed. Its
+// `pub extern func`s are then plain C functions to Rust:
+//
+//     extern "C" { fn add(a: i64, b: i64) -> i64; }
+//
+// Settings come from the module's `wheel.toml` like for `wheelc`; `Build`
+// overrides them. Unlike the rest of the library, this module prints: cargo
+// reads the directives from stdout.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use crate::builtins::Builtins;
+use crate::config::Config;
+use crate::defines::{self, Defines};
+use crate::edition::Edition;
+use crate::error::{Result, WheelError};
+use crate::Options;
+
+/// Build `path` with the defaults from its `wheel.toml` and the build
+/// script's environment.
+pub fn compile(path: impl Into<PathBuf>) -> Result<Library> {
+    Build::new(path).compile()
+}
+
+/// What [`Build::compile`] produced.
+#[derive(Debug, Clone)]
+pub struct Library {
+    /// Name passed to `rustc-link-lib`, the source's file stem
+    pub name: String,
+    pub archive: PathBuf,
+    pub header: PathBuf,
+}
+
+#[derive(Debug, Clone)]
+pub struct Build {
+    path: PathBuf,
+    name: Option<String>,
+    out_dir: Option<PathBuf>,
+    target: Option<String>,
+    opt_level: Option<u8>,
+    edition: Option<Edition>,
+    defines: Defines,
+}
+
+impl Build {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Build { path: path.into(), name: None, out_dir: None, target: None, opt_level: None, edition: None, defines: Defines::new() }
+    }
+
+    /// Library name instead of the source's file stem.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Output directory instead of `$OUT_DIR`.
+    pub fn out_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.out_dir = Some(dir.into());
+        self
+    }
+
+    /// Target triple instead of `$TARGET`.
+    pub fn target(mut self, triple: impl Into<String>) -> Self {
+        self.target = Some(triple.into());
+        self
+    }
+
+    /// LLVM optimization level (0-3) instead of the profile's `$OPT_LEVEL`.
+    pub fn opt_level(mut self, level: u8) -> Self {
+        self.opt_level = Some(level.min(3));
+        self
+    }
+
+    /// Language edition instead of `language` from `wheel.toml`.
+    pub fn edition(mut self, edition: Edition) -> Self {
+        self.edition = Some(edition);
+        self
+    }
+
+    /// Define NAME for `@if`, on top of the `[defines]` table.
+    pub fn define(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.defines.insert(name.into(), value.into());
+        self
+    }
+
+    /// Compile the module and print the cargo directives for it.
+    pub fn compile(&self) -> Result<Library> {
+        let (config, manifests) = Config::load(&self.path)?;
+        let out_dir = match &self.out_dir {
+            Some(dir) => dir.clone(),
+            None => PathBuf::from(std::env::var_os("OUT_DIR")
+                .ok_or_else(|| WheelError::config("OUT_DIR is not set; call wheel::build from a build script or set `out_dir`"))?),
+        };
+        let target = self.target.clone()
+            .or_else(|| std::env::var("TARGET").ok())
+            .unwrap_or_else(|| "x86_64-unknown-linux-gnu".to_string());
+        let opt_level = self.opt_level.unwrap_or_else(|| match std::env::var("OPT_LEVEL").as_deref() {
+            Ok("0") => 0,
+            Ok("1") => 1,
+            Ok("3") => 3,
+            _ => 2,
+        });
+        let name = self.name.clone().unwrap_or_else(|| {
+            self.path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_else(|| "wheel".to_string())
+        });
+
+        let mut opts = Options::new(&self.path);
+        opts.edition = match (self.edition, &config.language) {
+            (Some(e), _) => e,
+            (None, Some(s)) => Edition::parse(s).map_err(WheelError::config)?,
+            (None, None) => Edition::default(),
+        };
+        opts.defines = defines::builtin("ll", target_os(&target));
+        opts.defines.extend(config.define_values()?);
+        opts.defines.extend(self.defines.clone());
+        opts.assets = config.assets.clone();
+        opts.project_root = crate::config::project_root(&self.path);
+        opts.allowed_warnings = config.warnings.allow.clone().unwrap_or_default();
+        opts.builtins = Builtins::from_config(&config.builtins, &opts.project_root)?;
+
+        let src = fs::read_to_string(&self.path).map_err(|e| WheelError::io(&self.path, e))?;
+        let parsed = crate::parse_source(&src, &opts)?;
+        let mut inputs: Vec<PathBuf> = vec![self.path.clone()];
+        inputs.extend(manifests);
+        inputs.extend(parsed.imports.files().cloned());
+        inputs.extend(parsed.embedded.iter().cloned());
+        let artifacts = parsed.check(&opts)?;
+        let mut diagnostics = crate::check::check_inline(&artifacts.program, crate::ast::InlineTarget::Llvm);
+        diagnostics.extend(crate::builtins::check_compiled_calls(&artifacts.program, "ll"));
+        if !diagnostics.is_empty() {
+            return Err(WheelError::Type { diagnostics });
+        }
+        let compiled = crate::check::check_compiled(&artifacts.program).into_iter()
+            .filter(|d| !opts.allowed_warnings.iter().any(|a| a == d.code));
+        for w in artifacts.warnings.iter().cloned().chain(compiled) {
+            println!("cargo:warning={}", w);
+        }
+
+        fs::create_dir_all(&out_dir).map_err(|e| WheelError::io(&out_dir, e))?;
+        let header = out_dir.join(format!("{}.h", name));
+        fs::write(&header, crate::header::c_header(&artifacts.program, &name)).map_err(|e| WheelError::io(&header, e))?;
+        let archive = out_dir.join(format!("lib{}.a", name));
+        let links = emit_object(&artifacts.program, &archive, &target, opt_level, &opts.builtins)?;
+        let object = archive.with_extension("o");
+        let _ = fs::remove_file(&archive);
+        let ar = std::env::var("AR").unwrap_or_else(|_| "ar".to_string());
+        let status = Command::new(&ar).arg("rcs").arg(&archive).arg(&object).status()
+            .map_err(|e| WheelError::link(format!("failed to run {}: {}", ar, e)))?;
+        if !status.success() {
+            return Err(WheelError::link(format!("{} failed to create {}", ar, archive.display())));
+        }
+
+        for input in &inputs {
+            println!("cargo:rerun-if-changed={}", input.display());
+        }
+        println!("cargo:rerun-if-env-changed=WHEEL_PATH");
+        println!("cargo:rustc-link-search=native={}", out_dir.display());
+        println!("cargo:rustc-link-lib=static={}", name);
+        for link in links {
+            match link.strip_prefix("-l") {
+                Some(lib) => println!("cargo:rustc-link-lib={}", lib),
+                None => println!("cargo:rustc-link-arg={}", link),
+            }
+        }
+        for lib in config.build.libs.iter().flatten() {
+            println!("cargo:rustc-link-lib={}", lib);
+        }
+        Ok(Library { name, archive, header })
+    }
+}
+
+/// `TARGET_OS` define for a triple.
+fn target_os(triple: &str) -> &'static str {
+    if triple.contains("windows") {
+        "windows"
+    } else if triple.contains("apple") {
+        "macos"
+    } else if triple.contains("linux") {
+        "linux"
+    } else {
+        "unknown"
+    }
+}
+
+#[cfg(feature = "llvm")]
+fn emit_object(prog: &crate::ast::Program, out: &Path, target: &str, opt_level: u8, builtins: &Builtins) -> Result<Vec<String>> {
+    use crate::llvm_backend::llvm::{compile_with_llvm_target, OutputKind};
+    compile_with_llvm_target(prog, out, target, opt_level, builtins, OutputKind::StaticLib, false)
+}
+
+#[cfg(not(feature = "llvm"))]
+fn emit_object(_prog: &crate::ast::Program, _out: &Path, _target: &str, _opt_level: u8, _builtins: &Builtins) -> Result<Vec<String>> {
+    Err(WheelError::codegen("LLVM backend not enabled. Enable the `llvm` feature of the wheel build-dependency"))
+}
@@ -0,0 +1,176 @@
+// Building programs from Rust instead of source text.
+//
+// A tool that generates Wheel (a level editor, a DSL) can assemble the AST
+// directly and hand it to `compile_program`:
+//
+//     use wheel::builder::*;
+//
+//     let prog = program([
+//         func("sq", ["n"], [return_(ident("n") * ident("n"))]),
+//         let_("x", call("sq", [int(7)])),
+//         if_else(gt(ident("x"), int(40)), [print(string("big"))], [print(ident("x"))]),
+//     ]);
+//
+// Statement constructors are named after the Wheel keyword they stand for,
+// with a trailing `_` where that is a Rust keyword. Expressions combine with
+// `+`, `-`, `*` and `/`; comparisons have functions. Built programs carry no
+// source locations.
+use std::ops::{Add, Div, Mul, Sub};
+use crate::ast::{BinOp, Expr, Program, Stmt, Type};
+
+pub fn program(items: impl IntoIterator<Item = Stmt>) -> Program {
+    Program { items: items.into_iter().collect(), locs: Vec::new() }
+}
+
+pub fn int(v: i64) -> Expr {
+    Expr::Int(v)
+}
+
+/// A string literal.
+pub fn string(s: impl Into<String>) -> Expr {
+    Expr::Str(s.into())
+}
+
+pub fn ident(name: impl Into<String>) -> Expr {
+    Expr::Ident(name.into())
+}
+
+pub fn call(name: impl Into<String>, args: impl IntoIterator<Item = Expr>) -> Expr {
+    Expr::Call { name: name.into(), args: args.into_iter().collect() }
+}
+
+/// `array[index]`
+pub fn index(array: Expr, index: Expr) -> Expr {
+    Expr::ArrayAccess { array: Box::new(array), index: Box::new(index) }
+}
+
+/// `[a, b, ...]`
+pub fn array(items: impl IntoIterator<Item = Expr>) -> Expr {
+    Expr::ArrayLiteral(items.into_iter().collect())
+}
+
+pub fn binary(op: BinOp, left: Expr, right: Expr) -> Expr {
+    Expr::BinaryOp { op, left: Box::new(left), right: Box::new(right) }
+}
+
+pub fn lt(left: Expr, right: Expr) -> Expr {
+    binary(BinOp::Lt, left, right)
+}
+
+pub fn gt(left: Expr, right: Expr) -> Expr {
+    binary(BinOp::Gt, left, right)
+}
+
+pub fn le(left: Expr, right: Expr) -> Expr {
+    binary(BinOp::LtEq, left, right)
+}
+
+pub fn ge(left: Expr, right: Expr) -> Expr {
+    binary(BinOp::GtEq, left, right)
+}
+
+pub fn eq(left: Expr, right: Expr) -> Expr {
+    binary(BinOp::EqEq, left, right)
+}
+
+pub fn ne(left: Expr, right: Expr) -> Expr {
+    binary(BinOp::NotEq, left, right)
+}
+
+impl Add for Expr {
+    type Output = Expr;
+    fn add(self, rhs: Expr) -> Expr {
+        binary(BinOp::Add, self, rhs)
+    }
+}
+
+impl Sub for Expr {
+    type Output = Expr;
+    fn sub(self, rhs: Expr) -> Expr {
+        binary(BinOp::Sub, self, rhs)
+    }
+}
+
+impl Mul for Expr {
+    type Output = Expr;
+    fn mul(self, rhs: Expr) -> Expr {
+        binary(BinOp::Mul, self, rhs)
+    }
+}
+
+impl Div for Expr {
+    type Output = Expr;
+    fn div(self, rhs: Expr) -> Expr {
+        binary(BinOp::Div, self, rhs)
+    }
+}
+
+/// An expression evaluated for its effect, e.g. a call.
+pub fn expr(e: Expr) -> Stmt {
+    Stmt::Expr(e)
+}
+
+pub fn print(e: Expr) -> Stmt {
+    Stmt::Expr(call("print", [e]))
+}
+
+/// `let name = value;`
+pub fn let_(name: impl Into<String>, value: Expr) -> Stmt {
+    Stmt::Let { name: name.into(), ty: None, value }
+}
+
+/// `let name: ty = value;`
+pub fn let_typed(name: impl Into<String>, ty: Type, value: Expr) -> Stmt {
+    Stmt::Let { name: name.into(), ty: Some(ty), value }
+}
+
+/// `set name = value;`
+pub fn set(name: impl Into<String>, value: Expr) -> Stmt {
+    Stmt::Assign { name: name.into(), value }
+}
+
+/// `set array[index] = value;`
+pub fn set_index(array: impl Into<String>, index: Expr, value: Expr) -> Stmt {
+    Stmt::ArrayAssign { array: array.into(), index, value }
+}
+
+pub fn return_(value: Expr) -> Stmt {
+    Stmt::Return(Some(value))
+}
+
+/// `return;`
+pub fn return_void() -> Stmt {
+    Stmt::Return(None)
+}
+
+pub fn if_(cond: Expr, then_body: impl IntoIterator<Item = Stmt>) -> Stmt {
+    Stmt::If { cond, then_body: then_body.into_iter().collect(), else_body: None }
+}
+
+pub fn if_else(cond: Expr, then_body: impl IntoIterator<Item = Stmt>, else_body: impl IntoIterator<Item = Stmt>) -> Stmt {
+    Stmt::If { cond, then_body: then_body.into_iter().collect(), else_body: Some(else_body.into_iter().collect()) }
+}
+
+pub fn while_(cond: Expr, body: impl IntoIterator<Item = Stmt>) -> Stmt {
+    Stmt::While { cond, body: body.into_iter().collect() }
+}
+
+/// `for var in range(start, end) { ... }`
+pub fn for_range(var: impl Into<String>, start: Expr, end: Expr, body: impl IntoIterator<Item = Stmt>) -> Stmt {
+    Stmt::ForRange { var: var.into(), start, end, body: body.into_iter().collect() }
+}
+
+pub fn func<P: Into<String>>(name: impl Into<String>, params: impl IntoIterator<Item = P>, body: impl IntoIterator<Item = Stmt>) -> Stmt {
+    Stmt::Func {
+        name: name.into(),
+        params: params.into_iter().map(Into::into).collect(),
+        body: body.into_iter().collect(),
+        export: None,
+        comptime: false,
+    }
+}
+
+/// `import "path";`, resolved relative to `Options::path` by `compile_program`.
+pub fn import(path: impl Into<String>) -> Stmt {
+    Stmt::Import { path: path.into() }
+}
@@ -0,0 +1,443 @@
+// User-registered builtin functions.
+//
+// The compiler's own builtins (`print`, `sdl_*`, `mem_*`, ...) are hard-coded
+// in the backends. Anything else a program should be able to call without
+// defining it is registered here instead: a name, a signature and the object
+// file or library that implements it. The LLVM backend declares registered
+// builtins on first call and passes their link inputs on to the linker.
+//
+// `wheelc` fills the registry from the `[builtins]` table of the config, and
+// the program's own `extern func` declarations are added on top:
+//
+//     [builtins]
+//     clamp = { params = ["int", "int", "int"], returns = "int", link = "native/clamp.o" }
+//     log_line = { params = ["str"], link = "-lgamelog" }
+//
+// The C-callable runtime in `src/stdlib` registers its functions the same way
+// (`Builtins::runtime`); `wheelc` links its objects in the link step.
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use std::sync::OnceLock;
+use crate::error::{Result, WheelError};
+use serde::Deserialize;
+use crate::ast::{Expr, Program, Stmt, Type};
+use crate::diagnostic::Diagnostic;
+use crate::visit::{walk_expr, Visitor};
+
+/// Type of a builtin parameter or result. All are passed as 64-bit values;
+/// `str` is a pointer to NUL-terminated bytes (`const char *` in C) and
+/// `float` a `double`. Wheel has no floats yet, so ints are converted to
+/// `float` arguments and `float` results are truncated back to ints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValueType {
+    Int,
+    Str,
+    Float,
+}
+
+impl ValueType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValueType::Int => "int",
+            ValueType::Str => "str",
+            ValueType::Float => "float",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Builtin {
+    /// Name used in Wheel source and as the linker symbol
+    pub name: String,
+    pub params: Vec<ValueType>,
+    /// `None` for functions called only for their effect; such calls evaluate to 0
+    pub returns: Option<ValueType>,
+    /// Object file, shared library or `-l` flag handed to the linker
+    pub link: Option<String>,
+    /// Linker symbol, when it is not `name`
+    pub symbol: Option<String>,
+}
+
+impl Builtin {
+    pub fn new(name: impl Into<String>, params: Vec<ValueType>, returns: Option<ValueType>) -> Self {
+        Builtin { name: name.into(), params, returns, link: None, symbol: None }
+    }
+
+    pub fn with_link(mut self, link: impl Into<String>) -> Self {
+        self.link = Some(link.into());
+        self
+    }
+
+    pub fn with_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = Some(symbol.into());
+        self
+    }
+
+    /// The symbol a call links against.
+    pub fn symbol(&self) -> &str {
+        self.symbol.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Builtin for `extern func name(params) -> ret;`. The parser only
+    /// accepts `int` and `str` there; anything else is read as `int`.
+    pub fn from_extern(name: &str, params: &[(String, Type)], ret: Option<&Type>) -> Self {
+        let ty = |t: &Type| if matches!(t, Type::Str) { ValueType::Str } else { ValueType::Int };
+        Builtin::new(name, params.iter().map(|(_, t)| ty(t)).collect(), ret.map(ty))
+    }
+
+    /// Whether an `extern func` declared as `decl` can call this builtin: the
+    /// same number of parameters, with `str` in the same places. `int` stands
+    /// in for `float`, which Wheel cannot spell yet.
+    pub fn accepts(&self, decl: &Builtin) -> bool {
+        let same = |a: &ValueType, b: &ValueType| (*a == ValueType::Str) == (*b == ValueType::Str);
+        self.params.len() == decl.params.len()
+            && self.params.iter().zip(&decl.params).all(|(a, b)| same(a, b))
+            && match (&self.returns, &decl.returns) {
+                (Some(a), Some(b)) => same(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+
+    /// `name(int, str) -> int`, as shown in diagnostics.
+    pub fn signature(&self) -> String {
+        let params: Vec<_> = self.params.iter().map(ValueType::as_str).collect();
+        match self.returns {
+            Some(ret) => format!("{}({}) -> {}", self.name, params.join(", "), ret.as_str()),
+            None => format!("{}({})", self.name, params.join(", ")),
+        }
+    }
+}
+
+/// One entry of the `[builtins]` config table.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BuiltinConfig {
+    pub params: Vec<ValueType>,
+    pub returns: Option<ValueType>,
+    /// Relative paths are resolved against the project root; `-l` flags are passed as is
+    pub link: Option<String>,
+}
+
+/// Named constants every program can use, such as the SDL keycodes for
+/// `sdl_key_down` and the buttons for `sdl_mouse_pressed`. A variable of the
+/// same name hides the constant.
+pub const CONSTANTS: &[(&str, i64)] = &[
+    ("KEY_BACKSPACE", 8), ("KEY_TAB", 9), ("KEY_RETURN", 13), ("KEY_ESCAPE", 27), ("KEY_SPACE", 32),
+    ("KEY_0", 48), ("KEY_1", 49), ("KEY_2", 50), ("KEY_3", 51), ("KEY_4", 52),
+    ("KEY_5", 53), ("KEY_6", 54), ("KEY_7", 55), ("KEY_8", 56), ("KEY_9", 57),
+    ("KEY_A", 97), ("KEY_B", 98), ("KEY_C", 99), ("KEY_D", 100), ("KEY_E", 101), ("KEY_F", 102),
+    ("KEY_G", 103), ("KEY_H", 104), ("KEY_I", 105), ("KEY_J", 106), ("KEY_K", 107), ("KEY_L", 108),
+    ("KEY_M", 109), ("KEY_N", 110), ("KEY_O", 111), ("KEY_P", 112), ("KEY_Q", 113), ("KEY_R", 114),
+    ("KEY_S", 115), ("KEY_T", 116), ("KEY_U", 117), ("KEY_V", 118), ("KEY_W", 119), ("KEY_X", 120),
+    ("KEY_Y", 121), ("KEY_Z", 122),
+    ("KEY_RIGHT", 0x4000_004F), ("KEY_LEFT", 0x4000_0050), ("KEY_DOWN", 0x4000_0051), ("KEY_UP", 0x4000_0052),
+    ("KEY_LCTRL", 0x4000_00E0), ("KEY_LSHIFT", 0x4000_00E1), ("KEY_LALT", 0x4000_00E2),
+    ("MOUSE_LEFT", 1), ("MOUSE_MIDDLE", 2), ("MOUSE_RIGHT", 3),
+];
+
+/// Value of the constant `name`.
+pub fn constant(name: &str) -> Option<i64> {
+    CONSTANTS.iter().find(|c| c.0 == name).map(|c| c.1)
+}
+
+/// The registration of the runtime function `name`, from
+/// [`Builtins::runtime`], whichever registry a program is compiled with.
+pub fn runtime_builtin(name: &str) -> Option<&'static Builtin> {
+    static RUNTIME: OnceLock<Builtins> = OnceLock::new();
+    RUNTIME.get_or_init(Builtins::runtime).get(name)
+}
+
+/// One-argument functions of `math.o`, by their Wheel name.
+pub const MATH_UNARY: &[&str] = &["sin", "cos", "tan", "asin", "acos", "atan", "sqrt", "exp", "log", "log10", "ceil", "floor", "round"];
+
+/// Two-argument functions of `math.o`, by their Wheel name.
+pub const MATH_BINARY: &[&str] = &["atan2", "pow", "fmod"];
+
+/// The set of registered builtins, keyed by name.
+#[derive(Debug, Default, Clone)]
+pub struct Builtins {
+    map: BTreeMap<String, Builtin>,
+}
+
+impl Builtins {
+    pub fn new() -> Self {
+        Builtins::default()
+    }
+
+    /// The functions of the runtime objects in `src/stdlib`, such as the
+    /// `wheel_str_*` string functions of `strings.o` (with `int_to_str`,
+    /// `sb_new`, ... under their own names), the `math_*`
+    /// functions of `math.o` (called as `sqrt`, `pow`, ...) and the
+    /// `wheel_regex_*`, `wheel_vec_*` and `wheel_map_*` functions of
+    /// `regex.o`, `vec.o` and `map.o` (called as `regex_match`, `vec_push`,
+    /// `map_get`, ...), the pipe functions of `process.o` and the arenas of
+    /// `memory.o`. They carry no `link`: the objects are added by the link step.
+    pub fn runtime() -> Self {
+        use ValueType::{Float, Int, Str};
+        let mut builtins = Builtins::new();
+        for b in [
+            Builtin::new("wheel_str_concat", vec![Str, Str], Some(Str)),
+            Builtin::new("wheel_str_len", vec![Str], Some(Int)),
+            Builtin::new("wheel_str_compare", vec![Str, Str], Some(Int)),
+            Builtin::new("wheel_str_substring", vec![Str, Int, Int], Some(Str)),
+            Builtin::new("int_to_str", vec![Int], Some(Str)).with_symbol("wheel_str_from_int"),
+            Builtin::new("str_to_int", vec![Str], Some(Int)).with_symbol("wheel_str_to_int"),
+            Builtin::new("sb_new", vec![], Some(Int)).with_symbol("wheel_sb_new"),
+            Builtin::new("sb_append", vec![Int, Str], Some(Int)).with_symbol("wheel_sb_append"),
+            Builtin::new("sb_to_str", vec![Int], Some(Str)).with_symbol("wheel_sb_to_str"),
+            Builtin::new("regex_match", vec![Str, Str], Some(Int)).with_symbol("wheel_regex_match"),
+            Builtin::new("regex_find", vec![Str, Str], Some(Int)).with_symbol("wheel_regex_find"),
+            Builtin::new("regex_replace", vec![Str, Str, Str], Some(Str)).with_symbol("wheel_regex_replace"),
+            Builtin::new("vec_new", vec![], Some(Int)).with_symbol("wheel_vec_new"),
+            Builtin::new("vec_push", vec![Int, Int], Some(Int)).with_symbol("wheel_vec_push"),
+            Builtin::new("vec_pop", vec![Int], Some(Int)).with_symbol("wheel_vec_pop"),
+            Builtin::new("vec_get", vec![Int, Int], Some(Int)).with_symbol("wheel_vec_get"),
+            Builtin::new("vec_set", vec![Int, Int, Int], Some(Int)).with_symbol("wheel_vec_set"),
+            Builtin::new("vec_len", vec![Int], Some(Int)).with_symbol("wheel_vec_len"),
+            Builtin::new("map_new", vec![], Some(Int)).with_symbol("wheel_map_new"),
+            Builtin::new("map_set", vec![Int, Str, Int], Some(Int)).with_symbol("wheel_map_set"),
+            Builtin::new("map_get", vec![Int, Str], Some(Int)).with_symbol("wheel_map_get"),
+            Builtin::new("map_has", vec![Int, Str], Some(Int)).with_symbol("wheel_map_has"),
+            Builtin::new("map_keys", vec![Int], Some(Int)).with_symbol("wheel_map_keys"),
+            Builtin::new("process_spawn", vec![Str], Some(Int)),
+            Builtin::new("process_write", vec![Int, Str], Some(Int)),
+            Builtin::new("process_read_line", vec![Int], Some(Str)),
+            Builtin::new("process_eof", vec![Int], Some(Int)),
+            Builtin::new("process_exit_code", vec![Int], Some(Int)),
+            Builtin::new("arena_new", vec![Int], Some(Int)),
+            Builtin::new("arena_alloc", vec![Int, Int], Some(Int)),
+            Builtin::new("arena_reset", vec![Int], Some(Int)),
+        ].into_iter().chain(MATH_UNARY.iter().map(|name| Builtin::new(*name, vec![Float], Some(Float)).with_symbol(format!("math_{}", name))))
+            .chain(MATH_BINARY.iter().map(|name| Builtin::new(*name, vec![Float, Float], Some(Float)).with_symbol(format!("math_{}", name))))
+        {
+            builtins.map.insert(b.name.clone(), b);
+        }
+        builtins
+    }
+
+    /// The runtime registry plus a `[builtins]` table whose paths are
+    /// relative to `root`.
+    pub fn from_config(table: &BTreeMap<String, BuiltinConfig>, root: &Path) -> Result<Self> {
+        let mut builtins = Builtins::runtime();
+        for (name, entry) in table {
+            let mut builtin = Builtin::new(name, entry.params.clone(), entry.returns);
+            if let Some(link) = &entry.link {
+                builtin.link = Some(if link.starts_with('-') { link.clone() } else { root.join(link).to_string_lossy().into_owned() });
+            }
+            builtins.register(builtin)?;
+        }
+        Ok(builtins)
+    }
+
+    /// Add `builtin`, replacing an earlier registration of the same name,
+    /// a runtime function's included. Names of the compiler's own builtins
+    /// cannot be taken over.
+    pub fn register(&mut self, builtin: Builtin) -> Result<()> {
+        if crate::lint::is_builtin(&builtin.name) {
+            return Err(WheelError::config(format!("cannot register builtin `{}`: the name is reserved by the compiler", builtin.name)));
+        }
+        if !builtin.name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') || builtin.name.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(WheelError::config(format!("cannot register builtin `{}`: not a valid identifier", builtin.name)));
+        }
+        self.map.insert(builtin.name.clone(), builtin);
+        Ok(())
+    }
+
+    /// This registry plus the top-level `extern func` declarations of `prog`.
+    /// Declaring a name that is already registered keeps the registration,
+    /// symbol and link included, and declaring a runtime function adds its
+    /// registration, so headers such as `std/strings` can declare runtime
+    /// functions under their Wheel names. Declarations that cannot be
+    /// registered or do not match are skipped; [`check_calls`] reports them.
+    pub fn with_externs(&self, prog: &Program) -> Builtins {
+        let mut all = self.clone();
+        for stmt in &prog.items {
+            if let Stmt::Extern { name, params, ret } = stmt {
+                if self.get(name).is_some() {
+                    continue;
+                }
+                match runtime_builtin(name) {
+                    Some(runtime) => {
+                        all.map.insert(name.clone(), runtime.clone());
+                    }
+                    None => {
+                        let _ = all.register(Builtin::from_extern(name, params, ret.as_ref()));
+                    }
+                }
+            }
+        }
+        all
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Builtin> {
+        self.map.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Builtin> {
+        self.map.values()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Linker inputs of every registered builtin, without duplicates.
+    pub fn link_args(&self) -> Vec<String> {
+        let mut args: Vec<String> = Vec::new();
+        for link in self.map.values().filter_map(|b| b.link.as_ref()) {
+            if !args.contains(link) {
+                args.push(link.clone());
+            }
+        }
+        args
+    }
+
+    /// Stable text describing the registry, for cache keys.
+    pub fn fingerprint(&self) -> String {
+        self.map.values()
+            .map(|b| format!("{}={}@{}", b.signature(), b.symbol(), b.link.as_deref().unwrap_or("")))
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+}
+
+/// Names of the top-level `func`s of `prog`. A call to one of them calls the
+/// program's function, whatever builtin has the same name.
+pub fn user_functions(prog: &Program) -> HashSet<&str> {
+    prog.items.iter().filter_map(|s| match s {
+        Stmt::Func { name, .. } => Some(name.as_str()),
+        _ => None,
+    }).collect()
+}
+
+/// Errors for `extern func` declarations that take a compiler builtin's name
+/// and for calls to registered builtins or externs with the wrong number of
+/// arguments; calls to the program's own functions are left to
+/// [`check`](crate::check).
+pub fn check_calls(prog: &Program, builtins: &Builtins) -> Vec<Diagnostic> {
+    let mut res = Vec::new();
+    for stmt in &prog.items {
+        if let Stmt::Extern { name, params, ret } = stmt {
+            if crate::lint::BUILTINS.contains(&name.as_str()) {
+                res.push(Diagnostic::error("reserved-name", format!("`extern func {}` redeclares a compiler builtin", name))
+                    .with_suggestion(format!("call the builtin `{}` directly, or declare the C function under another name", name)));
+            } else if let Some(registered) = builtins.get(name).or_else(|| runtime_builtin(name)) {
+                let decl = Builtin::from_extern(name, params, ret.as_ref());
+                if !registered.accepts(&decl) {
+                    res.push(Diagnostic::error("extern-mismatch", format!(
+                        "`extern func {}` declares `{}`, but the registered builtin is `{}`", name, decl.signature(), registered.signature()
+                    )).with_suggestion("match the registered signature, or declare the C function under another name"));
+                }
+            }
+        }
+    }
+    let builtins = builtins.with_externs(prog);
+    let mut calls = Calls { builtins: &builtins, funcs: user_functions(prog), res };
+    calls.visit_program(prog);
+    calls.res
+}
+
+/// Builtins only the interpreter (`wheelc run`) implements.
+pub const INTERP_BUILTINS: &[&str] = &["json_parse", "json_stringify"];
+
+/// Errors for calls to [`INTERP_BUILTINS`], which every compiled mode would
+/// evaluate to 0. Each name is reported once.
+pub fn check_compiled_calls(prog: &Program, mode: &str) -> Vec<Diagnostic> {
+    let mut calls = InterpCalls { mode, seen: Vec::new(), res: Vec::new() };
+    calls.visit_program(prog);
+    calls.res
+}
+
+struct InterpCalls<'a> {
+    mode: &'a str,
+    seen: Vec<String>,
+    res: Vec<Diagnostic>,
+}
+
+impl Visitor for InterpCalls<'_> {
+    fn visit_expr(&mut self, e: &Expr) {
+        if let Expr::Call { name, .. } = e {
+            if INTERP_BUILTINS.contains(&name.as_str()) && !self.seen.contains(name) {
+                self.seen.push(name.clone());
+                self.res.push(Diagnostic::error("unsupported-in-mode", format!(
+                    "`{}` is only supported by the interpreter, not by `--mode {}`", name, self.mode
+                )).with_suggestion("run the program with `wheelc run`"));
+            }
+        }
+        walk_expr(self, e);
+    }
+}
+
+/// Errors for calls the `ge`/`gb` assembly backend cannot make. Registered
+/// builtins, externs and the compiler builtins outside
+/// [`ASM_BUILTINS`](crate::codegen::ASM_BUILTINS) live in objects that only
+/// `--mode ll` links, so the assembly would call nothing and carry on with 0;
+/// `irq_register` and the `mmio_*` builtins need a `kernel` image. Calls to
+/// the program's own functions are fine whatever their name. Each name is
+/// reported once.
+pub fn check_asm_calls(prog: &Program, builtins: &Builtins, mode: &str) -> Vec<Diagnostic> {
+    let builtins = builtins.with_externs(prog);
+    let mut calls = AsmCalls { builtins: &builtins, funcs: user_functions(prog), mode, seen: Vec::new(), res: Vec::new() };
+    calls.visit_program(prog);
+    calls.res
+}
+
+struct AsmCalls<'a> {
+    builtins: &'a Builtins,
+    funcs: HashSet<&'a str>,
+    mode: &'a str,
+    seen: Vec<String>,
+    res: Vec<Diagnostic>,
+}
+
+impl Visitor for AsmCalls<'_> {
+    fn visit_expr(&mut self, e: &Expr) {
+        if let Expr::Call { name, .. } = e {
+            if !self.seen.contains(name) {
+                // `check_compiled_calls` reports the interpreter's own
+                let runtime = crate::lint::BUILTINS.contains(&name.as_str())
+                    && !crate::codegen::ASM_BUILTINS.contains(&name.as_str())
+                    && !INTERP_BUILTINS.contains(&name.as_str());
+                // a program function is generated like any other
+                let linked = (runtime || self.builtins.get(name).is_some()) && !self.funcs.contains(name.as_str());
+                if linked {
+                    self.seen.push(name.clone());
+                    self.res.push(Diagnostic::error("unsupported-in-mode", format!(
+                        "`{}` is not supported by `--mode {}`, which does not link the runtime or other objects", name, self.mode
+                    )).with_suggestion("build with `--mode ll`"));
+                } else if name == "irq_register" && self.mode == "ge" {
+                    self.seen.push(name.clone());
+                    self.res.push(Diagnostic::error("unsupported-in-mode", "`irq_register` installs an interrupt handler, which a `--mode ge` program cannot do")
+                        .with_suggestion("build a kernel image with `--mode gb`, or use `--mode ll`"));
+                } else if (name == "mmio_read" || name == "mmio_write") && self.mode == "ge" {
+                    self.seen.push(name.clone());
+                    self.res.push(Diagnostic::error("unsupported-in-mode", format!("`{}` accesses a device register, which a `--mode ge` program has none of", name))
+                        .with_suggestion("build a kernel image with `--mode gb`, or use `--mode ll`"));
+                }
+            }
+        }
+        walk_expr(self, e);
+    }
+}
+
+struct Calls<'a> {
+    builtins: &'a Builtins,
+    funcs: HashSet<&'a str>,
+    res: Vec<Diagnostic>,
+}
+
+impl Visitor for Calls<'_> {
+    fn visit_expr(&mut self, e: &Expr) {
+        if let Expr::Call { name, args } = e {
+            let builtin = self.builtins.get(name).filter(|_| !self.funcs.contains(name.as_str()));
+            if let Some(b) = builtin.filter(|b| b.params.len() != args.len()) {
+                self.res.push(Diagnostic::error("builtin-arity", format!(
+                    "`{}` takes {} argument(s) but {} were given", name, b.params.len(), args.len()
+                )).with_suggestion(format!("the registered signature is `{}`", b.signature())));
+            }
+        }
+        walk_expr(self, e);
+    }
+}
@@ -0,0 +1,106 @@
+// Incremental compilation cache.
+//
+// Generated code is stored under `<build_dir>/cache` in the project root
+// (`target/wheel/cache` unless `[layout] build_dir` says otherwise),
+// keyed by a fingerprint of every module that went into the program (the input
+// and everything it imports, transitively) plus the settings that influence
+// codegen and the build of wheelc itself. Imports are still merged into a single program before codegen, so
+// today the whole program is one cache entry; the per-module hashes are kept
+// separate so they can key individual objects once modules compile separately.
+//
+// Only `Fingerprint` is available without the `native` feature.
+#[cfg(feature = "native")]
+use std::fs;
+#[cfg(feature = "native")]
+use std::path::{Path, PathBuf};
+#[cfg(feature = "native")]
+use crate::error::{Result, WheelError};
+
+/// Stable 64-bit FNV-1a; `DefaultHasher` output may change between Rust releases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fingerprint(u64);
+
+impl Fingerprint {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    pub fn new() -> Self {
+        Fingerprint(Self::OFFSET)
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        // length prefix keeps ("ab", "c") and ("a", "bc") apart
+        for b in (bytes.len() as u64).to_le_bytes().iter().chain(bytes) {
+            self.0 ^= *b as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub fn hex(&self) -> String {
+        format!("{:016x}", self.0)
+    }
+}
+
+impl Default for Fingerprint {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hash of one source module's contents.
+#[cfg(feature = "native")]
+pub fn module_hash(path: &Path) -> Result<Fingerprint> {
+    let src = fs::read(path).map_err(|e| WheelError::io(path, e))?;
+    let mut h = Fingerprint::new();
+    h.update(&src);
+    Ok(h)
+}
+
+/// Key for a whole program: compiler version and build (`WHEEL_BUILD_ID`,
+/// from build.rs), codegen settings and the hash of each module in import
+/// order.
+#[cfg(feature = "native")]
+pub fn program_key(modules: &[PathBuf], settings: &[&str]) -> Result<Fingerprint> {
+    let mut key = Fingerprint::new();
+    key.update(env!("CARGO_PKG_VERSION").as_bytes());
+    key.update(env!("WHEEL_BUILD_ID").as_bytes());
+    for s in settings {
+        key.update(s.as_bytes());
+    }
+    for module in modules {
+        key.update(&module_hash(module)?.0.to_le_bytes());
+    }
+    Ok(key)
+}
+
+#[cfg(feature = "native")]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+#[cfg(feature = "native")]
+impl Cache {
+    /// Cache stored in `<build_dir>/cache`.
+    pub fn new(build_dir: &Path) -> Self {
+        Cache { dir: build_dir.join("cache") }
+    }
+
+    fn entry(&self, key: Fingerprint, ext: &str) -> PathBuf {
+        self.dir.join(format!("{}.{}", key.hex(), ext))
+    }
+
+    pub fn get(&self, key: Fingerprint, ext: &str) -> Option<Vec<u8>> {
+        fs::read(self.entry(key, ext)).ok()
+    }
+
+    /// Store `data`; written to a temp file first so an interrupted build never
+    /// leaves a truncated entry behind.
+    pub fn put(&self, key: Fingerprint, ext: &str, data: &[u8]) -> Result<PathBuf> {
+        fs::create_dir_all(&self.dir).map_err(|e| WheelError::io(&self.dir, e))?;
+        let path = self.entry(key, ext);
+        let tmp = path.with_extension(format!("{}.tmp", ext));
+        fs::write(&tmp, data).map_err(|e| WheelError::io(&tmp, e))?;
+        fs::rename(&tmp, &path).map_err(|e| WheelError::io(&path, e))?;
+        Ok(path)
+    }
+}
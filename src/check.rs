@@ -0,0 +1,285 @@
+use std::collections::{HashMap, HashSet};
+use crate::ast::{InlineTarget, Program, Stmt, Expr};
+use crate::diagnostic::Diagnostic;
+use crate::imports::is_bundled;
+use crate::sourcemap::LocIndex;
+use crate::visit::{walk_expr, walk_stmt, Visitor};
+
+/// Run semantic checks over a parsed (and import-merged) program. Errors stop
+/// the build, warnings are only reported.
+///
+/// Scoping in Wheel is still flat, so a name counts as declared if it is
+/// introduced anywhere by `let`, a function parameter or a `for` variable.
+pub fn check_program(prog: &Program) -> Vec<Diagnostic> {
+    let mut res = Vec::new();
+
+    let mut declared = HashSet::new();
+    let mut funcs = HashSet::new();
+    collect_decls(&prog.items, &mut declared, &mut funcs, &mut res);
+
+    let mut used = HashSet::new();
+    check_stmts(&prog.items, &declared, &mut used, &mut res);
+
+    let arity = prog.items.iter().filter_map(|s| match s {
+        Stmt::Func { name, params, .. } => Some((name.as_str(), params.len())),
+        _ => None,
+    }).collect();
+    Callbacks { arity, res: &mut res }.visit_program(prog);
+    WindowCalls { res: &mut res }.visit_program(prog);
+    InlineBlocks { depth: 0, target: None, res: &mut res }.visit_program(prog);
+
+    // constants of the bundled `std/` modules are there to pick from
+    let locs = LocIndex::new(prog);
+    let library: HashSet<&str> = prog.items.iter().filter_map(|stmt| match stmt {
+        Stmt::Let { name, .. } if locs.get(stmt).and_then(|l| l.file.as_deref()).is_some_and(is_bundled) => Some(name.as_str()),
+        _ => None,
+    }).collect();
+    let mut lets = Vec::new();
+    collect_lets(&prog.items, &mut lets);
+    for name in lets {
+        if !used.contains(&name) && !library.contains(name.as_str()) {
+            res.push(Diagnostic::warning("unused-variable", format!("variable `{}` is never used", name))
+                .with_suggestion("remove the `let`, or silence the warning with `allow = [\"unused-variable\"]` under `[warnings]`"));
+        }
+    }
+    res
+}
+
+/// Errors for the `@asm`/`@llvm` blocks of `prog` that the backend taking
+/// `target` blocks cannot compile: each block is only for its own backend.
+pub fn check_inline(prog: &Program, target: InlineTarget) -> Vec<Diagnostic> {
+    let mut res = Vec::new();
+    InlineBlocks { depth: 0, target: Some(target), res: &mut res }.visit_program(prog);
+    res
+}
+
+/// Warnings for statements the compiled backends generate no code for yet;
+/// the interpreter runs them.
+pub fn check_compiled(prog: &Program) -> Vec<Diagnostic> {
+    let mut res = Vec::new();
+    Uncompiled { res: &mut res }.visit_program(prog);
+    res
+}
+
+fn collect_decls(stmts: &[Stmt], declared: &mut HashSet<String>, funcs: &mut HashSet<String>, res: &mut Vec<Diagnostic>) {
+    for stmt in stmts {
+        match stmt {
+            Stmt::Let { name, .. } => { declared.insert(name.clone()); }
+            Stmt::Func { name, params, body, export, .. } => {
+                if !funcs.insert(name.clone()) {
+                    res.push(Diagnostic::error("duplicate-function", format!("function `{}` is defined more than once", name)));
+                }
+                if export.is_some() && name == "main" {
+                    res.push(Diagnostic::error("reserved-name", "`main` cannot be exported with `pub extern func`")
+                        .with_suggestion("give the exported function another name; the program's entry point is generated"));
+                }
+                declared.extend(params.iter().cloned());
+                collect_decls(body, declared, funcs, res);
+            }
+            Stmt::Extern { name, .. } if !funcs.insert(name.clone()) => {
+                res.push(Diagnostic::error("duplicate-function", format!("function `{}` is defined more than once", name)));
+            }
+            Stmt::ForRange { var, body, .. } => {
+                declared.insert(var.clone());
+                collect_decls(body, declared, funcs, res);
+            }
+            Stmt::If { then_body, else_body, .. } => {
+                collect_decls(then_body, declared, funcs, res);
+                if let Some(eb) = else_body {
+                    collect_decls(eb, declared, funcs, res);
+                }
+            }
+            Stmt::While { body, .. } => collect_decls(body, declared, funcs, res),
+            _ => {}
+        }
+    }
+}
+
+fn collect_lets(stmts: &[Stmt], out: &mut Vec<String>) {
+    for stmt in stmts {
+        match stmt {
+            Stmt::Let { name, .. } if !out.contains(name) => out.push(name.clone()),
+            Stmt::Func { body, .. } | Stmt::While { body, .. } | Stmt::ForRange { body, .. } => collect_lets(body, out),
+            Stmt::If { then_body, else_body, .. } => {
+                collect_lets(then_body, out);
+                if let Some(eb) = else_body { collect_lets(eb, out); }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn check_stmts(stmts: &[Stmt], declared: &HashSet<String>, used: &mut HashSet<String>, res: &mut Vec<Diagnostic>) {
+    for stmt in stmts {
+        match stmt {
+            Stmt::Expr(e) => mark_used(e, used),
+            Stmt::Let { value, .. } => mark_used(value, used),
+            Stmt::Assign { name, value } => {
+                if !declared.contains(name) {
+                    res.push(Diagnostic::error("undeclared-variable", format!("assignment to undeclared variable `{}`", name))
+                        .with_suggestion(format!("declare it first with `let {} = ...;`", name)));
+                }
+                mark_used(value, used);
+            }
+            Stmt::ArrayAssign { array, index, value } => {
+                used.insert(array.clone());
+                mark_used(index, used);
+                mark_used(value, used);
+            }
+            Stmt::Func { body, .. } => check_stmts(body, declared, used, res),
+            Stmt::Return(Some(e)) => mark_used(e, used),
+            Stmt::If { cond, then_body, else_body } => {
+                mark_used(cond, used);
+                check_stmts(then_body, declared, used, res);
+                if let Some(eb) = else_body {
+                    check_stmts(eb, declared, used, res);
+                }
+            }
+            Stmt::While { cond, body } => {
+                mark_used(cond, used);
+                check_stmts(body, declared, used, res);
+            }
+            Stmt::ForRange { start, end, body, .. } => {
+                mark_used(start, used);
+                mark_used(end, used);
+                check_stmts(body, declared, used, res);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn mark_used(e: &Expr, used: &mut HashSet<String>) {
+    match e {
+        Expr::Ident(name) => { used.insert(name.clone()); }
+        Expr::BinaryOp { left, right, .. } => {
+            mark_used(left, used);
+            mark_used(right, used);
+        }
+        Expr::Call { args, .. } => args.iter().for_each(|a| mark_used(a, used)),
+        Expr::ArrayAccess { array, index } => {
+            mark_used(array, used);
+            mark_used(index, used);
+        }
+        Expr::ArrayLiteral(items) => items.iter().for_each(|a| mark_used(a, used)),
+        Expr::Int(_) | Expr::Str(_) => {}
+    }
+}
+
+/// `thread_spawn` and `irq_register` name the function to run with a string
+/// literal, which the backend turns into a function pointer, so the name has
+/// to resolve to a top-level function taking at most the one argument passed
+/// along. The interrupt stub also depends on the vector, which has to be
+/// known at compile time.
+struct Callbacks<'a> {
+    arity: HashMap<&'a str, usize>,
+    res: &'a mut Vec<Diagnostic>,
+}
+
+impl Visitor for Callbacks<'_> {
+    fn visit_expr(&mut self, e: &Expr) {
+        if let Expr::Call { name, args } = e {
+            if name == "irq_register" && args.len() == 2 {
+                if !matches!(args[0], Expr::Int(0..=255)) {
+                    self.res.push(Diagnostic::error("bad-irq", "`irq_register` takes the vector as an integer literal from 0 to 255"));
+                }
+                match &args[1] {
+                    Expr::Str(target) => match self.arity.get(target.as_str()) {
+                        None => self.res.push(Diagnostic::error("bad-irq", format!("`irq_register` of undefined function `{}`", target))),
+                        Some(&n) if n > 1 => self.res.push(Diagnostic::error("bad-irq", format!("`{}` takes {} arguments but an interrupt handler gets at most one, the error code", target, n))),
+                        Some(_) => {}
+                    },
+                    _ => self.res.push(Diagnostic::error("bad-irq", "`irq_register` takes the handler's name as a string literal")
+                        .with_suggestion("write `irq_register(33, \"on_keyboard\")`")),
+                }
+            }
+            if name == "thread_spawn" && matches!(args.len(), 1 | 2) {
+                let Expr::Str(target) = &args[0] else {
+                    self.res.push(Diagnostic::error("bad-spawn", "`thread_spawn` takes the function name as a string literal")
+                        .with_suggestion("write `thread_spawn(\"worker\")`"));
+                    return walk_expr(self, e);
+                };
+                match self.arity.get(target.as_str()) {
+                    None => self.res.push(Diagnostic::error("bad-spawn", format!("`thread_spawn` of undefined function `{}`", target))),
+                    Some(&n) if n > 1 => self.res.push(Diagnostic::error("bad-spawn", format!("`{}` takes {} arguments but a thread passes at most one", target, n))),
+                    Some(_) => {}
+                }
+            }
+        }
+        walk_expr(self, e);
+    }
+}
+
+/// `sdl_present` shows the frame drawn into one window, so unlike the
+/// drawing calls it has no form that picks the newest window.
+struct WindowCalls<'a> {
+    res: &'a mut Vec<Diagnostic>,
+}
+
+impl Visitor for WindowCalls<'_> {
+    fn visit_expr(&mut self, e: &Expr) {
+        if let Expr::Call { name, args } = e {
+            if name == "sdl_present" && args.len() != 1 {
+                self.res.push(Diagnostic::error("bad-sdl-call", "`sdl_present` takes the window handle from `sdl_create_window`")
+                    .with_suggestion("write `sdl_present(window)`"));
+            }
+        }
+        walk_expr(self, e);
+    }
+}
+
+/// Element assignments, which `ge`, `gb` and `ll` skip.
+struct Uncompiled<'a> {
+    res: &'a mut Vec<Diagnostic>,
+}
+
+impl Visitor for Uncompiled<'_> {
+    fn visit_stmt(&mut self, s: &Stmt) {
+        if let Stmt::ArrayAssign { array, .. } = s {
+            self.res.push(Diagnostic::warning("unsupported-statement", format!("assignment to an element of `{}` is not compiled yet and does nothing", array))
+                .with_suggestion("run the program with `wheelc run`, which supports it"));
+        }
+        walk_stmt(self, s);
+    }
+}
+
+/// Where `@asm`/`@llvm` blocks are: `@llvm` ones hold whole definitions, so
+/// they belong at the top level. With a `target`, reports the blocks for the
+/// other backend instead.
+struct InlineBlocks<'a> {
+    /// Blocks entered; the top level is 1
+    depth: usize,
+    target: Option<InlineTarget>,
+    res: &'a mut Vec<Diagnostic>,
+}
+
+impl Visitor for InlineBlocks<'_> {
+    fn visit_block(&mut self, stmts: &[Stmt]) {
+        self.depth += 1;
+        for s in stmts {
+            self.visit_stmt(s);
+        }
+        self.depth -= 1;
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        let Stmt::Inline { target, .. } = stmt else {
+            return walk_stmt(self, stmt);
+        };
+        match self.target {
+            None if *target == InlineTarget::Llvm && self.depth > 1 => {
+                self.res.push(Diagnostic::error("misplaced-inline", "`@llvm` blocks hold LLVM IR definitions and are only allowed at the top level")
+                    .with_suggestion("define a function in the `@llvm` block and call it through an `extern func`"));
+            }
+            Some(backend) if backend != *target => {
+                let (block, modes) = match target {
+                    InlineTarget::Asm => ("@asm", "`--mode ge` or `--mode gb`"),
+                    InlineTarget::Llvm => ("@llvm", "`--mode ll`"),
+                };
+                self.res.push(Diagnostic::error("inline-backend", format!("`{}` blocks are only compiled with {}", block, modes))
+                    .with_suggestion("wrap the block in `@if MODE == \"ll\"` (or `\"ge\"`) so each backend sees its own"));
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// `wheelc clean`: remove build outputs of a project.
+//
+// What gets removed follows the `[layout]` table of the config, resolved as
+// for builds (user config, then `wheel.toml`): the build directory
+// (incremental cache and intermediate assembly), the listed artifacts and the
+// companions the backends leave next to them (`.o` from `ll`, the
+// intermediate executable from `gb`). Nothing outside the project root is
+// ever removed.
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{bail, Context, Result};
+use crate::config::LayoutConfig;
+
+/// Paths under `root` that `clean` would remove, in removal order, resolved
+/// through symlinks and `..`. Only existing paths are returned; a layout
+/// entry that resolves to `root` itself or outside it is an error.
+pub fn targets(root: &Path, layout: &LayoutConfig) -> Result<Vec<PathBuf>> {
+    let root = root.canonicalize().with_context(|| format!("failed to resolve {}", root.display()))?;
+    let mut paths = vec![layout.build_dir(&root)];
+    for artifact in layout.artifacts.iter().flatten() {
+        let path = root.join(artifact);
+        paths.push(path.with_extension("o"));
+        if path.extension().is_none() {
+            paths.push(path.with_extension("exe"));
+        }
+        paths.push(path);
+    }
+
+    let mut seen = Vec::new();
+    for p in paths {
+        let Ok(resolved) = p.canonicalize() else { continue };
+        if resolved == root || !resolved.starts_with(&root) {
+            bail!("refusing to remove {}: it is not inside the project root {}", resolved.display(), root.display());
+        }
+        if !seen.contains(&resolved) {
+            seen.push(resolved);
+        }
+    }
+    Ok(seen)
+}
+
+/// Remove everything returned by [`targets`]; returns what was removed.
+pub fn clean(root: &Path, layout: &LayoutConfig, dry_run: bool) -> Result<Vec<PathBuf>> {
+    let paths = targets(root, layout)?;
+    if dry_run {
+        return Ok(paths);
+    }
+    for path in &paths {
+        let res = if path.is_dir() { fs::remove_dir_all(path) } else { fs::remove_file(path) };
+        res.with_context(|| format!("failed to remove {}", path.display()))?;
+    }
+    Ok(paths)
+}
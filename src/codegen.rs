@@ -1,8 +1,21 @@
-use crate::ast::{Program, Stmt, Expr, BinOp};
+use crate::ast::{InlineTarget, Loc, Program, Stmt, Expr, BinOp};
+use crate::assets;
+use crate::sourcemap::{LocIndex, Mapping, SourceMap};
+use crate::visit::{fold_expr, fold_stmt, walk_expr, walk_stmt, Fold, Visitor};
 use std::fmt::Write;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 
 pub fn codegen_to_asm(prog: &Program) -> String {
+    codegen_to_asm_with_map(prog).0
+}
+
+/// Assembly plus the map from its lines back to the statements that produced
+/// them. Statements with a known location are also preceded by DWARF `.loc`
+/// directives, which the assembler turns into line tables for debuggers.
+pub fn codegen_to_asm_with_map(prog: &Program) -> (String, SourceMap) {
+    let folded = ConstFolder.fold_program(prog.clone());
+    let prog = &folded;
     let mut out = String::new();
     writeln!(&mut out, "    .intel_syntax noprefix").unwrap();
     writeln!(&mut out, "    .section .rodata").unwrap();
@@ -11,9 +24,15 @@ pub fn codegen_to_asm(prog: &Program) -> String {
     let mut int_consts: HashMap<String,i64> = HashMap::new();
     let mut str_consts: HashMap<String,String> = HashMap::new();
 
+    // lets that change after their initialization are never constants
+    let mut reassigned = Reassigned::default();
+    reassigned.visit_program(prog);
+    let reassigned = reassigned.changed;
+
     // first pass: collect literal lets
     for item in &prog.items {
         if let Stmt::Let { name, value, .. } = item {
+            if reassigned.contains(name) { continue; }
             match value {
                 Expr::Int(v) => { int_consts.insert(name.clone(), *v); }
                 Expr::Str(s) => { str_consts.insert(name.clone(), s.clone()); if !strs.contains(s) { strs.push(s.clone()); } }
@@ -28,7 +47,7 @@ pub fn codegen_to_asm(prog: &Program) -> String {
         changed = false;
         for item in &prog.items {
             if let Stmt::Let { name, value, .. } = item {
-                if int_consts.contains_key(name) { continue; }
+                if int_consts.contains_key(name) || reassigned.contains(name) { continue; }
                 if let Some(v) = eval_const_expr_with_ctx(value, &int_consts) {
                     int_consts.insert(name.clone(), v);
                     changed = true;
@@ -37,36 +56,44 @@ pub fn codegen_to_asm(prog: &Program) -> String {
         }
     }
 
-    collect_strings_with_ctx(prog, &mut strs, &int_consts);
+    StringCollector { out: &mut strs, ctx: &int_consts }.visit_program(prog);
+    let mut registrations = IrqRegistrations(Vec::new());
+    registrations.visit_program(prog);
+    let registrations = registrations.0;
+    let handlers = irq_handlers(prog, &registrations);
     for (i, s) in strs.iter().enumerate() {
         writeln!(&mut out, "Lmsg{}:", i).unwrap();
-        let esc = s.replace('"', "\\\"");
+        // the bytes of the literal as written, so `mov rdx, <len>` matches
+        let esc = s.replace('\\', "\\\\").replace('"', "\\\"");
         writeln!(&mut out, "    .ascii \"{}\"", esc).unwrap();
     }
+    // embed("file") data, pulled in by the assembler
+    for path in assets::embedded_files(prog) {
+        writeln!(&mut out, "{}:", assets::label(&path)).unwrap();
+        writeln!(&mut out, "    .incbin \"{}\"", path.replace('\\', "\\\\").replace('"', "\\\"")).unwrap();
+    }
 
     writeln!(&mut out, "    .section .bss").unwrap();
     writeln!(&mut out, "input_buffer: .space 256").unwrap();
 
-    // allocate space for let variables
-    let mut let_names: Vec<String> = Vec::new();
-    for item in &prog.items {
-        if let Stmt::Let { name, .. } = item {
-            if !let_names.contains(name) {
-                let_names.push(name.clone());
-            }
-        }
+    // allocate space for let variables, including those in loops and
+    // branches; functions are not generated
+    let mut lets = LetNames(Vec::new());
+    for item in prog.items.iter().filter(|s| !matches!(s, Stmt::Func { .. })) {
+        lets.visit_stmt(item);
+    }
+    let mut let_names = lets.0;
+    for handler in &handlers {
+        let mut locals = LetNames(Vec::new());
+        locals.visit_block(&handler.body);
+        let_names.extend(handler.param.iter().cloned().chain(locals.0).filter(|n| !let_names.contains(n)).collect::<Vec<_>>());
     }
     // detect let variables that are initialized by `input()` -> need string buffers
-    let mut let_string_names: Vec<String> = Vec::new();
-    for item in &prog.items {
-        if let Stmt::Let { name, value, .. } = item {
-            if let Expr::Call { name: fnname, args: _ } = value {
-                if fnname == "input" {
-                    let_string_names.push(name.clone());
-                }
-            }
-        }
+    let mut inputs = InputLets(Vec::new());
+    for item in prog.items.iter().filter(|s| !matches!(s, Stmt::Func { .. })) {
+        inputs.visit_stmt(item);
     }
+    let let_string_names = inputs.0;
     for name in &let_names {
         writeln!(&mut out, "{}: .quad 0", name).unwrap();
     }
@@ -75,71 +102,145 @@ pub fn codegen_to_asm(prog: &Program) -> String {
         writeln!(&mut out, "{}_buf: .space 256", name).unwrap();
         writeln!(&mut out, "{}_len: .quad 0", name).unwrap();
     }
+    if !registrations.is_empty() {
+        writeln!(&mut out, "    .balign 16").unwrap();
+        writeln!(&mut out, "wheel_idt: .space 4096").unwrap();
+        writeln!(&mut out, "wheel_idt_loaded: .quad 0").unwrap();
+    }
 
     writeln!(&mut out, "    .section .text").unwrap();
     writeln!(&mut out, "    .global _start").unwrap();
     writeln!(&mut out, "_start:").unwrap();
 
-    let mut label_counter = 0;
-    let mut code = String::new();
-    codegen_stmts(&prog.items, &mut code, &strs, &int_consts, &str_consts, &let_names, &let_string_names, &mut label_counter);
-    out.push_str(&code);
+    let lines = Lines { locs: LocIndex::new(prog), files: Vec::new(), map: SourceMap::default() };
+    let mut ctx = Codegen { strs, int_consts, str_consts, let_names, let_string_names, label_counter: 0, lines };
+    codegen_stmts(&prog.items, &mut out, &mut ctx);
 
     writeln!(&mut out, "    mov rax, 60").unwrap();
     writeln!(&mut out, "    xor rdi, rdi").unwrap();
     writeln!(&mut out, "    syscall").unwrap();
 
-    out
+    for handler in &handlers {
+        writeln!(&mut out, "{}:", handler.name).unwrap();
+        if let Some(param) = &handler.param {
+            writeln!(&mut out, "    mov qword ptr [rip + {}], rdi", param).unwrap();
+        }
+        codegen_stmts(&handler.body, &mut out, &mut ctx);
+        writeln!(&mut out, "    xor eax, eax").unwrap();
+        writeln!(&mut out, "    ret").unwrap();
+    }
+    for (vector, handler) in &registrations {
+        irq_stub(&mut out, *vector, handler);
+    }
+    if !registrations.is_empty() {
+        out.push_str(IRQ_REGISTER);
+    }
+
+    let mut map = ctx.lines.map;
+    map.mappings.sort_by_key(|m| (m.lines.start, std::cmp::Reverse(m.lines.end)));
+    (out, map)
 }
 
-fn codegen_stmts(items: &[Stmt], out: &mut String, strs: &Vec<String>, int_consts: &HashMap<String,i64>, str_consts: &HashMap<String,String>, let_names: &Vec<String>, let_string_names: &Vec<String>, label_counter: &mut usize) {
+/// State shared by the statements and expressions of `_start`.
+struct Codegen<'p> {
+    /// `.rodata` strings; string `n` is labelled `Lmsg<n>`
+    strs: Vec<String>,
+    /// `let`s whose value is known at compile time
+    int_consts: HashMap<String, i64>,
+    str_consts: HashMap<String, String>,
+    /// Every top-level `let`, each a `.quad` in `.bss`
+    let_names: Vec<String>,
+    /// `let`s set from `input()`, with a buffer and a length
+    let_string_names: Vec<String>,
+    /// Next number for `if`/`while` labels
+    label_counter: usize,
+    lines: Lines<'p>,
+}
+
+/// Source locations of the statements being generated: `.file`/`.loc`
+/// directives in the output and the lines each statement produced.
+struct Lines<'p> {
+    locs: LocIndex<'p>,
+    /// Files named by `.file` so far; file `n` is `files[n - 1]`
+    files: Vec<PathBuf>,
+    map: SourceMap,
+}
+
+impl<'p> Lines<'p> {
+    /// Mark the start of `stmt`'s code; returns what `end` needs.
+    fn begin(&mut self, out: &mut String, stmt: &Stmt) -> Option<(usize, &'p Loc)> {
+        let loc = self.locs.get(stmt)?;
+        if let Some(file) = &loc.file {
+            let n = match self.files.iter().position(|f| f == file) {
+                Some(i) => i + 1,
+                None => {
+                    self.files.push(file.clone());
+                    let name = file.display().to_string().replace('\\', "\\\\").replace('"', "\\\"");
+                    writeln!(out, "    .file {} \"{}\"", self.files.len(), name).unwrap();
+                    self.files.len()
+                }
+            };
+            writeln!(out, "    .loc {} {} {}", n, loc.span.line, loc.span.column).unwrap();
+        }
+        Some((out.matches('\n').count() + 1, loc))
+    }
+
+    fn end(&mut self, out: &str, begun: Option<(usize, &'p Loc)>) {
+        if let Some((start, loc)) = begun {
+            let end = out.matches('\n').count() + 1;
+            if end > start {
+                self.map.mappings.push(Mapping { lines: start..end, loc: loc.clone() });
+            }
+        }
+    }
+}
+
+fn codegen_stmts<'p>(items: &[Stmt], out: &mut String, ctx: &mut Codegen<'p>) {
     for item in items {
+        let begun = ctx.lines.begin(out, item);
         match item {
             Stmt::Import { .. } => {}
+            Stmt::Let { name, value: Expr::Call { name: fnname, .. }, .. } if fnname == "input" => {
+                // special-case `let name = input();` -> read into name_buf and store length
+                // syscall: read(0, name_buf, 255)
+                writeln!(out, "    mov rax, 0").unwrap();
+                writeln!(out, "    mov rdi, 0").unwrap();
+                writeln!(out, "    lea rsi, [rip + {}_buf]", name).unwrap();
+                writeln!(out, "    mov rdx, 255").unwrap();
+                writeln!(out, "    syscall").unwrap();
+                // rax = bytes read; store to name_len
+                writeln!(out, "    mov qword ptr [rip + {}_len], rax", name).unwrap();
+            }
             Stmt::Let { name, value, .. } => {
                 // initialize variable at runtime if needed
-                // special-case `let name = input();` -> read into name_buf and store length
-                if let Expr::Call { name: fnname, args: _ } = value {
-                    if fnname == "input" {
-                        // syscall: read(0, name_buf, 255)
-                        writeln!(out, "    mov rax, 0").unwrap();
-                        writeln!(out, "    mov rdi, 0").unwrap();
-                        writeln!(out, "    lea rsi, [rip + {}_buf]", name).unwrap();
-                        writeln!(out, "    mov rdx, 255").unwrap();
-                        writeln!(out, "    syscall").unwrap();
-                        // rax = bytes read; store to name_len
-                        writeln!(out, "    mov qword ptr [rip + {}_len], rax", name).unwrap();
-                        continue;
-                    }
-                }
-                if let Some(v) = eval_const_expr_with_ctx(value, int_consts) {
+                if let Some(v) = eval_const_expr_with_ctx(value, &ctx.int_consts) {
                     writeln!(out, "    mov rax, {}", v).unwrap();
                     writeln!(out, "    mov qword ptr [rip + {}], rax", name).unwrap();
                 } else {
-                    gen_expr(value, out, strs, int_consts, str_consts);
+                    gen_expr(value, out, ctx);
                     writeln!(out, "    mov qword ptr [rip + {}], rax", name).unwrap();
                 }
             }
             Stmt::Assign { name, value } => {
-                gen_expr(value, out, strs, int_consts, str_consts);
+                gen_expr(value, out, ctx);
                 writeln!(out, "    mov qword ptr [rip + {}], rax", name).unwrap();
             }
             Stmt::If { cond, then_body, else_body } => {
-                let else_label = format!("Lelse_{}", label_counter);
-                let end_label = format!("Lend_{}", label_counter);
-                *label_counter += 1;
+                let else_label = format!("Lelse_{}", ctx.label_counter);
+                let end_label = format!("Lend_{}", ctx.label_counter);
+                ctx.label_counter += 1;
 
                 // Only constant-fold if the condition does not reference runtime
                 // `let` variables. If it references runtime vars we must emit a
                 // dynamic check.
-                if let Some(val) = eval_const_expr_with_ctx(cond, int_consts) {
-                    if !expr_uses_let(cond, let_names) {
+                if let Some(val) = eval_const_expr_with_ctx(cond, &ctx.int_consts) {
+                    if !expr_uses_let(cond, &ctx.let_names) {
                         if val == 0 {
                             if else_body.is_some() { writeln!(out, "    jmp {}", else_label).unwrap(); }
                             else { writeln!(out, "    jmp {}", end_label).unwrap(); }
                         }
                     } else {
-                        gen_expr(cond, out, strs, int_consts, str_consts);
+                        gen_expr(cond, out, ctx);
                         if else_body.is_some() {
                             writeln!(out, "    cmp rax, 0").unwrap();
                             writeln!(out, "    je {}", else_label).unwrap();
@@ -149,7 +250,7 @@ fn codegen_stmts(items: &[Stmt], out: &mut String, strs: &Vec<String>, int_const
                         }
                     }
                 } else {
-                    gen_expr(cond, out, strs, int_consts, str_consts);
+                    gen_expr(cond, out, ctx);
                     if else_body.is_some() {
                         writeln!(out, "    cmp rax, 0").unwrap();
                         writeln!(out, "    je {}", else_label).unwrap();
@@ -159,7 +260,7 @@ fn codegen_stmts(items: &[Stmt], out: &mut String, strs: &Vec<String>, int_const
                     }
                 }
 
-                codegen_stmts(then_body, out, strs, int_consts, str_consts, let_names, let_string_names, label_counter);
+                codegen_stmts(then_body, out, ctx);
 
                 if else_body.is_some() {
                     writeln!(out, "    jmp {}", end_label).unwrap();
@@ -167,35 +268,35 @@ fn codegen_stmts(items: &[Stmt], out: &mut String, strs: &Vec<String>, int_const
 
                 if let Some(eb) = else_body {
                     writeln!(out, "{}:", else_label).unwrap();
-                    codegen_stmts(eb, out, strs, int_consts, str_consts, let_names, let_string_names, label_counter);
+                    codegen_stmts(eb, out, ctx);
                 }
 
                 writeln!(out, "{}:", end_label).unwrap();
             }
             Stmt::While { cond, body } => {
-                let loop_label = format!("Lloop_{}", label_counter);
-                let exit_label = format!("Lexit_{}", label_counter);
-                *label_counter += 1;
+                let loop_label = format!("Lloop_{}", ctx.label_counter);
+                let exit_label = format!("Lexit_{}", ctx.label_counter);
+                ctx.label_counter += 1;
 
                 writeln!(out, "{}:", loop_label).unwrap();
 
                 // Constant-fold only when the condition does not reference runtime
                 // variables allocated in `let_names`.
-                if let Some(val) = eval_const_expr_with_ctx(cond, int_consts) {
-                    if !expr_uses_let(cond, let_names) {
+                if let Some(val) = eval_const_expr_with_ctx(cond, &ctx.int_consts) {
+                    if !expr_uses_let(cond, &ctx.let_names) {
                         if val == 0 { writeln!(out, "    jmp {}", exit_label).unwrap(); }
                     } else {
-                        gen_expr(cond, out, strs, int_consts, str_consts);
+                        gen_expr(cond, out, ctx);
                         writeln!(out, "    cmp rax, 0").unwrap();
                         writeln!(out, "    je {}", exit_label).unwrap();
                     }
                 } else {
-                    gen_expr(cond, out, strs, int_consts, str_consts);
+                    gen_expr(cond, out, ctx);
                     writeln!(out, "    cmp rax, 0").unwrap();
                     writeln!(out, "    je {}", exit_label).unwrap();
                 }
 
-                codegen_stmts(body, out, strs, int_consts, str_consts, let_names, let_string_names, label_counter);
+                codegen_stmts(body, out, ctx);
                 writeln!(out, "    jmp {}", loop_label).unwrap();
                 writeln!(out, "{}:", exit_label).unwrap();
             }
@@ -205,9 +306,9 @@ fn codegen_stmts(items: &[Stmt], out: &mut String, strs: &Vec<String>, int_const
 
                 match &args[0] {
                     Expr::Str(s) => {
-                        if let Some(idx) = strs.iter().position(|x| x==s) {
+                        if let Some(idx) = ctx.strs.iter().position(|x| x==s) {
                             writeln!(out, "    lea rsi, [rip + Lmsg{}]", idx).unwrap();
-                            writeln!(out, "    mov rdx, {}", s.as_bytes().len()).unwrap();
+                            writeln!(out, "    mov rdx, {}", s.len()).unwrap();
                         } else {
                             writeln!(out, "    mov rsi, 0").unwrap();
                             writeln!(out, "    mov rdx, 0").unwrap();
@@ -216,33 +317,33 @@ fn codegen_stmts(items: &[Stmt], out: &mut String, strs: &Vec<String>, int_const
                     Expr::Ident(id) => {
                         // If this identifier is a runtime variable (declared with let),
                         // it may be an integer or a string buffer (from input()).
-                        if let_string_names.contains(id) {
+                        if ctx.let_string_names.contains(id) {
                             // dynamic string: load pointer and length and write
                             writeln!(out, "    mov rax, 1").unwrap();
                             writeln!(out, "    mov rdi, 1").unwrap();
                             writeln!(out, "    lea rsi, [rip + {}_buf]", id).unwrap();
                             writeln!(out, "    mov rdx, qword ptr [rip + {}_len]", id).unwrap();
-                        } else if let_names.contains(id) {
-                            // integer variable: evaluate and print simple single-digit
-                            gen_expr(&Expr::Ident(id.clone()), out, strs, int_consts, str_consts);
-                            writeln!(out, "    lea rsi, [rip + input_buffer]").unwrap();
-                            writeln!(out, "    mov rbx, rax").unwrap();
-                            writeln!(out, "    add rbx, '0'").unwrap();
-                            writeln!(out, "    mov byte ptr [rsi], bl").unwrap();
-                            writeln!(out, "    mov rdx, 1").unwrap();
-                        } else if let Some(sv) = str_consts.get(id) {
-                            if let Some(idx) = strs.iter().position(|x| x==sv) {
+                        } else if let Some(idx) = ctx.str_consts.get(id).and_then(|sv| ctx.strs.iter().position(|x| x == sv)) {
+                            // a `let` of a string literal
+                            writeln!(out, "    lea rsi, [rip + Lmsg{}]", idx).unwrap();
+                            writeln!(out, "    mov rdx, {}", ctx.strs[idx].len()).unwrap();
+                        } else if ctx.let_names.contains(id) {
+                            // integer variable
+                            gen_expr(&Expr::Ident(id.clone()), out, ctx);
+                            gen_itoa(out, ctx);
+                        } else if let Some(sv) = ctx.str_consts.get(id) {
+                            if let Some(idx) = ctx.strs.iter().position(|x| x==sv) {
                                 writeln!(out, "    lea rsi, [rip + Lmsg{}]", idx).unwrap();
-                                writeln!(out, "    mov rdx, {}", sv.as_bytes().len()).unwrap();
+                                writeln!(out, "    mov rdx, {}", sv.len()).unwrap();
                             } else {
                                 writeln!(out, "    mov rsi, 0").unwrap();
                                 writeln!(out, "    mov rdx, 0").unwrap();
                             }
-                        } else if let Some(iv) = int_consts.get(id) {
+                        } else if let Some(iv) = ctx.int_consts.get(id) {
                             let s = iv.to_string();
-                            if let Some(idx) = strs.iter().position(|x| x==&s) {
+                            if let Some(idx) = ctx.strs.iter().position(|x| x==&s) {
                                 writeln!(out, "    lea rsi, [rip + Lmsg{}]", idx).unwrap();
-                                writeln!(out, "    mov rdx, {}", s.as_bytes().len()).unwrap();
+                                writeln!(out, "    mov rdx, {}", s.len()).unwrap();
                             } else {
                                 writeln!(out, "    mov rsi, 0").unwrap();
                                 writeln!(out, "    mov rdx, 0").unwrap();
@@ -254,33 +355,34 @@ fn codegen_stmts(items: &[Stmt], out: &mut String, strs: &Vec<String>, int_const
                     }
                     Expr::Int(v) => {
                         let s = v.to_string();
-                        if let Some(idx) = strs.iter().position(|x| x==&s) {
+                        if let Some(idx) = ctx.strs.iter().position(|x| x==&s) {
                             writeln!(out, "    lea rsi, [rip + Lmsg{}]", idx).unwrap();
-                            writeln!(out, "    mov rdx, {}", s.as_bytes().len()).unwrap();
+                            writeln!(out, "    mov rdx, {}", s.len()).unwrap();
                         } else {
                             writeln!(out, "    mov rsi, 0").unwrap();
                             writeln!(out, "    mov rdx, 0").unwrap();
                         }
                     }
+                    Expr::Call { name, args: embed_args } if name == "embed" => {
+                        if let [Expr::Str(path)] = embed_args.as_slice() {
+                            writeln!(out, "    lea rsi, [rip + {}]", assets::label(path)).unwrap();
+                            writeln!(out, "    mov rdx, {}", assets::embedded_len(path)).unwrap();
+                        }
+                    }
                     Expr::BinaryOp{..} => {
-                        if let Some(val) = eval_const_expr_with_ctx(&args[0], int_consts) {
+                        if let Some(val) = eval_const_expr_with_ctx(&args[0], &ctx.int_consts) {
                             let s = val.to_string();
-                            if let Some(idx) = strs.iter().position(|x| x==&s) {
+                            if let Some(idx) = ctx.strs.iter().position(|x| x==&s) {
                                 writeln!(out, "    lea rsi, [rip + Lmsg{}]", idx).unwrap();
-                                writeln!(out, "    mov rdx, {}", s.as_bytes().len()).unwrap();
+                                writeln!(out, "    mov rdx, {}", s.len()).unwrap();
                             } else {
                                 writeln!(out, "    mov rsi, 0").unwrap();
                                 writeln!(out, "    mov rdx, 0").unwrap();
                             }
                         } else {
-                            // dynamic expression: evaluate and print simple single-digit
-                            // positive integers by converting to a single ASCII digit.
-                            gen_expr(&args[0], out, strs, int_consts, str_consts);
-                            writeln!(out, "    lea rsi, [rip + input_buffer]").unwrap();
-                            writeln!(out, "    mov rbx, rax").unwrap();
-                            writeln!(out, "    add rbx, '0'").unwrap();
-                            writeln!(out, "    mov byte ptr [rsi], bl").unwrap();
-                            writeln!(out, "    mov rdx, 1").unwrap();
+                            // dynamic expression: evaluate and print in decimal
+                            gen_expr(&args[0], out, ctx);
+                            gen_itoa(out, ctx);
                         }
                     }
                     _ => { writeln!(out, "    mov rsi, 0").unwrap(); writeln!(out, "    mov rdx, 0").unwrap(); }
@@ -290,19 +392,68 @@ fn codegen_stmts(items: &[Stmt], out: &mut String, strs: &Vec<String>, int_const
                 writeln!(out, "    mov rdi, 1").unwrap();
                 writeln!(out, "    syscall").unwrap();
             }
+            Stmt::Expr(call @ Expr::Call { name, .. }) if matches!(name.as_str(), "irq_register" | "mmio_read" | "mmio_write") => gen_expr(call, out, ctx),
+            // only interrupt handlers are generated as functions
+            Stmt::Return(value) => {
+                match value {
+                    Some(v) => gen_expr(v, out, ctx),
+                    None => writeln!(out, "    xor eax, eax").unwrap(),
+                }
+                writeln!(out, "    ret").unwrap();
+            }
+            Stmt::Inline { target: InlineTarget::Asm, code } => {
+                // copied line by line; variables are reachable as `[rip + name]`
+                for line in code.lines() {
+                    writeln!(out, "{}", line).unwrap();
+                }
+            }
             _ => {}
         }
+        ctx.lines.end(out, begun);
     }
 }
 
-fn gen_expr(e: &Expr, out: &mut String, strs: &Vec<String>, int_consts: &HashMap<String,i64>, str_consts: &HashMap<String,String>) {
+/// Write `rax` in decimal into `input_buffer` for a `write` syscall: the
+/// digits start at `rsi` and `rdx` holds their count.
+fn gen_itoa(out: &mut String, ctx: &mut Codegen) {
+    let n = ctx.label_counter;
+    ctx.label_counter += 1;
+    writeln!(out, "    lea rsi, [rip + input_buffer + 32]").unwrap();
+    writeln!(out, "    mov rcx, 10").unwrap();
+    writeln!(out, "    mov r8, rax").unwrap();
+    writeln!(out, "    test rax, rax").unwrap();
+    writeln!(out, "    jns Litoa_{}", n).unwrap();
+    writeln!(out, "    neg rax").unwrap();
+    writeln!(out, "Litoa_{}:", n).unwrap();
+    writeln!(out, "    xor edx, edx").unwrap();
+    writeln!(out, "    div rcx").unwrap();
+    writeln!(out, "    add dl, '0'").unwrap();
+    writeln!(out, "    dec rsi").unwrap();
+    writeln!(out, "    mov byte ptr [rsi], dl").unwrap();
+    writeln!(out, "    test rax, rax").unwrap();
+    writeln!(out, "    jnz Litoa_{}", n).unwrap();
+    writeln!(out, "    test r8, r8").unwrap();
+    writeln!(out, "    jns Litoa_end_{}", n).unwrap();
+    writeln!(out, "    dec rsi").unwrap();
+    writeln!(out, "    mov byte ptr [rsi], '-'").unwrap();
+    writeln!(out, "Litoa_end_{}:", n).unwrap();
+    writeln!(out, "    lea rdx, [rip + input_buffer + 32]").unwrap();
+    writeln!(out, "    sub rdx, rsi").unwrap();
+}
+
+fn gen_expr(e: &Expr, out: &mut String, ctx: &Codegen) {
     match e {
         Expr::Int(v) => {
             writeln!(out, "    mov rax, {}", v).unwrap();
         }
-        Expr::Ident(name) => {
+        Expr::Ident(name) if ctx.let_names.contains(name) => {
             writeln!(out, "    mov rax, qword ptr [rip + {}]", name).unwrap();
         }
+        // `KEY_*` and the other builtin constants; anything else is 0, as
+        // with `--mode ll`
+        Expr::Ident(name) => {
+            writeln!(out, "    mov rax, {}", crate::builtins::constant(name).unwrap_or(0)).unwrap();
+        }
         Expr::ArrayAccess { .. } => {
             writeln!(out, "    mov rax, 0").unwrap(); // placeholder for array access
         }
@@ -310,9 +461,9 @@ fn gen_expr(e: &Expr, out: &mut String, strs: &Vec<String>, int_consts: &HashMap
             writeln!(out, "    mov rax, 0").unwrap(); // placeholder for array literal
         }
         Expr::BinaryOp { op, left, right } => {
-            gen_expr(left, out, strs, int_consts, str_consts);
+            gen_expr(left, out, ctx);
             writeln!(out, "    push rax").unwrap();
-            gen_expr(right, out, strs, int_consts, str_consts);
+            gen_expr(right, out, ctx);
             writeln!(out, "    mov rbx, rax").unwrap();
             writeln!(out, "    pop rax").unwrap();
             match op {
@@ -356,7 +507,7 @@ fn gen_expr(e: &Expr, out: &mut String, strs: &Vec<String>, int_consts: &HashMap
             }
         }
         Expr::Str(s) => {
-            if let Some(idx) = strs.iter().position(|x| x==s) {
+            if let Some(idx) = ctx.strs.iter().position(|x| x==s) {
                 writeln!(out, "    lea rax, [rip + Lmsg{}]", idx).unwrap();
             } else {
                 writeln!(out, "    mov rax, 0").unwrap();
@@ -380,33 +531,298 @@ fn gen_expr(e: &Expr, out: &mut String, strs: &Vec<String>, int_consts: &HashMap
                 // Note: rbx holds length, rax holds pointer
                 return;
             }
-            // Other calls: no runtime support yet, return 0
+            if let (true, [Expr::Str(path)]) = (assets::EMBED_BUILTINS.contains(&name.as_str()), args.as_slice()) {
+                if name == "embed" {
+                    writeln!(out, "    lea rax, [rip + {}]", assets::label(path)).unwrap();
+                } else {
+                    writeln!(out, "    mov rax, {}", assets::embedded_len(path)).unwrap();
+                }
+                return;
+            }
+            if let ("irq_register", [Expr::Int(vector), Expr::Str(handler)]) = (name.as_str(), args.as_slice()) {
+                writeln!(out, "    mov rdi, {}", vector).unwrap();
+                writeln!(out, "    lea rsi, [rip + {}]", irq_stub_label(*vector, handler)).unwrap();
+                writeln!(out, "    call wheel_irq_register").unwrap();
+                return;
+            }
+            // Memory-mapped device registers: 32-bit accesses, like the
+            // volatile i32 loads and stores of `--mode ll`
+            match (name.as_str(), args.as_slice()) {
+                ("mmio_read", [addr]) => {
+                    gen_expr(addr, out, ctx);
+                    writeln!(out, "    mov eax, dword ptr [rax]").unwrap();
+                    return;
+                }
+                ("mmio_write", [addr, value]) => {
+                    gen_expr(addr, out, ctx);
+                    writeln!(out, "    push rax").unwrap();
+                    gen_expr(value, out, ctx);
+                    writeln!(out, "    mov rbx, rax").unwrap();
+                    writeln!(out, "    pop rax").unwrap();
+                    writeln!(out, "    mov dword ptr [rax], ebx").unwrap();
+                    writeln!(out, "    xor eax, eax").unwrap();
+                    return;
+                }
+                _ => {}
+            }
+            // Calls to Wheel functions are not generated yet and return 0;
+            // builtins outside `ASM_BUILTINS` and externs were rejected by
+            // `check_asm_calls`
             for _a in args { }
             writeln!(out, "    mov rax, 0").unwrap();
         }
     }
 }
 
-fn eval_const_expr(e: &Expr) -> Option<i64> {
-    match e {
-        Expr::Int(v) => Some(*v),
-        Expr::BinaryOp { op, left, right } => {
-            let l = eval_const_expr(left)?;
-            let r = eval_const_expr(right)?;
-            match op {
-                BinOp::Add => Some(l + r),
-                BinOp::Sub => Some(l - r),
-                BinOp::Mul => Some(l * r),
-                BinOp::Div => if r!=0 { Some(l / r) } else { None },
-                BinOp::Lt => Some(if l < r { 1 } else { 0 }),
-                BinOp::Gt => Some(if l > r { 1 } else { 0 }),
-                BinOp::LtEq => Some(if l <= r { 1 } else { 0 }),
-                BinOp::GtEq => Some(if l >= r { 1 } else { 0 }),
-                BinOp::EqEq => Some(if l == r { 1 } else { 0 }),
-                BinOp::NotEq => Some(if l != r { 1 } else { 0 }),
+/// The builtins `gen_expr` generates code for; the others of
+/// [`lint::BUILTINS`](crate::lint::BUILTINS) need an object that only
+/// `--mode ll` links. `irq_register` and the `mmio_*` builtins are for
+/// `--mode gb` kernels.
+pub const ASM_BUILTINS: &[&str] = &["print", "input", "embed", "embed_len", "irq_register", "mmio_read", "mmio_write"];
+
+/// Vectors the CPU pushes an error code for.
+pub(crate) const IRQ_ERROR_CODE_VECTORS: &[i64] = &[8, 10, 11, 12, 13, 14, 17, 21, 29, 30];
+
+/// `(vector, handler)` of every `irq_register` call, each once.
+struct IrqRegistrations(Vec<(i64, String)>);
+
+impl Visitor for IrqRegistrations {
+    fn visit_expr(&mut self, e: &Expr) {
+        if let Expr::Call { name, args } = e {
+            if let ("irq_register", [Expr::Int(vector), Expr::Str(handler)]) = (name.as_str(), args.as_slice()) {
+                if !self.0.contains(&(*vector, handler.clone())) {
+                    self.0.push((*vector, handler.clone()));
+                }
             }
         }
-        _ => None
+        walk_expr(self, e);
+    }
+}
+
+/// An interrupt handler, the one kind of function the assembly backend
+/// generates. Its parameter and `let`s are `.bss` quads like the top
+/// level's, prefixed with `<handler>.` so the two cannot clash.
+struct IrqHandler {
+    name: String,
+    param: Option<String>,
+    body: Vec<Stmt>,
+}
+
+/// The functions `registrations` point at, with their locals renamed.
+fn irq_handlers(prog: &Program, registrations: &[(i64, String)]) -> Vec<IrqHandler> {
+    prog.items.iter()
+        .filter_map(|item| match item {
+            Stmt::Func { name, params, body, .. } => {
+                registrations.iter().any(|(_, h)| h == name).then(|| {
+                    let mut lets = LetNames(Vec::new());
+                    lets.visit_block(body);
+                    let mut scoped = Scoped { prefix: name, names: lets.0.into_iter().chain(params.iter().cloned()).collect() };
+                    IrqHandler {
+                        name: name.clone(),
+                        param: params.first().map(|p| scoped.name(p.clone())),
+                        body: scoped.fold_block(body.clone()),
+                    }
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Names bound by the `let`s of a body, in order.
+struct LetNames(Vec<String>);
+
+impl Visitor for LetNames {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Let { name, .. } if !self.0.contains(name) => self.0.push(name.clone()),
+            _ => {}
+        }
+        walk_stmt(self, stmt);
+    }
+}
+
+/// Names assigned to with `=` or bound by more than one `let`.
+#[derive(Default)]
+struct Reassigned {
+    bound: HashSet<String>,
+    changed: HashSet<String>,
+}
+
+impl Visitor for Reassigned {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Let { name, .. } if !self.bound.insert(name.clone()) => { self.changed.insert(name.clone()); }
+            Stmt::Assign { name, .. } => { self.changed.insert(name.clone()); }
+            _ => {}
+        }
+        walk_stmt(self, stmt);
+    }
+}
+
+/// Names bound by `let name = input();`, which get a buffer and a length.
+struct InputLets(Vec<String>);
+
+impl Visitor for InputLets {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Let { name, value: Expr::Call { name: fnname, .. }, .. } if fnname == "input" && !self.0.contains(name) => self.0.push(name.clone()),
+            _ => {}
+        }
+        walk_stmt(self, stmt);
+    }
+}
+
+/// Renames the locals of a handler to `<prefix>.<name>`.
+struct Scoped<'a> {
+    prefix: &'a str,
+    names: HashSet<String>,
+}
+
+impl Scoped<'_> {
+    fn name(&self, name: String) -> String {
+        if self.names.contains(&name) { format!("{}.{}", self.prefix, name) } else { name }
+    }
+}
+
+impl Fold for Scoped<'_> {
+    fn fold_stmt(&mut self, stmt: Stmt) -> Stmt {
+        match fold_stmt(self, stmt) {
+            Stmt::Let { name, ty, value } => Stmt::Let { name: self.name(name), ty, value },
+            Stmt::Assign { name, value } => Stmt::Assign { name: self.name(name), value },
+            s => s,
+        }
+    }
+
+    fn fold_expr(&mut self, expr: Expr) -> Expr {
+        match fold_expr(self, expr) {
+            Expr::Ident(name) => Expr::Ident(self.name(name)),
+            e => e,
+        }
+    }
+}
+
+fn irq_stub_label(vector: i64, handler: &str) -> String {
+    format!("wheel_irq_{}_{}", vector, handler)
+}
+
+/// The stub `irq_register` points the gate at: it saves the registers the
+/// handler may change, passes it the error code (0 for vectors without
+/// one), keeps the stack 16-byte aligned across the call and returns with
+/// `iretq`.
+fn irq_stub(out: &mut String, vector: i64, handler: &str) {
+    const SAVED: [&str; 10] = ["rax", "rcx", "rdx", "rbx", "rsi", "rdi", "r8", "r9", "r10", "r11"];
+    let has_error = IRQ_ERROR_CODE_VECTORS.contains(&vector);
+    writeln!(out, "{}:", irq_stub_label(vector, handler)).unwrap();
+    for reg in SAVED {
+        writeln!(out, "    push {}", reg).unwrap();
+    }
+    if has_error {
+        // above the saved registers; the CPU pushed it 16-byte aligned
+        writeln!(out, "    mov rdi, qword ptr [rsp + {}]", SAVED.len() * 8).unwrap();
+    } else {
+        writeln!(out, "    xor edi, edi").unwrap();
+        writeln!(out, "    sub rsp, 8").unwrap();
+    }
+    writeln!(out, "    cld").unwrap();
+    writeln!(out, "    call {}", handler).unwrap();
+    if !has_error {
+        writeln!(out, "    add rsp, 8").unwrap();
+    }
+    for reg in SAVED.iter().rev() {
+        writeln!(out, "    pop {}", reg).unwrap();
+    }
+    if has_error {
+        writeln!(out, "    add rsp, 8").unwrap();
+    }
+    writeln!(out, "    iretq").unwrap();
+}
+
+/// `wheel_irq_register(vector, stub)`, as in hwio.c: the first call copies
+/// the gates of the IDT in use into `wheel_idt` and loads that with `lidt`,
+/// so earlier handlers keep working; every call points `vector` at `stub`
+/// with a present 64-bit interrupt gate. Returns 1.
+const IRQ_REGISTER: &str = "\
+wheel_irq_register:
+    cmp qword ptr [rip + wheel_idt_loaded], 0
+    jne Lirq_gate
+    sub rsp, 16
+    sidt [rsp]
+    movzx rcx, word ptr [rsp]
+    inc rcx
+    mov rax, qword ptr [rsp + 2]
+    add rsp, 16
+    test rax, rax
+    jz Lirq_gate
+    cmp rcx, 4096
+    ja Lirq_gate
+    push rsi
+    push rdi
+    mov rsi, rax
+    lea rdi, [rip + wheel_idt]
+    rep movsb
+    pop rdi
+    pop rsi
+Lirq_gate:
+    lea rdx, [rip + wheel_idt]
+    shl rdi, 4
+    add rdx, rdi
+    mov rax, rsi
+    mov word ptr [rdx], ax
+    mov cx, cs
+    mov word ptr [rdx + 2], cx
+    mov word ptr [rdx + 4], 0x8E00
+    shr rax, 16
+    mov word ptr [rdx + 6], ax
+    shr rax, 16
+    mov dword ptr [rdx + 8], eax
+    mov dword ptr [rdx + 12], 0
+    cmp qword ptr [rip + wheel_idt_loaded], 0
+    jne Lirq_done
+    sub rsp, 16
+    mov word ptr [rsp], 4095
+    lea rax, [rip + wheel_idt]
+    mov qword ptr [rsp + 2], rax
+    lidt [rsp]
+    add rsp, 16
+    mov qword ptr [rip + wheel_idt_loaded], 1
+Lirq_done:
+    mov eax, 1
+    ret
+";
+
+/// Folds integer arithmetic and comparisons between literals, e.g. `2 * 3` to
+/// `6`, so later passes see plain constants.
+pub struct ConstFolder;
+
+impl Fold for ConstFolder {
+    fn fold_expr(&mut self, expr: Expr) -> Expr {
+        match fold_expr(self, expr) {
+            Expr::BinaryOp { op, left, right } => match (&*left, &*right) {
+                (Expr::Int(l), Expr::Int(r)) => match const_binop(op, *l, *r) {
+                    Some(v) => Expr::Int(v),
+                    None => Expr::BinaryOp { op, left, right },
+                },
+                _ => Expr::BinaryOp { op, left, right },
+            },
+            e => e,
+        }
+    }
+}
+
+/// `l op r`, or `None` if it would overflow or divide by zero.
+fn const_binop(op: BinOp, l: i64, r: i64) -> Option<i64> {
+    match op {
+        BinOp::Add => l.checked_add(r),
+        BinOp::Sub => l.checked_sub(r),
+        BinOp::Mul => l.checked_mul(r),
+        BinOp::Div => l.checked_div(r),
+        BinOp::Lt => Some((l < r) as i64),
+        BinOp::Gt => Some((l > r) as i64),
+        BinOp::LtEq => Some((l <= r) as i64),
+        BinOp::GtEq => Some((l >= r) as i64),
+        BinOp::EqEq => Some((l == r) as i64),
+        BinOp::NotEq => Some((l != r) as i64),
     }
 }
 
@@ -417,74 +833,44 @@ fn eval_const_expr_with_ctx(e: &Expr, ctx: &HashMap<String,i64>) -> Option<i64>
         Expr::BinaryOp { op, left, right } => {
             let l = eval_const_expr_with_ctx(left, ctx)?;
             let r = eval_const_expr_with_ctx(right, ctx)?;
-            match op {
-                BinOp::Add => Some(l + r),
-                BinOp::Sub => Some(l - r),
-                BinOp::Mul => Some(l * r),
-                BinOp::Div => if r!=0 { Some(l / r) } else { None },
-                BinOp::Lt => Some(if l < r { 1 } else { 0 }),
-                BinOp::Gt => Some(if l > r { 1 } else { 0 }),
-                BinOp::LtEq => Some(if l <= r { 1 } else { 0 }),
-                BinOp::GtEq => Some(if l >= r { 1 } else { 0 }),
-                BinOp::EqEq => Some(if l == r { 1 } else { 0 }),
-                BinOp::NotEq => Some(if l != r { 1 } else { 0 }),
-            }
+            const_binop(*op, l, r)
         }
         _ => None
     }
 }
 
-fn collect_strings_with_ctx(prog: &Program, out: &mut Vec<String>, ctx: &HashMap<String,i64>) {
-    fn collect_stmts(stmts: &[Stmt], out: &mut Vec<String>, ctx: &HashMap<String,i64>) {
-        for item in stmts {
-            match item {
-                Stmt::Let { name: _, value, .. } => {
-                    if let Expr::Str(s) = value {
-                        if !out.contains(s) {
-                            out.push(s.clone());
-                        }
-                    }
-                }
-                Stmt::Expr(Expr::Call { name, args }) => {
-                    if name == "print" && args.len()==1 {
-                        match &args[0] {
-                            Expr::Str(s) => {
-                                if !out.contains(s) { out.push(s.clone()); }
-                            }
-                            Expr::Int(v) => {
-                                let s = v.to_string();
-                                if !out.contains(&s) { out.push(s); }
-                            }
-                            Expr::BinaryOp{..} => {
-                                if let Some(v) = eval_const_expr_with_ctx(&args[0], ctx) {
-                                    let s = v.to_string();
-                                    if !out.contains(&s) { out.push(s); }
-                                }
-                            }
-                            Expr::Ident(id) => {
-                                if let Some(iv) = ctx.get(id) {
-                                    let s = iv.to_string();
-                                    if !out.contains(&s) { out.push(s); }
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-                Stmt::If { cond: _, then_body, else_body } => {
-                    collect_stmts(then_body, out, ctx);
-                    if let Some(eb) = else_body {
-                        collect_stmts(eb, out, ctx);
+/// Strings `.rodata` needs: string `let`s and the text of every `print` whose
+/// argument is known at compile time.
+struct StringCollector<'a> {
+    out: &'a mut Vec<String>,
+    ctx: &'a HashMap<String,i64>,
+}
+
+impl StringCollector<'_> {
+    fn add(&mut self, s: String) {
+        if !self.out.contains(&s) {
+            self.out.push(s);
+        }
+    }
+}
+
+impl Visitor for StringCollector<'_> {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Let { value: Expr::Str(s), .. } => self.add(s.clone()),
+            Stmt::Expr(Expr::Call { name, args }) if name == "print" && args.len() == 1 => match &args[0] {
+                Expr::Str(s) => self.add(s.clone()),
+                e @ (Expr::Int(_) | Expr::BinaryOp { .. } | Expr::Ident(_)) => {
+                    if let Some(v) = eval_const_expr_with_ctx(e, self.ctx) {
+                        self.add(v.to_string());
                     }
                 }
-                Stmt::While { cond: _, body } => {
-                    collect_stmts(body, out, ctx);
-                }
                 _ => {}
-            }
+            },
+            _ => {}
         }
+        walk_stmt(self, stmt);
     }
-    collect_stmts(&prog.items, out, ctx);
 }
 
 fn expr_uses_let(e: &Expr, let_names: &Vec<String>) -> bool {
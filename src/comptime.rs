@@ -0,0 +1,186 @@
+// Compile-time function evaluation.
+//
+// A function declared `comptime func` is still compiled like any other, but
+// a call to it whose arguments are all constants is run by the interpreter
+// while compiling and replaced by its result: an int, a str, or an array of
+// those. Lookup tables and checksums (a multiboot header's, say) can then be
+// written as ordinary Wheel instead of precomputed by hand. Calls with a
+// runtime argument stay calls. Literals count as constants, and outside
+// function bodies so does a top-level `let` of one that comes before the
+// call and is never assigned or bound again, so `let N = 8;` makes `sum(N)`
+// fold.
+//
+// The interpreter knows every function of the program but none of its
+// globals, so a comptime function sees only its parameters. What it prints is
+// discarded, and it fails the build if it errors, calls something the
+// interpreter cannot run, returns a map or does not finish within
+// [`STEP_LIMIT`]. Handles (`vec_new`, `map_new`, ...) name interpreter
+// state that is gone once the call is folded, so the builtins that create
+// them fail the build too.
+use std::collections::{HashMap, HashSet};
+use crate::ast::{Expr, Program, Stmt};
+use crate::diagnostic::Diagnostic;
+use crate::interp::{Interpreter, Value};
+use crate::visit::{fold_expr, fold_stmt, walk_stmt, Fold, Visitor};
+
+/// Blocks (loop iterations, calls) one comptime call may run.
+pub const STEP_LIMIT: u64 = 1_000_000;
+
+/// Replace every comptime call on constants in `prog` by its result.
+/// Returns the calls that could not be evaluated.
+pub fn fold(prog: Program) -> (Program, Vec<Diagnostic>) {
+    let names: HashSet<String> = prog.items.iter()
+        .filter_map(|s| match s {
+            Stmt::Func { name, comptime: true, .. } => Some(name.clone()),
+            _ => None,
+        })
+        .collect();
+    if names.is_empty() {
+        return (prog, Vec::new());
+    }
+    let functions: Vec<Stmt> = prog.items.iter()
+        .filter(|s| matches!(s, Stmt::Func { .. } | Stmt::Extern { .. }))
+        .cloned()
+        .collect();
+    let mut bindings = Bindings::default();
+    bindings.visit_program(&prog);
+    let mut folder = Comptime { names, functions, rebound: bindings.rebound, consts: HashMap::new(), diagnostics: Vec::new() };
+    let prog = folder.fold_program(prog);
+    (prog, folder.diagnostics)
+}
+
+struct Comptime {
+    /// Functions declared `comptime`
+    names: HashSet<String>,
+    /// Every definition, for the interpreter
+    functions: Vec<Stmt>,
+    /// Names that cannot be constants (see [`Bindings`])
+    rebound: HashSet<String>,
+    /// Top-level `let`s seen so far whose value is a constant, with it
+    consts: HashMap<String, Expr>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Comptime {
+    fn eval(&self, call: &Expr) -> Result<Value, String> {
+        // a fresh interpreter per call, so one cannot leave state for the next
+        let mut interp = Interpreter::new(std::io::sink()).with_step_limit(STEP_LIMIT).without_handles();
+        interp.eval(&self.functions).map_err(|e| e.to_string())?;
+        match interp.eval(&[Stmt::Expr(call.clone())]) {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Err("it produced no value".to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+impl Fold for Comptime {
+    fn fold_program(&mut self, prog: Program) -> Program {
+        let mut items = Vec::with_capacity(prog.items.len());
+        for stmt in prog.items {
+            let stmt = self.fold_stmt(stmt);
+            if let Stmt::Let { name, value, .. } = &stmt {
+                if !self.rebound.contains(name) && is_const(value, &self.consts) {
+                    self.consts.insert(name.clone(), resolve(value.clone(), &self.consts));
+                }
+            }
+            items.push(stmt);
+        }
+        Program { items, locs: prog.locs }
+    }
+
+    fn fold_stmt(&mut self, stmt: Stmt) -> Stmt {
+        if !matches!(stmt, Stmt::Func { .. }) {
+            return fold_stmt(self, stmt);
+        }
+        // a function may be called before the `let`s above it have run
+        let consts = std::mem::take(&mut self.consts);
+        let stmt = fold_stmt(self, stmt);
+        self.consts = consts;
+        stmt
+    }
+
+    fn fold_expr(&mut self, expr: Expr) -> Expr {
+        match fold_expr(self, expr) {
+            Expr::Call { name, args } if self.names.contains(&name) && args.iter().all(|a| is_const(a, &self.consts)) => {
+                let resolved = Expr::Call { name: name.clone(), args: args.iter().map(|a| resolve(a.clone(), &self.consts)).collect() };
+                let call = Expr::Call { name: name.clone(), args };
+                match self.eval(&resolved).and_then(|v| to_expr(&v)) {
+                    Ok(e) => e,
+                    Err(msg) => {
+                        self.diagnostics.push(Diagnostic::error(
+                            "comptime-error",
+                            format!("evaluating `{}` at compile time failed: {}", name, msg),
+                        ));
+                        call
+                    }
+                }
+            }
+            e => e,
+        }
+    }
+}
+
+/// Whether `e` has the same value in every run of the program, given the
+/// constant bindings `consts`.
+fn is_const(e: &Expr, consts: &HashMap<String, Expr>) -> bool {
+    match e {
+        Expr::Int(_) | Expr::Str(_) => true,
+        Expr::Ident(name) => consts.contains_key(name),
+        Expr::BinaryOp { left, right, .. } => is_const(left, consts) && is_const(right, consts),
+        Expr::ArrayLiteral(items) => items.iter().all(|i| is_const(i, consts)),
+        Expr::ArrayAccess { array, index } => is_const(array, consts) && is_const(index, consts),
+        Expr::Call { .. } => false,
+    }
+}
+
+/// `e` with the names of `consts` replaced by their values.
+fn resolve(e: Expr, consts: &HashMap<String, Expr>) -> Expr {
+    match e {
+        Expr::Ident(name) => consts.get(&name).cloned().unwrap_or(Expr::Ident(name)),
+        Expr::BinaryOp { op, left, right } => Expr::BinaryOp { op, left: Box::new(resolve(*left, consts)), right: Box::new(resolve(*right, consts)) },
+        Expr::ArrayLiteral(items) => Expr::ArrayLiteral(items.into_iter().map(|i| resolve(i, consts)).collect()),
+        Expr::ArrayAccess { array, index } => Expr::ArrayAccess { array: Box::new(resolve(*array, consts)), index: Box::new(resolve(*index, consts)) },
+        e => e,
+    }
+}
+
+/// Names bound by more than one `let`, used as a `for` variable or assigned
+/// anywhere in the program; the value of a top-level `let` of one depends on
+/// where it is read.
+#[derive(Default)]
+struct Bindings {
+    seen: HashSet<String>,
+    rebound: HashSet<String>,
+}
+
+impl Bindings {
+    fn bind(&mut self, name: &str) {
+        if !self.seen.insert(name.to_string()) {
+            self.rebound.insert(name.to_string());
+        }
+    }
+}
+
+impl Visitor for Bindings {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Let { name, .. } => self.bind(name),
+            Stmt::ForRange { var: name, .. } | Stmt::Assign { name, .. } | Stmt::ArrayAssign { array: name, .. } => {
+                self.rebound.insert(name.clone());
+            }
+            _ => {}
+        }
+        walk_stmt(self, stmt);
+    }
+}
+
+/// The literal for `value`.
+fn to_expr(value: &Value) -> Result<Expr, String> {
+    Ok(match value {
+        Value::Int(n) => Expr::Int(*n),
+        Value::Str(s) => Expr::Str(s.clone()),
+        Value::Array(items) => Expr::ArrayLiteral(items.iter().map(to_expr).collect::<Result<_, _>>()?),
+        Value::Map(_) => return Err("it returned a map, which has no literal".to_string()),
+    })
+}
@@ -0,0 +1,185 @@
+// User and project configuration.
+//
+// Defaults are read from the user file (`$XDG_CONFIG_HOME/wheel/config.toml`,
+// falling back to `~/.config/wheel/config.toml`) and then from the nearest
+// `wheel.toml` found walking up from the input file. Project values override
+// user values; command-line flags override both. Finding and reading the
+// files needs the `native` feature; the types are always available.
+use std::collections::BTreeMap;
+#[cfg(feature = "native")]
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::Deserialize;
+use crate::error::{Result, WheelError};
+
+pub const PROJECT_FILE: &str = "wheel.toml";
+/// Where build outputs go when `[layout] build_dir` is not set, relative to the project root.
+pub const DEFAULT_BUILD_DIR: &str = "target/wheel";
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Language edition, e.g. `language = "0.2"`
+    pub language: Option<String>,
+    pub build: BuildConfig,
+    pub warnings: WarningsConfig,
+    pub layout: LayoutConfig,
+    /// Compile-time defines for `@if`, e.g. `BARE_METAL = true`
+    pub defines: BTreeMap<String, toml::Value>,
+    /// Named files for `embed("name")`, relative to the project root
+    pub assets: BTreeMap<String, String>,
+    /// Per-rule levels for `wheelc lint`, e.g. `magic-number = "warn"`
+    pub lint: BTreeMap<String, crate::lint::Level>,
+    /// Extra functions for the LLVM backend, e.g.
+    /// `clamp = { params = ["int", "int", "int"], returns = "int", link = "native/clamp.o" }`
+    pub builtins: BTreeMap<String, crate::builtins::BuiltinConfig>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct BuildConfig {
+    /// Default backend/mode: `ge`, `gb` or `ll`
+    pub mode: Option<String>,
+    /// LLVM optimization level, 0-3
+    pub opt_level: Option<u8>,
+    pub target: Option<String>,
+    pub linker: Option<String>,
+    /// Libraries to link, e.g. `libs = ["m", "curl"]` for `-lm -lcurl`
+    pub libs: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct WarningsConfig {
+    /// Treat warnings as errors
+    pub deny: Option<bool>,
+    /// Warning codes to silence, e.g. `unused-variable`
+    pub allow: Option<Vec<String>>,
+}
+
+/// Project layout; only meaningful in `wheel.toml`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default)]
+pub struct LayoutConfig {
+    /// Directory for the incremental cache and intermediates, relative to the project root
+    pub build_dir: Option<String>,
+    /// Outputs produced by the project (e.g. `bin/game`), removed by `wheelc clean`
+    pub artifacts: Option<Vec<String>>,
+}
+
+impl LayoutConfig {
+    pub fn build_dir(&self, root: &Path) -> PathBuf {
+        root.join(self.build_dir.as_deref().unwrap_or(DEFAULT_BUILD_DIR))
+    }
+}
+
+impl Config {
+    /// Load user config then the project `wheel.toml` for `input`.
+    /// Returns the merged config and the files that were read.
+    #[cfg(feature = "native")]
+    pub fn load(input: &Path) -> Result<(Config, Vec<PathBuf>)> {
+        Config::load_dir(input.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new(".")))
+    }
+
+    /// Load user config then the nearest `wheel.toml` in `dir` or any
+    /// parent, for commands that take a directory rather than an input file.
+    #[cfg(feature = "native")]
+    pub fn load_dir(dir: &Path) -> Result<(Config, Vec<PathBuf>)> {
+        let mut config = Config::default();
+        let mut sources = Vec::new();
+
+        if let Some(path) = user_config_path() {
+            if path.exists() {
+                config = config.merge(Config::from_file(&path)?);
+                sources.push(path);
+            }
+        }
+        if let Some(path) = find_manifest_from(dir) {
+            config = config.merge(Config::from_file(&path)?);
+            sources.push(path);
+        }
+        Ok((config, sources))
+    }
+
+    #[cfg(feature = "native")]
+    pub fn from_file(path: &Path) -> Result<Config> {
+        let text = fs::read_to_string(path).map_err(|e| WheelError::io(path, e))?;
+        toml::from_str(&text)
+            .map_err(|e| WheelError::Config { path: Some(path.to_path_buf()), message: e.to_string() })
+    }
+
+    /// The `[defines]` table as `@if` values: booleans become 1/0.
+    pub fn define_values(&self) -> Result<crate::defines::Defines> {
+        let mut defines = crate::defines::Defines::new();
+        for (name, value) in &self.defines {
+            let value = match value {
+                toml::Value::Boolean(b) => (*b as i64).to_string(),
+                toml::Value::Integer(n) => n.to_string(),
+                toml::Value::String(s) => s.clone(),
+                other => return Err(WheelError::config(format!("define `{}` must be a boolean, integer or string, found {}", name, other.type_str()))),
+            };
+            defines.insert(name.clone(), value);
+        }
+        Ok(defines)
+    }
+
+    /// Overlay `other` on top of `self`; values set in `other` win.
+    pub fn merge(self, other: Config) -> Config {
+        Config {
+            language: other.language.or(self.language),
+            build: BuildConfig {
+                mode: other.build.mode.or(self.build.mode),
+                opt_level: other.build.opt_level.or(self.build.opt_level),
+                target: other.build.target.or(self.build.target),
+                linker: other.build.linker.or(self.build.linker),
+                libs: other.build.libs.or(self.build.libs),
+            },
+            warnings: WarningsConfig {
+                deny: other.warnings.deny.or(self.warnings.deny),
+                allow: other.warnings.allow.or(self.warnings.allow),
+            },
+            layout: LayoutConfig {
+                build_dir: other.layout.build_dir.or(self.layout.build_dir),
+                artifacts: other.layout.artifacts.or(self.layout.artifacts),
+            },
+            defines: self.defines.into_iter().chain(other.defines).collect(),
+            assets: self.assets.into_iter().chain(other.assets).collect(),
+            lint: self.lint.into_iter().chain(other.lint).collect(),
+            builtins: self.builtins.into_iter().chain(other.builtins).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "native")]
+pub fn user_config_path() -> Option<PathBuf> {
+    let base = match std::env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => dirs::home_dir()?.join(".config"),
+    };
+    Some(base.join("wheel").join("config.toml"))
+}
+
+/// Nearest `wheel.toml` in the input file's directory or any parent.
+#[cfg(feature = "native")]
+pub fn find_project_file(input: &Path) -> Option<PathBuf> {
+    let start = input.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    find_manifest_from(start)
+}
+
+/// Nearest `wheel.toml` in `dir` or any parent.
+#[cfg(feature = "native")]
+pub fn find_manifest_from(dir: &Path) -> Option<PathBuf> {
+    let start = dir.canonicalize().ok()?;
+    start.ancestors()
+        .map(|dir| dir.join(PROJECT_FILE))
+        .find(|p| p.is_file())
+}
+
+/// Directory holding the project's `wheel.toml`, or the current directory when
+/// the input is not part of a project. Build outputs such as the cache live here.
+#[cfg(feature = "native")]
+pub fn project_root(input: &Path) -> PathBuf {
+    find_project_file(input)
+        .and_then(|p| p.parent().map(Path::to_path_buf))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
@@ -0,0 +1,83 @@
+// Lossless token stream for tools that rewrite source text.
+//
+// The parser only needs tokens; a formatter or a refactoring has to put the
+// comments and blank lines back where they were. `TokenStream::lex` keeps
+// every byte of the input: each token carries the trivia (whitespace,
+// comments, the shebang line, stray characters) in front of it, and the final
+// `EOF` token carries whatever trails the last real token. Printing the stream
+// gives back the exact source, and `Parser::from_stream` parses it without
+// lexing a second time.
+use std::fmt;
+use crate::diagnostic::{Diagnostic, Span};
+use crate::lexer::{Lexer, Token, Trivia, TriviaKind};
+
+/// A token with the trivia in front of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxToken {
+    pub token: Token,
+    pub span: Span,
+    pub leading: Vec<Trivia>,
+}
+
+pub struct TokenStream<'a> {
+    src: &'a str,
+    tokens: Vec<SyntaxToken>,
+    errors: Vec<Diagnostic>,
+}
+
+impl<'a> TokenStream<'a> {
+    pub fn lex(src: &'a str) -> Self {
+        let mut lx = Lexer::new(src);
+        let mut tokens = Vec::new();
+        loop {
+            let token = lx.next_token();
+            let done = token == Token::EOF;
+            tokens.push(SyntaxToken { token, span: lx.span(), leading: lx.trivia().to_vec() });
+            if done {
+                break;
+            }
+        }
+        TokenStream { src, tokens, errors: lx.diagnostics().to_vec() }
+    }
+
+    pub fn source(&self) -> &'a str {
+        self.src
+    }
+
+    /// All tokens, ending with `EOF`.
+    pub fn tokens(&self) -> &[SyntaxToken] {
+        &self.tokens
+    }
+
+    /// Source text of `span`, e.g. of a token or a comment.
+    pub fn text(&self, span: Span) -> &'a str {
+        &self.src[span.start..span.end]
+    }
+
+    /// Problems found while lexing, such as unterminated literals.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.errors
+    }
+
+    /// Comments in source order.
+    pub fn comments(&self) -> impl Iterator<Item = &Trivia> {
+        self.tokens.iter().flat_map(|t| &t.leading).filter(|t| t.kind == TriviaKind::Comment)
+    }
+
+    /// The tokens the parser sees, trivia dropped.
+    pub fn to_tokens(&self) -> Vec<(Token, Span)> {
+        self.tokens.iter().map(|t| (t.token.clone(), t.span)).collect()
+    }
+}
+
+impl fmt::Display for TokenStream<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for t in &self.tokens {
+            for trivia in &t.leading {
+                f.write_str(self.text(trivia.span))?;
+            }
+            f.write_str(self.text(t.span))?;
+        }
+        Ok(())
+    }
+}
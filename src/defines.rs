@@ -0,0 +1,163 @@
+// Compile-time defines and `@if` resolution.
+//
+// Defines come from the built-ins below, the `[defines]` table of the config
+// files and `-D NAME[=value]` on the command line (later sources win). `@if`
+// blocks are replaced by the chosen branch right after parsing, before imports
+// are followed, so a conditional block may pull in different modules:
+//
+//     @if BARE_METAL { import "hwio_console"; } else { import "libc_console"; }
+use std::collections::BTreeMap;
+use crate::ast::{BinOp, Expr, Program, Stmt};
+use crate::sourcemap::{self, LocCursor};
+
+pub type Defines = BTreeMap<String, String>;
+
+/// `-D NAME` or `-D NAME=value`; a bare name is defined as `1`.
+pub fn parse_define(s: &str) -> Result<(String, String), String> {
+    let (name, value) = s.split_once('=').unwrap_or((s, "1"));
+    let valid = name.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(format!("invalid define name `{}`", name));
+    }
+    Ok((name.to_string(), value.to_string()))
+}
+
+/// Defines every build gets: `MODE` (`ge`/`gb`/`ll`, `interp` for `wheelc run`) and `TARGET_OS` (`linux`/`windows`/`macos`).
+pub fn builtin(mode: &str, target_os: &str) -> Defines {
+    let mut d = Defines::new();
+    d.insert("MODE".to_string(), mode.to_string());
+    d.insert("TARGET_OS".to_string(), target_os.to_string());
+    d
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Int(i64),
+    Str(String),
+}
+
+impl Value {
+    fn from_define(s: &str) -> Value {
+        s.parse().map(Value::Int).unwrap_or_else(|_| Value::Str(s.to_string()))
+    }
+
+    fn truthy(&self) -> bool {
+        match self {
+            Value::Int(n) => *n != 0,
+            Value::Str(s) => !s.is_empty(),
+        }
+    }
+
+    fn as_str(&self) -> String {
+        match self {
+            Value::Int(n) => n.to_string(),
+            Value::Str(s) => s.clone(),
+        }
+    }
+}
+
+/// Evaluate an `@if` condition. Undefined names are `0`; `defined(NAME)` tests
+/// presence regardless of value. Comparisons are numeric when both sides are
+/// integers and textual otherwise.
+fn eval(e: &Expr, defines: &Defines) -> Result<Value, String> {
+    match e {
+        Expr::Int(n) => Ok(Value::Int(*n)),
+        Expr::Str(s) => Ok(Value::Str(s.clone())),
+        Expr::Ident(name) => Ok(defines.get(name).map(|v| Value::from_define(v)).unwrap_or(Value::Int(0))),
+        Expr::Call { name, args } if name == "defined" => match args.as_slice() {
+            [Expr::Ident(n)] => Ok(Value::Int(defines.contains_key(n) as i64)),
+            _ => Err("`defined` takes a single name".to_string()),
+        },
+        Expr::BinaryOp { op, left, right } => {
+            let (l, r) = (eval(left, defines)?, eval(right, defines)?);
+            if let (Value::Int(a), Value::Int(b)) = (&l, &r) {
+                let (a, b) = (*a, *b);
+                return Ok(Value::Int(match op {
+                    BinOp::Add => a.wrapping_add(b),
+                    BinOp::Sub => a.wrapping_sub(b),
+                    BinOp::Mul => a.wrapping_mul(b),
+                    BinOp::Div if b == 0 => return Err("division by zero in `@if` condition".to_string()),
+                    BinOp::Div => a / b,
+                    BinOp::Lt => (a < b) as i64,
+                    BinOp::Gt => (a > b) as i64,
+                    BinOp::LtEq => (a <= b) as i64,
+                    BinOp::GtEq => (a >= b) as i64,
+                    BinOp::EqEq => (a == b) as i64,
+                    BinOp::NotEq => (a != b) as i64,
+                }));
+            }
+            let (a, b) = (l.as_str(), r.as_str());
+            match op {
+                BinOp::EqEq => Ok(Value::Int((a == b) as i64)),
+                BinOp::NotEq => Ok(Value::Int((a != b) as i64)),
+                BinOp::Lt => Ok(Value::Int((a < b) as i64)),
+                BinOp::Gt => Ok(Value::Int((a > b) as i64)),
+                BinOp::LtEq => Ok(Value::Int((a <= b) as i64)),
+                BinOp::GtEq => Ok(Value::Int((a >= b) as i64)),
+                _ => Err(format!("arithmetic on non-integer values `{}` and `{}` in `@if` condition", a, b)),
+            }
+        }
+        _ => Err("unsupported expression in `@if` condition (use names, literals, comparisons or `defined(NAME)`)".to_string()),
+    }
+}
+
+/// Replace every `@if` in `prog` with the statements of the selected branch.
+pub fn resolve(prog: &mut Program, defines: &Defines) -> Result<(), String> {
+    let mut locs = LocCursor::take(prog);
+    resolve_stmts(&mut prog.items, defines, &mut locs)?;
+    prog.locs = locs.out;
+    Ok(())
+}
+
+fn resolve_stmts(stmts: &mut Vec<Stmt>, defines: &Defines, locs: &mut LocCursor) -> Result<(), String> {
+    let mut out = Vec::with_capacity(stmts.len());
+    for stmt in stmts.drain(..) {
+        match stmt {
+            Stmt::CfgIf { cond, mut then_body, else_body } => {
+                locs.skip(1);
+                let mut else_body = else_body.unwrap_or_default();
+                let chosen = if eval(&cond, defines)?.truthy() {
+                    resolve_stmts(&mut then_body, defines, locs)?;
+                    locs.skip(sourcemap::count(&else_body));
+                    then_body
+                } else {
+                    locs.skip(sourcemap::count(&then_body));
+                    resolve_stmts(&mut else_body, defines, locs)?;
+                    else_body
+                };
+                out.extend(chosen);
+            }
+            Stmt::Func { name, params, mut body, export, comptime } => {
+                locs.keep(1);
+                resolve_stmts(&mut body, defines, locs)?;
+                out.push(Stmt::Func { name, params, body, export, comptime });
+            }
+            Stmt::If { cond, mut then_body, else_body } => {
+                locs.keep(1);
+                resolve_stmts(&mut then_body, defines, locs)?;
+                let else_body = match else_body {
+                    Some(mut eb) => { resolve_stmts(&mut eb, defines, locs)?; Some(eb) }
+                    None => None,
+                };
+                out.push(Stmt::If { cond, then_body, else_body });
+            }
+            Stmt::While { cond, mut body } => {
+                locs.keep(1);
+                resolve_stmts(&mut body, defines, locs)?;
+                out.push(Stmt::While { cond, body });
+            }
+            Stmt::ForRange { var, start, end, mut body } => {
+                locs.keep(1);
+                resolve_stmts(&mut body, defines, locs)?;
+                out.push(Stmt::ForRange { var, start, end, body });
+            }
+            other => {
+                locs.keep(1);
+                out.push(other);
+            }
+        }
+    }
+    *stmts = out;
+    Ok(())
+}
@@ -0,0 +1,109 @@
+// Compiler diagnostics as data.
+//
+// The lexer, parser and checker report problems as `Diagnostic` values and
+// never print anything; colouring and layout are left to the caller (the CLI
+// renders them in `term`). `Display` gives the plain one-line form,
+// `file:line:col: message [code]`, used in build reports and error chains.
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// A range of source text: byte offsets plus the 1-based line and column of
+/// its start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    /// Span of `src[start..end]`.
+    pub fn new(src: &str, start: usize, end: usize) -> Self {
+        let before = &src[..start];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        Span { start, end, line, column }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Stable name used to allow/deny or look up the problem, e.g. `unused-variable`
+    pub code: &'static str,
+    /// File the span refers to; `None` until the caller knows it (or for
+    /// whole-program checks that have no single location)
+    pub file: Option<PathBuf>,
+    pub span: Option<Span>,
+    pub message: String,
+    /// Ways to fix the problem, each a complete sentence fragment
+    pub suggestions: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn error(code: &'static str, message: impl Into<String>) -> Self {
+        Diagnostic { severity: Severity::Error, code, file: None, span: None, message: message.into(), suggestions: Vec::new() }
+    }
+
+    pub fn warning(code: &'static str, message: impl Into<String>) -> Self {
+        Diagnostic { severity: Severity::Warning, ..Diagnostic::error(code, message) }
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestions.push(suggestion.into());
+        self
+    }
+
+    /// Attribute the diagnostic to `file` unless it already names one.
+    pub fn in_file(mut self, file: &Path) -> Self {
+        if self.file.is_none() {
+            self.file = Some(file.to_path_buf());
+        }
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// `file:line:col`, as much of it as is known.
+    pub fn location(&self) -> Option<String> {
+        match (&self.file, &self.span) {
+            (Some(f), Some(s)) => Some(format!("{}:{}:{}", f.display(), s.line, s.column)),
+            (Some(f), None) => Some(f.display().to_string()),
+            (None, Some(s)) => Some(format!("line {}:{}", s.line, s.column)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(loc) = self.location() {
+            write!(f, "{}: ", loc)?;
+        }
+        write!(f, "{} [{}]", self.message, self.code)
+    }
+}
@@ -0,0 +1,78 @@
+// Language editions.
+//
+// Syntax added after the first release is tied to the edition that introduced
+// it, so programs written for an older edition keep their meaning as the
+// language grows. The edition is chosen with `language = "0.2"` in the config
+// files or `--language 0.2`; without either, sources are read as 0.1.
+use std::fmt;
+use crate::diagnostic::Diagnostic;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Edition {
+    /// The original language: `let`, `func`, `if`, `while`, `for`, `import`, ...
+    #[default]
+    V0_1,
+    /// Adds `@if` conditional compilation, `extern func`/`pub extern func`,
+    /// `comptime func` and `@asm`/`@llvm` blocks
+    V0_2,
+}
+
+pub const EDITIONS: &[&str] = &["0.1", "0.2"];
+
+impl Edition {
+    pub fn parse(s: &str) -> Result<Edition, String> {
+        match s {
+            "0.1" => Ok(Edition::V0_1),
+            "0.2" => Ok(Edition::V0_2),
+            _ => Err(format!("unknown language edition `{}` (known: {})", s, EDITIONS.join(", "))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Edition::V0_1 => "0.1",
+            Edition::V0_2 => "0.2",
+        }
+    }
+}
+
+impl fmt::Display for Edition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Syntax that only exists from a given edition on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    CfgIf,
+    Extern,
+    Comptime,
+    Inline,
+}
+
+impl Feature {
+    pub fn since(&self) -> Edition {
+        match self {
+            Feature::CfgIf | Feature::Extern | Feature::Comptime | Feature::Inline => Edition::V0_2,
+        }
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            Feature::CfgIf => "`@if` conditional compilation",
+            Feature::Extern => "`extern func`",
+            Feature::Comptime => "`comptime func`",
+            Feature::Inline => "inline `@asm`/`@llvm` code",
+        }
+    }
+
+    /// Diagnostic for using this feature under `edition`, if it is not available there.
+    pub fn check(&self, edition: Edition) -> Option<Diagnostic> {
+        let since = self.since();
+        (edition < since).then(|| {
+            Diagnostic::error("requires-edition", format!("{} requires language edition {} (current: {})", self.describe(), since, edition))
+                .with_suggestion(format!("set `language = \"{}\"` in wheel.toml or pass `--language {}`", since, since))
+        })
+    }
+}
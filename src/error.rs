@@ -0,0 +1,88 @@
+// Errors returned by the library.
+//
+// Embedders match on `WheelError` instead of parsing messages: front-end
+// failures carry the diagnostics behind them, I/O failures the file and the
+// `io::Error`. `wheelc` wraps them in `anyhow` like its own errors and picks
+// the exit code from `WheelError::kind`.
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use crate::diagnostic::Diagnostic;
+use crate::report::FailureKind;
+
+pub type Result<T, E = WheelError> = std::result::Result<T, E>;
+
+#[derive(Debug, Error)]
+pub enum WheelError {
+    /// Reading or writing `path` failed
+    #[error("{}: {source}", path.display())]
+    Io { path: PathBuf, #[source] source: std::io::Error },
+    /// Malformed tokens, such as an unterminated string
+    #[error("{}", lines(diagnostics))]
+    Lex { diagnostics: Vec<Diagnostic> },
+    /// Syntax errors
+    #[error("{}", lines(diagnostics))]
+    Parse { diagnostics: Vec<Diagnostic> },
+    /// Semantic errors: undeclared names, calls that do not match a
+    /// signature, bad `@if` conditions, missing embeds; the warnings found
+    /// alongside are included
+    #[error("{}", lines(diagnostics))]
+    Type { diagnostics: Vec<Diagnostic> },
+    /// An invalid setting in `wheel.toml`, a define, builtin or lint level;
+    /// `path` is the config file when there is one
+    #[error("{}{message}", path.as_ref().map(|p| format!("{}: ", p.display())).unwrap_or_default())]
+    Config { path: Option<PathBuf>, message: String },
+    /// A backend could not produce code, e.g. because it is not enabled or
+    /// LLVM rejected the target
+    #[error("{message}")]
+    Codegen { message: String },
+    /// The assembler, linker or archiver failed or could not be started
+    #[error("{message}")]
+    Link { message: String },
+    /// A program run by the interpreter failed
+    #[error("{message}")]
+    Runtime { message: String },
+}
+
+fn lines(diagnostics: &[Diagnostic]) -> String {
+    diagnostics.iter().map(|d| d.to_string()).collect::<Vec<_>>().join("\n")
+}
+
+impl WheelError {
+    pub fn io(path: impl AsRef<Path>, source: std::io::Error) -> Self {
+        WheelError::Io { path: path.as_ref().to_path_buf(), source }
+    }
+
+    pub fn config(message: impl Into<String>) -> Self {
+        WheelError::Config { path: None, message: message.into() }
+    }
+
+    pub fn codegen(message: impl Into<String>) -> Self {
+        WheelError::Codegen { message: message.into() }
+    }
+
+    pub fn link(message: impl Into<String>) -> Self {
+        WheelError::Link { message: message.into() }
+    }
+
+    pub fn runtime(message: impl Into<String>) -> Self {
+        WheelError::Runtime { message: message.into() }
+    }
+
+    /// Which build stage failed, and so which exit code `wheelc` uses.
+    pub fn kind(&self) -> FailureKind {
+        match self {
+            WheelError::Lex { .. } | WheelError::Parse { .. } => FailureKind::Parse,
+            WheelError::Type { .. } => FailureKind::Semantic,
+            WheelError::Link { .. } => FailureKind::Link,
+            WheelError::Io { .. } | WheelError::Config { .. } | WheelError::Codegen { .. } | WheelError::Runtime { .. } => FailureKind::Other,
+        }
+    }
+
+    /// The structured problems behind a front-end failure; empty otherwise.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        match self {
+            WheelError::Lex { diagnostics } | WheelError::Parse { diagnostics } | WheelError::Type { diagnostics } => diagnostics,
+            _ => &[],
+        }
+    }
+}
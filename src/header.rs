@@ -0,0 +1,48 @@
+// C headers for Wheel static libraries.
+//
+// `pub extern func` definitions are exported under their own name with the C
+// calling convention. `--emit header` turns them into prototypes so C (or
+// Rust, through bindgen or a hand-written `extern "C"` block) can call a
+// library built with `--mode ll -o libNAME.a`.
+use crate::ast::{Program, Stmt, Type};
+
+/// Entry point of a static library: runs the top-level statements.
+pub const INIT_FN: &str = "wheel_init";
+
+/// C spelling of a Wheel type at the C boundary.
+pub fn c_type(ty: Option<&Type>) -> &'static str {
+    match ty {
+        None => "void",
+        Some(Type::Str) => "const char *",
+        Some(_) => "int64_t",
+    }
+}
+
+/// `int64_t x` / `const char *s`.
+fn declare(ty: Option<&Type>, name: &str) -> String {
+    let ty = c_type(ty);
+    if ty.ends_with('*') { format!("{}{}", ty, name) } else { format!("{} {}", ty, name) }
+}
+
+/// Header declaring every `pub extern func` of `prog` (in source order),
+/// guarded by a macro derived from `name`, e.g. `game` -> `GAME_H`.
+pub fn c_header(prog: &Program, name: &str) -> String {
+    let guard: String = name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect::<String>() + "_H";
+    let mut out = format!("/* {}: generated by `wheelc --emit header`; do not edit. */\n", name);
+    out += &format!("#ifndef {}\n#define {}\n\n#include <stdint.h>\n\n", guard, guard);
+    out += "#ifdef __cplusplus\nextern \"C\" {\n#endif\n\n";
+    out += "/* Runs the library's top-level statements; returns 0. */\n";
+    out += &format!("int {}(void);\n\n", INIT_FN);
+    for stmt in &prog.items {
+        if let Stmt::Func { name, params, export: Some(sig), .. } = stmt {
+            let args: Vec<String> = params.iter().zip(&sig.params).map(|(p, ty)| declare(Some(ty), p)).collect();
+            let args = if args.is_empty() { "void".to_string() } else { args.join(", ") };
+            out += &format!("{}({});\n", declare(sig.ret.as_ref(), name), args);
+        }
+    }
+    out += "\n#ifdef __cplusplus\n}\n#endif\n\n";
+    out += &format!("#endif /* {} */\n", guard);
+    out
+}
@@ -1,31 +1,192 @@
 use std::collections::HashSet;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use crate::ast::{Program, Stmt};
+use crate::defines::Defines;
+use crate::edition::Edition;
+use crate::error::{Result, WheelError};
+use crate::sourcemap::{self, LocCursor};
 use std::fs;
 
-pub fn process_imports(prog: &mut Program, base_dir: &Path, processed: &mut HashSet<String>) -> anyhow::Result<()> {
+/// Modules bundled with the compiler, imported as `std/<name>`: headers
+/// declaring the runtime builtins with their types, plus constants and
+/// helpers written in Wheel.
+const STD_MODULES: &[(&str, &str)] = &[
+    ("arena", include_str!("std/arena.wheel")),
+    ("map", include_str!("std/map.wheel")),
+    ("math", include_str!("std/math.wheel")),
+    ("process", include_str!("std/process.wheel")),
+    ("regex", include_str!("std/regex.wheel")),
+    ("strings", include_str!("std/strings.wheel")),
+    ("vec", include_str!("std/vec.wheel")),
+];
+
+/// Edition the bundled modules are written in, whatever the importer's.
+const STD_EDITION: Edition = Edition::V0_2;
+
+/// Directory bundled modules appear under in the import graph and in
+/// diagnostics; `std/math` is `<std>/math.wheel`. Nothing exists there.
+pub const STD_ROOT: &str = "<std>";
+
+/// Whether `path` names a bundled module rather than a file.
+pub fn is_bundled(path: &Path) -> bool {
+    path.starts_with(STD_ROOT)
+}
+
+/// The `WHEEL_PATH` search list: directories, separated like `PATH`.
+pub fn wheel_path() -> Vec<PathBuf> {
+    std::env::var_os("WHEEL_PATH").map(|v| std::env::split_paths(&v).collect()).unwrap_or_default()
+}
+
+/// Import graph discovered while inlining imports. Node 0 is the root input.
+#[derive(Debug, Default)]
+pub struct ImportGraph {
+    pub nodes: Vec<PathBuf>,
+    /// `(importer, imported)` node indices
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl ImportGraph {
+    pub fn new(root: &Path) -> Self {
+        ImportGraph { nodes: vec![root.to_path_buf()], edges: Vec::new() }
+    }
+
+    fn node(&mut self, path: &Path) -> usize {
+        match self.nodes.iter().position(|n| n == path) {
+            Some(i) => i,
+            None => {
+                self.nodes.push(path.to_path_buf());
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn add_edge(&mut self, from: &Path, to: &Path) {
+        let edge = (self.node(from), self.node(to));
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    /// Imported modules, in the order they were loaded (the root is not included).
+    pub fn modules(&self) -> &[PathBuf] {
+        &self.nodes[1..]
+    }
+
+    /// The imported modules that are files, leaving out the bundled `std/` ones.
+    pub fn files(&self) -> impl Iterator<Item = &PathBuf> {
+        self.modules().iter().filter(|p| !is_bundled(p))
+    }
+
+    fn reaches(&self, from: usize, to: usize) -> bool {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut stack = vec![from];
+        while let Some(n) = stack.pop() {
+            if n == to {
+                return true;
+            }
+            if !std::mem::replace(&mut seen[n], true) {
+                stack.extend(self.edges.iter().filter(|e| e.0 == n).map(|e| e.1));
+            }
+        }
+        false
+    }
+
+    /// Edges that are part of an import cycle (`b` can reach back to `a`).
+    pub fn cycle_edges(&self) -> Vec<(usize, usize)> {
+        self.edges.iter().copied().filter(|&(a, b)| self.reaches(b, a)).collect()
+    }
+
+    /// Graphviz DOT rendering; modules and edges on a cycle are drawn in red.
+    /// Paths are shown relative to the root input's directory.
+    pub fn to_dot(&self) -> String {
+        let base = self.nodes[0].parent().unwrap_or(Path::new(""));
+        let label = |p: &Path| p.strip_prefix(base).unwrap_or(p).display().to_string().replace('"', "\\\"");
+        let cyclic = self.cycle_edges();
+
+        let mut out = String::from("digraph imports {\n    rankdir=LR;\n    node [shape=box];\n");
+        for (i, node) in self.nodes.iter().enumerate() {
+            let mut attrs = format!("label=\"{}\"", label(node));
+            if i == 0 {
+                attrs.push_str(", style=bold");
+            }
+            if cyclic.iter().any(|&(a, _)| a == i) {
+                attrs.push_str(", color=red, fontcolor=red");
+            }
+            out.push_str(&format!("    n{} [{}];\n", i, attrs));
+        }
+        for &(a, b) in &self.edges {
+            if cyclic.contains(&(a, b)) {
+                out.push_str(&format!("    n{} -> n{} [color=red, penwidth=2];\n", a, b));
+            } else {
+                out.push_str(&format!("    n{} -> n{};\n", a, b));
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Settings every imported file is parsed and resolved with.
+pub struct ImportOptions<'a> {
+    pub edition: Edition,
+    pub defines: &'a Defines,
+    /// Searched, in order, for imports not found next to the importing file
+    pub search_path: &'a [PathBuf],
+}
+
+/// Where `import "<path>"` in a file in `base_dir` comes from: the first of
+/// `base_dir`, the search path and, for `std/<name>`, the bundled module that
+/// has it. Bundled modules come with their source.
+fn resolve(path: &str, base_dir: &Path, search_path: &[PathBuf]) -> Option<(PathBuf, Option<&'static str>)> {
+    let file = if path.ends_with(".wheel") { path.to_string() } else { format!("{}.wheel", path) };
+    let found = std::iter::once(base_dir).chain(search_path.iter().map(PathBuf::as_path))
+        .map(|dir| dir.join(&file))
+        .find(|p| p.exists());
+    if let Some(found) = found {
+        return Some((found, None));
+    }
+    let name = path.strip_prefix("std/")?;
+    let name = name.strip_suffix(".wheel").unwrap_or(name);
+    let (name, src) = STD_MODULES.iter().find(|m| m.0 == name)?;
+    Some((Path::new(STD_ROOT).join(format!("{}.wheel", name)), Some(*src)))
+}
+
+/// Inline imported files into `prog`, which was parsed from `file`. Every
+/// import, including ones skipped because they were already inlined, is
+/// recorded in `graph`. `@if` blocks of imported files are resolved against
+/// the defines in `opts` before their own imports are followed.
+pub fn process_imports(prog: &mut Program, file: &Path, opts: &ImportOptions, processed: &mut HashSet<String>, graph: &mut ImportGraph) -> Result<()> {
+    let base_dir = file.parent().unwrap_or_else(|| Path::new("."));
     let mut imported_stmts = Vec::new();
     let mut remaining_stmts = Vec::new();
+    // locations follow their statements; a module without them drops all
+    let mut complete = prog.locs.len() == sourcemap::count(&prog.items);
+    let mut locs = LocCursor::take(prog);
+    let mut imported_locs = Vec::new();
 
     for stmt in prog.items.drain(..) {
         if let Stmt::Import { path } = &stmt {
+            locs.skip(1);
+            let Some((import_path, bundled)) = resolve(path, base_dir, opts.search_path) else {
+                continue;
+            };
+            graph.add_edge(file, &import_path);
+
             if !processed.contains(path) {
                 processed.insert(path.clone());
-                let import_path = if path.ends_with(".wheel") {
-                    base_dir.join(path)
-                } else {
-                    base_dir.join(format!("{}.wheel", path))
+                let (import_src, edition) = match bundled {
+                    Some(src) => (src.to_string(), STD_EDITION),
+                    None => (fs::read_to_string(&import_path).map_err(|e| WheelError::io(&import_path, e))?, opts.edition),
                 };
-
-                if import_path.exists() {
-                    let import_src = fs::read_to_string(&import_path)?;
-                    let mut import_parser = crate::parser::Parser::new(&import_src);
-                    let mut imported_prog = import_parser.parse_program();
-                    process_imports(&mut imported_prog, import_path.parent().unwrap_or(base_dir), processed)?;
-                    imported_stmts.extend(imported_prog.items);
-                }
+                let mut imported_prog = crate::parse(&import_src, &import_path, edition)?;
+                crate::resolve_defines(&mut imported_prog, opts.defines, &import_path)?;
+                process_imports(&mut imported_prog, &import_path, opts, processed, graph)?;
+                complete &= imported_prog.locs.len() == sourcemap::count(&imported_prog.items);
+                imported_stmts.extend(imported_prog.items);
+                imported_locs.extend(imported_prog.locs);
             }
         } else {
+            locs.keep(sourcemap::count(std::slice::from_ref(&stmt)));
             remaining_stmts.push(stmt);
         }
     }
@@ -33,5 +194,9 @@ pub fn process_imports(prog: &mut Program, base_dir: &Path, processed: &mut Hash
     // First include imported statements, then original
     prog.items = imported_stmts;
     prog.items.extend(remaining_stmts);
+    if complete {
+        imported_locs.extend(locs.out);
+        prog.locs = imported_locs;
+    }
     Ok(())
 }
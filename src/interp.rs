@@ -0,0 +1,1231 @@
+// Tree-walking interpreter: the `interp` backend.
+//
+// Evaluates a checked program directly instead of generating code, so running
+// Wheel needs neither an assembler and linker nor LLVM. `wheelc run` uses it,
+// and an `Interpreter` keeps its globals and functions between calls to
+// `eval`, which is what a REPL needs.
+//
+// The semantics follow the native backends: top-level statements run in
+// order, then `main` is called if the program defines one; `print` writes its
+// argument and a newline; comparisons evaluate to 1 or 0. Builtins are
+// implemented on the Rust standard library, as are the `wheel_str_*`
+// functions of the string runtime, the math functions, the regex functions,
+// the vectors, the maps and the process pipes. Those that drive hardware,
+// SDL or the rest of the C memory and process runtimes have no interpreter
+// version and fail when called, as do `extern func`s and other registered
+// builtins.
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+use std::io::{BufRead, Read, Write};
+use std::rc::Rc;
+use crate::builtins::{MATH_BINARY, MATH_UNARY};
+use crate::error::{Result, WheelError};
+use crate::ast::{BinOp, Expr, InlineTarget, Program, Stmt};
+
+/// Fail the run with a formatted [`WheelError::Runtime`].
+macro_rules! bail {
+    ($($arg:tt)*) => { return Err(WheelError::runtime(format!($($arg)*))) };
+}
+
+/// Builtins returning a handle: an index into the interpreter's threads,
+/// vectors, processes and so on, meaningless anywhere else.
+pub const HANDLE_BUILTINS: &[&str] = &[
+    "thread_spawn", "mutex_new", "chan_new", "vec_new", "map_new", "map_keys", "sb_new", "process_spawn", "arena_new",
+];
+
+/// Nested calls allowed before a program is stopped for runaway recursion.
+const MAX_DEPTH: usize = 1000;
+
+/// Value of a Wheel expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Int(i64),
+    Str(String),
+    Array(Vec<Value>),
+    /// JSON objects from `json_parse`, indexed with string keys
+    Map(BTreeMap<String, Value>),
+}
+
+impl Value {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Int(_) => "int",
+            Value::Str(_) => "str",
+            Value::Array(_) => "array",
+            Value::Map(_) => "map",
+        }
+    }
+
+    fn as_int(&self, what: &str) -> Result<i64> {
+        match self {
+            Value::Int(n) => Ok(*n),
+            other => bail!("{} must be an int, found {}", what, other.type_name()),
+        }
+    }
+
+    fn as_str(&self, what: &str) -> Result<&str> {
+        match self {
+            Value::Str(s) => Ok(s),
+            other => bail!("{} must be a str, found {}", what, other.type_name()),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Str(s) => f.write_str(s),
+            Value::Array(items) => {
+                f.write_str("[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                f.write_str("]")
+            }
+            Value::Map(entries) => {
+                f.write_str("{")?;
+                for (i, (key, item)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}: {}", key, item)?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+/// Run `prog` with `out` as standard output and no standard input.
+/// Returns the exit code: the value of a top-level `return`, otherwise 0.
+pub fn run(prog: &Program, out: impl Write) -> Result<i32> {
+    Interpreter::new(out).run(prog)
+}
+
+struct Function {
+    params: Vec<String>,
+    body: Vec<Stmt>,
+}
+
+/// Outcome of executing a statement.
+enum Flow {
+    Next,
+    Return(Value),
+}
+
+pub struct Interpreter<'a> {
+    out: Box<dyn Write + 'a>,
+    input: Box<dyn BufRead + 'a>,
+    /// Tokens of the current input line not yet consumed by `input()`
+    pending: VecDeque<String>,
+    globals: HashMap<String, Value>,
+    /// Locals of the active calls, innermost last
+    frames: Vec<HashMap<String, Value>>,
+    functions: HashMap<String, Rc<Function>>,
+    externs: Vec<String>,
+    /// Results of `thread_spawn`ed calls not yet joined, by handle - 1
+    threads: Vec<Option<Value>>,
+    /// Whether each `mutex_new` mutex is locked, by handle - 1
+    mutexes: Vec<bool>,
+    /// Queued values and capacity of each `chan_new` channel, by handle - 1
+    channels: Vec<(VecDeque<Value>, usize)>,
+    /// Elements of each `vec_new` vector, by handle - 1
+    vecs: Vec<Vec<Value>>,
+    /// Entries and keys in insertion order of each `map_new` map, by handle - 1
+    maps: Vec<(HashMap<String, Value>, Vec<String>)>,
+    /// Text of each `sb_new` string builder, by handle - 1
+    builders: Vec<String>,
+    /// `process_spawn` children with their buffered stdout, by handle
+    processes: Vec<Piped>,
+    rng: u64,
+    /// Blocks (loop iterations, calls, branches) left to run, if limited
+    steps: Option<u64>,
+    /// Whether the builtins of [`HANDLE_BUILTINS`] fail
+    no_handles: bool,
+}
+
+impl<'a> Interpreter<'a> {
+    pub fn new(out: impl Write + 'a) -> Self {
+        Interpreter {
+            out: Box::new(out),
+            input: Box::new(std::io::empty()),
+            pending: VecDeque::new(),
+            globals: HashMap::new(),
+            frames: Vec::new(),
+            functions: HashMap::new(),
+            externs: Vec::new(),
+            threads: Vec::new(),
+            mutexes: Vec::new(),
+            channels: Vec::new(),
+            vecs: Vec::new(),
+            maps: Vec::new(),
+            builders: Vec::new(),
+            processes: Vec::new(),
+            rng: seed(),
+            steps: None,
+            no_handles: false,
+        }
+    }
+
+    /// Read `input()` from `input` instead of an empty stream.
+    pub fn with_input(mut self, input: impl BufRead + 'a) -> Self {
+        self.input = Box::new(input);
+        self
+    }
+
+    /// Stop with an error after `steps` blocks have run, so a program that
+    /// never finishes cannot hang its caller.
+    pub fn with_step_limit(mut self, steps: u64) -> Self {
+        self.steps = Some(steps);
+        self
+    }
+
+    /// Fail calls to the builtins of [`HANDLE_BUILTINS`], for a caller that
+    /// keeps results after this interpreter is gone.
+    pub fn without_handles(mut self) -> Self {
+        self.no_handles = true;
+        self
+    }
+
+    /// Run a whole program: its top-level statements, then `main`.
+    pub fn run(&mut self, prog: &Program) -> Result<i32> {
+        self.declare(&prog.items);
+        let code = match self.exec_block(&prog.items)? {
+            // like the native entry point, only the low 32 bits survive
+            Flow::Return(value) => value.as_int("the program's return value")? as i32,
+            Flow::Next => {
+                if self.functions.contains_key("main") {
+                    self.call("main", Vec::new())?;
+                }
+                0
+            }
+        };
+        self.out.flush().map_err(|e| WheelError::io("<stdout>", e))?;
+        Ok(code)
+    }
+
+    /// Execute `items` on top of the state left by earlier calls and return
+    /// the value of the last statement when it is an expression. Functions
+    /// are defined, but `main` is not called.
+    pub fn eval(&mut self, items: &[Stmt]) -> Result<Option<Value>> {
+        self.declare(items);
+        let mut last = None;
+        for stmt in items {
+            last = None;
+            match stmt {
+                Stmt::Expr(e) => last = Some(self.eval_expr(e)?),
+                _ => {
+                    if let Flow::Return(value) = self.exec(stmt)? {
+                        last = Some(value);
+                        break;
+                    }
+                }
+            }
+        }
+        self.out.flush().map_err(|e| WheelError::io("<stdout>", e))?;
+        Ok(last)
+    }
+
+    /// Value of a top-level variable.
+    pub fn global(&self, name: &str) -> Option<&Value> {
+        self.globals.get(name)
+    }
+
+    /// Functions are visible before their definition, as in compiled code.
+    fn declare(&mut self, items: &[Stmt]) {
+        for stmt in items {
+            match stmt {
+                Stmt::Func { name, params, body, .. } => {
+                    let func = Function { params: params.clone(), body: body.clone() };
+                    self.functions.insert(name.clone(), Rc::new(func));
+                }
+                Stmt::Extern { name, .. } => self.externs.push(name.clone()),
+                _ => {}
+            }
+        }
+    }
+
+    fn exec_block(&mut self, stmts: &[Stmt]) -> Result<Flow> {
+        if let Some(steps) = &mut self.steps {
+            if *steps == 0 {
+                bail!("step limit reached; the program does not seem to finish");
+            }
+            *steps -= 1;
+        }
+        for stmt in stmts {
+            if let Flow::Return(value) = self.exec(stmt)? {
+                return Ok(Flow::Return(value));
+            }
+        }
+        Ok(Flow::Next)
+    }
+
+    fn exec(&mut self, stmt: &Stmt) -> Result<Flow> {
+        match stmt {
+            Stmt::Expr(e) => {
+                self.eval_expr(e)?;
+            }
+            Stmt::Let { name, value, .. } => {
+                let value = self.eval_expr(value)?;
+                self.scope().insert(name.clone(), value);
+            }
+            Stmt::Assign { name, value } => {
+                let value = self.eval_expr(value)?;
+                *self.lookup_mut(name)? = value;
+            }
+            Stmt::ArrayAssign { array, index, value } => {
+                let index = self.eval_expr(index)?.as_int("an array index")?;
+                let value = self.eval_expr(value)?;
+                let Value::Array(items) = self.lookup_mut(array)? else {
+                    bail!("`{}` is not an array", array);
+                };
+                let len = items.len();
+                let slot = usize::try_from(index).ok().and_then(|i| items.get_mut(i))
+                    .ok_or_else(|| WheelError::runtime(format!("index {} out of bounds for `{}` of length {}", index, array, len)))?;
+                *slot = value;
+            }
+            Stmt::Return(value) => {
+                let value = match value {
+                    Some(e) => self.eval_expr(e)?,
+                    None => Value::Int(0),
+                };
+                return Ok(Flow::Return(value));
+            }
+            Stmt::If { cond, then_body, else_body } => {
+                if self.eval_expr(cond)?.as_int("a condition")? != 0 {
+                    return self.exec_block(then_body);
+                }
+                if let Some(eb) = else_body {
+                    return self.exec_block(eb);
+                }
+            }
+            Stmt::While { cond, body } => {
+                while self.eval_expr(cond)?.as_int("a condition")? != 0 {
+                    if let Flow::Return(value) = self.exec_block(body)? {
+                        return Ok(Flow::Return(value));
+                    }
+                }
+            }
+            Stmt::ForRange { var, start, end, body } => {
+                let mut i = self.eval_expr(start)?.as_int("a range bound")?;
+                // the bound is re-evaluated every iteration, as in compiled code
+                while i < self.eval_expr(end)?.as_int("a range bound")? {
+                    self.scope().insert(var.clone(), Value::Int(i));
+                    if let Flow::Return(value) = self.exec_block(body)? {
+                        return Ok(Flow::Return(value));
+                    }
+                    i += 1;
+                }
+            }
+            Stmt::Inline { target: InlineTarget::Asm, .. } => {
+                bail!("`@asm` blocks are not supported by the interpreter; build with `--mode ge` instead");
+            }
+            // definitions are handled by `declare`, and `@llvm` ones can only
+            // be reached through an `extern func`; imports and `@if` are
+            // resolved by the front end; `use` only matters for linking
+            Stmt::Func { .. } | Stmt::Extern { .. } | Stmt::StructDef { .. } | Stmt::Inline { .. }
+            | Stmt::Import { .. } | Stmt::Use { .. } | Stmt::CfgIf { .. } => {}
+        }
+        Ok(Flow::Next)
+    }
+
+    /// Variables of the innermost call, or the globals at top level.
+    fn scope(&mut self) -> &mut HashMap<String, Value> {
+        match self.frames.last_mut() {
+            Some(frame) => frame,
+            None => &mut self.globals,
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Result<&Value> {
+        self.frames.last().and_then(|f| f.get(name))
+            .or_else(|| self.globals.get(name))
+            .ok_or_else(|| WheelError::runtime(format!("use of undefined variable `{}`", name)))
+    }
+
+    fn lookup_mut(&mut self, name: &str) -> Result<&mut Value> {
+        if let Some(value) = self.frames.last_mut().and_then(|f| f.get_mut(name)) {
+            return Ok(value);
+        }
+        self.globals.get_mut(name).ok_or_else(|| WheelError::runtime(format!("assignment to undefined variable `{}`", name)))
+    }
+
+    fn eval_expr(&mut self, e: &Expr) -> Result<Value> {
+        Ok(match e {
+            Expr::Int(n) => Value::Int(*n),
+            Expr::Str(s) => Value::Str(s.clone()),
+            Expr::Ident(name) => match (self.lookup(name), crate::builtins::constant(name)) {
+                (Err(_), Some(n)) => Value::Int(n),
+                (value, _) => value?.clone(),
+            },
+            Expr::BinaryOp { op, left, right } => {
+                let l = self.eval_expr(left)?;
+                let r = self.eval_expr(right)?;
+                binary(*op, l, r)?
+            }
+            Expr::Call { name, args } => {
+                let args = args.iter().map(|a| self.eval_expr(a)).collect::<Result<Vec<_>>>()?;
+                self.call(name, args)?
+            }
+            Expr::ArrayAccess { array, index } => {
+                let array = self.eval_expr(array)?;
+                if let Value::Map(entries) = &array {
+                    let key = self.eval_expr(index)?;
+                    let key = key.as_str("a map key")?;
+                    return entries.get(key).cloned().ok_or_else(|| WheelError::runtime(format!("no key `{}` in map", key)));
+                }
+                let index = self.eval_expr(index)?.as_int("an array index")?;
+                let item = match &array {
+                    Value::Array(items) => usize::try_from(index).ok().and_then(|i| items.get(i)).cloned(),
+                    // strings index to their bytes
+                    Value::Str(s) => usize::try_from(index).ok().and_then(|i| s.as_bytes().get(i)).map(|b| Value::Int(*b as i64)),
+                    Value::Int(_) => bail!("cannot index into an int"),
+                    Value::Map(_) => unreachable!(),
+                };
+                item.ok_or_else(|| WheelError::runtime(format!("index {} out of bounds for {} of length {}", index, array.type_name(), len(&array))))?
+            }
+            Expr::ArrayLiteral(items) => Value::Array(items.iter().map(|i| self.eval_expr(i)).collect::<Result<_>>()?),
+        })
+    }
+
+    fn call(&mut self, name: &str, args: Vec<Value>) -> Result<Value> {
+        let Some(func) = self.functions.get(name).cloned() else {
+            return self.builtin(name, args);
+        };
+        if func.params.len() != args.len() {
+            bail!("`{}` takes {} argument(s) but {} were given", name, func.params.len(), args.len());
+        }
+        if self.frames.len() >= MAX_DEPTH {
+            bail!("stack overflow: more than {} nested calls (in `{}`)", MAX_DEPTH, name);
+        }
+        self.frames.push(func.params.iter().cloned().zip(args).collect());
+        let flow = self.exec_block(&func.body);
+        self.frames.pop();
+        Ok(match flow? {
+            Flow::Return(value) => value,
+            Flow::Next => Value::Int(0),
+        })
+    }
+
+    fn builtin(&mut self, name: &str, args: Vec<Value>) -> Result<Value> {
+        if self.no_handles && HANDLE_BUILTINS.contains(&name) {
+            bail!("`{}` returns a handle to state that does not outlive the interpreter", name);
+        }
+        let int = |i: usize| args[i].as_int(&format!("argument {} of `{}`", i + 1, name));
+        let string = |i: usize| args[i].as_str(&format!("argument {} of `{}`", i + 1, name));
+        Ok(match (name, args.len()) {
+            ("print", 1) => {
+                writeln!(self.out, "{}", args[0]).map_err(|e| WheelError::io("<stdout>", e))?;
+                Value::Int(0)
+            }
+            ("input", 0) => Value::Int(self.read_token()?.map(|t| atoi(&t)).unwrap_or(0)),
+            ("embed", 1) | ("embed_len", 1) => {
+                let Value::Str(path) = &args[0] else { bail!("`{}` takes a file name", name) };
+                let data = std::fs::read(path).map_err(|e| WheelError::io(path, e))?;
+                match name {
+                    "embed" => Value::Array(data.into_iter().map(|b| Value::Int(b as i64)).collect()),
+                    _ => Value::Int(data.len() as i64),
+                }
+            }
+            ("luck_random", 1) => {
+                let max = int(0)?;
+                Value::Int(if max <= 0 { 0 } else { (self.next_random() % (max as u64 + 1)) as i64 })
+            }
+            ("luck_random_range", 2) => {
+                let (a, b) = (int(0)?, int(1)?);
+                let (min, max) = if a <= b { (a, b) } else { (b, a) };
+                let span = max.wrapping_sub(min) as u64;
+                Value::Int(min.wrapping_add(self.next_random().checked_rem(span.wrapping_add(1)).unwrap_or(0) as i64))
+            }
+            ("luck_seed", 1) => {
+                self.rng = (int(0)? as u64 ^ 0x9E37_79B9_7F4A_7C15).max(1);
+                Value::Int(0)
+            }
+            // millionths, like luck.c: there is no float value
+            ("luck_random_float", 0) => Value::Int((self.next_random() % 1_000_000) as i64),
+            ("luck_shuffle", 1) | ("luck_choice", 1) => {
+                let Value::Array(items) = &args[0] else { bail!("`{}` takes an array, not {}", name, args[0].type_name()) };
+                let mut items = items.clone();
+                if name == "luck_choice" {
+                    if items.is_empty() {
+                        bail!("`luck_choice` of an empty array");
+                    }
+                    let i = (self.next_random() % items.len() as u64) as usize;
+                    return Ok(items.swap_remove(i));
+                }
+                // Fisher-Yates; arrays are values, so the shuffled copy is returned
+                for i in (1..items.len()).rev() {
+                    items.swap(i, (self.next_random() % (i as u64 + 1)) as usize);
+                }
+                Value::Array(items)
+            }
+            ("getpid", 0) | ("sleep", 1) | ("time_now", 0) | ("time_millis", 0) | ("time_nanos", 0) | ("env_get", 1) | ("env_set", 2) => host_builtin(name, &args)?,
+            // The interpreter has one thread: a spawned function runs to
+            // completion right away and `thread_join` hands back its result.
+            ("thread_spawn", 1 | 2) => {
+                let target = string(0)?.to_string();
+                let arity = self.functions.get(&target).map_or(0, |f| f.params.len());
+                // like the native runtime, a missing argument reads as 0
+                let mut passed: Vec<Value> = args.into_iter().skip(1).collect();
+                passed.resize(arity, Value::Int(0));
+                let result = self.call(&target, passed)?;
+                self.threads.push(Some(result));
+                Value::Int(self.threads.len() as i64)
+            }
+            ("thread_join", 1) => {
+                let handle = int(0)?;
+                let slot = usize::try_from(handle - 1).ok().and_then(|i| self.threads.get_mut(i));
+                match slot.and_then(Option::take) {
+                    Some(result) => result,
+                    None => bail!("`thread_join` of {}, which is not a thread that is still to be joined", handle),
+                }
+            }
+            ("mutex_new", 0) => {
+                self.mutexes.push(false);
+                Value::Int(self.mutexes.len() as i64)
+            }
+            ("mutex_lock", 1) | ("mutex_unlock", 1) => {
+                let handle = int(0)?;
+                let Some(locked) = usize::try_from(handle - 1).ok().and_then(|i| self.mutexes.get_mut(i)) else {
+                    bail!("`{}` of {}, which is not a mutex", name, handle)
+                };
+                let lock = name == "mutex_lock";
+                // with one thread, nothing else could ever unlock it
+                if lock && *locked {
+                    bail!("deadlock: mutex {} is already locked", handle);
+                }
+                let changed = *locked != lock;
+                *locked = lock;
+                Value::Int(changed as i64)
+            }
+            ("chan_new", 1) => {
+                self.channels.push((VecDeque::new(), int(0)?.max(1) as usize));
+                Value::Int(self.channels.len() as i64)
+            }
+            ("chan_send", 2) | ("chan_try_send", 2) | ("chan_recv", 1) | ("chan_try_recv", 2) => {
+                let handle = int(0)?;
+                let Some((queue, cap)) = usize::try_from(handle - 1).ok().and_then(|i| self.channels.get_mut(i)) else {
+                    bail!("`{}` of {}, which is not a channel", name, handle)
+                };
+                // a blocking call that would wait could only wait forever
+                match name {
+                    "chan_send" | "chan_try_send" if queue.len() < *cap => {
+                        queue.push_back(args[1].clone());
+                        Value::Int(1)
+                    }
+                    "chan_try_send" => Value::Int(0),
+                    "chan_send" => bail!("deadlock: channel {} is full", handle),
+                    _ => match queue.pop_front() {
+                        Some(value) => value,
+                        None if name == "chan_try_recv" => args[1].clone(),
+                        None => bail!("deadlock: channel {} is empty", handle),
+                    },
+                }
+            }
+            ("vec_new", 0) => {
+                self.vecs.push(Vec::new());
+                Value::Int(self.vecs.len() as i64)
+            }
+            ("vec_push", 2) | ("vec_pop", 1) | ("vec_get", 2) | ("vec_set", 3) | ("vec_len", 1) => {
+                let handle = int(0)?;
+                let Some(items) = usize::try_from(handle - 1).ok().and_then(|i| self.vecs.get_mut(i)) else {
+                    bail!("`{}` of {}, which is not a vector", name, handle)
+                };
+                match name {
+                    "vec_push" => {
+                        items.push(args[1].clone());
+                        Value::Int(items.len() as i64)
+                    }
+                    "vec_pop" => match items.pop() {
+                        Some(value) => value,
+                        None => bail!("`vec_pop` of empty vector {}", handle),
+                    },
+                    "vec_len" => Value::Int(items.len() as i64),
+                    _ => {
+                        let index = int(1)?;
+                        let len = items.len();
+                        let Some(item) = usize::try_from(index).ok().and_then(|i| items.get_mut(i)) else {
+                            bail!("`{}`: index {} out of range for vector {} of length {}", name, index, handle, len)
+                        };
+                        if name == "vec_get" {
+                            item.clone()
+                        } else {
+                            *item = args[2].clone();
+                            Value::Int(1)
+                        }
+                    }
+                }
+            }
+            ("map_new", 0) => {
+                self.maps.push(Default::default());
+                Value::Int(self.maps.len() as i64)
+            }
+            ("map_set", 3) | ("map_get", 2) | ("map_has", 2) | ("map_keys", 1) => {
+                let handle = int(0)?;
+                let Some((entries, keys)) = usize::try_from(handle - 1).ok().and_then(|i| self.maps.get_mut(i)) else {
+                    bail!("`{}` of {}, which is not a map", name, handle)
+                };
+                match name {
+                    "map_set" => {
+                        let key = string(1)?;
+                        if entries.insert(key.to_string(), args[2].clone()).is_none() {
+                            keys.push(key.to_string());
+                        }
+                        Value::Int(1)
+                    }
+                    "map_get" => match entries.get(string(1)?) {
+                        Some(value) => value.clone(),
+                        None => bail!("`map_get`: key {:?} is not in map {}", string(1)?, handle),
+                    },
+                    "map_has" => Value::Int(entries.contains_key(string(1)?) as i64),
+                    // like map.o, the keys come back as a vector
+                    _ => {
+                        let keys = keys.iter().cloned().map(Value::Str).collect();
+                        self.vecs.push(keys);
+                        Value::Int(self.vecs.len() as i64)
+                    }
+                }
+            }
+            // handles count from 0, like process.c
+            ("process_spawn", 1) => match spawn_piped(string(0)?)? {
+                Some(piped) => {
+                    self.processes.push(piped);
+                    Value::Int(self.processes.len() as i64 - 1)
+                }
+                None => Value::Int(-1),
+            },
+            ("process_write", 2) | ("process_read_line", 1) | ("process_eof", 1) | ("process_exit_code", 1) => {
+                let handle = int(0)?;
+                let Some((child, stdout)) = usize::try_from(handle).ok().and_then(|i| self.processes.get_mut(i)) else {
+                    bail!("`{}` of {}, which is not a `process_spawn` process", name, handle)
+                };
+                match name {
+                    "process_write" => {
+                        let data = string(1)?;
+                        match child.stdin.as_mut().map(|stdin| stdin.write_all(data.as_bytes())) {
+                            Some(Ok(())) => Value::Int(data.len() as i64),
+                            _ => Value::Int(-1),
+                        }
+                    }
+                    "process_read_line" => {
+                        let mut line = String::new();
+                        if stdout.read_line(&mut line).is_err() {
+                            line.clear();
+                        }
+                        Value::Str(line.strip_suffix('\n').unwrap_or(&line).to_string())
+                    }
+                    "process_eof" => Value::Int(stdout.fill_buf().map_or(true, |rest| rest.is_empty()) as i64),
+                    _ => {
+                        // closing stdin lets the child see the end of its input,
+                        // and reading all of its output first lets it finish
+                        drop(child.stdin.take());
+                        let mut rest = Vec::new();
+                        let _ = stdout.read_to_end(&mut rest);
+                        *stdout = Box::new(std::io::Cursor::new(rest));
+                        Value::Int(child.wait().ok().and_then(|status| status.code()).map_or(-1, i64::from))
+                    }
+                }
+            }
+            ("time_format", 2) => Value::Str(format_time(int(0)?, string(1)?)?),
+            ("time_parse", 2) => Value::Int(parse_time(string(0)?, string(1)?)?),
+            ("time_year", 1) => Value::Int(utc(int(0)?)[0]),
+            ("time_month", 1) => Value::Int(utc(int(0)?)[1]),
+            ("time_day", 1) => Value::Int(utc(int(0)?)[2]),
+            ("time_hour", 1) => Value::Int(utc(int(0)?)[3]),
+            ("json_parse", 1) => Json { src: string(0)?.as_bytes(), pos: 0 }.document()?,
+            ("json_stringify", 1) => Value::Str(to_json(&args[0])),
+            // math.o, on ints until Wheel has floats
+            (_, 1) if MATH_UNARY.contains(&name) => Value::Int(math(name, int(0)? as f64, 0.0) as i64),
+            (_, 2) if MATH_BINARY.contains(&name) => Value::Int(math(name, int(0)? as f64, int(1)? as f64) as i64),
+            ("regex_match", 2) => Value::Int(Regex::new(string(0)?)?.search(string(1)?.as_bytes(), 0).is_some() as i64),
+            ("regex_find", 2) => Value::Int(Regex::new(string(0)?)?.search(string(1)?.as_bytes(), 0).map_or(-1, |m| m.0 as i64)),
+            ("regex_replace", 3) => Value::Str(Regex::new(string(0)?)?.replace(string(1)?, string(2)?)),
+            ("int_to_str", 1) => Value::Str(int(0)?.to_string()),
+            ("str_to_int", 1) => Value::Int(atoi(string(0)?.trim_start())),
+            ("sb_new", 0) => {
+                self.builders.push(String::new());
+                Value::Int(self.builders.len() as i64)
+            }
+            ("sb_append", 2) | ("sb_to_str", 1) => {
+                let handle = int(0)?;
+                let Some(text) = usize::try_from(handle - 1).ok().and_then(|i| self.builders.get_mut(i)) else {
+                    bail!("`{}` of {}, which is not a string builder", name, handle)
+                };
+                if name == "sb_to_str" {
+                    Value::Str(text.clone())
+                } else {
+                    text.push_str(string(1)?);
+                    Value::Int(1)
+                }
+            }
+            ("wheel_str_concat", 2) => Value::Str(format!("{}{}", string(0)?, string(1)?)),
+            ("wheel_str_len", 1) => Value::Int(string(0)?.len() as i64),
+            ("wheel_str_compare", 2) => Value::Int(string(0)?.cmp(string(1)?) as i64),
+            ("wheel_str_substring", 3) => {
+                let bytes = string(0)?.as_bytes();
+                let start = int(1)?.clamp(0, bytes.len() as i64) as usize;
+                let count = int(2)?.clamp(0, (bytes.len() - start) as i64) as usize;
+                Value::Str(String::from_utf8_lossy(&bytes[start..start + count]).into_owned())
+            }
+            // before the externs: `std/` headers declare runtime functions too
+            _ if crate::lint::is_builtin(name) || crate::builtins::runtime_builtin(name).is_some() => {
+                bail!("`{}` with {} argument(s) is not supported by the interpreter; build with `--mode ll` instead", name, args.len())
+            }
+            _ if self.externs.iter().any(|e| e == name) => {
+                bail!("`{}` is an `extern func`; the interpreter cannot call foreign code", name)
+            }
+            _ => bail!("call to undefined function `{}`", name),
+        })
+    }
+
+    /// Next whitespace-separated word of standard input, like `scanf("%s")`.
+    fn read_token(&mut self) -> Result<Option<String>> {
+        while self.pending.is_empty() {
+            let mut line = String::new();
+            if self.input.read_line(&mut line).map_err(|e| WheelError::io("<stdin>", e))? == 0 {
+                return Ok(None);
+            }
+            self.pending.extend(line.split_whitespace().map(str::to_string));
+        }
+        Ok(self.pending.pop_front())
+    }
+
+    /// xorshift64*; statistical quality matches the C `rand()` it stands in for.
+    fn next_random(&mut self) -> u64 {
+        self.rng ^= self.rng >> 12;
+        self.rng ^= self.rng << 25;
+        self.rng ^= self.rng >> 27;
+        self.rng.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+fn len(value: &Value) -> usize {
+    match value {
+        Value::Int(_) => 0,
+        Value::Str(s) => s.len(),
+        Value::Array(items) => items.len(),
+        Value::Map(entries) => entries.len(),
+    }
+}
+
+/// The functions of math.o; `y` is only used by the two-argument ones.
+fn math(name: &str, x: f64, y: f64) -> f64 {
+    match name {
+        "sin" => x.sin(),
+        "cos" => x.cos(),
+        "tan" => x.tan(),
+        "asin" => x.asin(),
+        "acos" => x.acos(),
+        "atan" => x.atan(),
+        "sqrt" => x.sqrt(),
+        "exp" => x.exp(),
+        "log" => x.ln(),
+        "log10" => x.log10(),
+        "ceil" => x.ceil(),
+        "floor" => x.floor(),
+        "round" => x.round(),
+        "atan2" => x.atan2(y),
+        "pow" => x.powf(y),
+        "fmod" => x % y,
+        _ => unreachable!("`{}` is not in MATH_UNARY or MATH_BINARY", name),
+    }
+}
+
+/// Year, month, day, hour, minute and second of `epoch` in UTC, like
+/// `gmtime` in os_wrappers.c.
+fn utc(epoch: i64) -> [i64; 6] {
+    let (days, secs) = (epoch.div_euclid(86_400), epoch.rem_euclid(86_400));
+    // civil_from_days: https://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    [year, month, day, secs / 3600, secs % 3600 / 60, secs % 60]
+}
+
+/// Inverse of `utc`.
+fn epoch_of(year: i64, month: i64, day: i64, hour: i64, minute: i64, second: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+    days * 86_400 + hour * 3600 + minute * 60 + second
+}
+
+/// The `strftime` conversions the interpreter implements, by the index of
+/// their field in `utc`.
+const TIME_FIELDS: &[(char, usize)] = &[('Y', 0), ('m', 1), ('d', 2), ('H', 3), ('M', 4), ('S', 5)];
+
+fn time_field(name: &str, conv: Option<char>) -> Result<usize> {
+    match TIME_FIELDS.iter().find(|f| Some(f.0) == conv) {
+        Some(&(_, field)) => Ok(field),
+        None => bail!("`{}`: the interpreter supports %Y %m %d %H %M %S and %%, not %{}", name, conv.map(String::from).unwrap_or_default()),
+    }
+}
+
+/// `time_format` for the subset of `strftime` in `TIME_FIELDS`.
+fn format_time(epoch: i64, fmt: &str) -> Result<String> {
+    let fields = utc(epoch);
+    let mut out = String::new();
+    let mut chars = fmt.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => match chars.next() {
+                Some('%') => out.push('%'),
+                conv => {
+                    let field = time_field("time_format", conv)?;
+                    let width = if field == 0 { 4 } else { 2 };
+                    out.push_str(&format!("{:0width$}", fields[field], width = width));
+                }
+            },
+            c => out.push(c),
+        }
+    }
+    Ok(out)
+}
+
+/// `time_parse` for the subset of `strptime` in `TIME_FIELDS`; -1 when
+/// `text` does not match.
+fn parse_time(text: &str, fmt: &str) -> Result<i64> {
+    let mut fields = [1970, 1, 1, 0, 0, 0];
+    let mut text = text.as_bytes();
+    let mut chars = fmt.chars();
+    while let Some(c) = chars.next() {
+        let literal = match c {
+            '%' => match chars.next() {
+                Some('%') => '%',
+                conv => {
+                    let field = time_field("time_parse", conv)?;
+                    let max_digits = if field == 0 { 4 } else { 2 };
+                    let n = text.iter().take(max_digits).take_while(|b| b.is_ascii_digit()).count();
+                    if n == 0 {
+                        return Ok(-1);
+                    }
+                    fields[field] = std::str::from_utf8(&text[..n]).unwrap().parse().unwrap();
+                    text = &text[n..];
+                    continue;
+                }
+            },
+            c => c,
+        };
+        let mut buf = [0; 4];
+        let Some(rest) = text.strip_prefix(literal.encode_utf8(&mut buf).as_bytes()) else { return Ok(-1) };
+        text = rest;
+    }
+    let [year, month, day, hour, minute, second] = fields;
+    if !text.is_empty() || !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return Ok(-1);
+    }
+    Ok(epoch_of(year, month, day, hour, minute, second))
+}
+
+/// Parser for `json_parse`. Numbers must be integers; `true`, `false` and
+/// `null` read as 1, 0 and 0.
+struct Json<'a> {
+    src: &'a [u8],
+    pos: usize,
+}
+
+impl Json<'_> {
+    fn document(mut self) -> Result<Value> {
+        let value = self.value()?;
+        self.skip_ws();
+        if self.pos < self.src.len() {
+            return Err(self.error("end of input"));
+        }
+        Ok(value)
+    }
+
+    fn error(&self, expected: &str) -> WheelError {
+        WheelError::runtime(format!("json_parse: expected {} at byte {}", expected, self.pos))
+    }
+
+    fn skip_ws(&mut self) {
+        while self.src.get(self.pos).is_some_and(u8::is_ascii_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consume `c` after optional whitespace.
+    fn eat(&mut self, c: u8) -> bool {
+        self.skip_ws();
+        let found = self.src.get(self.pos) == Some(&c);
+        self.pos += found as usize;
+        found
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_ws();
+        let rest = &self.src[self.pos..];
+        for (word, n) in [("true", 1), ("false", 0), ("null", 0)] {
+            if rest.starts_with(word.as_bytes()) {
+                self.pos += word.len();
+                return Ok(Value::Int(n));
+            }
+        }
+        match rest.first() {
+            Some(b'"') => Ok(Value::Str(self.string()?)),
+            Some(b'[') => {
+                self.pos += 1;
+                let mut items = Vec::new();
+                if !self.eat(b']') {
+                    loop {
+                        items.push(self.value()?);
+                        if self.eat(b']') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return Err(self.error("`,` or `]`"));
+                        }
+                    }
+                }
+                Ok(Value::Array(items))
+            }
+            Some(b'{') => {
+                self.pos += 1;
+                let mut entries = BTreeMap::new();
+                if !self.eat(b'}') {
+                    loop {
+                        self.skip_ws();
+                        let key = self.string()?;
+                        if !self.eat(b':') {
+                            return Err(self.error("`:`"));
+                        }
+                        entries.insert(key, self.value()?);
+                        if self.eat(b'}') {
+                            break;
+                        }
+                        if !self.eat(b',') {
+                            return Err(self.error("`,` or `}`"));
+                        }
+                    }
+                }
+                Ok(Value::Map(entries))
+            }
+            Some(b'-' | b'0'..=b'9') => {
+                let start = self.pos;
+                self.pos += 1;
+                while self.src.get(self.pos).is_some_and(u8::is_ascii_digit) {
+                    self.pos += 1;
+                }
+                if matches!(self.src.get(self.pos), Some(b'.' | b'e' | b'E')) {
+                    return Err(self.error("an integer"));
+                }
+                let digits = std::str::from_utf8(&self.src[start..self.pos]).unwrap_or_default();
+                digits.parse().map(Value::Int).map_err(|_| {
+                    self.pos = start;
+                    self.error("a 64-bit integer")
+                })
+            }
+            _ => Err(self.error("a value")),
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        if self.src.get(self.pos) != Some(&b'"') {
+            return Err(self.error("a string"));
+        }
+        self.pos += 1;
+        let mut out = Vec::new();
+        loop {
+            let Some(&c) = self.src.get(self.pos) else { return Err(self.error("closing `\"`")) };
+            self.pos += 1;
+            match c {
+                b'"' => break,
+                b'\\' => {
+                    let escaped = match self.src.get(self.pos) {
+                        Some(b'n') => '\n',
+                        Some(b't') => '\t',
+                        Some(b'r') => '\r',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(&c @ (b'"' | b'\\' | b'/')) => c as char,
+                        Some(b'u') => {
+                            let hex = self.src.get(self.pos + 1..self.pos + 5).and_then(|h| std::str::from_utf8(h).ok());
+                            let code = hex.and_then(|h| u32::from_str_radix(h, 16).ok()).ok_or_else(|| self.error("four hex digits"))?;
+                            self.pos += 4;
+                            char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+                        }
+                        _ => return Err(self.error("an escape sequence")),
+                    };
+                    self.pos += 1;
+                    out.extend_from_slice(escaped.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                c => out.push(c),
+            }
+        }
+        Ok(String::from_utf8_lossy(&out).into_owned())
+    }
+}
+
+/// `json_stringify`: compact JSON with object keys in sorted order.
+fn to_json(value: &Value) -> String {
+    match value {
+        Value::Int(n) => n.to_string(),
+        Value::Str(s) => crate::report::json_str(s),
+        Value::Array(items) => format!("[{}]", items.iter().map(to_json).collect::<Vec<_>>().join(",")),
+        Value::Map(entries) => {
+            let entries: Vec<_> = entries.iter().map(|(k, v)| format!("{}:{}", crate::report::json_str(k), to_json(v))).collect();
+            format!("{{{}}}", entries.join(","))
+        }
+    }
+}
+
+/// Pattern of the `regex_*` builtins, in the subset regex.c implements.
+/// Unlike regex.c, which lets a malformed pattern match nothing, `new`
+/// rejects one.
+struct Regex<'a>(&'a [u8]);
+
+impl<'a> Regex<'a> {
+    fn new(pattern: &'a str) -> Result<Self> {
+        let re = pattern.as_bytes();
+        let mut i = usize::from(re.first() == Some(&b'^'));
+        while i < re.len() {
+            match Regex::atom_len(&re[i..]) {
+                Some(n) => i += n,
+                None => bail!("regex: malformed pattern `{}`: unterminated `{}`", pattern, re[i] as char),
+            }
+            if matches!(re.get(i), Some(b'*' | b'+' | b'?')) {
+                i += 1;
+            }
+        }
+        Ok(Regex(re))
+    }
+
+    /// Length of the atom at the start of `re`: a byte, `.`, `\x` or
+    /// `[...]`; `None` if it is not terminated.
+    fn atom_len(re: &[u8]) -> Option<usize> {
+        match re[0] {
+            b'\\' => (re.len() > 1).then_some(2),
+            b'[' => {
+                let mut i = 1;
+                if re.get(i) == Some(&b'^') {
+                    i += 1;
+                }
+                // a `]` right after the `[` is part of the class
+                if re.get(i) == Some(&b']') {
+                    i += 1;
+                }
+                while i < re.len() && re[i] != b']' {
+                    i += if re[i] == b'\\' && i + 1 < re.len() { 2 } else { 1 };
+                }
+                (i < re.len()).then_some(i + 1)
+            }
+            _ => Some(1),
+        }
+    }
+
+    fn escape_matches(e: u8, c: u8) -> bool {
+        match e {
+            b'd' => c.is_ascii_digit(),
+            b'w' => c.is_ascii_alphanumeric() || c == b'_',
+            b's' => c.is_ascii_whitespace(),
+            _ => c == e,
+        }
+    }
+
+    fn atom_matches(atom: &[u8], c: u8) -> bool {
+        match atom[0] {
+            b'.' => true,
+            b'\\' => Regex::escape_matches(atom[1], c),
+            b'[' => {
+                let set = &atom[1..atom.len() - 1];
+                let (negate, set) = match set.strip_prefix(b"^") {
+                    Some(rest) => (true, rest),
+                    None => (false, set),
+                };
+                let mut found = false;
+                let mut i = 0;
+                while i < set.len() {
+                    if set[i] == b'\\' && i + 1 < set.len() {
+                        found |= Regex::escape_matches(set[i + 1], c);
+                        i += 2;
+                    } else if i + 2 < set.len() && set[i + 1] == b'-' {
+                        found |= (set[i]..=set[i + 2]).contains(&c);
+                        i += 3;
+                    } else {
+                        found |= set[i] == c;
+                        i += 1;
+                    }
+                }
+                found != negate
+            }
+            b => b == c,
+        }
+    }
+
+    /// End of a match of `re` starting at `text[at]`, trying the longest
+    /// repetitions first.
+    fn match_here(re: &[u8], text: &[u8], at: usize) -> Option<usize> {
+        if re.is_empty() {
+            return Some(at);
+        }
+        if re == b"$" {
+            return (at == text.len()).then_some(at);
+        }
+        let (atom, rest) = re.split_at(Regex::atom_len(re)?);
+        let one = |i: usize| i < text.len() && Regex::atom_matches(atom, text[i]);
+        match rest.first() {
+            Some(&q @ (b'*' | b'+' | b'?')) => {
+                let mut k = 0;
+                while (q != b'?' || k < 1) && one(at + k) {
+                    k += 1;
+                }
+                (usize::from(q == b'+')..=k).rev().find_map(|k| Regex::match_here(&rest[1..], text, at + k))
+            }
+            _ if one(at) => Regex::match_here(rest, text, at + 1),
+            _ => None,
+        }
+    }
+
+    /// Start and end of the first match in `text` at or after `from`.
+    fn search(&self, text: &[u8], from: usize) -> Option<(usize, usize)> {
+        if let Some(re) = self.0.strip_prefix(b"^") {
+            return if from == 0 { Regex::match_here(re, text, 0).map(|end| (0, end)) } else { None };
+        }
+        (from..=text.len()).find_map(|start| Regex::match_here(self.0, text, start).map(|end| (start, end)))
+    }
+
+    /// `text` with every non-overlapping match replaced by `with`.
+    fn replace(&self, text: &str, with: &str) -> String {
+        let text = text.as_bytes();
+        let mut out = Vec::new();
+        let mut at = 0;
+        while let Some((start, end)) = self.search(text, at) {
+            out.extend_from_slice(&text[at..start]);
+            out.extend_from_slice(with.as_bytes());
+            if end > start {
+                at = end;
+            } else {
+                // step over the byte after an empty match
+                out.extend_from_slice(&text[start..(start + 1).min(text.len())]);
+                at = start + 1;
+            }
+            if at > text.len() {
+                break;
+            }
+        }
+        if at <= text.len() {
+            out.extend_from_slice(&text[at..]);
+        }
+        String::from_utf8_lossy(&out).into_owned()
+    }
+}
+
+fn binary(op: BinOp, l: Value, r: Value) -> Result<Value> {
+    let truth = |b: bool| Value::Int(b as i64);
+    Ok(match (op, l, r) {
+        (BinOp::EqEq, l, r) => truth(l == r),
+        (BinOp::NotEq, l, r) => truth(l != r),
+        (op, Value::Int(a), Value::Int(b)) => match op {
+            BinOp::Add => Value::Int(a.wrapping_add(b)),
+            BinOp::Sub => Value::Int(a.wrapping_sub(b)),
+            BinOp::Mul => Value::Int(a.wrapping_mul(b)),
+            BinOp::Div if b == 0 => bail!("division by zero"),
+            BinOp::Div => Value::Int(a.wrapping_div(b)),
+            BinOp::Lt => truth(a < b),
+            BinOp::Gt => truth(a > b),
+            BinOp::LtEq => truth(a <= b),
+            BinOp::GtEq => truth(a >= b),
+            BinOp::EqEq | BinOp::NotEq => unreachable!(),
+        },
+        (BinOp::Add, Value::Str(a), Value::Str(b)) => Value::Str(a + &b),
+        (op @ (BinOp::Lt | BinOp::Gt | BinOp::LtEq | BinOp::GtEq), Value::Str(a), Value::Str(b)) => truth(match op {
+            BinOp::Lt => a < b,
+            BinOp::Gt => a > b,
+            BinOp::LtEq => a <= b,
+            _ => a >= b,
+        }),
+        (op, l, r) => bail!("unsupported operands for {:?}: {} and {}", op, l.type_name(), r.type_name()),
+    })
+}
+
+/// `atoi`: optional sign and leading digits, 0 when there are none.
+fn atoi(s: &str) -> i64 {
+    let (sign, digits) = match s.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let end = digits.find(|c: char| !c.is_ascii_digit()).unwrap_or(digits.len());
+    sign * digits[..end].parse::<i64>().unwrap_or(0)
+}
+
+/// Builtins that need the operating system.
+#[cfg(feature = "native")]
+fn host_builtin(name: &str, args: &[Value]) -> Result<Value> {
+    use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+    let string = |i: usize| args[i].as_str(&format!("argument {} of `{}`", i + 1, name));
+    Ok(Value::Int(match name {
+        // like `wheel_env_get` in os_wrappers.c, an unset variable reads as 0
+        "env_get" => return Ok(std::env::var(string(0)?).map_or(Value::Int(0), Value::Str)),
+        "env_set" => {
+            let (var, value) = (string(0)?, string(1)?);
+            if var.is_empty() || var.contains(['=', '\0']) || value.contains('\0') {
+                0
+            } else {
+                std::env::set_var(var, value);
+                1
+            }
+        }
+        // like CLOCK_MONOTONIC, counted from an arbitrary point: here the first reading
+        "time_millis" | "time_nanos" => {
+            static START: std::sync::OnceLock<Instant> = std::sync::OnceLock::new();
+            let elapsed = START.get_or_init(Instant::now).elapsed();
+            if name == "time_millis" { elapsed.as_millis() as i64 } else { elapsed.as_nanos() as i64 }
+        }
+        "getpid" => std::process::id() as i64,
+        "sleep" => {
+            let secs = args[0].as_int("argument 1 of `sleep`")?;
+            std::thread::sleep(Duration::from_secs(secs.max(0) as u64));
+            0
+        }
+        _ => SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs() as i64).unwrap_or(0),
+    }))
+}
+
+#[cfg(not(feature = "native"))]
+fn host_builtin(name: &str, _args: &[Value]) -> Result<Value> {
+    bail!("`{}` needs the `native` feature", name)
+}
+
+/// A `process_spawn` child and its buffered stdout.
+type Piped = (std::process::Child, Box<dyn std::io::BufRead>);
+
+/// `command` run by `/bin/sh` with piped stdin and stdout, like
+/// `process_spawn` in process.c; `None` if it could not be started.
+#[cfg(feature = "native")]
+fn spawn_piped(command: &str) -> Result<Option<Piped>> {
+    use std::process::{Command, Stdio};
+    let Ok(mut child) = Command::new("/bin/sh").arg("-c").arg(command).stdin(Stdio::piped()).stdout(Stdio::piped()).spawn() else {
+        return Ok(None);
+    };
+    let stdout = child.stdout.take().map(std::io::BufReader::new);
+    Ok(stdout.map(|stdout| (child, Box::new(stdout) as Box<dyn std::io::BufRead>)))
+}
+
+#[cfg(not(feature = "native"))]
+fn spawn_piped(_command: &str) -> Result<Option<Piped>> {
+    bail!("`process_spawn` needs the `native` feature")
+}
+
+#[cfg(feature = "native")]
+fn seed() -> u64 {
+    let nanos = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or(0);
+    (nanos ^ std::process::id() as u64) | 1
+}
+
+#[cfg(not(feature = "native"))]
+fn seed() -> u64 {
+    0x9E37_79B9_7F4A_7C15
+}
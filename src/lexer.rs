@@ -1,3 +1,5 @@
+use crate::diagnostic::{Diagnostic, Span};
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Token {
     Int(i64),
@@ -8,25 +10,79 @@ pub enum Token {
     LBracket, RBracket, Colon,
     Let, Func, Return, Import, From, Use,
     Print, If, Else, Then, While, For, In, Range,
-    Set, Struct,
+    Set, Struct, Extern, Pub, Comptime,
     Eq,
     Lt, Gt, LtEq, GtEq, EqEq, NotEq,
-    Hash,
+    Hash, At,
     Semicolon,
     EOF,
 }
 
-pub struct Lexer<'a> { src: &'a str, pos: usize }
+/// Source text between tokens that the parser never sees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaKind {
+    Whitespace,
+    /// `// ...` up to the end of the line
+    Comment,
+    /// A `#!/usr/bin/env wheelc` first line, which makes the file directly executable
+    Shebang,
+    /// Characters that start no token
+    Skipped,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+}
+
+pub struct Lexer<'a> { src: &'a str, pos: usize, start: usize, trivia: Vec<Trivia>, errors: Vec<Diagnostic> }
 
 impl<'a> Lexer<'a> {
-    pub fn new(src: &'a str) -> Self { Self { src, pos: 0 } }
+    pub fn new(src: &'a str) -> Self {
+        Self { src, pos: 0, start: 0, trivia: Vec::new(), errors: Vec::new() }
+    }
+
+    /// Span of the token most recently returned by `next_token`.
+    pub fn span(&self) -> Span { Span::new(self.src, self.start, self.pos) }
+
+    /// Trivia skipped right before the token most recently returned by `next_token`.
+    pub fn trivia(&self) -> &[Trivia] { &self.trivia }
+
+    /// Problems found while lexing, such as unterminated literals.
+    pub fn diagnostics(&self) -> &[Diagnostic] { &self.errors }
 
     fn peek(&self) -> Option<char> { self.src[self.pos..].chars().next() }
     fn bump(&mut self) { if let Some(ch) = self.peek() { self.pos += ch.len_utf8(); } }
 
+    /// Consume the trivia at the current position, if any.
+    fn skip_trivia(&mut self) -> Option<TriviaKind> {
+        let rest = &self.src[self.pos..];
+        let ch = rest.chars().next()?;
+        let kind = if rest.starts_with("//") || self.pos == 0 && rest.starts_with("#!") {
+            let kind = if rest.starts_with("//") { TriviaKind::Comment } else { TriviaKind::Shebang };
+            self.pos += rest.find('\n').unwrap_or(rest.len());
+            kind
+        } else if ch.is_whitespace() {
+            while self.peek().is_some_and(char::is_whitespace) { self.bump(); }
+            TriviaKind::Whitespace
+        } else if ch.is_ascii_alphanumeric() || ch == '_' || "\"+-*/(){}[],:=<>!%#@;".contains(ch) {
+            return None;
+        } else {
+            self.bump();
+            TriviaKind::Skipped
+        };
+        Some(kind)
+    }
+
     pub fn next_token(&mut self) -> Token {
-        while let Some(ch) = self.peek() {
-            if ch.is_whitespace() { self.bump(); continue }
+        self.trivia.clear();
+        loop {
+            self.start = self.pos;
+            let Some(kind) = self.skip_trivia() else { break };
+            self.trivia.push(Trivia { kind, span: Span::new(self.src, self.start, self.pos) });
+        }
+        if let Some(ch) = self.peek() {
             if ch.is_ascii_digit() {
                 let start = self.pos;
                 while let Some(c) = self.peek() { if c.is_ascii_digit() { self.bump(); } else { break } }
@@ -41,6 +97,9 @@ impl<'a> Lexer<'a> {
                 return match s {
                     "let" => Token::Let,
                     "func" => Token::Func,
+                    "extern" => Token::Extern,
+                    "pub" => Token::Pub,
+                    "comptime" => Token::Comptime,
                     "return" => Token::Return,
                     "import" => Token::Import,
                     "from" => Token::From,
@@ -68,7 +127,12 @@ impl<'a> Lexer<'a> {
                         self.bump();
                     }
                     let s = &self.src[start..self.pos];
-                    if self.peek() == Some('"') { self.bump(); }
+                    if self.peek() == Some('"') { self.bump(); } else {
+                        let err = Diagnostic::error("unterminated-string", "unterminated string literal")
+                            .with_span(Span::new(self.src, self.start, self.pos))
+                            .with_suggestion("close the string with `\"`");
+                        self.errors.push(err);
+                    }
                     return Token::Str(s.to_string());
                 }
                 '+' => { self.bump(); return Token::Plus }
@@ -117,8 +181,9 @@ impl<'a> Lexer<'a> {
                 }
                 '%' => { self.bump(); return Token::Percent }
                 '#' => { self.bump(); return Token::Hash }
+                '@' => { self.bump(); return Token::At }
                 ';' => { self.bump(); return Token::Semicolon }
-                _ => { self.bump(); continue }
+                _ => unreachable!("`{}` is trivia", ch),
             }
         }
         Token::EOF
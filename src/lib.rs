@@ -0,0 +1,221 @@
+//! The Wheel compiler as a library.
+//!
+//! `wheelc` is a thin consumer of this crate: it loads configuration, drives
+//! the pipeline below, caches and links. Other tools can do the same, or just
+//! call [`compile_source`]:
+//!
+//! ```no_run
+//! let artifacts = wheel::compile_source("print(\"hi\");", wheel::Options::new("hi.wheel")).unwrap();
+//! std::fs::write("hi.s", artifacts.asm()).unwrap();
+//! ```
+//!
+//! The stages are also available separately: [`parse_source`] (parsing,
+//! `@if`, imports, embeds, `comptime` calls) and [`Parsed::check`]
+//! (semantic checks).
+//! Programs generated by other tools can skip the text entirely: build them
+//! with [`builder`] and pass them to [`compile_program`].
+//!
+//! Without the default `native` feature the crate builds for
+//! `wasm32-unknown-unknown`: the front end and the `ge` code generator stay,
+//! while everything that runs programs, writes files or reads config files
+//! (the cache, [`config::Config::load`], `build`, the `wheelc` binary) is
+//! left out. Imports and embeds are then only found where `std::fs` works.
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+pub mod assets;
+pub mod ast;
+#[cfg(feature = "native")]
+pub mod build;
+pub mod builder;
+pub mod builtins;
+pub mod cache;
+pub mod check;
+pub mod codegen;
+pub mod comptime;
+pub mod config;
+pub mod cst;
+pub mod defines;
+pub mod diagnostic;
+pub mod edition;
+pub mod error;
+#[cfg(feature = "native")]
+pub mod elf_writer;
+pub mod header;
+pub mod imports;
+pub mod interp;
+pub mod lexer;
+pub mod lint;
+pub mod llvm_backend;
+pub mod objdump;
+pub mod parser;
+pub mod report;
+pub mod sourcemap;
+pub mod visit;
+
+use ast::Program;
+use builtins::Builtins;
+use defines::Defines;
+use diagnostic::Diagnostic;
+use edition::Edition;
+use imports::{ImportGraph, ImportOptions};
+pub use error::{Result, WheelError};
+
+/// Everything the front end needs besides the source text.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// Where the source lives; imports and embeds are resolved relative to it
+    pub path: PathBuf,
+    pub edition: Edition,
+    pub defines: Defines,
+    /// `[assets]` table; its paths are relative to `project_root`
+    pub assets: BTreeMap<String, String>,
+    pub project_root: PathBuf,
+    /// Warning codes that are dropped instead of reported
+    pub allowed_warnings: Vec<String>,
+    /// Extra functions callable without a definition (LLVM backend only)
+    pub builtins: Builtins,
+    /// Directories searched for imports not found next to the importing
+    /// file, before the bundled `std/` modules
+    pub search_path: Vec<PathBuf>,
+}
+
+impl Options {
+    /// Defaults for a source at `path`: edition 0.1, no defines, assets or
+    /// extra builtins, project root next to the file, imports searched for
+    /// along `WHEEL_PATH`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let project_root = path.parent().unwrap_or(Path::new("")).to_path_buf();
+        Options {
+            path,
+            edition: Edition::default(),
+            defines: Defines::new(),
+            assets: BTreeMap::new(),
+            project_root,
+            allowed_warnings: Vec::new(),
+            builtins: Builtins::new(),
+            search_path: imports::wheel_path(),
+        }
+    }
+}
+
+/// A program with `@if` resolved, imports inlined, embeds located and
+/// `comptime` calls folded.
+#[derive(Debug)]
+pub struct Parsed {
+    pub program: Program,
+    pub imports: ImportGraph,
+    /// Canonical paths of files baked in with `embed`, in first-use order
+    pub embedded: Vec<PathBuf>,
+}
+
+/// A program that passed semantic checks, ready for a backend.
+#[derive(Debug)]
+pub struct Artifacts {
+    pub program: Program,
+    pub imports: ImportGraph,
+    pub embedded: Vec<PathBuf>,
+    pub warnings: Vec<Diagnostic>,
+}
+
+impl Artifacts {
+    /// x86_64 assembly (Intel syntax) for the `ge`/`gb` backends.
+    pub fn asm(&self) -> String {
+        codegen::codegen_to_asm(&self.program)
+    }
+
+    /// The assembly of [`Artifacts::asm`] and the map from its lines back to
+    /// the Wheel statements that produced them.
+    pub fn asm_with_source_map(&self) -> (String, sourcemap::SourceMap) {
+        codegen::codegen_to_asm_with_map(&self.program)
+    }
+}
+
+/// Parse `src` and run the whole front end over it.
+pub fn compile_source(src: &str, opts: Options) -> Result<Artifacts> {
+    parse_source(src, &opts)?.check(&opts)
+}
+
+/// Parse `src`, resolve `@if` blocks, inline imports and locate embedded files.
+/// Failures are [`WheelError::Lex`] or [`WheelError::Parse`] for syntax
+/// errors and [`WheelError::Type`] for the rest, all with their diagnostics.
+pub fn parse_source(src: &str, opts: &Options) -> Result<Parsed> {
+    Parsed::from_program(parse(src, &opts.path, opts.edition)?, opts)
+}
+
+/// Parse `src`, the contents of `path`, into a program whose locations name `path`.
+fn parse(src: &str, path: &Path, edition: Edition) -> Result<Program> {
+    let in_file = |ds: &[Diagnostic]| ds.iter().map(|d| d.clone().in_file(path)).collect();
+    let tokens = cst::TokenStream::lex(src);
+    if !tokens.diagnostics().is_empty() {
+        return Err(WheelError::Lex { diagnostics: in_file(tokens.diagnostics()) });
+    }
+    let mut p = parser::Parser::from_stream(&tokens, edition);
+    let mut program = p.parse_program();
+    let diagnostics = p.diagnostics();
+    if !diagnostics.is_empty() {
+        return Err(WheelError::Parse { diagnostics: in_file(&diagnostics) });
+    }
+    for loc in &mut program.locs {
+        loc.file = Some(path.to_path_buf());
+    }
+    Ok(program)
+}
+
+/// Resolve the `@if` blocks of `prog`, parsed from `path`.
+fn resolve_defines(prog: &mut Program, defines: &Defines, path: &Path) -> Result<()> {
+    defines::resolve(prog, defines).map_err(|e| WheelError::Type {
+        diagnostics: vec![Diagnostic::error("invalid-cfg", e).in_file(path)],
+    })
+}
+
+/// Run the whole front end over a program built in Rust, e.g. with
+/// [`builder`], as if it had been parsed from `opts.path`.
+pub fn compile_program(program: Program, opts: Options) -> Result<Artifacts> {
+    Parsed::from_program(program, &opts)?.check(&opts)
+}
+
+impl Parsed {
+    /// Resolve `@if` blocks, inline imports, locate embedded files and
+    /// evaluate `comptime` calls in a program that is already parsed or was
+    /// built directly.
+    pub fn from_program(mut program: Program, opts: &Options) -> Result<Parsed> {
+        let input = opts.path.as_path();
+        resolve_defines(&mut program, &opts.defines, input)?;
+
+        let mut processed = HashSet::new();
+        let mut graph = ImportGraph::new(input);
+        let import_opts = ImportOptions { edition: opts.edition, defines: &opts.defines, search_path: &opts.search_path };
+        imports::process_imports(&mut program, input, &import_opts, &mut processed, &mut graph)?;
+
+        let resolver = assets::AssetResolver {
+            base_dir: input.parent().unwrap_or(Path::new("")),
+            root: &opts.project_root,
+            table: &opts.assets,
+        };
+        let embedded = resolver.resolve(&mut program)
+            .map_err(|e| WheelError::Type { diagnostics: vec![Diagnostic::error("invalid-embed", e).in_file(input)] })?;
+
+        let (program, errors) = comptime::fold(program);
+        if !errors.is_empty() {
+            return Err(WheelError::Type { diagnostics: errors.into_iter().map(|d| d.in_file(input)).collect() });
+        }
+        Ok(Parsed { program, imports: graph, embedded })
+    }
+
+    /// Run semantic checks. Errors fail with a [`WheelError::Type`]
+    /// carrying every diagnostic, warnings included; otherwise the warnings
+    /// not listed in `opts.allowed_warnings` are returned.
+    pub fn check(self, opts: &Options) -> Result<Artifacts> {
+        let mut diagnostics = builtins::check_calls(&self.program, &opts.builtins);
+        diagnostics.extend(check::check_program(&self.program));
+        let diagnostics: Vec<_> = diagnostics.into_iter()
+            .filter(|d| d.is_error() || !opts.allowed_warnings.iter().any(|a| a == d.code))
+            .collect();
+        if diagnostics.iter().any(Diagnostic::is_error) {
+            return Err(WheelError::Type { diagnostics });
+        }
+        Ok(Artifacts { program: self.program, imports: self.imports, embedded: self.embedded, warnings: diagnostics })
+    }
+}
@@ -0,0 +1,288 @@
+// `wheelc lint`: style and hygiene rules over the AST.
+//
+// Lints never fail a build; they only run through the `lint` subcommand. Each
+// rule has a default level that the `[lint]` table of the config files and the
+// `--allow/--warn/--deny` flags can override:
+//
+//     [lint]
+//     magic-number = "warn"
+//     empty-block = "deny"
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+#[cfg(feature = "native")]
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use crate::ast::{Expr, Program, Stmt};
+use crate::edition::Edition;
+use crate::error::{Result, WheelError};
+use crate::parser::Parser;
+use crate::visit::{walk_expr, walk_stmt, Visitor};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl Level {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Level::Allow => "allow",
+            Level::Warn => "warn",
+            Level::Deny => "deny",
+        }
+    }
+}
+
+pub struct Rule {
+    pub name: &'static str,
+    pub default: Level,
+    pub description: &'static str,
+}
+
+pub const RULES: &[Rule] = &[
+    Rule { name: "magic-number", default: Level::Allow, description: "integer literals other than 0 and 1 outside a `let` that names them" },
+    Rule { name: "empty-block", default: Level::Warn, description: "`if`/`else`/`while`/`for`/`func` bodies with no statements" },
+    Rule { name: "shadowed-builtin", default: Level::Warn, description: "variables, parameters or functions named like a builtin" },
+    Rule { name: "unused-import", default: Level::Warn, description: "imports none of whose definitions are used by the importing file" },
+];
+
+/// Functions the compiler generates code for itself; the functions of the
+/// runtime objects are registered in [`Builtins::runtime`](crate::builtins::Builtins::runtime).
+pub const BUILTINS: &[&str] = &[
+    "print", "input", "embed", "embed_len",
+    "sdl_init", "sdl_create_window", "sdl_draw_pixel", "sdl_draw_rect", "sdl_clear", "sdl_present",
+    "sdl_poll_event", "sdl_delay", "sdl_destroy_window", "sdl_quit",
+    "sdl_key_down", "sdl_get_mouse_x", "sdl_get_mouse_y", "sdl_mouse_pressed", "sdl_load_image", "sdl_draw_image",
+    "port_read_byte", "port_write_byte", "getpid", "sleep", "time_now", "time_millis", "time_nanos",
+    "time_format", "time_parse", "time_year", "time_month", "time_day", "time_hour",
+    "env_get", "env_set",
+    "json_parse", "json_stringify",
+    "thread_spawn", "thread_join", "mutex_new", "mutex_lock", "mutex_unlock",
+    "atomic_load", "atomic_store", "atomic_add",
+    "chan_new", "chan_send", "chan_try_send", "chan_recv", "chan_try_recv",
+    "luck_random", "luck_random_range", "luck_seed", "luck_random_float", "luck_shuffle", "luck_choice",
+    "mem_alloc", "mem_free", "mem_get_used", "mem_get_free",
+    "io_read_port", "io_write_port", "io_enable_interrupts", "io_disable_interrupts", "io_halt",
+    "mmio_read", "mmio_write", "irq_register",
+    "fs_open", "fs_close", "fs_read_block", "fs_write_block",
+    "process_init", "process_create", "process_wait", "process_is_running", "process_yield", "process_get_current_pid",
+];
+
+/// Whether `name` is one of [`BUILTINS`]. The functions of the runtime
+/// objects are not reserved: a program may define its own `log` or
+/// `vec_push`, which then takes precedence.
+pub fn is_builtin(name: &str) -> bool {
+    BUILTINS.contains(&name)
+}
+
+/// Effective level of every rule.
+#[derive(Debug, Clone)]
+pub struct Levels(BTreeMap<&'static str, Level>);
+
+impl Default for Levels {
+    fn default() -> Self {
+        Levels(RULES.iter().map(|r| (r.name, r.default)).collect())
+    }
+}
+
+impl Levels {
+    pub fn set(&mut self, rule: &str, level: Level) -> Result<()> {
+        let known = RULES.iter().find(|r| r.name == rule).ok_or_else(|| WheelError::config(format!(
+            "unknown lint rule `{}` (known: {})", rule, RULES.iter().map(|r| r.name).collect::<Vec<_>>().join(", ")
+        )))?;
+        self.0.insert(known.name, level);
+        Ok(())
+    }
+
+    pub fn get(&self, rule: &str) -> Level {
+        self.0.get(rule).copied().unwrap_or(Level::Allow)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Finding {
+    pub rule: &'static str,
+    pub level: Level,
+    pub message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} [{}]", self.message, self.rule)
+    }
+}
+
+/// Lint one source file. Imports are resolved relative to it only to learn
+/// what they define; their own contents are not linted.
+#[cfg(feature = "native")]
+pub fn lint_file(path: &Path, edition: Edition, levels: &Levels) -> Result<Vec<Finding>> {
+    let src = fs::read_to_string(path).map_err(|e| WheelError::io(path, e))?;
+    lint_source(&src, path, edition, levels)
+}
+
+/// Lint `src`, reported as `path`. Without the `native` feature imports
+/// cannot be read, so `unused-import` never fires.
+pub fn lint_source(src: &str, path: &Path, edition: Edition, levels: &Levels) -> Result<Vec<Finding>> {
+    let prog = crate::parse(src, path, edition)?;
+
+    let mut findings = Vec::new();
+    let mut push = |rule: &'static str, message: String| {
+        let level = levels.get(rule);
+        if level != Level::Allow {
+            findings.push(Finding { rule, level, message });
+        }
+    };
+
+    let mut numbers = MagicNumbers(Vec::new());
+    numbers.visit_program(&prog);
+    for n in numbers.0 {
+        push("magic-number", format!("magic number `{}`; consider naming it with `let`", n));
+    }
+
+    let mut empty = EmptyBlocks { context: "top level".to_string(), found: Vec::new() };
+    empty.visit_program(&prog);
+    for what in empty.found {
+        push("empty-block", format!("empty block in {}", what));
+    }
+
+    let mut shadows = ShadowedBuiltins(Vec::new());
+    shadows.visit_program(&prog);
+    for (kind, name) in shadows.0 {
+        push("shadowed-builtin", format!("{} `{}` shadows a builtin", kind, name));
+    }
+
+    let base_dir = path.parent().unwrap_or(Path::new(""));
+    let mut used = UsedNames(HashSet::new());
+    used.visit_program(&prog);
+    for stmt in &prog.items {
+        if let Stmt::Import { path: import } = stmt {
+            let file = if import.ends_with(".wheel") { base_dir.join(import) } else { base_dir.join(format!("{}.wheel", import)) };
+            let Some(import_src) = read_import(&file) else { continue };
+            let defined = top_level_names(&Parser::with_edition(&import_src, edition).parse_program());
+            // a module that defines nothing is imported for its side effects
+            if !defined.is_empty() && !defined.iter().any(|d| used.0.contains(d)) {
+                push("unused-import", format!("import `{}` is unused", import));
+            }
+        }
+    }
+    Ok(findings)
+}
+
+#[cfg(feature = "native")]
+fn read_import(file: &Path) -> Option<String> {
+    fs::read_to_string(file).ok()
+}
+
+#[cfg(not(feature = "native"))]
+fn read_import(_file: &Path) -> Option<String> {
+    None
+}
+
+/// Integer literals other than 0 and 1.
+struct MagicNumbers(Vec<i64>);
+
+impl Visitor for MagicNumbers {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        // `let limit = 10;` is how a number gets a name
+        if !matches!(stmt, Stmt::Let { value: Expr::Int(_), .. }) {
+            walk_stmt(self, stmt);
+        }
+    }
+
+    fn visit_expr(&mut self, e: &Expr) {
+        match e {
+            Expr::Int(n) if *n != 0 && *n != 1 => self.0.push(*n),
+            _ => walk_expr(self, e),
+        }
+    }
+}
+
+/// Descriptions of empty bodies, e.g. "`while` (function `main`)".
+struct EmptyBlocks {
+    /// The body currently being walked
+    context: String,
+    found: Vec<String>,
+}
+
+impl Visitor for EmptyBlocks {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        let blocks: Vec<(String, &[Stmt])> = match stmt {
+            Stmt::Func { name, body, .. } => vec![(format!("function `{}`", name), body)],
+            Stmt::If { then_body, else_body, .. } => {
+                let mut blocks = vec![(format!("`if` ({})", self.context), then_body.as_slice())];
+                if let Some(eb) = else_body {
+                    blocks.push((format!("`else` ({})", self.context), eb));
+                }
+                blocks
+            }
+            Stmt::While { body, .. } => vec![(format!("`while` ({})", self.context), body)],
+            Stmt::ForRange { var, body, .. } => vec![(format!("`for {}` ({})", var, self.context), body)],
+            // an empty `@if` branch is the normal way to compile something out
+            _ => return walk_stmt(self, stmt),
+        };
+        for (what, body) in blocks {
+            if body.is_empty() {
+                self.found.push(what.clone());
+            }
+            let outer = std::mem::replace(&mut self.context, what);
+            self.visit_block(body);
+            self.context = outer;
+        }
+    }
+}
+
+/// Variables, parameters and functions named like a builtin, with their kind.
+struct ShadowedBuiltins(Vec<(&'static str, String)>);
+
+impl Visitor for ShadowedBuiltins {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        let builtin = is_builtin;
+        match stmt {
+            Stmt::Let { name, .. } if builtin(name) => self.0.push(("variable", name.clone())),
+            Stmt::Func { name, params, .. } => {
+                if builtin(name) {
+                    self.0.push(("function", name.clone()));
+                }
+                for p in params.iter().filter(|p| builtin(p)) {
+                    self.0.push(("parameter", p.clone()));
+                }
+            }
+            Stmt::ForRange { var, .. } if builtin(var) => self.0.push(("loop variable", var.clone())),
+            _ => {}
+        }
+        walk_stmt(self, stmt);
+    }
+}
+
+/// Names defined at the top level of a module: functions, variables and structs.
+fn top_level_names(prog: &Program) -> Vec<String> {
+    prog.items.iter().filter_map(|s| match s {
+        Stmt::Func { name, .. } | Stmt::Let { name, .. } | Stmt::StructDef { name, .. } => Some(name.clone()),
+        _ => None,
+    }).collect()
+}
+
+/// Every name referenced as a variable, a callee or a type.
+struct UsedNames(HashSet<String>);
+
+impl Visitor for UsedNames {
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Let { ty: Some(crate::ast::Type::Struct(s)), .. } => { self.0.insert(s.clone()); }
+            Stmt::Assign { name, .. } | Stmt::ArrayAssign { array: name, .. } => { self.0.insert(name.clone()); }
+            _ => {}
+        }
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, e: &Expr) {
+        if let Expr::Ident(name) | Expr::Call { name, .. } = e {
+            self.0.insert(name.clone());
+        }
+        walk_expr(self, e);
+    }
+}
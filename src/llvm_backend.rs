@@ -1,28 +1,80 @@
 // LLVM backend for Wheel (optional). Build with `--features llvm`.
 #[cfg(feature = "llvm")]
 pub mod llvm {
-    use crate::ast::{Program, Stmt, Expr, BinOp};
+    use crate::ast::{InlineTarget, Program, Stmt, Expr, BinOp};
+    use crate::builtins::{Builtin, Builtins, ValueType};
     use inkwell::context::Context;
+    use inkwell::memory_buffer::MemoryBuffer;
     use inkwell::targets::{Target, InitializationConfig, FileType};
     use inkwell::OptimizationLevel;
-    use inkwell::values::{PointerValue, BasicValueEnum};
-    use inkwell::AddressSpace;
+    use inkwell::module::Linkage;
+    use inkwell::types::BasicMetadataTypeEnum;
+    use inkwell::attributes::{Attribute, AttributeLoc};
+    use inkwell::types::AnyType;
+    use inkwell::values::{PointerValue, BasicValue, BasicValueEnum, BasicMetadataValueEnum, FunctionValue};
+    use inkwell::{AddressSpace, AtomicOrdering, AtomicRMWBinOp};
     use std::path::Path;
     use std::collections::HashMap;
-    use anyhow::Result;
+    use crate::error::{Result, WheelError};
+    use crate::sourcemap::LocIndex;
 
-    pub fn compile_with_llvm(prog: &Program, out_path: &Path) -> Result<Vec<String>> {
-        compile_with_llvm_target(prog, out_path, "x86_64-unknown-linux-gnu")
+    /// What the object file is for.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum OutputKind {
+        /// The top-level statements become `main`
+        Executable,
+        /// For a static library called from C: the top-level statements
+        /// become `int wheel_init(void)` and only `pub extern func`s are
+        /// visible outside the object
+        StaticLib,
     }
 
-    pub fn compile_with_llvm_target(prog: &Program, out_path: &Path, target_triple: &str) -> Result<Vec<String>> {
-        // dump AST for debugging
-        let _ = std::fs::write("/workspaces/Wheel/tmp.ast", format!("{:#?}", prog));
+    pub fn compile_with_llvm(prog: &Program, out_path: &Path) -> Result<Vec<String>> {
+        compile_with_llvm_target(prog, out_path, "x86_64-unknown-linux-gnu", 2, &Builtins::new(), OutputKind::Executable, false)
+    }
 
+    /// Write `<out_path>.o` for `target_triple`. Returns the extra linker
+    /// inputs the program needs: `use`d local libraries and the link inputs
+    /// of the registered `builtins`. With `debug_alloc`, `mem_alloc` and
+    /// `mem_free` call the checking allocator of debug_alloc.o instead,
+    /// passing the file and line of the statement.
+    pub fn compile_with_llvm_target(prog: &Program, out_path: &Path, target_triple: &str, opt_level: u8, builtins: &Builtins, kind: OutputKind, debug_alloc: bool) -> Result<Vec<String>> {
     // initialize all targets for cross-compilation support
     Target::initialize_all(&InitializationConfig::default());
 
     let context = Context::create();
+        let (module, extra_link_args) = build_module(&context, prog, builtins, kind, debug_alloc)?;
+
+        // write object file with specified target triple
+        let triple = inkwell::targets::TargetTriple::create(target_triple);
+        let target = Target::from_triple(&triple).map_err(|e| WheelError::codegen(format!("target lookup failed for {}: {:?}", target_triple, e)))?;
+        let opt = match opt_level {
+            0 => OptimizationLevel::None,
+            1 => OptimizationLevel::Less,
+            2 => OptimizationLevel::Default,
+            _ => OptimizationLevel::Aggressive,
+        };
+        let tm = target.create_target_machine(&triple, "generic", "", opt, inkwell::targets::RelocMode::Default, inkwell::targets::CodeModel::Default).ok_or_else(|| WheelError::codegen(format!("failed to create target machine for {}", target_triple)))?;
+
+        let obj_path = out_path.with_extension("o");
+        tm.write_to_file(&module, FileType::Object, &obj_path).map_err(|e| WheelError::codegen(format!("write object failed: {:?}", e)))?;
+        Ok(extra_link_args)
+    }
+
+    /// The module `compile_with_llvm_target` would compile, as textual IR.
+    pub fn llvm_ir(prog: &Program, target_triple: &str, builtins: &Builtins, kind: OutputKind, debug_alloc: bool) -> Result<String> {
+        let context = Context::create();
+        let (module, _) = build_module(&context, prog, builtins, kind, debug_alloc)?;
+        module.set_triple(&inkwell::targets::TargetTriple::create(target_triple));
+        Ok(module.print_to_string().to_string())
+    }
+
+    /// Generate the module for `prog`; also returns the extra linker inputs.
+    /// Fails only when an `@llvm` block does not parse or clashes with the
+    /// generated code.
+    fn build_module<'ctx>(context: &'ctx Context, prog: &Program, builtins: &Builtins, kind: OutputKind, debug_alloc: bool) -> Result<(inkwell::module::Module<'ctx>, Vec<String>)> {
+        // `extern func` declarations are called exactly like registered builtins
+        let builtins = &builtins.with_externs(prog);
         let module = context.create_module("wheel_module");
         let builder = context.create_builder();
 
@@ -88,12 +140,29 @@ pub mod llvm {
         input_buf.set_initializer(&input_buf_ty.const_zero());
         input_buf.set_constant(false);
 
-        // create main(argc, argv)
-        let main_fn_ty = i32_t.fn_type(&[i32_t.into(), i8ptr_t.ptr_type(AddressSpace::default()).into()], false);
-        let main_fn = module.add_function("main", main_fn_ty, None);
+        // create main(argc, argv), or wheel_init() for a library
+        let main_fn = match kind {
+            OutputKind::Executable => {
+                let main_fn_ty = i32_t.fn_type(&[i32_t.into(), i8ptr_t.ptr_type(AddressSpace::default()).into()], false);
+                module.add_function("main", main_fn_ty, None)
+            }
+            OutputKind::StaticLib => module.add_function(crate::header::INIT_FN, i32_t.fn_type(&[], false), None),
+        };
         let entry = context.append_basic_block(main_fn, "entry");
         builder.position_at_end(entry);
 
+        // `--debug-alloc`: every statement stores its location here for the
+        // allocation calls it makes
+        let locs = debug_alloc.then(|| LocIndex::new(prog));
+        let locs = locs.as_ref();
+        if debug_alloc {
+            for name in ["wheel_debug_file", "wheel_debug_line"] {
+                let gv = module.add_global(i64_t, None, name);
+                gv.set_linkage(Linkage::Internal);
+                gv.set_initializer(&i64_t.const_int(0, false));
+            }
+        }
+
         // allocate local variables for `let` bindings at entry
         let mut locals: HashMap<String, PointerValue> = HashMap::new();
 
@@ -110,17 +179,29 @@ pub mod llvm {
             }
         }
 
-        // Second pass: Generate function definitions
-        let mut user_main_fn: Option<inkwell::values::FunctionValue> = None;
+        // Second pass: declare every function, so a call, `thread_spawn` or
+        // `irq_register` finds functions defined further down
         for item in &prog.items {
-            if let Stmt::Func { name, params, body } = item {
-                eprintln!("Generating function: {}", name);
-                
+            if let Stmt::Func { name, params, export, .. } = item {
                 // If function is named "main", create it as "user_main" first
                 let actual_func_name = if name == "main" { "user_main" } else { name };
-                
+
                 let func_type = i64_t.fn_type(&vec![i64_t.into(); params.len()], false);
-                let func = module.add_function(actual_func_name, func_type, None);
+                // `pub extern func`s keep external linkage and the C calling
+                // convention; `int` and `str` (a pointer) both travel in a
+                // 64-bit integer register, so the i64 signature matches the
+                // C prototype. Everything else stays private to the object.
+                let linkage = if export.is_some() { None } else { Some(Linkage::Internal) };
+                module.add_function(actual_func_name, func_type, linkage);
+            }
+        }
+
+        // Third pass: Generate function definitions
+        let mut user_main_fn: Option<inkwell::values::FunctionValue> = None;
+        for item in &prog.items {
+            if let Stmt::Func { name, params, body, .. } = item {
+                let actual_func_name = if name == "main" { "user_main" } else { name };
+                let func = module.get_function(actual_func_name).expect("declared in the second pass");
                 
                 if name == "main" {
                     user_main_fn = Some(func);
@@ -143,7 +224,7 @@ pub mod llvm {
                 // Generate function body
                 let mut func_initial_vals: HashMap<String, Expr> = HashMap::new();
                 for stmt in body {
-                    codegen_stmt(stmt, &context, &module, &builder, &printf, &mut func_locals, &func, i64_t, i32_t, &mut func_initial_vals, &mut extra_link_args);
+                    codegen_stmt(stmt, &context, &module, &builder, &printf, &mut func_locals, &func, i64_t, i32_t, &mut func_initial_vals, &mut extra_link_args, builtins, locs);
                 }
                 
                 // Default return 0 if not explicitly returned
@@ -154,10 +235,10 @@ pub mod llvm {
         // Position back to main entry for remaining code
         builder.position_at_end(entry);
 
-        // Third pass: Generate code for statements (non-function) sequentially
+        // Fourth pass: Generate code for statements (non-function) sequentially
         for item in &prog.items {
             if !matches!(item, Stmt::Func { .. }) {
-                codegen_stmt(item, &context, &module, &builder, &printf, &mut locals, &main_fn, i64_t, i32_t, &mut initial_vals, &mut extra_link_args);
+                codegen_stmt(item, &context, &module, &builder, &printf, &mut locals, &main_fn, i64_t, i32_t, &mut initial_vals, &mut extra_link_args, builtins, locs);
             }
         }
 
@@ -167,19 +248,24 @@ pub mod llvm {
         }
 
         // return 0
-        builder.build_return(Some(&i64_t.const_int(0, false)));
-
-        // write object file with specified target triple
-        let triple = inkwell::targets::TargetTriple::create(target_triple);
-        let target = Target::from_triple(&triple).map_err(|e| anyhow::anyhow!("target lookup failed for {}: {:?}", target_triple, e))?;
-        let tm = target.create_target_machine(&triple, "generic", "", OptimizationLevel::Default, inkwell::targets::RelocMode::Default, inkwell::targets::CodeModel::Default).ok_or_else(|| anyhow::anyhow!("failed to create target machine for {}", target_triple))?;
+        builder.build_return(Some(&i32_t.const_int(0, false)));
 
-        // dump IR for debugging
-        let _ = std::fs::write("/workspaces/Wheel/tmp.ll", module.print_to_string().to_string());
-        let obj_path = out_path.with_extension("o");
-        tm.write_to_file(&module, FileType::Object, &obj_path).map_err(|e| anyhow::anyhow!("write object failed: {:?}", e))?;
+        // `@llvm` blocks are whole definitions, linked in as modules of
+        // their own; internal Wheel functions are not visible to them
+        for (i, code) in prog.items.iter().filter_map(|s| match s {
+            Stmt::Inline { target: InlineTarget::Llvm, code } => Some(code),
+            _ => None,
+        }).enumerate() {
+            let name = format!("llvm_block_{}", i);
+            let buf = MemoryBuffer::create_from_memory_range_copy(code.as_bytes(), &name);
+            let block = context.create_module_from_ir(buf)
+                .map_err(|e| WheelError::codegen(format!("`@llvm` block {} is not valid IR: {}", i + 1, e.to_string().trim())))?;
+            module.link_in_module(block)
+                .map_err(|e| WheelError::codegen(format!("`@llvm` block {} could not be linked: {}", i + 1, e.to_string().trim())))?;
+        }
 
-        Ok(extra_link_args)
+        extra_link_args.extend(builtins.link_args());
+        Ok((module, extra_link_args))
     }
 
     fn codegen_stmt<'ctx>(
@@ -194,11 +280,29 @@ pub mod llvm {
         i32_t: inkwell::types::IntType<'ctx>,
         initial_vals: &mut HashMap<String, Expr>,
         extra_link_args: &mut Vec<String>,
+        builtins: &Builtins,
+        locs: Option<&LocIndex>,
     ) {
+        if let (Some(loc), Some(file_gv), Some(line_gv)) = (locs.and_then(|l| l.get(stmt)), module.get_global("wheel_debug_file"), module.get_global("wheel_debug_line")) {
+            let file = loc.file.as_ref().map_or_else(|| "<input>".to_string(), |f| f.display().to_string());
+            let name = format!("wheel_debug_path.{}", file);
+            let path = module.get_global(&name).unwrap_or_else(|| {
+                let mut bytes = file.into_bytes();
+                bytes.push(0);
+                let arr = context.const_string(&bytes, true);
+                let gv = module.add_global(arr.get_type(), None, &name);
+                gv.set_initializer(&arr);
+                gv.set_constant(true);
+                gv
+            });
+            let path = builder.build_ptr_to_int(path.as_pointer_value(), i64_t, "debug_path");
+            builder.build_store(file_gv.as_pointer_value(), path);
+            builder.build_store(line_gv.as_pointer_value(), i64_t.const_int(loc.span.line as u64, false));
+        }
         match stmt {
             Stmt::Use { lib } => {
-                // Handle library imports. Try to resolve local objects/shared libs
-                eprintln!("Using library: {}", lib);
+                // Handle library imports. Try to resolve local objects/shared
+                // libs; the caller reports what gets linked
                 let cwd = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
                 let candidates = [
                     cwd.join(format!("{}.o", lib)),
@@ -207,25 +311,25 @@ pub mod llvm {
                     cwd.join(format!("lib{}.so", lib)),
                     cwd.join(format!("{}", lib)),
                 ];
-                for cand in &candidates {
+                for (i, cand) in candidates.iter().enumerate() {
+                    // the name as written only when it names an object or
+                    // library (`foo.a`), not a program that happens to share it
+                    if i == candidates.len() - 1 && Path::new(lib).extension().is_none() {
+                        continue;
+                    }
                     if cand.exists() {
                         // Add absolute path to link args
-                        if let Ok(abs) = cand.canonicalize() {
-                            extra_link_args.push(abs.to_string_lossy().to_string());
-                            eprintln!("Will link with: {}", abs.display());
-                        } else {
-                            extra_link_args.push(cand.to_string_lossy().to_string());
-                            eprintln!("Will link with: {}", cand.display());
+                        match cand.canonicalize() {
+                            Ok(abs) => extra_link_args.push(abs.to_string_lossy().to_string()),
+                            Err(_) => extra_link_args.push(cand.to_string_lossy().to_string()),
                         }
                         // stop at first match
                         break;
                     }
                 }
             }
-            Stmt::Import { path } => {
-                // Handle file imports
-                eprintln!("Importing from: {}", path);
-            }
+            // imports were merged into the program by the front end
+            Stmt::Import { .. } => {}
             Stmt::Let { name, value, .. } => {
                     // support reversed form `let input() = Var;` where parser produced
                     // name == "input()" and value == Ident(var). In that case treat
@@ -234,13 +338,13 @@ pub mod llvm {
                     if name.contains('(') {
                         if let Expr::Ident(varname) = value {
                             if !locals.contains_key(varname) {
-                                let alloca = builder.build_alloca(i64_t, varname);
+                                let alloca = entry_alloca(context, main_fn, i64_t, varname);
                                 locals.insert(varname.clone(), alloca);
                             }
                             // record that varname is initialized from input()
                             initial_vals.insert(varname.clone(), Expr::Call { name: "input".to_string(), args: Vec::new() });
                             let input_expr = Expr::Call { name: "input".to_string(), args: Vec::new() };
-                            let ival = gen_expr(&input_expr, context, module, builder, locals, i64_t, initial_vals).into_int_value();
+                            let ival = gen_expr(&input_expr, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
                             if let Some(ptr) = locals.get(varname) {
                                 builder.build_store(*ptr, ival);
                             }
@@ -249,7 +353,7 @@ pub mod llvm {
                     }
                     let val = match value {
                             Expr::Int(_) | Expr::BinaryOp { .. } | Expr::Ident(_) | Expr::Call { .. } => {
-                                gen_expr(value, context, module, builder, locals, i64_t, initial_vals).into_int_value()
+                                gen_expr(value, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value()
                             }
                     Expr::Str(s) => {
                         // create global string and store pointer as integer
@@ -264,7 +368,7 @@ pub mod llvm {
                         if let Some(ptr_loc) = locals.get(name) {
                             builder.build_store(*ptr_loc, ptr_i64);
                         } else {
-                            let alloca = builder.build_alloca(i64_t, name);
+                            let alloca = entry_alloca(context, main_fn, i64_t, name);
                             locals.insert(name.clone(), alloca);
                             builder.build_store(alloca, ptr_i64);
                         }
@@ -276,15 +380,30 @@ pub mod llvm {
                     builder.build_store(*ptr, val);
                 } else {
                     // create alloca on demand for lets inside blocks and store
-                    let alloca = builder.build_alloca(i64_t, name);
+                    let alloca = entry_alloca(context, main_fn, i64_t, name);
                     locals.insert(name.clone(), alloca);
                     // record initial value expression so later code knows this name came from input/str
                     initial_vals.insert(name.clone(), value.clone());
                     builder.build_store(alloca, val);
                 }
             }
+            Stmt::Return(value) => {
+                let val = match value {
+                    Some(v) => gen_expr(v, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value(),
+                    None => i64_t.const_int(0, false),
+                };
+                // the entry function returns a C int
+                if main_fn.get_type().get_return_type() == Some(i32_t.into()) {
+                    builder.build_return(Some(&builder.build_int_truncate(val, i32_t, "ret_i32")));
+                } else {
+                    builder.build_return(Some(&val));
+                }
+                // anything after `return` goes into a block nothing jumps to
+                let dead = context.append_basic_block(*main_fn, "after_return");
+                builder.position_at_end(dead);
+            }
             Stmt::Assign { name, value } => {
-                let val = gen_expr(value, context, module, builder, locals, i64_t, initial_vals).into_int_value();
+                let val = gen_expr(value, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
                 if let Some(ptr) = locals.get(name) {
                     builder.build_store(*ptr, val);
                 }
@@ -305,9 +424,16 @@ pub mod llvm {
                         let fmt_ptr = builder.build_bitcast(fmt_g, context.i8_type().ptr_type(AddressSpace::default()), "fmt_s_cast").into_pointer_value();
                         builder.build_call(*printf, &[fmt_ptr.into(), ptr.into()], "call_printf");
                     }
+                    e if returns_str(e, initial_vals, builtins) => {
+                        let val = gen_expr(e, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
+                        let ptr = builder.build_int_to_ptr(val, context.i8_type().ptr_type(AddressSpace::default()), "str_ptr");
+                        let fmt_g = module.get_global("_fmt_s").unwrap().as_pointer_value();
+                        let fmt_ptr = builder.build_bitcast(fmt_g, context.i8_type().ptr_type(AddressSpace::default()), "fmt_s_cast").into_pointer_value();
+                        builder.build_call(*printf, &[fmt_ptr.into(), ptr.into()], "call_printf");
+                    }
                     _ => {
                         // For any other expression (int literal, binary op, ident, call), generate the expression and print as integer
-                        let val = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals).into_int_value();
+                        let val = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
                         let fmt_g = module.get_global("_fmt_ld").unwrap().as_pointer_value();
                         let fmt_ptr = builder.build_bitcast(fmt_g, context.i8_type().ptr_type(AddressSpace::default()), "fmt_ld_cast").into_pointer_value();
                         builder.build_call(*printf, &[fmt_ptr.into(), val.into()], "call_printf");
@@ -316,7 +442,7 @@ pub mod llvm {
             }
             Stmt::If { cond, then_body, else_body } => {
                 // evaluate condition
-                let cond_val = gen_expr(cond, context, module, builder, locals, i64_t, initial_vals).into_int_value();
+                let cond_val = gen_expr(cond, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
                 let zero = i64_t.const_int(0, false);
                 let cond_bool = builder.build_int_compare(inkwell::IntPredicate::NE, cond_val, zero, "ifcond");
 
@@ -328,7 +454,7 @@ pub mod llvm {
                 // then
                 builder.position_at_end(then_bb);
                 for s in then_body {
-                    codegen_stmt(s, context, module, builder, printf, locals, main_fn, i64_t, i32_t, initial_vals, extra_link_args);
+                    codegen_stmt(s, context, module, builder, printf, locals, main_fn, i64_t, i32_t, initial_vals, extra_link_args, builtins, locs);
                 }
                 builder.build_unconditional_branch(merge_bb);
 
@@ -336,7 +462,7 @@ pub mod llvm {
                 builder.position_at_end(else_bb);
                 if let Some(eb) = else_body {
                     for s in eb {
-                        codegen_stmt(s, context, module, builder, printf, locals, main_fn, i64_t, i32_t, initial_vals, extra_link_args);
+                        codegen_stmt(s, context, module, builder, printf, locals, main_fn, i64_t, i32_t, initial_vals, extra_link_args, builtins, locs);
                     }
                 }
                 builder.build_unconditional_branch(merge_bb);
@@ -350,13 +476,13 @@ pub mod llvm {
                 let after_bb = context.append_basic_block(*main_fn, "loopafter");
                 builder.build_unconditional_branch(loop_bb);
                 builder.position_at_end(loop_bb);
-                let cond_val = gen_expr(cond, context, module, builder, locals, i64_t, initial_vals).into_int_value();
+                let cond_val = gen_expr(cond, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
                 let zero = i64_t.const_int(0, false);
                 let cond_bool = builder.build_int_compare(inkwell::IntPredicate::NE, cond_val, zero, "whilecond");
                 builder.build_conditional_branch(cond_bool, body_bb, after_bb);
                 builder.position_at_end(body_bb);
                 for s in body {
-                    codegen_stmt(s, context, module, builder, printf, locals, main_fn, i64_t, i32_t, initial_vals, extra_link_args);
+                    codegen_stmt(s, context, module, builder, printf, locals, main_fn, i64_t, i32_t, initial_vals, extra_link_args, builtins, locs);
                 }
                 builder.build_unconditional_branch(loop_bb);
                 builder.position_at_end(after_bb);
@@ -367,7 +493,7 @@ pub mod llvm {
                 let loop_var = builder.build_alloca(i64_t, &format!("for_{}", var));
 
                 // initialize with start
-                let start_val = gen_expr(start, context, module, builder, locals, i64_t, initial_vals).into_int_value();
+                let start_val = gen_expr(start, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
                 builder.build_store(loop_var, start_val);
 
                 // create blocks
@@ -380,7 +506,7 @@ pub mod llvm {
                 // check condition
                 builder.position_at_end(check_bb);
                 let cur = builder.build_load(i64_t, loop_var, &format!("load_{}", var)).into_int_value();
-                let end_val = gen_expr(end, context, module, builder, locals, i64_t, initial_vals).into_int_value();
+                let end_val = gen_expr(end, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
                 let cond = builder.build_int_compare(IntPredicate::SLT, cur, end_val, "for_cond");
                 builder.build_conditional_branch(cond, body_bb, after_bb);
 
@@ -389,7 +515,7 @@ pub mod llvm {
                 let mut body_locals = locals.clone();
                 body_locals.insert(var.clone(), loop_var);
                 for s in body {
-                    codegen_stmt(s, context, module, builder, printf, &mut body_locals, main_fn, i64_t, i32_t, initial_vals, extra_link_args);
+                    codegen_stmt(s, context, module, builder, printf, &mut body_locals, main_fn, i64_t, i32_t, initial_vals, extra_link_args, builtins, locs);
                 }
 
                 // increment
@@ -401,18 +527,111 @@ pub mod llvm {
 
                 builder.position_at_end(after_bb);
             }
-            Stmt::ArrayAssign { .. } => {
-                // placeholder for array assignment
-                eprintln!("Array assignment not yet fully implemented");
-            }
-            Stmt::StructDef { .. } => {
-                // placeholder for struct definition
-                eprintln!("Struct definition not yet fully implemented");
+            // not generated yet; `check::check_compiled` warns about it
+            Stmt::ArrayAssign { .. } => {}
+            // a definition generates no code
+            Stmt::StructDef { .. } => {}
+            // a call made for its effect, `vec_push(v, 1);` or
+            // `irq_register(32, "on_timer");`
+            Stmt::Expr(e) => {
+                gen_expr(e, context, module, builder, locals, i64_t, initial_vals, builtins);
             }
             _ => {}
         }
     }
 
+    /// Whether `e` is a call, or a variable initialized by one or by a string
+    /// literal, that yields a string: `env_get`, `time_format` or a builtin
+    /// registered as returning `str`.
+    fn returns_str(e: &Expr, initial_vals: &HashMap<String, Expr>, builtins: &Builtins) -> bool {
+        match e {
+            Expr::Call { name, .. } => matches!(name.as_str(), "env_get" | "time_format") || builtins.get(name).is_some_and(|b| b.returns == Some(ValueType::Str)),
+            Expr::Ident(id) => initial_vals.get(id).is_some_and(|v| matches!(v, Expr::Str(_)) || matches!(v, Expr::Call { .. }) && returns_str(v, initial_vals, builtins)),
+            _ => false,
+        }
+    }
+
+    /// Arguments of an SDL drawing call besides the optional window handle.
+    fn sdl_window_arity(name: &str) -> Option<usize> {
+        Some(match name {
+            "sdl_draw_pixel" => 5,
+            "sdl_draw_rect" => 7,
+            "sdl_clear" => 3,
+            "sdl_load_image" => 1,
+            "sdl_destroy_window" => 0,
+            _ => return None,
+        })
+    }
+
+    /// LLVM's `x86_intrcc` calling convention.
+    const X86_INTR_CALL_CONV: u32 = 83;
+
+    /// `wheel_irq_<vector>_<handler>`, the interrupt handler `irq_register`
+    /// installs: it calls `handler` with the error code (0 for vectors without
+    /// one), and its calling convention saves every register it touches and
+    /// returns with `iretq`. The builder is left where it was.
+    fn irq_stub<'ctx>(
+        vector: i64,
+        handler: FunctionValue<'ctx>,
+        context: &'ctx Context,
+        module: &inkwell::module::Module<'ctx>,
+        builder: &inkwell::builder::Builder<'ctx>,
+        i64_t: inkwell::types::IntType<'ctx>,
+    ) -> FunctionValue<'ctx> {
+        let name = format!("wheel_irq_{}_{}", vector, handler.get_name().to_string_lossy());
+        if let Some(stub) = module.get_function(&name) {
+            return stub;
+        }
+        // the frame the CPU pushed (rip, cs, rflags, rsp, ss), then the error code
+        let frame_t = i64_t.array_type(5);
+        let mut params: Vec<BasicMetadataTypeEnum> = vec![frame_t.ptr_type(AddressSpace::default()).into()];
+        let has_error = crate::codegen::IRQ_ERROR_CODE_VECTORS.contains(&vector);
+        if has_error {
+            params.push(i64_t.into());
+        }
+        let stub = module.add_function(&name, context.void_type().fn_type(&params, false), Some(Linkage::Internal));
+        stub.set_call_conventions(X86_INTR_CALL_CONV);
+        let byval = context.create_type_attribute(Attribute::get_named_enum_kind_id("byval"), frame_t.as_any_type_enum());
+        stub.add_attribute(AttributeLoc::Param(0), byval);
+
+        let resume = builder.get_insert_block();
+        builder.position_at_end(context.append_basic_block(stub, "entry"));
+        let error = match stub.get_nth_param(1) {
+            Some(code) => code,
+            None => i64_t.const_int(0, false).into(),
+        };
+        let args: Vec<BasicMetadataValueEnum> = (0..handler.count_params()).map(|_| error.into()).collect();
+        builder.build_call(handler, &args, "call_handler");
+        builder.build_return(None);
+        if let Some(block) = resume {
+            builder.position_at_end(block);
+        }
+        stub
+    }
+
+    /// Call the `wheel_<name>` channel function of threads.o; they all take
+    /// and return int64s.
+    fn chan_call<'ctx>(
+        name: &str,
+        args: &[Expr],
+        context: &'ctx Context,
+        module: &inkwell::module::Module<'ctx>,
+        builder: &inkwell::builder::Builder<'ctx>,
+        locals: &HashMap<String, PointerValue<'ctx>>,
+        i64_t: inkwell::types::IntType<'ctx>,
+        initial_vals: &HashMap<String, Expr>,
+        builtins: &Builtins,
+    ) -> BasicValueEnum<'ctx> {
+        let runtime_name = format!("wheel_{}", name);
+        let fn_ty = i64_t.fn_type(&vec![i64_t.into(); args.len()], false);
+        let func = match module.get_function(&runtime_name) {
+            Some(f) => f,
+            None => module.add_function(&runtime_name, fn_ty, None),
+        };
+        let args: Vec<BasicMetadataValueEnum> = args.iter().map(|a| gen_expr(a, context, module, builder, locals, i64_t, initial_vals, builtins).into()).collect();
+        builder.build_call(func, &args, &format!("call_{}", name)).try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+    }
+
     fn gen_expr<'ctx>(
         e: &Expr,
         context: &'ctx Context,
@@ -421,6 +640,7 @@ pub mod llvm {
         locals: &HashMap<String, PointerValue<'ctx>>,
         i64_t: inkwell::types::IntType<'ctx>,
         initial_vals: &HashMap<String, Expr>,
+        builtins: &Builtins,
     ) -> BasicValueEnum<'ctx> {
         match e {
             Expr::Int(v) => i64_t.const_int(*v as u64, true).into(),
@@ -428,7 +648,7 @@ pub mod llvm {
                 if let Some(ptr) = locals.get(name) {
                         builder.build_load(i64_t, *ptr, &format!("load_{}", name))
                 } else {
-                    i64_t.const_int(0, false).into()
+                    i64_t.const_int(crate::builtins::constant(name).unwrap_or(0) as u64, true).into()
                 }
             }
             Expr::ArrayAccess { .. } => {
@@ -461,20 +681,23 @@ pub mod llvm {
                     let left_is_str = is_string_like(&*left);
                     let right_is_str = is_string_like(&*right);
                     if left_is_str || right_is_str {
-                        // Converte ambos os lados para inteiro com `atoi` e compara os inteiros.
-                        let lval = gen_expr(&*left, context, module, builder, locals, i64_t, initial_vals).into_int_value();
-                        let rval = gen_expr(&*right, context, module, builder, locals, i64_t, initial_vals).into_int_value();
-                        let i8ptr = context.i8_type().ptr_type(AddressSpace::default());
-                        let lptr = builder.build_int_to_ptr(lval, i8ptr, "lptr");
-                        let rptr = builder.build_int_to_ptr(rval, i8ptr, "rptr");
+                        // Converte os lados que sao strings para inteiro com `atoi` e compara os inteiros;
+                        // um valor de `input()` ja e inteiro.
                         let atoi_fn = module.get_function("atoi").unwrap_or_else(|| {
                             let atoi_ty = context.i32_type().fn_type(&[context.i8_type().ptr_type(AddressSpace::default()).into()], false);
                             module.add_function("atoi", atoi_ty, None)
                         });
-                        let lcall = builder.build_call(atoi_fn, &[lptr.into()], "call_atoi_l");
-                        let rcall = builder.build_call(atoi_fn, &[rptr.into()], "call_atoi_r");
-                        let li = lcall.try_as_basic_value().left().unwrap().into_int_value();
-                        let ri = rcall.try_as_basic_value().left().unwrap().into_int_value();
+                        let to_int = |ex: &Expr, is_str: bool, side: &str| {
+                            let val = gen_expr(ex, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
+                            if !is_str {
+                                return val;
+                            }
+                            let ptr = builder.build_int_to_ptr(val, context.i8_type().ptr_type(AddressSpace::default()), &format!("{}ptr", side));
+                            let call = builder.build_call(atoi_fn, &[ptr.into()], &format!("call_atoi_{}", side));
+                            builder.build_int_s_extend(call.try_as_basic_value().left().unwrap().into_int_value(), i64_t, &format!("atoi_{}", side))
+                        };
+                        let li = to_int(left, left_is_str, "l");
+                        let ri = to_int(right, right_is_str, "r");
                         let cmpi = match op {
                             BinOp::EqEq => builder.build_int_compare(IntPredicate::EQ, li, ri, "eqi"),
                             BinOp::NotEq => builder.build_int_compare(IntPredicate::NE, li, ri, "nei"),
@@ -486,8 +709,8 @@ pub mod llvm {
                 }
 
                 // compute operands
-                let mut l = gen_expr(left, context, module, builder, locals, i64_t, initial_vals).into_int_value();
-                let mut r = gen_expr(right, context, module, builder, locals, i64_t, initial_vals).into_int_value();
+                let mut l = gen_expr(left, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
+                let mut r = gen_expr(right, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
 
                 // if arithmetic operation and operands are string-like, convert with atoi
                 let is_arith = matches!(op, BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div);
@@ -602,7 +825,29 @@ pub mod llvm {
                         // Extend i32 result to i64
                         builder.build_int_z_extend(atoi_val, i64_t, "atoi_to_i64").into()
                     }
-                    
+
+                    // embed("file"): bytes baked into a constant global, paths resolved by crate::assets
+                    "embed" if args.len() == 1 => {
+                        let Expr::Str(path) = &args[0] else { return i64_t.const_int(0, false).into() };
+                        let name = crate::assets::label(path);
+                        let gv = module.get_global(&name).unwrap_or_else(|| {
+                            let data = std::fs::read(path).unwrap_or_default();
+                            let arr = context.const_string(&data, false);
+                            let gv = module.add_global(arr.get_type(), None, &name);
+                            gv.set_initializer(&arr);
+                            gv.set_constant(true);
+                            gv
+                        });
+                        builder.build_ptr_to_int(gv.as_pointer_value(), i64_t, "embedptrtoi").into()
+                    }
+                    "embed_len" if args.len() == 1 => {
+                        let len = match &args[0] {
+                            Expr::Str(path) => crate::assets::embedded_len(path),
+                            _ => 0,
+                        };
+                        i64_t.const_int(len, false).into()
+                    }
+
                     // SDL Library functions
                     "sdl_init" if args.len() == 0 => {
                         let fn_ty = i64_t.fn_type(&[], false);
@@ -618,57 +863,39 @@ pub mod llvm {
                             Some(f) => f,
                             None => module.add_function("sdl_create_window", fn_ty, None),
                         };
-                        let w = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
-                        let h = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals);
-                        let t = gen_expr(&args[2], context, module, builder, locals, i64_t, initial_vals);
+                        let w = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let h = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let t = gen_expr(&args[2], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[w.into(), h.into(), t.into()], "call_sdl_create_window").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
-                    "sdl_draw_pixel" if args.len() == 5 => {
-                        let fn_ty = i64_t.fn_type(&[i64_t.into(), i64_t.into(), i64_t.into(), i64_t.into(), i64_t.into()], false);
-                        let func = match module.get_function("sdl_draw_pixel") {
-                            Some(f) => f,
-                            None => module.add_function("sdl_draw_pixel", fn_ty, None),
-                        };
-                        let x = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
-                        let y = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals);
-                        let r = gen_expr(&args[2], context, module, builder, locals, i64_t, initial_vals);
-                        let g = gen_expr(&args[3], context, module, builder, locals, i64_t, initial_vals);
-                        let b = gen_expr(&args[4], context, module, builder, locals, i64_t, initial_vals);
-                        builder.build_call(func, &[x.into(), y.into(), r.into(), g.into(), b.into()], "call_sdl_draw_pixel").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
-                    }
-                    "sdl_draw_rect" if args.len() == 7 => {
-                        let fn_ty = i64_t.fn_type(&[i64_t.into(), i64_t.into(), i64_t.into(), i64_t.into(), i64_t.into(), i64_t.into(), i64_t.into()], false);
-                        let func = match module.get_function("sdl_draw_rect") {
-                            Some(f) => f,
-                            None => module.add_function("sdl_draw_rect", fn_ty, None),
-                        };
-                        let x = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
-                        let y = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals);
-                        let w = gen_expr(&args[2], context, module, builder, locals, i64_t, initial_vals);
-                        let h = gen_expr(&args[3], context, module, builder, locals, i64_t, initial_vals);
-                        let r = gen_expr(&args[4], context, module, builder, locals, i64_t, initial_vals);
-                        let g = gen_expr(&args[5], context, module, builder, locals, i64_t, initial_vals);
-                        let b = gen_expr(&args[6], context, module, builder, locals, i64_t, initial_vals);
-                        builder.build_call(func, &[x.into(), y.into(), w.into(), h.into(), r.into(), g.into(), b.into()], "call_sdl_draw_rect").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
-                    }
-                    "sdl_clear" if args.len() == 3 => {
-                        let fn_ty = i64_t.fn_type(&[i64_t.into(), i64_t.into(), i64_t.into()], false);
-                        let func = match module.get_function("sdl_clear") {
+                    // Drawing calls take the handle from `sdl_create_window` first; without
+                    // it they go to the most recently created window (handle 0)
+                    "sdl_draw_pixel" | "sdl_draw_rect" | "sdl_clear" | "sdl_destroy_window" | "sdl_load_image"
+                        if sdl_window_arity(name).is_some_and(|n| args.len() == n || args.len() == n + 1) =>
+                    {
+                        let n = sdl_window_arity(name).unwrap_or(0);
+                        let fn_ty = i64_t.fn_type(&vec![i64_t.into(); n + 1], false);
+                        let func = match module.get_function(name) {
                             Some(f) => f,
-                            None => module.add_function("sdl_clear", fn_ty, None),
+                            None => module.add_function(name, fn_ty, None),
+                        };
+                        let window = if args.len() > n {
+                            gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins)
+                        } else {
+                            i64_t.const_int(0, false).into()
                         };
-                        let r = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
-                        let g = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals);
-                        let b = gen_expr(&args[2], context, module, builder, locals, i64_t, initial_vals);
-                        builder.build_call(func, &[r.into(), g.into(), b.into()], "call_sdl_clear").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                        let mut argv: Vec<BasicMetadataValueEnum> = vec![window.into()];
+                        argv.extend(args[args.len() - n..].iter().map(|a| BasicMetadataValueEnum::from(gen_expr(a, context, module, builder, locals, i64_t, initial_vals, builtins))));
+                        builder.build_call(func, &argv, &format!("call_{}", name)).try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
-                    "sdl_present" if args.len() == 0 => {
-                        let fn_ty = i64_t.fn_type(&[], false);
-                        let func = match module.get_function("sdl_present") {
+                    "sdl_draw_image" if args.len() == 5 => {
+                        let fn_ty = i64_t.fn_type(&[i64_t.into(); 5], false);
+                        let func = match module.get_function("sdl_draw_image") {
                             Some(f) => f,
-                            None => module.add_function("sdl_present", fn_ty, None),
+                            None => module.add_function("sdl_draw_image", fn_ty, None),
                         };
-                        builder.build_call(func, &[], "call_sdl_present").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                        let argv: Vec<BasicMetadataValueEnum> = args.iter().map(|a| gen_expr(a, context, module, builder, locals, i64_t, initial_vals, builtins).into()).collect();
+                        builder.build_call(func, &argv, "call_sdl_draw_image").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     "sdl_poll_event" if args.len() == 0 => {
                         let fn_ty = i64_t.fn_type(&[], false);
@@ -678,23 +905,33 @@ pub mod llvm {
                         };
                         builder.build_call(func, &[], "call_sdl_poll_event").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
+                    "sdl_get_mouse_x" | "sdl_get_mouse_y" if args.is_empty() => {
+                        let fn_ty = i64_t.fn_type(&[], false);
+                        let func = match module.get_function(name) {
+                            Some(f) => f,
+                            None => module.add_function(name, fn_ty, None),
+                        };
+                        builder.build_call(func, &[], &format!("call_{}", name)).try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
+                    // `sdl_present` always names the window it shows
+                    "sdl_present" | "sdl_key_down" | "sdl_mouse_pressed" if args.len() == 1 => {
+                        let fn_ty = i64_t.fn_type(&[i64_t.into()], false);
+                        let func = match module.get_function(name) {
+                            Some(f) => f,
+                            None => module.add_function(name, fn_ty, None),
+                        };
+                        let code = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        builder.build_call(func, &[code.into()], &format!("call_{}", name)).try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
                     "sdl_delay" if args.len() == 1 => {
                         let fn_ty = i64_t.fn_type(&[i64_t.into()], false);
                         let func = match module.get_function("sdl_delay") {
                             Some(f) => f,
                             None => module.add_function("sdl_delay", fn_ty, None),
                         };
-                        let ms = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let ms = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[ms.into()], "call_sdl_delay").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
-                    "sdl_destroy_window" if args.len() == 0 => {
-                        let fn_ty = i64_t.fn_type(&[], false);
-                        let func = match module.get_function("sdl_destroy_window") {
-                            Some(f) => f,
-                            None => module.add_function("sdl_destroy_window", fn_ty, None),
-                        };
-                        builder.build_call(func, &[], "call_sdl_destroy_window").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
-                    }
                     "sdl_quit" if args.len() == 0 => {
                         let fn_ty = i64_t.fn_type(&[], false);
                         let func = match module.get_function("sdl_quit") {
@@ -711,7 +948,7 @@ pub mod llvm {
                             Some(f) => f,
                             None => module.add_function("port_read_byte", fn_ty, None),
                         };
-                        let port = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let port = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[port.into()], "call_port_read_byte").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     "port_write_byte" if args.len() == 2 => {
@@ -720,8 +957,8 @@ pub mod llvm {
                             Some(f) => f,
                             None => module.add_function("port_write_byte", fn_ty, None),
                         };
-                        let port = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
-                        let val = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals);
+                        let port = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let val = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[port.into(), val.into()], "call_port_write_byte").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     "getpid" if args.len() == 0 => {
@@ -738,7 +975,7 @@ pub mod llvm {
                             Some(f) => f,
                             None => module.add_function("wheel_sleep", fn_ty, None),
                         };
-                        let secs = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let secs = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[secs.into()], "call_sleep").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     "time_now" if args.len() == 0 => {
@@ -749,6 +986,178 @@ pub mod llvm {
                         };
                         builder.build_call(func, &[], "call_time_now").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
+                    "time_millis" | "time_nanos" if args.is_empty() => {
+                        let fn_ty = i64_t.fn_type(&[], false);
+                        let runtime_name = format!("wheel_{}", name);
+                        let func = match module.get_function(&runtime_name) {
+                            Some(f) => f,
+                            None => module.add_function(&runtime_name, fn_ty, None),
+                        };
+                        builder.build_call(func, &[], &format!("call_{}", name)).try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
+                    // Calendar time in UTC (os_wrappers.o); strings travel as pointers in an i64
+                    "time_format" | "time_parse" if args.len() == 2 => {
+                        let fn_ty = i64_t.fn_type(&[i64_t.into(), i64_t.into()], false);
+                        let runtime_name = format!("wheel_{}", name);
+                        let func = match module.get_function(&runtime_name) {
+                            Some(f) => f,
+                            None => module.add_function(&runtime_name, fn_ty, None),
+                        };
+                        let first = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let fmt = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        builder.build_call(func, &[first.into(), fmt.into()], &format!("call_{}", name)).try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
+                    "time_year" | "time_month" | "time_day" | "time_hour" if args.len() == 1 => {
+                        let fn_ty = i64_t.fn_type(&[i64_t.into()], false);
+                        let runtime_name = format!("wheel_{}", name);
+                        let func = match module.get_function(&runtime_name) {
+                            Some(f) => f,
+                            None => module.add_function(&runtime_name, fn_ty, None),
+                        };
+                        let epoch = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        builder.build_call(func, &[epoch.into()], &format!("call_{}", name)).try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
+                    "env_get" if args.len() == 1 => {
+                        let fn_ty = i64_t.fn_type(&[i64_t.into()], false);
+                        let func = match module.get_function("wheel_env_get") {
+                            Some(f) => f,
+                            None => module.add_function("wheel_env_get", fn_ty, None),
+                        };
+                        let var = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        builder.build_call(func, &[var.into()], "call_env_get").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
+                    "env_set" if args.len() == 2 => {
+                        let fn_ty = i64_t.fn_type(&[i64_t.into(), i64_t.into()], false);
+                        let func = match module.get_function("wheel_env_set") {
+                            Some(f) => f,
+                            None => module.add_function("wheel_env_set", fn_ty, None),
+                        };
+                        let var = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let value = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        builder.build_call(func, &[var.into(), value.into()], "call_env_set").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
+
+                    // Threads runtime (threads.o): the function to run is
+                    // named by a string literal, checked by `check_program`,
+                    // and passed to the runtime as a pointer
+                    "thread_spawn" if matches!(args.as_slice(), [Expr::Str(_)] | [Expr::Str(_), _]) => {
+                        let Expr::Str(target) = &args[0] else { unreachable!() };
+                        let target = if target == "main" { "user_main" } else { target.as_str() };
+                        let Some(target) = module.get_function(target) else {
+                            return i64_t.const_int(0, false).into(); // not a function, `bad-spawn`
+                        };
+                        let fn_ty = i64_t.fn_type(&[i64_t.into(), i64_t.into()], false);
+                        let func = match module.get_function("wheel_thread_spawn") {
+                            Some(f) => f,
+                            None => module.add_function("wheel_thread_spawn", fn_ty, None),
+                        };
+                        let fn_ptr = builder.build_ptr_to_int(target.as_global_value().as_pointer_value(), i64_t, "fn_ptr");
+                        let arg = match args.get(1) {
+                            Some(arg) => gen_expr(arg, context, module, builder, locals, i64_t, initial_vals, builtins),
+                            None => i64_t.const_int(0, false).into(),
+                        };
+                        builder.build_call(func, &[fn_ptr.into(), arg.into()], "call_thread_spawn").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
+                    "thread_join" if args.len() == 1 => {
+                        let fn_ty = i64_t.fn_type(&[i64_t.into()], false);
+                        let func = match module.get_function("wheel_thread_join") {
+                            Some(f) => f,
+                            None => module.add_function("wheel_thread_join", fn_ty, None),
+                        };
+                        let handle = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        builder.build_call(func, &[handle.into()], "call_thread_join").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
+                    "mutex_new" if args.is_empty() => {
+                        let fn_ty = i64_t.fn_type(&[], false);
+                        let func = match module.get_function("wheel_mutex_new") {
+                            Some(f) => f,
+                            None => module.add_function("wheel_mutex_new", fn_ty, None),
+                        };
+                        builder.build_call(func, &[], "call_mutex_new").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
+                    "mutex_lock" | "mutex_unlock" if args.len() == 1 => {
+                        let fn_ty = i64_t.fn_type(&[i64_t.into()], false);
+                        let runtime_name = format!("wheel_{}", name);
+                        let func = match module.get_function(&runtime_name) {
+                            Some(f) => f,
+                            None => module.add_function(&runtime_name, fn_ty, None),
+                        };
+                        let mutex = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        builder.build_call(func, &[mutex.into()], &format!("call_{}", name)).try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
+
+                    "chan_new" | "chan_recv" if args.len() == 1 => chan_call(name, args, context, module, builder, locals, i64_t, initial_vals, builtins),
+                    "chan_send" | "chan_try_send" | "chan_try_recv" if args.len() == 2 => chan_call(name, args, context, module, builder, locals, i64_t, initial_vals, builtins),
+
+                    // Atomics work on the 8-byte cell at an address, e.g. one
+                    // from `mem_alloc(8)`, and are sequentially consistent
+                    "atomic_load" if args.len() == 1 => {
+                        let addr = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
+                        let cell = builder.build_int_to_ptr(addr, i64_t.ptr_type(AddressSpace::default()), "atomic_cell");
+                        let value = builder.build_load(i64_t, cell, "atomic_load");
+                        let load = value.as_instruction_value().unwrap();
+                        load.set_alignment(8).unwrap();
+                        load.set_atomic_ordering(AtomicOrdering::SequentiallyConsistent).unwrap();
+                        value
+                    }
+                    "atomic_store" if args.len() == 2 => {
+                        let addr = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
+                        let cell = builder.build_int_to_ptr(addr, i64_t.ptr_type(AddressSpace::default()), "atomic_cell");
+                        let value = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let store = builder.build_store(cell, value);
+                        store.set_alignment(8).unwrap();
+                        store.set_atomic_ordering(AtomicOrdering::SequentiallyConsistent).unwrap();
+                        value
+                    }
+                    // returns the value before the addition, like `fetch_add`
+                    "atomic_add" if args.len() == 2 => {
+                        let addr = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
+                        let cell = builder.build_int_to_ptr(addr, i64_t.ptr_type(AddressSpace::default()), "atomic_cell");
+                        let delta = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
+                        builder.build_atomicrmw(AtomicRMWBinOp::Add, cell, delta, AtomicOrdering::SequentiallyConsistent).unwrap().into()
+                    }
+
+                    // Memory-mapped device registers: 32-bit volatile
+                    // accesses, which LLVM never removes, merges or reorders
+                    // against each other
+                    "mmio_read" if args.len() == 1 => {
+                        let i32_t = context.i32_type();
+                        let addr = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
+                        let reg = builder.build_int_to_ptr(addr, i32_t.ptr_type(AddressSpace::default()), "mmio_reg");
+                        let value = builder.build_load(i32_t, reg, "mmio_read");
+                        value.as_instruction_value().unwrap().set_volatile(true).unwrap();
+                        builder.build_int_z_extend(value.into_int_value(), i64_t, "mmio_value").into()
+                    }
+                    "mmio_write" if args.len() == 2 => {
+                        let i32_t = context.i32_type();
+                        let addr = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
+                        let reg = builder.build_int_to_ptr(addr, i32_t.ptr_type(AddressSpace::default()), "mmio_reg");
+                        let value = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
+                        let store = builder.build_store(reg, builder.build_int_truncate(value, i32_t, "mmio_value"));
+                        store.set_volatile(true).unwrap();
+                        i64_t.const_int(0, false).into()
+                    }
+
+                    // Interrupts (hwio.o): the handler is named by a string
+                    // literal and the vector is a literal, both checked by
+                    // `check_program`; the runtime gets the address of the
+                    // stub that enters the handler
+                    "irq_register" if matches!(args.as_slice(), [Expr::Int(_), Expr::Str(_)]) => {
+                        let [Expr::Int(vector), Expr::Str(target)] = args.as_slice() else { unreachable!() };
+                        let target = if target == "main" { "user_main" } else { target.as_str() };
+                        let Some(handler) = module.get_function(target) else {
+                            return i64_t.const_int(0, false).into(); // not a function, `bad-irq`
+                        };
+                        let stub = irq_stub(*vector, handler, context, module, builder, i64_t);
+                        let fn_ty = i64_t.fn_type(&[i64_t.into(), i64_t.into()], false);
+                        let func = match module.get_function("wheel_irq_register") {
+                            Some(f) => f,
+                            None => module.add_function("wheel_irq_register", fn_ty, None),
+                        };
+                        let stub_ptr = builder.build_ptr_to_int(stub.as_global_value().as_pointer_value(), i64_t, "irq_stub");
+                        let vector = i64_t.const_int(*vector as u64, false);
+                        builder.build_call(func, &[vector.into(), stub_ptr.into()], "call_irq_register").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
                     
                     // Luck library functions - random number generation
                     "luck_random" if args.len() == 1 => {
@@ -757,7 +1166,7 @@ pub mod llvm {
                             Some(f) => f,
                             None => module.add_function("luck_random", fn_ty, None),
                         };
-                        let max = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let max = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[max.into()], "call_luck_random").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     "luck_random_range" if args.len() == 2 => {
@@ -766,17 +1175,48 @@ pub mod llvm {
                             Some(f) => f,
                             None => module.add_function("luck_random_range", fn_ty, None),
                         };
-                        let min = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
-                        let max = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals);
+                        let min = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let max = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[min.into(), max.into()], "call_luck_random_range").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
+                    "luck_seed" if args.len() == 1 => {
+                        let fn_ty = context.void_type().fn_type(&[i64_t.into()], false);
+                        let func = match module.get_function("luck_seed") {
+                            Some(f) => f,
+                            None => module.add_function("luck_seed", fn_ty, None),
+                        };
+                        let seed = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        builder.build_call(func, &[seed.into()], "call_luck_seed");
+                        i64_t.const_int(0, false).into()
+                    }
+                    "luck_random_float" if args.is_empty() => {
+                        let fn_ty = i64_t.fn_type(&[], false);
+                        let func = match module.get_function("luck_random_float") {
+                            Some(f) => f,
+                            None => module.add_function("luck_random_float", fn_ty, None),
+                        };
+                        builder.build_call(func, &[], "call_luck_random_float").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
                     
                     // Memory management functions
+                    // `--debug-alloc`: the checking allocator, told where the call is
+                    "mem_alloc" | "mem_free" if args.len() == 1 && module.get_global("wheel_debug_line").is_some() => {
+                        let symbol = if name == "mem_alloc" { "wheel_debug_alloc" } else { "wheel_debug_free" };
+                        let fn_ty = i64_t.fn_type(&[i64_t.into(); 3], false);
+                        let func = module.get_function(symbol).unwrap_or_else(|| module.add_function(symbol, fn_ty, None));
+                        let arg = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let mut argv: Vec<BasicMetadataValueEnum> = vec![arg.into()];
+                        for global in ["wheel_debug_file", "wheel_debug_line"] {
+                            let gv = module.get_global(global).map(|g| g.as_pointer_value());
+                            argv.extend(gv.map(|gv| BasicMetadataValueEnum::from(builder.build_load(i64_t, gv, global))));
+                        }
+                        builder.build_call(func, &argv, &format!("call_{}", symbol)).try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
+                    }
                     "mem_alloc" if args.len() == 1 => {
                         let i8_ptr = context.i8_type().ptr_type(inkwell::AddressSpace::default());
                         let fn_ty = i8_ptr.fn_type(&[i64_t.into()], false);
                         let func = module.get_function("mem_alloc").unwrap_or_else(|| module.add_function("mem_alloc", fn_ty, None));
-                        let size = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let size = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         let ptr_result = builder.build_call(func, &[size.into()], "call_mem_alloc").try_as_basic_value().left().unwrap();
                         if let inkwell::values::BasicValueEnum::PointerValue(ptr) = ptr_result {
                             builder.build_ptr_to_int(ptr, i64_t, "ptr_to_i64").into()
@@ -788,7 +1228,7 @@ pub mod llvm {
                         let i8_ptr = context.i8_type().ptr_type(inkwell::AddressSpace::default());
                         let fn_ty = context.void_type().fn_type(&[i8_ptr.into()], false);
                         let func = module.get_function("mem_free").unwrap_or_else(|| module.add_function("mem_free", fn_ty, None));
-                        let ptr = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let ptr = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[ptr.into()], "call_mem_free");
                         i64_t.const_int(0, false).into()
                     }
@@ -807,14 +1247,14 @@ pub mod llvm {
                     "io_read_port" if args.len() == 1 => {
                         let fn_ty = i64_t.fn_type(&[i64_t.into()], false);
                         let func = module.get_function("io_read_port").unwrap_or_else(|| module.add_function("io_read_port", fn_ty, None));
-                        let port = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let port = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[port.into()], "call_io_read_port").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     "io_write_port" if args.len() == 2 => {
                         let fn_ty = context.void_type().fn_type(&[i64_t.into(), i64_t.into()], false);
                         let func = module.get_function("io_write_port").unwrap_or_else(|| module.add_function("io_write_port", fn_ty, None));
-                        let port = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
-                        let value = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals);
+                        let port = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let value = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[port.into(), value.into()], "call_io_write_port");
                         i64_t.const_int(0, false).into()
                     }
@@ -842,13 +1282,13 @@ pub mod llvm {
                         let i8_ptr = context.i8_type().ptr_type(inkwell::AddressSpace::default());
                         let fn_ty = i64_t.fn_type(&[i8_ptr.into()], false);
                         let func = module.get_function("fs_open").unwrap_or_else(|| module.add_function("fs_open", fn_ty, None));
-                        let device = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let device = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[device.into()], "call_fs_open").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     "fs_close" if args.len() == 1 => {
                         let fn_ty = context.void_type().fn_type(&[i64_t.into()], false);
                         let func = module.get_function("fs_close").unwrap_or_else(|| module.add_function("fs_close", fn_ty, None));
-                        let handle = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let handle = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[handle.into()], "call_fs_close");
                         i64_t.const_int(0, false).into()
                     }
@@ -856,18 +1296,18 @@ pub mod llvm {
                         let i8_ptr = context.i8_type().ptr_type(inkwell::AddressSpace::default());
                         let fn_ty = i64_t.fn_type(&[i64_t.into(), i64_t.into(), i8_ptr.into()], false);
                         let func = module.get_function("fs_read_block").unwrap_or_else(|| module.add_function("fs_read_block", fn_ty, None));
-                        let handle = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
-                        let block = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals);
-                        let buffer = gen_expr(&args[2], context, module, builder, locals, i64_t, initial_vals);
+                        let handle = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let block = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let buffer = gen_expr(&args[2], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[handle.into(), block.into(), buffer.into()], "call_fs_read_block").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     "fs_write_block" if args.len() == 3 => {
                         let i8_ptr = context.i8_type().ptr_type(inkwell::AddressSpace::default());
                         let fn_ty = i64_t.fn_type(&[i64_t.into(), i64_t.into(), i8_ptr.into()], false);
                         let func = module.get_function("fs_write_block").unwrap_or_else(|| module.add_function("fs_write_block", fn_ty, None));
-                        let handle = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
-                        let block = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals);
-                        let buffer = gen_expr(&args[2], context, module, builder, locals, i64_t, initial_vals);
+                        let handle = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let block = gen_expr(&args[1], context, module, builder, locals, i64_t, initial_vals, builtins);
+                        let buffer = gen_expr(&args[2], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[handle.into(), block.into(), buffer.into()], "call_fs_write_block").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     
@@ -882,19 +1322,19 @@ pub mod llvm {
                         let i8_ptr = context.i8_type().ptr_type(inkwell::AddressSpace::default());
                         let fn_ty = i64_t.fn_type(&[i8_ptr.into()], false);
                         let func = module.get_function("process_create").unwrap_or_else(|| module.add_function("process_create", fn_ty, None));
-                        let command = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let command = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[command.into()], "call_process_create").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     "process_wait" if args.len() == 1 => {
                         let fn_ty = i64_t.fn_type(&[i64_t.into()], false);
                         let func = module.get_function("process_wait").unwrap_or_else(|| module.add_function("process_wait", fn_ty, None));
-                        let pid = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let pid = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[pid.into()], "call_process_wait").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     "process_is_running" if args.len() == 1 => {
                         let fn_ty = i64_t.fn_type(&[i64_t.into()], false);
                         let func = module.get_function("process_is_running").unwrap_or_else(|| module.add_function("process_is_running", fn_ty, None));
-                        let pid = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals);
+                        let pid = gen_expr(&args[0], context, module, builder, locals, i64_t, initial_vals, builtins);
                         builder.build_call(func, &[pid.into()], "call_process_is_running").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     "process_yield" if args.is_empty() => {
@@ -909,13 +1349,104 @@ pub mod llvm {
                         builder.build_call(func, &[], "call_process_get_current_pid").try_as_basic_value().left().unwrap_or(i64_t.const_int(0, false).into())
                     }
                     
-                    // Default: unknown function
-                    _ => i64_t.const_int(0, false).into()
+                    // Wheel functions of the program, which take precedence
+                    // over builtins of the same name as in the interpreter
+                    _ if user_function(module, name, args.len(), i64_t).is_some() => {
+                        let func = user_function(module, name, args.len(), i64_t).unwrap();
+                        let args: Vec<_> = args.iter()
+                            .map(|a| gen_expr(a, context, module, builder, locals, i64_t, initial_vals, builtins).into())
+                            .collect();
+                        builder.build_call(func, &args, &format!("call_{}", name)).try_as_basic_value().left().unwrap()
+                    }
+                    // Builtins registered through crate::builtins (e.g. the `[builtins]` table)
+                    _ => match builtins.get(name) {
+                        Some(b) if b.params.len() == args.len() => {
+                            gen_builtin_call(b, args, context, module, builder, locals, i64_t, initial_vals, builtins)
+                        }
+                        // Default: unknown function
+                        _ => i64_t.const_int(0, false).into(),
+                    }
                 }
             }
         }
     }
 
+    /// The Wheel function `name` taking `argc` arguments, as declared before
+    /// any code is generated (`main` as `user_main`). Wheel functions take and
+    /// return only i64s, so the C functions the module declares for itself
+    /// are not mistaken for one.
+    fn user_function<'ctx>(module: &inkwell::module::Module<'ctx>, name: &str, argc: usize, i64_t: inkwell::types::IntType<'ctx>) -> Option<inkwell::values::FunctionValue<'ctx>> {
+        let func = module.get_function(if name == "main" { "user_main" } else { name })?;
+        let ty = func.get_type();
+        let int = |t: inkwell::types::BasicTypeEnum<'ctx>| t == i64_t.into();
+        (!ty.is_var_arg() && ty.get_return_type().is_some_and(int) && ty.get_param_types().len() == argc && ty.get_param_types().into_iter().all(int))
+            .then_some(func)
+    }
+
+    /// Call a registered builtin, declaring it on first use. `str` values are
+    /// i64 pointers in Wheel, so they are converted to `i8*` and back; ints
+    /// are converted to `float` (`double`) arguments and `float` results are
+    /// truncated back to ints.
+    fn gen_builtin_call<'ctx>(
+        b: &Builtin,
+        args: &[Expr],
+        context: &'ctx Context,
+        module: &inkwell::module::Module<'ctx>,
+        builder: &inkwell::builder::Builder<'ctx>,
+        locals: &HashMap<String, PointerValue<'ctx>>,
+        i64_t: inkwell::types::IntType<'ctx>,
+        initial_vals: &HashMap<String, Expr>,
+        builtins: &Builtins,
+    ) -> BasicValueEnum<'ctx> {
+        let i8ptr_t = context.i8_type().ptr_type(AddressSpace::default());
+        let f64_t = context.f64_type();
+        let func = module.get_function(b.symbol()).unwrap_or_else(|| {
+            let params: Vec<BasicMetadataTypeEnum> = b.params.iter()
+                .map(|p| match p {
+                    ValueType::Int => i64_t.into(),
+                    ValueType::Str => i8ptr_t.into(),
+                    ValueType::Float => f64_t.into(),
+                })
+                .collect();
+            let fn_ty = match b.returns {
+                Some(ValueType::Int) => i64_t.fn_type(&params, false),
+                Some(ValueType::Str) => i8ptr_t.fn_type(&params, false),
+                Some(ValueType::Float) => f64_t.fn_type(&params, false),
+                None => context.void_type().fn_type(&params, false),
+            };
+            module.add_function(b.symbol(), fn_ty, None)
+        });
+        let argv: Vec<BasicMetadataValueEnum> = b.params.iter().zip(args)
+            .map(|(p, a)| {
+                let v = gen_expr(a, context, module, builder, locals, i64_t, initial_vals, builtins).into_int_value();
+                match p {
+                    ValueType::Int => v.into(),
+                    ValueType::Str => builder.build_int_to_ptr(v, i8ptr_t, "builtin_arg_ptr").into(),
+                    ValueType::Float => builder.build_signed_int_to_float(v, f64_t, "builtin_arg_f64").into(),
+                }
+            })
+            .collect();
+        let call = builder.build_call(func, &argv, &format!("call_{}", b.name));
+        match call.try_as_basic_value().left() {
+            Some(BasicValueEnum::PointerValue(ptr)) => builder.build_ptr_to_int(ptr, i64_t, "builtin_ret_i64").into(),
+            Some(BasicValueEnum::FloatValue(f)) => builder.build_float_to_signed_int(f, i64_t, "builtin_ret_i64").into(),
+            Some(v) => v,
+            None => i64_t.const_int(0, false).into(),
+        }
+    }
+
+    /// A slot for a `let` inside a block, placed in the entry block of
+    /// `func` so it dominates every use, even from a later loop iteration.
+    fn entry_alloca<'ctx>(context: &'ctx Context, func: &FunctionValue<'ctx>, i64_t: inkwell::types::IntType<'ctx>, name: &str) -> PointerValue<'ctx> {
+        let builder = context.create_builder();
+        let entry = func.get_first_basic_block().expect("functions have an entry block");
+        match entry.get_first_instruction() {
+            Some(first) => builder.position_before(&first),
+            None => builder.position_at_end(entry),
+        }
+        builder.build_alloca(i64_t, name)
+    }
+
     fn sanitize_name(s: &str) -> String {
         s.chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect()
     }
@@ -925,11 +1456,11 @@ pub mod llvm {
 pub mod llvm {
     use crate::ast::Program;
     use std::path::Path;
-    use anyhow::Result;
+    use crate::error::{Result, WheelError};
     pub fn compile_with_llvm(_prog: &Program, _out: &Path) -> Result<Vec<String>> {
-        Err(anyhow::anyhow!("LLVM backend not enabled. Build with --features llvm"))
+        Err(WheelError::codegen("LLVM backend not enabled. Build with --features llvm"))
     }
-    pub fn compile_with_llvm_target(_prog: &Program, _out: &Path, _target: &str) -> Result<Vec<String>> {
-        Err(anyhow::anyhow!("LLVM backend not enabled. Build with --features llvm"))
+    pub fn compile_with_llvm_target(_prog: &Program, _out: &Path, _target: &str, _opt_level: u8) -> Result<Vec<String>> {
+        Err(WheelError::codegen("LLVM backend not enabled. Build with --features llvm"))
     }
 }
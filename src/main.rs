@@ -1,24 +1,37 @@
 use std::fs::{self, OpenOptions};
 use std::io::Write;
-use std::process::Command;
-use std::path::PathBuf;
-use clap::Parser;
+use std::process::{Command, ExitCode};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use clap::{Parser, Subcommand, ValueEnum};
 use anyhow::{Result, Context};
-use std::collections::HashSet;
-
-mod lexer;
-mod parser;
-mod ast;
-mod codegen;
-mod elf_writer;
-mod imports;
-mod llvm_backend;
-use parser::Parser as WheelParser;
+
+mod clean;
+mod runtime;
+mod script;
+mod self_update;
+mod term;
+use wheel::{ast, cache, check, codegen, config, defines, edition, lint, objdump, report};
 use codegen::codegen_to_asm;
-use imports::process_imports;
+use report::BuildReport;
+use wheel::diagnostic::Diagnostic;
+use wheel::WheelError;
+use cache::Cache;
+use config::Config;
+use defines::Defines;
+use edition::Edition;
+use term::{ColorChoice, Stream};
+
+/// Triples accepted by `--target` / `build.target`.
+const SUPPORTED_TARGETS: &[&str] = &[
+    "x86_64-unknown-linux-gnu",
+    "x86_64-pc-windows-msvc",
+    "x86_64-pc-windows-gnu",
+    "x86_64-apple-darwin",
+];
 
 /// Target OS and configuration
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum TargetOS {
     Linux,
     Windows,
@@ -36,6 +49,15 @@ impl TargetOS {
         }
     }
 
+    fn from_triple(triple: &str) -> Option<Self> {
+        match triple {
+            "x86_64-unknown-linux-gnu" => Some(TargetOS::Linux),
+            "x86_64-pc-windows-msvc" | "x86_64-pc-windows-gnu" => Some(TargetOS::Windows),
+            "x86_64-apple-darwin" => Some(TargetOS::MacOS),
+            _ => None,
+        }
+    }
+
     fn triple(&self) -> &'static str {
         match self {
             TargetOS::Linux => "x86_64-unknown-linux-gnu",
@@ -63,6 +85,16 @@ impl TargetOS {
         }
     }
 
+    /// Value of the built-in `TARGET_OS` define.
+    fn define_name(&self) -> &'static str {
+        match self {
+            TargetOS::Linux => "linux",
+            TargetOS::Windows => "windows",
+            TargetOS::MacOS => "macos",
+            TargetOS::Unknown => "unknown",
+        }
+    }
+
     fn format_name(&self) -> &'static str {
         match self {
             TargetOS::Linux => "ELF (Linux)",
@@ -73,27 +105,563 @@ impl TargetOS {
     }
 }
 
+/// `-o` when not given
+const DEFAULT_OUTPUT: &str = "a.out";
+
 /// Wheel compiler (MVP)
 #[derive(Parser)]
 #[command(author, version, about = "Wheel compiler (MVP) - generates native binaries for x86_64 Linux")]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Commands>,
+
     /// Input .wheel source file
-    input: PathBuf,
+    #[arg(required = true)]
+    input: Option<PathBuf>,
+
+    /// Arguments for a `#!` script run without `-o`
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true, value_name = "ARGS")]
+    args: Vec<String>,
+
+    /// Output file [default: a.out, a.exe for Windows targets]
+    #[arg(short = 'o', long = "out")]
+    output: Option<PathBuf>,
+
+    /// Mode: ge generate executable, gb generate raw binary [default: ge]
+    #[arg(long = "mode")]
+    mode: Option<String>,
+
+    /// LLVM optimization level (0-3) [default: 2]
+    #[arg(long = "opt-level", value_parser = clap::value_parser!(u8).range(0..=3))]
+    opt_level: Option<u8>,
+
+    /// Language edition the sources are written for [default: 0.1]
+    #[arg(long = "language", value_parser = clap::builder::PossibleValuesParser::new(edition::EDITIONS))]
+    language: Option<String>,
+
+    /// Target triple [default: host]
+    #[arg(long = "target", value_parser = clap::builder::PossibleValuesParser::new(SUPPORTED_TARGETS))]
+    target: Option<String>,
+
+    /// Linker/driver used for the final link step (`ge` and `ll`; `gb` always uses gcc)
+    #[arg(long = "linker")]
+    linker: Option<String>,
+
+    /// Link with library LIB (passed to the linker as `-lLIB`, LLVM backend); repeatable
+    #[arg(short = 'l', value_name = "LIB")]
+    libs: Vec<String>,
+
+    /// Define NAME (as 1, or as VALUE) for `@if` conditional compilation; repeatable
+    #[arg(short = 'D', value_name = "NAME[=VALUE]", value_parser = defines::parse_define)]
+    define: Vec<(String, String)>,
+
+    /// Treat warnings as errors
+    #[arg(long = "deny-warnings")]
+    deny_warnings: bool,
+
+    /// Link the garbage-collected heap: allocations are reclaimed automatically and frees do nothing (`--mode ll`)
+    #[arg(long = "gc")]
+    gc: bool,
+
+    /// Check `mem_alloc`/`mem_free`: abort on double free or use of freed memory, report leaks at exit (`--mode ll`)
+    #[arg(long = "debug-alloc", conflicts_with = "gc")]
+    debug_alloc: bool,
+
+    /// Emit an intermediate product to stdout instead of building
+    #[arg(long = "emit", value_enum)]
+    emit: Option<Emit>,
+
+    /// Always regenerate code instead of reusing target/wheel/cache
+    #[arg(long = "no-cache")]
+    no_cache: bool,
 
-    /// Output file
-    #[arg(short = 'o', long = "out", default_value = "a.out")]
-    output: PathBuf,
+    /// Emit a machine-readable build summary
+    #[arg(long = "build-report", value_enum)]
+    build_report: Option<ReportFormat>,
 
-    /// Mode: ge generate executable, gb generate raw binary
-    #[arg(long = "mode", default_value = "ge")]
+    /// Write the build report to this file instead of stdout
+    #[arg(long = "build-report-file", requires = "build_report")]
+    build_report_file: Option<PathBuf>,
+
+    /// When to use colors in diagnostics and progress output (honours NO_COLOR)
+    #[arg(long = "color", value_enum, default_value_t = ColorChoice::Auto, global = true)]
+    color: ColorChoice,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Show sections, symbols and disassembly of a binary produced by wheelc
+    Objdump {
+        /// Executable, object file or flat binary to inspect
+        file: PathBuf,
+
+        /// Print the section table
+        #[arg(long = "sections")]
+        sections: bool,
+
+        /// Print the symbol table
+        #[arg(long = "symbols")]
+        symbols: bool,
+
+        /// Disassemble executable sections
+        #[arg(short = 'd', long = "disassemble")]
+        disassemble: bool,
+
+        /// Treat FILE as a flat binary (`--mode gb`) loaded at this address, e.g. 0x100000
+        #[arg(long = "raw", value_parser = parse_address, num_args = 0..=1, default_missing_value = "0")]
+        raw: Option<u64>,
+
+        /// Show the Wheel source line above the instructions generated for it
+        #[arg(short = 'l', long = "line-numbers")]
+        line_numbers: bool,
+    },
+    /// Check sources against style rules configured in the [lint] table
+    Lint {
+        /// Source files to lint
+        #[arg(required_unless_present = "list")]
+        files: Vec<PathBuf>,
+
+        /// Turn RULE off
+        #[arg(long = "allow", value_name = "RULE")]
+        allow: Vec<String>,
+
+        /// Report RULE as a warning
+        #[arg(long = "warn", value_name = "RULE")]
+        warn: Vec<String>,
+
+        /// Report RULE as an error (non-zero exit)
+        #[arg(long = "deny", value_name = "RULE")]
+        deny: Vec<String>,
+
+        /// List the available rules and their default levels
+        #[arg(long = "list")]
+        list: bool,
+    },
+    /// Download and install the latest wheelc release (requires `--features self-update`)
+    SelfUpdate {
+        /// Only report whether a newer release exists
+        #[arg(long = "check")]
+        check: bool,
+
+        /// Reinstall even if already on the latest version
+        #[arg(long = "force")]
+        force: bool,
+    },
+    /// Run a program without building an executable
+    Run {
+        /// Source file to run
+        file: PathBuf,
+
+        /// How to execute the program
+        #[arg(long = "backend", value_enum, default_value_t = Backend::Interp)]
+        backend: Backend,
+
+        /// Define NAME (as 1, or as VALUE) for `@if` conditional compilation; repeatable
+        #[arg(short = 'D', value_name = "NAME[=VALUE]", value_parser = defines::parse_define)]
+        define: Vec<(String, String)>,
+    },
+    /// Remove the project's build directory, cache and intermediates
+    Clean {
+        /// Project directory; the nearest wheel.toml above it decides the layout [default: .]
+        dir: Option<PathBuf>,
+
+        /// Only list what would be removed
+        #[arg(long = "dry-run")]
+        dry_run: bool,
+    },
+    /// Extract or compile the runtime objects `--mode ll` links into the build directory
+    BuildStdlib {
+        /// Target triple [default: host]
+        #[arg(long = "target", value_parser = clap::builder::PossibleValuesParser::new(SUPPORTED_TARGETS))]
+        target: Option<String>,
+
+        /// Build directory; objects go to `<DIR>/stdlib/<triple>` [default: the project's]
+        #[arg(long = "build-dir", value_name = "DIR")]
+        build_dir: Option<PathBuf>,
+
+        /// Redo objects that are up to date
+        #[arg(long = "force")]
+        force: bool,
+    },
+}
+
+fn parse_address(s: &str) -> Result<u64, String> {
+    let parsed = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => s.parse(),
+    };
+    parsed.map_err(|e| format!("invalid address `{}`: {}", s, e))
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Emit {
+    /// Graphviz DOT of the import graph; cycles are drawn in red
+    Depgraph,
+    /// The program after `@if`, imports and embeds, as JSON (requires `--features serde`)
+    #[value(name = "ast=json")]
+    AstJson,
+    /// C header with prototypes for the `pub extern func`s
+    Header,
+    /// x86_64 assembly generated by the `ge`/`gb` backends
+    Asm,
+    /// JSON map from lines of `--emit asm` back to Wheel source locations
+    Sourcemap,
+    /// LLVM IR generated by the `ll` backend (requires `--features llvm`)
+    #[value(name = "llvm-ir")]
+    LlvmIr,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Backend {
+    /// Evaluate the AST directly; needs no assembler, linker or LLVM
+    Interp,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum ReportFormat {
+    Json,
+}
+
+/// Effective build settings: command-line flags over `wheel.toml` over the user config.
+struct Settings {
     mode: String,
+    opt_level: u8,
+    target_os: TargetOS,
+    target_triple: String,
+    linker: Option<String>,
+    /// `-l` libraries: `[build] libs` followed by the command line's
+    #[cfg_attr(not(feature = "llvm"), allow(dead_code))]
+    libs: Vec<String>,
+    /// `--gc`: link `gc.o` and route allocation through it
+    #[cfg_attr(not(feature = "llvm"), allow(dead_code))]
+    gc: bool,
+    /// `--debug-alloc`: route `mem_alloc`/`mem_free` through `debug_alloc.o`
+    debug_alloc: bool,
+    deny_warnings: bool,
+    allowed_warnings: Vec<String>,
+    defines: Defines,
+    edition: Edition,
 }
 
-fn main() -> Result<()> {
+impl Settings {
+    /// Output path with the target's executable extension applied when missing.
+    /// The default `a.out` becomes `a.exe`.
+    fn executable_path(&self, output: &Path) -> PathBuf {
+        let ext = self.target_os.executable_extension();
+        if ext.is_empty() {
+            output.to_path_buf()
+        } else if output == Path::new(DEFAULT_OUTPUT) {
+            output.with_extension(ext.trim_start_matches('.'))
+        } else if output.extension().is_some() {
+            output.to_path_buf()
+        } else {
+            let mut name = output.as_os_str().to_owned();
+            name.push(ext);
+            PathBuf::from(name)
+        }
+    }
+
+    fn resolve(cli: &Cli, config: &Config) -> Result<Self> {
+        let opt_level = cli.opt_level.or(config.build.opt_level).unwrap_or(2);
+        if opt_level > 3 {
+            anyhow::bail!("invalid opt_level {} in config (expected 0-3)", opt_level);
+        }
+        let target_triple = cli.target.clone()
+            .or_else(|| config.build.target.clone())
+            .unwrap_or_else(|| TargetOS::current().triple().to_string());
+        let target_os = TargetOS::from_triple(&target_triple).ok_or_else(|| anyhow::anyhow!(
+            "unsupported target `{}` (supported: {})", target_triple, SUPPORTED_TARGETS.join(", ")
+        ))?;
+        // mingw is the only sensible default for the GNU flavour of Windows
+        let default_linker = (target_triple == "x86_64-pc-windows-gnu").then(|| "x86_64-w64-mingw32-gcc".to_string());
+        let mode = cli.mode.clone().or_else(|| config.build.mode.clone()).unwrap_or_else(|| "ge".to_string());
+        if cli.gc && mode != "ll" {
+            anyhow::bail!("--gc needs --mode ll: only the LLVM backend links the heap runtime");
+        }
+        if cli.debug_alloc && mode != "ll" {
+            anyhow::bail!("--debug-alloc needs --mode ll: only the LLVM backend links the heap runtime");
+        }
+
+        let mut defines = defines::builtin(&mode, target_os.define_name());
+        defines.extend(config.define_values()?);
+        defines.extend(cli.define.iter().cloned());
+
+        let edition = match cli.language.as_deref().or(config.language.as_deref()) {
+            Some(s) => Edition::parse(s).map_err(|e| anyhow::anyhow!(e))?,
+            None => Edition::default(),
+        };
+
+        Ok(Self {
+            mode,
+            opt_level,
+            target_os,
+            target_triple,
+            linker: cli.linker.clone().or_else(|| config.build.linker.clone()).or(default_linker),
+            libs: config.build.libs.iter().flatten().chain(&cli.libs).cloned().collect(),
+            gc: cli.gc,
+            debug_alloc: cli.debug_alloc,
+            deny_warnings: cli.deny_warnings || config.warnings.deny.unwrap_or(false),
+            allowed_warnings: config.warnings.allow.clone().unwrap_or_default(),
+            defines,
+            edition,
+        })
+    }
+}
+
+fn main() -> ExitCode {
     let cli = Cli::parse();
+    term::init(cli.color);
+
+    if let Some(command) = &cli.command {
+        return match run_command(command) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                let kind = report::failure_kind(&e);
+                print_error(&e);
+                ExitCode::from(kind.exit_code())
+            }
+        };
+    }
+
+    let started = Instant::now();
+    let mut report = BuildReport::default();
+    let result = run(&cli, &mut report);
+    report.total = started.elapsed();
 
-    let src = fs::read_to_string(&cli.input)
-        .with_context(|| format!("failed to read input file {}", cli.input.display()))?;
+    let code = match &result {
+        Ok(()) => 0,
+        Err(e) => {
+            let kind = report::failure_kind(e);
+            print_error(e);
+            report.failure = Some((kind, format!("{:#}", e)));
+            kind.exit_code()
+        }
+    };
+
+    if cli.build_report.is_some() {
+        let json = report.to_json();
+        match &cli.build_report_file {
+            Some(path) => {
+                if let Err(e) = fs::write(path, json + "\n") {
+                    term::error(format_args!("failed to write build report {}: {}", path.display(), e));
+                }
+            }
+            None => println!("{}", json),
+        }
+    }
+
+    ExitCode::from(code)
+}
+
+/// Print a failure: the diagnostics behind it when the failing stage produced
+/// any, the error chain otherwise.
+fn print_error(e: &anyhow::Error) {
+    let diagnostics = report::diagnostics(e);
+    if diagnostics.is_empty() {
+        term::error(format_args!("{:#}", e));
+    } else {
+        diagnostics.iter().for_each(term::diagnostic);
+    }
+}
+
+/// Print a human status line unless the JSON report owns stdout.
+fn status_line(cli: &Cli, msg: &str) {
+    if cli.build_report.is_some() && cli.build_report_file.is_none() {
+        term::status(Stream::Stderr, msg);
+    } else {
+        term::status(Stream::Stdout, msg);
+    }
+}
+
+fn link_error(msg: impl Into<String>) -> anyhow::Error {
+    WheelError::link(msg).into()
+}
+
+fn run_command(command: &Commands) -> Result<()> {
+    match command {
+        Commands::Objdump { file, sections, symbols, disassemble, raw, line_numbers } => {
+            // no selection means "show everything"
+            let all = !(*sections || *symbols || *disassemble);
+            let opts = objdump::DumpOptions {
+                sections: all || *sections,
+                symbols: all || *symbols,
+                disassemble: all || *disassemble,
+                raw_base: *raw,
+                line_numbers: *line_numbers,
+            };
+            let listing = objdump::dump_file(file, opts)?;
+            // ignore EPIPE so `wheelc objdump a.out | head` exits quietly
+            let _ = std::io::Write::write_all(&mut std::io::stdout(), listing.as_bytes());
+            Ok(())
+        }
+        Commands::Lint { files, allow, warn, deny, list } => {
+            if *list {
+                for rule in lint::RULES {
+                    println!("{:<18} {:<6} {}", rule.name, rule.default.as_str(), rule.description);
+                }
+                return Ok(());
+            }
+            let mut errors = 0;
+            for file in files {
+                let (config, _) = Config::load(file)?;
+                let edition = match config.language.as_deref() {
+                    Some(s) => Edition::parse(s).map_err(|e| anyhow::anyhow!(e))?,
+                    None => Edition::default(),
+                };
+                let mut levels = lint::Levels::default();
+                for (rule, level) in &config.lint {
+                    levels.set(rule, *level)?;
+                }
+                for (rules, level) in [(allow, lint::Level::Allow), (warn, lint::Level::Warn), (deny, lint::Level::Deny)] {
+                    for rule in rules {
+                        levels.set(rule, level)?;
+                    }
+                }
+                for finding in lint::lint_file(file, edition, &levels)? {
+                    let msg = format!("{}: {}", file.display(), finding);
+                    if finding.level == lint::Level::Deny {
+                        errors += 1;
+                        term::error(msg);
+                    } else {
+                        term::warning(msg);
+                    }
+                }
+            }
+            if errors > 0 {
+                let denied = Diagnostic::error("denied-lints", format!("{} denied lint finding(s)", errors));
+                return Err(WheelError::Type { diagnostics: vec![denied] }.into());
+            }
+            Ok(())
+        }
+        Commands::SelfUpdate { check, force } => {
+            match self_update::run(*check, *force)? {
+                self_update::Outcome::UpToDate(v) => term::status(Stream::Stdout, &format!("Up-to-date: wheelc {}", v)),
+                self_update::Outcome::Available(v) => term::status(Stream::Stdout, &format!("Available: wheelc {} (run `wheelc self-update` to install)", v)),
+                self_update::Outcome::Updated { from, to } => term::status(Stream::Stdout, &format!("Updated wheelc {} -> {}", from, to)),
+            }
+            Ok(())
+        }
+        Commands::Run { file, backend: Backend::Interp, define } => {
+            let (config, _) = Config::load(file)?;
+            let mut opts = wheel::Options::new(file);
+            if let Some(s) = &config.language {
+                opts.edition = Edition::parse(s).map_err(|e| anyhow::anyhow!(e))?;
+            }
+            opts.defines = defines::builtin("interp", TargetOS::current().define_name());
+            opts.defines.extend(config.define_values()?);
+            opts.defines.extend(define.iter().cloned());
+            opts.assets = config.assets.clone();
+            opts.project_root = config::project_root(file);
+            opts.allowed_warnings = config.warnings.allow.clone().unwrap_or_default();
+            opts.builtins = wheel::builtins::Builtins::from_config(&config.builtins, &opts.project_root)?;
+
+            let src = fs::read_to_string(file)
+                .with_context(|| format!("failed to read input file {}", file.display()))?;
+            let artifacts = wheel::compile_source(&src, opts)?;
+            artifacts.warnings.iter().for_each(term::diagnostic);
+            let code = wheel::interp::Interpreter::new(std::io::stdout())
+                .with_input(std::io::stdin().lock())
+                .run(&artifacts.program)?;
+            if code != 0 {
+                std::process::exit(code);
+            }
+            Ok(())
+        }
+        Commands::Clean { dir, dry_run } => {
+            let dir = dir.as_deref().unwrap_or(Path::new("."));
+            // the layout builds use: user config, then `wheel.toml`
+            let (config, _) = Config::load_dir(dir)?;
+            let root = config::find_manifest_from(dir)
+                .and_then(|manifest| manifest.parent().map(Path::to_path_buf))
+                .unwrap_or_else(|| dir.to_path_buf());
+            let removed = clean::clean(&root, &config.layout, *dry_run)?;
+            let verb = if *dry_run { "Would remove" } else { "Removed" };
+            for path in &removed {
+                term::status(Stream::Stdout, &format!("{} {}", verb, path.display()));
+            }
+            if removed.is_empty() {
+                term::status(Stream::Stdout, "Nothing to clean");
+            }
+            Ok(())
+        }
+        Commands::BuildStdlib { target, build_dir, force } => {
+            let build_dir = match build_dir {
+                Some(dir) => dir.clone(),
+                None => match config::find_manifest_from(Path::new(".")) {
+                    Some(manifest) => {
+                        let config = Config::from_file(&manifest)?;
+                        config.layout.build_dir(manifest.parent().unwrap_or(Path::new(".")))
+                    }
+                    None => Config::default().layout.build_dir(Path::new(".")),
+                },
+            };
+            let triple = target.as_deref().unwrap_or(TargetOS::current().triple());
+            let stdlib = runtime::build(&build_dir, triple, *force)?;
+            for (object, outcome) in &stdlib.objects {
+                match outcome {
+                    runtime::Outcome::Compiled(_) => term::status(Stream::Stdout, &format!("Compiled {}.o", object.name)),
+                    runtime::Outcome::Extracted(_) => term::status(Stream::Stdout, &format!("Extracted {}.o", object.name)),
+                    runtime::Outcome::Fresh(_) => term::status(Stream::Stdout, &format!("Fresh {}.o", object.name)),
+                    runtime::Outcome::Failed(msg) => term::warning(format_args!("{}.o left out: {}", object.name, msg)),
+                }
+            }
+            term::status(Stream::Stdout, &format!("Runtime objects for {} in {}", triple, stdlib.dir.display()));
+            Ok(())
+        }
+    }
+}
+
+/// Fail on the `@asm`/`@llvm` blocks of `prog` that are not for the backend
+/// taking `target` blocks.
+fn inline_blocks_for(prog: &ast::Program, target: ast::InlineTarget) -> Result<()> {
+    let diagnostics = check::check_inline(prog, target);
+    if diagnostics.is_empty() {
+        return Ok(());
+    }
+    Err(WheelError::Type { diagnostics }.into())
+}
+
+/// Write the assembly handed to gcc into the build directory.
+fn scratch_asm(build_dir: &Path, asm: &str) -> Result<PathBuf> {
+    fs::create_dir_all(build_dir)
+        .with_context(|| format!("failed to create build directory {}", build_dir.display()))?;
+    let path = build_dir.join("wheel_tmp.s");
+    fs::write(&path, asm.as_bytes())?;
+    Ok(path)
+}
+
+/// Assembly for `prog`, reused from the cache when the key matches.
+/// Returns the code and whether it came from the cache.
+fn cached_asm(cache: Option<&(Cache, cache::Fingerprint)>, prog: &ast::Program) -> (String, bool) {
+    if let Some((c, key)) = cache {
+        if let Some(asm) = c.get(*key, "s").and_then(|b| String::from_utf8(b).ok()) {
+            return (asm, true);
+        }
+    }
+    let asm = codegen_to_asm(prog);
+    if let Some((c, key)) = cache {
+        // a failed store only costs the next build a recompile
+        let _ = c.put(*key, "s", asm.as_bytes());
+    }
+    (asm, false)
+}
+
+fn run(cli: &Cli, report: &mut BuildReport) -> Result<()> {
+    let input = cli.input.as_deref().context("no input file given")?;
+    let (config, _config_files) = Config::load(input)?;
+    let settings = Settings::resolve(cli, &config)?;
+    report.mode = settings.mode.clone();
+
+    let src = fs::read_to_string(input)
+        .with_context(|| format!("failed to read input file {}", input.display()))?;
+    let output = cli.output.clone().unwrap_or_else(|| PathBuf::from(DEFAULT_OUTPUT));
+    // `wheelc script.wheel` runs a `#!` script instead of leaving an a.out behind
+    let script = cli.output.is_none() && cli.emit.is_none() && cli.build_report.is_none()
+        && settings.mode == "ge" && script::is_script(&src);
+    if !script && !cli.args.is_empty() {
+        anyhow::bail!("unexpected argument `{}`; only `#!` scripts take arguments", cli.args[0]);
+    }
 
     // First-run installer: detect install root and add to PATH
     // Marker file: ~/.wheel_installed
@@ -104,12 +672,12 @@ fn main() -> Result<()> {
             if let Ok(exe) = std::env::current_exe() {
                 if let Some(root) = exe.parent() {
                     let root_str = root.to_string_lossy().to_string();
-                    eprintln!("First run detected: adding {} to PATH in shell profiles", root_str);
+                    term::status(Stream::Stderr, &format!("First run detected: adding {} to PATH in shell profiles", root_str));
                     let shells = [".profile", ".bashrc", ".zshrc"];
                     let os_name = std::env::consts::OS;
                     if os_name == "windows" {
                         // Try to update the user PATH using setx (will affect future sessions)
-                        eprintln!("First run detected: adding {} to PATH (Windows) using setx", root_str);
+                        term::status(Stream::Stderr, &format!("First run detected: adding {} to PATH (Windows) using setx", root_str));
                         if let Ok(cur_path) = std::env::var("PATH") {
                             let new_path = format!("{};{}", cur_path, root_str);
                             let _ = Command::new("setx").arg("PATH").arg(&new_path).status();
@@ -167,209 +735,330 @@ fn main() -> Result<()> {
 
     // Debug: dump lexer tokens if WHEEL_LEX_DUMP env var is set
     if std::env::var("WHEEL_LEX_DUMP").is_ok() {
-        use crate::lexer::Lexer;
+        use wheel::lexer::Lexer;
         let mut lx = Lexer::new(&src);
         loop {
             let t = lx.next_token();
             eprintln!("TOKEN: {:?}", t);
-            if t == crate::lexer::Token::EOF { break; }
+            if t == wheel::lexer::Token::EOF { break; }
         }
         return Ok(());
     }
 
-    // parse program and process imports
-    let mut p = WheelParser::new(&src);
-    let mut prog = p.parse_program();
-    
-    // Process imports by loading and merging imported files
-    let input_dir = cli.input.parent().unwrap_or_else(|| std::path::Path::new("."));
-    let mut processed_imports = HashSet::new();
-    process_imports(&mut prog, input_dir, &mut processed_imports)?;
-
-    if cli.mode == "ge" {
-        // Generate executable using assembly + gcc/clang
-        let asm = codegen_to_asm(&prog);
-        // normal executable: asm as generated
-        let asm_path = std::env::temp_dir().join("wheel_tmp.s");
-        fs::write(&asm_path, asm.as_bytes())?;
-
-        let target_os = std::env::consts::OS;
-        let status = if target_os == "linux" {
-            Command::new("gcc")
-                .arg("-nostdlib")
-                .arg("-o").arg(&cli.output)
-                .arg(&asm_path)
-                .status()?
-        } else if target_os == "macos" {
-            Command::new("clang")
-                .arg("-nostdlib")
-                .arg("-o").arg(&cli.output)
-                .arg(&asm_path)
-                .status()?
-        } else if target_os == "windows" {
-            // Try common linkers/compilers available on Windows (mingw/clang)
-            let mut ok = false;
-            let mut last_status = None;
-            for cmd in &["gcc", "clang", "lld-link"] {
-                let res = Command::new(cmd)
-                    .arg("-nostdlib")
-                    .arg("-o").arg(&cli.output)
-                    .arg(&asm_path)
-                    .status();
-                match res {
-                    Ok(s) => {
-                        last_status = Some(s);
-                        if s.success() {
-                            ok = true;
-                            break;
-                        }
-                    }
-                    Err(_) => continue,
-                }
+    report.add_input(input);
+
+    // parse program, resolve `@if`, inline imports and locate embeds
+    let opts = wheel::Options {
+        path: input.to_path_buf(),
+        edition: settings.edition,
+        defines: settings.defines.clone(),
+        assets: config.assets.clone(),
+        project_root: config::project_root(input),
+        allowed_warnings: settings.allowed_warnings.clone(),
+        builtins: wheel::builtins::Builtins::from_config(&config.builtins, &config::project_root(input))?,
+        search_path: wheel::imports::wheel_path(),
+    };
+    let parsed = report.time("parse", || wheel::parse_source(&src, &opts))?;
+    // imported and embedded files are inputs too, so editing one invalidates the cache;
+    // bundled `std/` modules change only with wheelc, which is part of the key
+    for path in parsed.imports.files() {
+        report.add_input(path);
+    }
+    for path in &parsed.embedded {
+        report.add_input(path);
+    }
+
+    match cli.emit {
+        Some(Emit::Depgraph) => {
+            print!("{}", parsed.imports.to_dot());
+            return Ok(());
+        }
+        Some(Emit::Header) => {
+            let name = input.file_stem().map(|s| s.to_string_lossy()).unwrap_or_default();
+            print!("{}", wheel::header::c_header(&parsed.program, &name));
+            return Ok(());
+        }
+        Some(emit @ (Emit::Asm | Emit::Sourcemap)) => {
+            if settings.mode == "ll" {
+                anyhow::bail!("--emit asm and --emit sourcemap describe the ge/gb assembly; the ll backend has no source map yet");
             }
-            if ok {
-                // unwrap is safe because ok==true implies last_status is Some
-                last_status.unwrap()
-            } else if let Some(s) = last_status {
-                s
-            } else {
-                anyhow::bail!("Unsupported OS: {} (no usable compiler/linker found in PATH). Install mingw-w64 or clang.", target_os)
+            inline_blocks_for(&parsed.program, ast::InlineTarget::Asm)?;
+            let (asm, map) = codegen::codegen_to_asm_with_map(&parsed.program);
+            let text = if matches!(emit, Emit::Asm) { asm } else { map.to_json() };
+            // ignore EPIPE so `--emit asm | head` exits quietly
+            let _ = std::io::Write::write_all(&mut std::io::stdout(), text.as_bytes());
+            return Ok(());
+        }
+        Some(Emit::LlvmIr) => {
+            #[cfg(not(feature = "llvm"))]
+            anyhow::bail!("--emit llvm-ir not enabled. Rebuild with `--features llvm`");
+            #[cfg(feature = "llvm")]
+            {
+                use wheel::llvm_backend::llvm;
+                inline_blocks_for(&parsed.program, ast::InlineTarget::Llvm)?;
+                let kind = if output.extension().is_some_and(|e| e == "a") { llvm::OutputKind::StaticLib } else { llvm::OutputKind::Executable };
+                let ir = llvm::llvm_ir(&parsed.program, &settings.target_triple, &opts.builtins, kind, settings.debug_alloc)?;
+                // ignore EPIPE so `--emit llvm-ir | head` exits quietly
+                let _ = std::io::Write::write_all(&mut std::io::stdout(), ir.as_bytes());
+                return Ok(());
             }
-        } else {
-            anyhow::bail!("Unsupported OS: {}", target_os)
+        }
+        Some(Emit::AstJson) => {
+            #[cfg(not(feature = "serde"))]
+            anyhow::bail!("--emit ast=json not enabled. Rebuild with `--features serde`");
+            #[cfg(feature = "serde")]
+            {
+                let json = serde_json::to_string_pretty(&parsed.program)? + "\n";
+                // ignore EPIPE so `--emit ast=json | head` exits quietly
+                let _ = std::io::Write::write_all(&mut std::io::stdout(), json.as_bytes());
+                return Ok(());
+            }
+        }
+        None => {}
+    }
+
+    let artifacts = report.time("check", || parsed.check(&opts))?;
+    let mut warnings = artifacts.warnings;
+    warnings.extend(check::check_compiled(&artifacts.program).into_iter()
+        .filter(|d| !opts.allowed_warnings.iter().any(|a| a == d.code)));
+    for w in &warnings {
+        term::diagnostic(w);
+    }
+    report.warnings += warnings.len();
+    if settings.deny_warnings && !warnings.is_empty() {
+        let msg = format!("{} warning(s) treated as errors", warnings.len());
+        return Err(WheelError::Type { diagnostics: vec![Diagnostic::error("denied-warnings", msg)] }.into());
+    }
+
+    let prog = artifacts.program;
+    inline_blocks_for(&prog, if settings.mode == "ll" { ast::InlineTarget::Llvm } else { ast::InlineTarget::Asm })?;
+    let mut diagnostics = wheel::builtins::check_compiled_calls(&prog, &settings.mode);
+    if settings.mode != "ll" {
+        diagnostics.extend(wheel::builtins::check_asm_calls(&prog, &opts.builtins, &settings.mode));
+    }
+    if !diagnostics.is_empty() {
+        return Err(WheelError::Type { diagnostics }.into());
+    }
+
+    // cache key covers every module that went into `prog`
+    let build_dir = if script {
+        script::build_dir(input, &config.layout)
+    } else {
+        config.layout.build_dir(&config::project_root(input))
+    };
+    let opt_level = settings.opt_level.to_string();
+    let defines = settings.defines.iter().map(|(k, v)| format!("{}={}", k, v)).collect::<Vec<_>>().join(";");
+    let builtins = opts.builtins.fingerprint();
+    // `-o libNAME.a` with `--mode ll` builds a static library for C callers
+    let static_lib = settings.mode == "ll" && output.extension().is_some_and(|e| e == "a");
+    let kind = [static_lib.then_some("staticlib"), settings.gc.then_some("gc"), settings.debug_alloc.then_some("debug-alloc")]
+        .into_iter().flatten().collect::<Vec<_>>().join(",");
+    let key = || cache::program_key(&report.inputs, &[&settings.mode, &settings.target_triple, &opt_level, &defines, &builtins, &kind]);
+    let cache = if cli.no_cache {
+        None
+    } else {
+        Some((Cache::new(&build_dir), key()?))
+    };
+    let script_exe = match (script, &cache) {
+        (false, _) => None,
+        (true, Some((_, k))) => Some(script::executable(&build_dir, *k)?),
+        (true, None) => Some(script::executable(&build_dir, key()?)?),
+    };
+    if let Some(exe) = &script_exe {
+        if cache.is_some() && exe.exists() {
+            return script::exec(exe, input, &cli.args);
+        }
+    }
+
+    if settings.mode == "ge" {
+        // the assembly makes Linux system calls from its own `_start`
+        if settings.target_os != TargetOS::Linux {
+            anyhow::bail!("--mode ge only builds x86_64 Linux executables; use --mode ll for {}", settings.target_triple);
+        }
+        // Generate executable using assembly + gcc
+        let (asm, hit) = report.time("codegen", || cached_asm(cache.as_ref(), &prog));
+        report.cache_hit = cache.as_ref().map(|_| hit);
+        // normal executable: asm as generated
+        let asm_path = scratch_asm(&build_dir, &asm)?;
+
+        let target_os = settings.target_os;
+        // scripts link to a private name and are renamed into place, so a
+        // concurrent run never starts a half-written executable
+        let exe_path = match &script_exe {
+            Some(exe) => exe.with_extension(format!("tmp{}", std::process::id())),
+            None => settings.executable_path(&output),
         };
+        let link_start = Instant::now();
+        let linker = settings.linker.as_deref().unwrap_or(target_os.linker());
+        let status = Command::new(linker)
+            .arg("-nostdlib")
+            .arg("-o").arg(&exe_path)
+            .arg(&asm_path)
+            .status()
+            .map_err(|e| link_error(format!("failed to run {}: {}", linker, e)))?;
+
+        report.record("link", link_start);
 
         if !status.success() {
-            anyhow::bail!("compiler failed");
+            return Err(link_error(format!("{} failed", linker)));
         }
 
-        println!("Generated executable: {}", cli.output.display());
+        if let Some(exe) = &script_exe {
+            fs::rename(&exe_path, exe)?;
+            return script::exec(exe, input, &cli.args);
+        }
+
+        report.add_artifact(&exe_path);
+        status_line(cli, &format!("Generated executable: {} ({})", exe_path.display(), target_os.format_name()));
 
         // If running on Windows, copy the produced executable into dist/Windows/wheelcv1.0.1.exe
-        if target_os == "windows" {
+        if std::env::consts::OS == "windows" {
             let dist_dir = std::path::Path::new("dist").join("Windows");
             let _ = fs::create_dir_all(&dist_dir);
             let dest = dist_dir.join("wheelcv1.0.1.exe");
-            let _ = fs::copy(&cli.output, &dest);
-            println!("Also staged Windows executable at {}", dest.display());
+            let _ = fs::copy(&exe_path, &dest);
+            status_line(cli, &format!("Also staged Windows executable at {}", dest.display()));
         }
 
-    } else if cli.mode == "gb" {
+    } else if settings.mode == "gb" {
         // Generate flat binary using assembly + gcc
         // Prepend a minimal Multiboot header to help bootloaders (GRUB) detect
         // the image. This is a simple header; building a full kernel image may
         // require a proper linker script and more advanced layout.
         let mut asm = String::new();
         asm.push_str("    .section .multiboot\n    .align 4\n    .long 0x1BADB002\n    .long 0x00010003\n    .long -(0x1BADB002 + 0x00010003)\n\n");
-        asm.push_str(&codegen_to_asm(&prog));
-        let asm_path = std::env::temp_dir().join("wheel_tmp.s");
-        fs::write(&asm_path, asm.as_bytes())?;
+        let (code, hit) = report.time("codegen", || cached_asm(cache.as_ref(), &prog));
+        report.cache_hit = cache.as_ref().map(|_| hit);
+        asm.push_str(&code);
+        let asm_path = scratch_asm(&build_dir, &asm)?;
 
-        let exe = cli.output.with_extension("exe");
+        let link_start = Instant::now();
+        let exe = output.with_extension("exe");
+        // gcc assembles the image as well, so a configured `linker` (which
+        // may be `ld`, `lld-link`, ...) is not used for it
         let status = Command::new("gcc")
             .arg("-nostdlib")
             .arg("-o").arg(&exe)
             .arg(&asm_path)
             .status()
-            .context("failed to run gcc")?;
+            .map_err(|e| link_error(format!("failed to run gcc: {}", e)))?;
 
         if !status.success() {
-            anyhow::bail!("gcc failed");
+            return Err(link_error("gcc failed"));
         }
+        report.add_artifact(&exe);
 
         let status2 = Command::new("objcopy")
             .arg("-O").arg("binary")
             .arg(&exe)
-            .arg(&cli.output)
+            .arg(&output)
             .status()
-            .context("failed to run objcopy")?;
+            .map_err(|e| link_error(format!("failed to run objcopy: {}", e)))?;
+        report.record("link", link_start);
 
         if !status2.success() {
-            anyhow::bail!("objcopy failed");
+            return Err(link_error("objcopy failed"));
         }
 
-        println!("Generated flat binary: {}", cli.output.display());
-    } else if cli.mode == "ll" {
+        report.add_artifact(&output);
+        status_line(cli, &format!("Generated flat binary: {}", output.display()));
+    } else if settings.mode == "ll" {
         // LLVM backend path (requires building with `--features llvm`)
         #[cfg(feature = "llvm")]
         {
-            let target_os = TargetOS::current();
-            let target_triple = target_os.triple();
-            let linker = target_os.linker();
+            let target_os = settings.target_os;
+            let target_triple = settings.target_triple.as_str();
+            let linker = settings.linker.as_deref().unwrap_or(target_os.linker());
+            let exe_path = settings.executable_path(&output);
             
-            eprintln!("Target: {} ({})", target_os.format_name(), target_triple);
+            term::status(Stream::Stderr, &format!("Target: {} ({})", target_os.format_name(), target_triple));
             
-            let out_obj = cli.output.with_extension("o");
-            let extra_links = llvm_backend::llvm::compile_with_llvm_target(&prog, &cli.output, target_triple)
-                .context("llvm compilation failed")?;
+            use wheel::llvm_backend::llvm;
+            let out_obj = output.with_extension("o");
+            let output_kind = if static_lib { llvm::OutputKind::StaticLib } else { llvm::OutputKind::Executable };
+            let cached = cache.as_ref().and_then(|(c, key)| Some((c.get(*key, "o")?, c.get(*key, "links")?)));
+            report.cache_hit = cache.as_ref().map(|_| cached.is_some());
+            let extra_links: Vec<String> = if let Some((obj, links)) = cached {
+                fs::write(&out_obj, obj)?;
+                String::from_utf8_lossy(&links).lines().map(str::to_string).collect()
+            } else {
+                let links = report.time("codegen", || llvm::compile_with_llvm_target(&prog, &output, target_triple, settings.opt_level, &opts.builtins, output_kind, settings.debug_alloc))
+                    .context("llvm compilation failed")?;
+                if let Some((c, key)) = &cache {
+                    // a failed store only costs the next build a recompile
+                    let _ = fs::read(&out_obj).map(|obj| c.put(*key, "o", &obj));
+                    let _ = c.put(*key, "links", links.join("\n").as_bytes());
+                }
+                links
+            };
+            report.add_artifact(&out_obj);
+            let link_start = Instant::now();
+
+            if static_lib {
+                // the library's users link its `extern func` libraries themselves
+                let _ = fs::remove_file(&output);
+                let status = Command::new("ar").arg("rcs").arg(&output).arg(&out_obj).status()
+                    .map_err(|e| link_error(format!("failed to run ar: {}", e)))?;
+                report.record("link", link_start);
+                if !status.success() {
+                    return Err(link_error("ar failed"));
+                }
+                report.add_artifact(&output);
+                status_line(cli, &format!("Generated static library (LLVM): {} ({})", output.display(), target_os.format_name()));
+                return Ok(());
+            }
 
             // compiled object file should be at <output>.o; link with system linker
             let mut cmd = Command::new(linker);
-            cmd.arg("-o").arg(&cli.output)
+            cmd.arg("-o").arg(&exe_path)
                 .arg(&out_obj);
             
-            // Add library linking flags based on presence of wrapper objects
-            let sdl_obj = std::path::Path::new("src/stdlib/sdl_wrappers.o");
-            if sdl_obj.exists() {
-                cmd.arg("src/stdlib/sdl_wrappers.o").arg("-lSDL2");
-            }
-
-            let os_obj = std::path::Path::new("src/stdlib/os_wrappers.o");
-            if os_obj.exists() {
-                cmd.arg("src/stdlib/os_wrappers.o");
-            }
-
-            let luck_obj = std::path::Path::new("src/stdlib/luck.o");
-            if luck_obj.exists() {
-                cmd.arg("src/stdlib/luck.o");
-            }
-
-            let memory_obj = std::path::Path::new("src/stdlib/memory.o");
-            if memory_obj.exists() {
-                cmd.arg("src/stdlib/memory.o");
-            }
+            // runtime objects, built for the target on first use
+            let stdlib = report.time("stdlib", || runtime::build(&build_dir, target_triple, false))?;
+            let requested = [
+                settings.gc.then_some("gc"),
+                settings.debug_alloc.then_some("debug_alloc"),
+                runtime::calls_sdl(&prog).then_some("sdl_wrappers"),
+            ];
+            let requested: Vec<&str> = requested.into_iter().flatten().collect();
+            let runtime_args = stdlib.link_args(&requested).map_err(|e| link_error(format!("{:#}", e)))?;
+            cmd.args(&runtime_args);
 
-            let hwio_obj = std::path::Path::new("src/stdlib/hwio.o");
-            if hwio_obj.exists() {
-                cmd.arg("src/stdlib/hwio.o");
-            }
-
-            let filesystem_obj = std::path::Path::new("src/stdlib/filesystem.o");
-            if filesystem_obj.exists() {
-                cmd.arg("src/stdlib/filesystem.o");
-            }
-
-            let process_obj = std::path::Path::new("src/stdlib/process.o");
-            if process_obj.exists() {
-                cmd.arg("src/stdlib/process.o");
-            }
-            
             // Append any extra link args returned by the LLVM backend (e.g., local lib .o/.so)
             for arg in extra_links {
+                if !arg.starts_with('-') {
+                    status_line(cli, &format!("Linking {}", arg));
+                }
                 cmd.arg(arg);
             }
+            for lib in &settings.libs {
+                cmd.arg(format!("-l{}", lib));
+            }
 
             let status = cmd.status()
-                .context("failed to link object with linker")?;
+                .map_err(|e| link_error(format!("failed to link object with linker: {}", e)))?;
+            report.record("link", link_start);
 
             if !status.success() {
-                anyhow::bail!("linking failed");
+                let failed = stdlib.failed();
+                if failed.is_empty() {
+                    return Err(link_error("linking failed"));
+                }
+                return Err(link_error(format!(
+                    "linking failed; runtime objects left out because they could not be built: {} (see `wheelc build-stdlib`)",
+                    failed.join(", ")
+                )));
             }
+            report.add_artifact(&exe_path);
 
-            let exe_name = if let Some(file_name) = cli.output.file_name() {
-                format!("{}{}", file_name.to_string_lossy(), target_os.executable_extension())
-            } else {
-                format!("{}{}", cli.output.display(), target_os.executable_extension())
-            };
-            
-            println!("Generated executable (LLVM): {} ({})", exe_name, target_os.format_name());
+            status_line(cli, &format!("Generated executable (LLVM): {} ({})", exe_path.display(), target_os.format_name()));
         }
         #[cfg(not(feature = "llvm"))]
         {
             anyhow::bail!("LLVM backend not enabled. Rebuild with `--features llvm`");
         }
     } else {
-        println!("Unknown mode: {}. Use 'ge', 'gb' or 'll'", cli.mode);
+        anyhow::bail!("Unknown mode: {}. Use 'ge', 'gb' or 'll'", settings.mode);
     }
 
     Ok(())
@@ -0,0 +1,175 @@
+// `wheelc objdump`: inspect binaries produced by the compiler without binutils.
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+#[cfg(feature = "native")]
+use std::fs;
+#[cfg(feature = "native")]
+use std::path::Path;
+use iced_x86::{Decoder, DecoderOptions, Formatter, Instruction, IntelFormatter};
+use object::{Object, ObjectSection, ObjectSymbol, SectionKind, SymbolKind};
+use crate::error::{Result, WheelError};
+
+#[derive(Debug, Clone, Copy)]
+pub struct DumpOptions {
+    pub sections: bool,
+    pub symbols: bool,
+    pub disassemble: bool,
+    /// Treat the input as a flat binary (`--mode gb`) loaded at this address
+    pub raw_base: Option<u64>,
+    /// Print the Wheel source line before the instructions generated for it
+    pub line_numbers: bool,
+}
+
+#[cfg(feature = "native")]
+pub fn dump_file(path: &Path, opts: DumpOptions) -> Result<String> {
+    let data = fs::read(path).map_err(|e| WheelError::io(path, e))?;
+    dump(&data, &path.display().to_string(), opts)
+}
+
+/// Dump an in-memory binary; `name` labels it in the output and errors.
+pub fn dump(data: &[u8], name: &str, opts: DumpOptions) -> Result<String> {
+    if let Some(base) = opts.raw_base {
+        return Ok(dump_raw(data, base));
+    }
+    let file = object::File::parse(data).map_err(|e| {
+        let msg = format!("not a recognised object file (use --raw for flat binaries): {}", e);
+        WheelError::io(name, std::io::Error::new(std::io::ErrorKind::InvalidData, msg))
+    })?;
+
+    let mut out = String::new();
+    writeln!(out, "{}: {:?} {:?}, entry 0x{:x}", name, file.format(), file.architecture(), file.entry()).unwrap();
+
+    if opts.sections {
+        writeln!(out, "\nSections:").unwrap();
+        writeln!(out, "  {:<3} {:<20} {:>16} {:>10}  kind", "idx", "name", "address", "size").unwrap();
+        for (i, section) in file.sections().enumerate() {
+            writeln!(out, "  {:<3} {:<20} {:>16x} {:>10x}  {:?}",
+                i, section.name().unwrap_or("?"), section.address(), section.size(), section.kind()).unwrap();
+        }
+    }
+
+    let symbols = symbol_map(&file);
+    if opts.symbols {
+        writeln!(out, "\nSymbols:").unwrap();
+        for sym in file.symbols().chain(file.dynamic_symbols()) {
+            let name = sym.name().unwrap_or("?");
+            if name.is_empty() { continue; }
+            let scope = if sym.is_global() { "g" } else { "l" };
+            writeln!(out, "  {:016x} {} {:<8} {:>8x} {}",
+                sym.address(), scope, format!("{:?}", sym.kind()), sym.size(), name).unwrap();
+        }
+    }
+
+    if opts.disassemble {
+        let mut lines = if opts.line_numbers { SourceLines::new(line_table(&file)) } else { SourceLines::default() };
+        for section in file.sections().filter(|s| s.kind() == SectionKind::Text) {
+            let bytes = section.data().unwrap_or(&[]);
+            if bytes.is_empty() { continue; }
+            writeln!(out, "\nDisassembly of section {}:", section.name().unwrap_or("?")).unwrap();
+            disassemble(&mut out, bytes, section.address(), &symbols, &mut lines);
+        }
+    }
+    Ok(out)
+}
+
+fn dump_raw(data: &[u8], base: u64) -> String {
+    let mut out = String::new();
+    writeln!(out, "flat binary, {} bytes, loaded at 0x{:x}", data.len(), base).unwrap();
+    disassemble(&mut out, data, base, &BTreeMap::new(), &mut SourceLines::default());
+    out
+}
+
+/// Rows of the DWARF line tables (`.loc` directives in `ge` assembly):
+/// address -> (file, line).
+fn line_table(file: &object::File) -> BTreeMap<u64, (String, u64)> {
+    let load = |id: gimli::SectionId| -> Result<Cow<[u8]>, gimli::Error> {
+        Ok(file.section_by_name(id.name()).and_then(|s| s.uncompressed_data().ok()).unwrap_or(Cow::Borrowed(&[])))
+    };
+    let mut rows = BTreeMap::new();
+    let Ok(sections) = gimli::DwarfSections::load(load) else { return rows };
+    let endian = if file.is_little_endian() { gimli::RunTimeEndian::Little } else { gimli::RunTimeEndian::Big };
+    let dwarf = sections.borrow(|section| gimli::EndianSlice::new(section, endian));
+    let mut units = dwarf.units();
+    while let Ok(Some(header)) = units.next() {
+        let Ok(unit) = dwarf.unit(header) else { continue };
+        let Some(program) = unit.line_program.clone() else { continue };
+        let mut program = program.rows();
+        while let Ok(Some((header, row))) = program.next_row() {
+            let Some(line) = row.line().filter(|_| !row.end_sequence()) else { continue };
+            let name = row.file(header)
+                .and_then(|f| dwarf.attr_string(&unit, f.path_name()).ok())
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "??".to_string());
+            rows.insert(row.address(), (name, line.get()));
+        }
+    }
+    rows
+}
+
+/// Source annotations for the disassembly.
+#[derive(Default)]
+struct SourceLines {
+    rows: BTreeMap<u64, (String, u64)>,
+    /// Lines of the source files read so far; `None` if unreadable
+    files: HashMap<String, Option<Vec<String>>>,
+}
+
+impl SourceLines {
+    fn new(rows: BTreeMap<u64, (String, u64)>) -> Self {
+        SourceLines { rows, files: HashMap::new() }
+    }
+
+    /// `file:line` and the line's text, if an instruction at `addr` starts one.
+    fn at(&mut self, addr: u64) -> Option<String> {
+        let (file, line) = self.rows.get(&addr)?;
+        let text = self.files.entry(file.clone()).or_insert_with(|| read_lines(file))
+            .as_ref()
+            .and_then(|lines| lines.get(*line as usize - 1))
+            .map(|l| format!(" {}", l.trim()))
+            .unwrap_or_default();
+        Some(format!("{}:{}:{}", file, line, text))
+    }
+}
+
+#[cfg(feature = "native")]
+fn read_lines(path: &str) -> Option<Vec<String>> {
+    fs::read_to_string(path).ok().map(|s| s.lines().map(str::to_string).collect())
+}
+
+#[cfg(not(feature = "native"))]
+fn read_lines(_path: &str) -> Option<Vec<String>> {
+    None
+}
+
+/// Address -> name for function and label symbols, used to annotate the listing.
+fn symbol_map(file: &object::File) -> BTreeMap<u64, String> {
+    file.symbols()
+        .filter(|s| matches!(s.kind(), SymbolKind::Text | SymbolKind::Label | SymbolKind::Unknown))
+        .filter_map(|s| s.name().ok().filter(|n| !n.is_empty()).map(|n| (s.address(), n.to_string())))
+        .collect()
+}
+
+fn disassemble(out: &mut String, bytes: &[u8], base: u64, symbols: &BTreeMap<u64, String>, lines: &mut SourceLines) {
+    let mut decoder = Decoder::with_ip(64, bytes, base, DecoderOptions::NONE);
+    let mut formatter = IntelFormatter::new();
+    formatter.options_mut().set_first_operand_char_index(8);
+    let mut insn = Instruction::default();
+    let mut text = String::new();
+
+    while decoder.can_decode() {
+        decoder.decode_out(&mut insn);
+        if let Some(name) = symbols.get(&insn.ip()) {
+            writeln!(out, "\n{:016x} <{}>:", insn.ip(), name).unwrap();
+        }
+        if let Some(line) = lines.at(insn.ip()) {
+            writeln!(out, "{}", line).unwrap();
+        }
+        text.clear();
+        formatter.format(&insn, &mut text);
+
+        let start = (insn.ip() - base) as usize;
+        let raw: Vec<String> = bytes[start..start + insn.len()].iter().map(|b| format!("{:02x}", b)).collect();
+        writeln!(out, "  {:8x}:  {:<30} {}", insn.ip(), raw.join(" "), text).unwrap();
+    }
+}
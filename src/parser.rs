@@ -1,20 +1,236 @@
-use crate::lexer::{Lexer, Token};
-use crate::ast::{Expr, BinOp, Stmt, Program, Type};
+use crate::cst::TokenStream;
+use crate::lexer::Token;
+use crate::ast::{CSignature, Expr, BinOp, InlineTarget, Loc, Stmt, Program, Type};
+use crate::diagnostic::{Diagnostic, Span};
+use crate::edition::{Edition, Feature};
 
 pub struct Parser<'a> {
-    lex: Lexer<'a>,
+    src: &'a str,
+    tokens: std::vec::IntoIter<(Token, Span)>,
+    /// Problems the lexer found, such as unterminated literals
+    lex_errors: Vec<Diagnostic>,
     lookahead: Token,
+    /// Where `lookahead` is in the source
+    span: Span,
+    /// End of the last token consumed
+    prev_end: usize,
+    /// Span of every statement parsed, in the order they were completed
+    stmt_spans: Vec<Span>,
+    errors: Vec<Diagnostic>,
+    edition: Edition,
 }
 
 impl<'a> Parser<'a> {
-    pub fn new(src: &'a str) -> Self {
-        let mut lx = Lexer::new(src);
-        let la = lx.next_token();
-        Self { lex: lx, lookahead: la }
+    /// Parser that rejects syntax newer than `edition`.
+    pub fn with_edition(src: &'a str, edition: Edition) -> Self {
+        Self::from_stream(&TokenStream::lex(src), edition)
+    }
+
+    /// Parser over an already lexed source, e.g. one a formatter also reads the comments of.
+    pub fn from_stream(stream: &TokenStream<'a>, edition: Edition) -> Self {
+        let mut tokens = stream.to_tokens().into_iter();
+        let (la, span) = tokens.next().expect("a token stream ends with EOF");
+        Self {
+            src: stream.source(), tokens, lex_errors: stream.diagnostics().to_vec(), lookahead: la, span,
+            prev_end: span.start, stmt_spans: Vec::new(), errors: Vec::new(), edition,
+        }
+    }
+
+    /// Record an error located at the lookahead token.
+    fn error(&mut self, diag: Diagnostic) {
+        self.errors.push(diag.with_span(self.span));
+    }
+
+    fn require(&mut self, feature: Feature) {
+        if let Some(diag) = feature.check(self.edition) {
+            self.error(diag);
+        }
+    }
+
+    /// Syntax errors found so far, including unterminated literals reported by the lexer.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        let mut all = self.lex_errors.clone();
+        all.extend(self.errors.iter().cloned());
+        all.sort_by_key(|d| d.span.map(|s| s.start));
+        all
     }
 
     fn bump(&mut self) {
-        self.lookahead = self.lex.next_token();
+        self.prev_end = self.span.end;
+        // past the end, `EOF` stays the lookahead
+        if let Some((token, span)) = self.tokens.next() {
+            self.lookahead = token;
+            self.span = span;
+        }
+    }
+
+    fn close_block(&mut self, what: &str) {
+        if self.lookahead == Token::RBrace {
+            self.bump();
+        } else {
+            self.error(Diagnostic::error("unclosed-block", format!("unclosed `{}` block, expected `}}` before end of file", what))
+                .with_suggestion(format!("add `}}` to close the `{}` block", what)));
+        }
+    }
+
+    /// Statements up to the closing `}` of a block whose `{` was already consumed.
+    fn parse_block(&mut self, what: &str) -> Vec<Stmt> {
+        let mut body = Vec::new();
+        while self.lookahead != Token::RBrace && self.lookahead != Token::EOF {
+            if let Some(s) = self.parse_stmt() {
+                body.push(s);
+            } else {
+                self.bump();
+            }
+        }
+        self.close_block(what);
+        body
+    }
+
+    /// `@if COND { ... }` with optional `else { ... }` / `else @if ...`; the `@` was consumed.
+    fn parse_cfg_if(&mut self) -> Option<Stmt> {
+        if self.lookahead != Token::If {
+            self.error(Diagnostic::error("expected-token", "expected `if` after `@`"));
+            return None;
+        }
+        self.bump();
+        let cond = self.parse_expr()?;
+        if self.lookahead != Token::LBrace {
+            self.error(Diagnostic::error("expected-token", "expected `{` after `@if` condition"));
+            return None;
+        }
+        self.bump();
+        let then_body = self.parse_block("@if");
+        let else_body = if self.lookahead == Token::Else {
+            self.bump();
+            if self.lookahead == Token::At {
+                self.bump();
+                self.parse_cfg_if().map(|s| vec![s])
+            } else if self.lookahead == Token::LBrace {
+                self.bump();
+                Some(self.parse_block("else"))
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        Some(Stmt::CfgIf { cond, then_body, else_body })
+    }
+
+    /// `asm("...")` or `llvm("...")` after `@`: one string literal, which may
+    /// span lines.
+    fn parse_inline(&mut self, target: InlineTarget) -> Option<Stmt> {
+        let what = match target {
+            InlineTarget::Asm => "@asm",
+            InlineTarget::Llvm => "@llvm",
+        };
+        self.bump();
+        if self.lookahead != Token::LParen {
+            self.error(Diagnostic::error("expected-token", format!("expected `(` after `{}`", what)));
+            return None;
+        }
+        self.bump();
+        let Token::Str(code) = &self.lookahead else {
+            self.error(Diagnostic::error("expected-token", format!("`{}` takes a string literal", what))
+                .with_suggestion(format!("write the code as `{}(\"...\")`", what)));
+            return None;
+        };
+        let code = code.clone();
+        self.bump();
+        if self.lookahead != Token::RParen {
+            self.error(Diagnostic::error("expected-token", format!("expected `)` after the `{}` code", what)));
+            return None;
+        }
+        self.bump();
+        if self.lookahead == Token::Semicolon { self.bump(); }
+        Some(Stmt::Inline { target, code })
+    }
+
+    /// `func NAME(PARAM: TYPE, ...) -> TYPE` after `extern`, then `;` for a
+    /// foreign declaration or, when `public` (`pub extern func`), a body
+    /// exported to C. Only `int` and `str` cross the C boundary; the return
+    /// type is optional.
+    fn parse_extern(&mut self, public: bool) -> Option<Stmt> {
+        if self.lookahead != Token::Func {
+            self.error(Diagnostic::error("expected-token", "expected `func` after `extern`"));
+            return None;
+        }
+        self.bump();
+        let Token::Ident(name) = &self.lookahead else {
+            self.error(Diagnostic::error("expected-token", "expected a function name after `extern func`"));
+            return None;
+        };
+        let name = name.clone();
+        self.bump();
+        if self.lookahead != Token::LParen {
+            self.error(Diagnostic::error("expected-token", format!("expected `(` after `extern func {}`", name)));
+            return None;
+        }
+        self.bump();
+        let mut params = Vec::new();
+        while self.lookahead != Token::RParen && self.lookahead != Token::EOF {
+            let Token::Ident(p) = &self.lookahead else {
+                self.error(Diagnostic::error("expected-token", format!("expected parameter name in `{}`", name)));
+                return None;
+            };
+            let p = p.clone();
+            self.bump();
+            if self.lookahead != Token::Colon {
+                self.error(Diagnostic::error("expected-token", format!("expected `: TYPE` after parameter `{}`", p))
+                    .with_suggestion(format!("extern parameters need a type, e.g. `{}: int`", p)));
+                return None;
+            }
+            self.bump();
+            params.push((p, self.parse_extern_type()?));
+            if self.lookahead == Token::Comma {
+                self.bump();
+            }
+        }
+        if self.lookahead == Token::RParen {
+            self.bump();
+        }
+        let ret = if self.lookahead == Token::Minus {
+            self.bump();
+            if self.lookahead != Token::Gt {
+                self.error(Diagnostic::error("expected-token", "expected `->` before the return type"));
+                return None;
+            }
+            self.bump();
+            Some(self.parse_extern_type()?)
+        } else {
+            None
+        };
+        if public {
+            if self.lookahead != Token::LBrace {
+                self.error(Diagnostic::error("expected-token", format!("expected the body of `pub extern func {}`", name))
+                    .with_suggestion("drop `pub` to declare a C function instead of defining one"));
+                return None;
+            }
+            self.bump();
+            let body = self.parse_block("func");
+            let (params, types) = params.into_iter().unzip();
+            return Some(Stmt::Func { name, params, body, export: Some(CSignature { params: types, ret }), comptime: false });
+        }
+        if self.lookahead == Token::LBrace {
+            self.error(Diagnostic::error("expected-token", format!("extern function `{}` cannot have a body", name))
+                .with_suggestion("end the declaration with `;`, or define it with `pub extern func` to export it"));
+            return None;
+        }
+        if self.lookahead == Token::Semicolon {
+            self.bump();
+        }
+        Some(Stmt::Extern { name, params, ret })
+    }
+
+    fn parse_extern_type(&mut self) -> Option<Type> {
+        match self.parse_type() {
+            Some(ty @ (Type::Int | Type::Str)) => Some(ty),
+            _ => {
+                self.error(Diagnostic::error("extern-type", "extern functions only take and return `int` or `str`"));
+                None
+            }
+        }
     }
 
     fn parse_type(&mut self) -> Option<Type> {
@@ -62,10 +278,27 @@ impl<'a> Parser<'a> {
                 self.bump();
             }
         }
-        Program { items }
+        // a statement starts before the ones nested in it, so source order
+        // is pre-order; statements parsed inside a construct that was later
+        // dropped leave extra spans, and then there are no locations at all
+        let mut spans = std::mem::take(&mut self.stmt_spans);
+        spans.sort_by_key(|s| s.start);
+        let locs = if spans.len() == crate::sourcemap::count(&items) {
+            spans.into_iter().map(|span| Loc { file: None, span }).collect()
+        } else {
+            Vec::new()
+        };
+        Program { items, locs }
     }
 
     fn parse_stmt(&mut self) -> Option<Stmt> {
+        let start = self.span.start;
+        let stmt = self.parse_stmt_kind()?;
+        self.stmt_spans.push(Span::new(self.src, start, self.prev_end.max(start)));
+        Some(stmt)
+    }
+
+    fn parse_stmt_kind(&mut self) -> Option<Stmt> {
         match &self.lookahead {
             Token::Struct => {
                 self.bump();
@@ -92,9 +325,7 @@ impl<'a> Parser<'a> {
                                 self.bump();
                             }
                         }
-                        if self.lookahead == Token::RBrace {
-                            self.bump();
-                        }
+                        self.close_block("struct");
                         if self.lookahead == Token::Semicolon {
                             self.bump();
                         }
@@ -120,9 +351,7 @@ impl<'a> Parser<'a> {
                             self.bump();
                         }
                     }
-                    if self.lookahead == Token::RBrace {
-                        self.bump();
-                    }
+                    self.close_block("if");
                     let else_body = if self.lookahead == Token::Else {
                         self.bump();
                         // Support `else if` (elif) by allowing `else` followed by `if`.
@@ -141,9 +370,7 @@ impl<'a> Parser<'a> {
                                     self.bump();
                                 }
                             }
-                            if self.lookahead == Token::RBrace {
-                                self.bump();
-                            }
+                            self.close_block("else");
                             Some(eb)
                         } else {
                             None
@@ -168,9 +395,7 @@ impl<'a> Parser<'a> {
                             self.bump();
                         }
                     }
-                    if self.lookahead == Token::RBrace {
-                        self.bump();
-                    }
+                    self.close_block("while");
                     return Some(Stmt::While { cond, body });
                 }
                 None
@@ -205,7 +430,7 @@ impl<'a> Parser<'a> {
                                             body.push(s);
                                         } else { self.bump(); }
                                     }
-                                    if self.lookahead == Token::RBrace { self.bump(); }
+                                    self.close_block("for");
                                     return Some(Stmt::ForRange { var, start, end, body });
                                 }
                             }
@@ -214,6 +439,50 @@ impl<'a> Parser<'a> {
                 }
                 None
             }
+            Token::At => {
+                let at = self.span;
+                self.bump();
+                let inline = match &self.lookahead {
+                    Token::Ident(name) if name == "asm" => Some(InlineTarget::Asm),
+                    Token::Ident(name) if name == "llvm" => Some(InlineTarget::Llvm),
+                    _ => None,
+                };
+                let feature = if inline.is_some() { Feature::Inline } else { Feature::CfgIf };
+                if let Some(diag) = feature.check(self.edition) {
+                    self.errors.push(diag.with_span(at));
+                }
+                match inline {
+                    Some(target) => self.parse_inline(target),
+                    None => self.parse_cfg_if(),
+                }
+            }
+            Token::Extern => {
+                self.require(Feature::Extern);
+                self.bump();
+                self.parse_extern(false)
+            }
+            Token::Pub => {
+                self.require(Feature::Extern);
+                self.bump();
+                if self.lookahead != Token::Extern {
+                    self.error(Diagnostic::error("expected-token", "expected `extern func` after `pub`"));
+                    return None;
+                }
+                self.bump();
+                self.parse_extern(true)
+            }
+            Token::Comptime => {
+                self.require(Feature::Comptime);
+                self.bump();
+                if self.lookahead != Token::Func {
+                    self.error(Diagnostic::error("expected-token", "expected `func` after `comptime`"));
+                    return None;
+                }
+                match self.parse_stmt() {
+                    Some(Stmt::Func { name, params, body, export, .. }) => Some(Stmt::Func { name, params, body, export, comptime: true }),
+                    other => other,
+                }
+            }
             Token::Import => {
                 self.bump();
                 // Esperamos: import "lib_name" ou import "path/to/lib"
@@ -281,6 +550,10 @@ impl<'a> Parser<'a> {
                             if let Token::Ident(p) = &self.lookahead {
                                 params.push(p.clone());
                                 self.bump();
+                            } else if self.lookahead != Token::Comma {
+                                // e.g. a keyword used as a parameter name; skip it instead of spinning
+                                self.error(Diagnostic::error("expected-token", format!("expected parameter name in `{}`", n)));
+                                self.bump();
                             }
                             if self.lookahead == Token::Comma {
                                 self.bump();
@@ -299,10 +572,8 @@ impl<'a> Parser<'a> {
                                     self.bump();
                                 }
                             }
-                            if self.lookahead == Token::RBrace {
-                                self.bump();
-                            }
-                            return Some(Stmt::Func { name: n, params, body });
+                            self.close_block("func");
+                            return Some(Stmt::Func { name: n, params, body, export: None, comptime: false });
                         }
                     }
                 }
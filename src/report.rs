@@ -0,0 +1,148 @@
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use crate::diagnostic::Diagnostic;
+use crate::error::WheelError;
+
+/// Which stage of the build failed. Each kind maps to a distinct process exit
+/// code so build systems can tell broken source apart from a broken toolchain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// I/O problems, unsupported host, disabled backend, ...
+    Other,
+    Parse,
+    Semantic,
+    Link,
+}
+
+impl FailureKind {
+    pub fn exit_code(&self) -> u8 {
+        // 2 is left to clap for command-line usage errors
+        match self {
+            FailureKind::Other => 1,
+            FailureKind::Parse => 3,
+            FailureKind::Semantic => 4,
+            FailureKind::Link => 5,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FailureKind::Other => "other",
+            FailureKind::Parse => "parse",
+            FailureKind::Semantic => "semantic",
+            FailureKind::Link => "link",
+        }
+    }
+}
+
+/// Diagnostics of the [`WheelError`] in `err`'s chain, if any.
+pub fn diagnostics(err: &anyhow::Error) -> &[Diagnostic] {
+    err.chain()
+        .find_map(|e| e.downcast_ref::<WheelError>())
+        .map(WheelError::diagnostics)
+        .unwrap_or_default()
+}
+
+/// Classify an error by the [`WheelError`] in its chain; `Other` without one.
+pub fn failure_kind(err: &anyhow::Error) -> FailureKind {
+    err.chain()
+        .find_map(|e| e.downcast_ref::<WheelError>())
+        .map(WheelError::kind)
+        .unwrap_or(FailureKind::Other)
+}
+
+/// Machine-readable summary of one compiler invocation (`--build-report json`).
+#[derive(Debug, Default)]
+pub struct BuildReport {
+    pub mode: String,
+    pub inputs: Vec<PathBuf>,
+    pub artifacts: Vec<PathBuf>,
+    pub warnings: usize,
+    /// Whether codegen was served from `target/wheel/cache`; `None` with `--no-cache`
+    pub cache_hit: Option<bool>,
+    pub phases: Vec<(&'static str, Duration)>,
+    pub total: Duration,
+    pub failure: Option<(FailureKind, String)>,
+}
+
+impl BuildReport {
+    /// Run `f` and record how long it took under `phase`.
+    pub fn time<T>(&mut self, phase: &'static str, f: impl FnOnce() -> T) -> T {
+        let start = Instant::now();
+        let out = f();
+        self.phases.push((phase, start.elapsed()));
+        out
+    }
+
+    /// Record a phase that started at `since` and ends now.
+    pub fn record(&mut self, phase: &'static str, since: Instant) {
+        self.phases.push((phase, since.elapsed()));
+    }
+
+    pub fn add_input(&mut self, path: &Path) {
+        self.inputs.push(path.to_path_buf());
+    }
+
+    pub fn add_artifact(&mut self, path: &Path) {
+        self.artifacts.push(path.to_path_buf());
+    }
+
+    pub fn to_json(&self) -> String {
+        let paths = |ps: &[PathBuf]| ps.iter()
+            .map(|p| json_str(&p.display().to_string()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let phases = self.phases.iter()
+            .map(|(name, d)| format!("{}: {:.3}", json_str(name), ms(*d)))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut out = String::from("{\n");
+        out.push_str(&format!("  \"status\": {},\n", json_str(if self.failure.is_some() { "failed" } else { "ok" })));
+        out.push_str(&format!("  \"mode\": {},\n", json_str(&self.mode)));
+        out.push_str(&format!("  \"inputs\": [{}],\n", paths(&self.inputs)));
+        out.push_str(&format!("  \"artifacts\": [{}],\n", paths(&self.artifacts)));
+        out.push_str(&format!("  \"warnings\": {},\n", self.warnings));
+        let cache = match self.cache_hit {
+            Some(true) => json_str("hit"),
+            Some(false) => json_str("miss"),
+            None => "null".to_string(),
+        };
+        out.push_str(&format!("  \"cache\": {},\n", cache));
+        out.push_str(&format!("  \"durations_ms\": {{{}}},\n", phases));
+        out.push_str(&format!("  \"total_ms\": {:.3},\n", ms(self.total)));
+        match &self.failure {
+            Some((kind, msg)) => {
+                out.push_str(&format!(
+                    "  \"error\": {{\"kind\": {}, \"exit_code\": {}, \"message\": {}}}\n",
+                    json_str(kind.as_str()), kind.exit_code(), json_str(msg)
+                ));
+            }
+            None => out.push_str("  \"error\": null\n"),
+        }
+        out.push('}');
+        out
+    }
+}
+
+fn ms(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+pub(crate) fn json_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,191 @@
+// Runtime objects: the C and Rust sources in `src/stdlib` that `--mode ll`
+// programs link against, built by wheelc itself.
+//
+// Both the sources and the objects the build script precompiled from them
+// for wheelc's own target are embedded in the binary, so no checkout or C
+// compiler is needed. They end up per target triple in
+// `<build_dir>/stdlib/<triple>` (also what `wheelc build-stdlib` fills):
+// extracted when wheelc carries the object for the triple, compiled from
+// source otherwise, and redone only when that object, the source, the
+// compiler command or wheelc changes. An object whose compiler or headers
+// are missing (SDL2, say) is left out of the link; the failure is kept so a
+// failed link can name it.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use anyhow::{Context, Result};
+use wheel::ast::{Expr, Program};
+use wheel::cache::Fingerprint;
+use wheel::visit::{walk_expr, Visitor};
+
+/// Triple the embedded objects were built for: the one wheelc was built for.
+pub const EMBEDDED_TRIPLE: &str = env!("WHEEL_STDLIB_TRIPLE");
+
+// `Object`, `OBJECTS` and the compiler commands, shared with the build script
+include!("runtime_objects.rs");
+// `embedded(name)`: the source of an object and the bytes the build script
+// precompiled from it, empty if it could not
+include!(concat!(env!("OUT_DIR"), "/runtime_embedded.rs"));
+
+/// How an object came out of [`build`].
+#[cfg_attr(not(feature = "llvm"), allow(dead_code))]
+pub enum Outcome {
+    /// Up to date from an earlier build
+    Fresh(PathBuf),
+    /// Written out from the embedded object
+    Extracted(PathBuf),
+    Compiled(PathBuf),
+    /// Compiler output or the reason it could not run
+    Failed(String),
+}
+
+/// The runtime objects of one target triple.
+pub struct Runtime {
+    pub dir: PathBuf,
+    pub objects: Vec<(&'static Object, Outcome)>,
+}
+
+/// Directory the objects for `triple` are built in.
+pub fn dir(build_dir: &Path, triple: &str) -> PathBuf {
+    build_dir.join("stdlib").join(triple)
+}
+
+/// Extract or build every object for `triple` under `build_dir`, reusing the
+/// ones whose key still matches unless `force`. A compile failure is
+/// recorded in the result; only failing to write the directory is an error.
+pub fn build(build_dir: &Path, triple: &str, force: bool) -> Result<Runtime> {
+    let dir = dir(build_dir, triple);
+    let src_dir = dir.join("src");
+    fs::create_dir_all(&src_dir).with_context(|| format!("failed to create {}", src_dir.display()))?;
+    let mut objects = Vec::new();
+    for object in OBJECTS {
+        let out = dir.join(format!("{}.o", object.name));
+        let stamp = dir.join(format!("{}.key", object.name));
+        let (code, bytes) = embedded(object.name);
+        let embedded = (triple == EMBEDDED_TRIPLE && !bytes.is_empty()).then_some(bytes);
+        let key = object.key(triple, code, embedded);
+        if !force && out.is_file() && fs::read_to_string(&stamp).is_ok_and(|k| k == key) {
+            objects.push((object, Outcome::Fresh(out)));
+            continue;
+        }
+        // written to a temp file first so an interrupted build never leaves a
+        // truncated object behind
+        let tmp = out.with_extension("o.tmp");
+        let made = match embedded {
+            Some(bytes) => {
+                fs::write(&tmp, bytes).with_context(|| format!("failed to write {}", tmp.display()))?;
+                Ok(Outcome::Extracted as fn(PathBuf) -> Outcome)
+            }
+            None => {
+                let src = src_dir.join(object.file);
+                fs::write(&src, code).with_context(|| format!("failed to write {}", src.display()))?;
+                object.compile(triple, &src, &tmp).map(|()| Outcome::Compiled as fn(PathBuf) -> Outcome)
+            }
+        };
+        let outcome = match made {
+            Ok(outcome) => {
+                fs::rename(&tmp, &out).with_context(|| format!("failed to write {}", out.display()))?;
+                fs::write(&stamp, key).with_context(|| format!("failed to write {}", stamp.display()))?;
+                outcome(out)
+            }
+            Err(msg) => {
+                let _ = fs::remove_file(&tmp);
+                Outcome::Failed(msg)
+            }
+        };
+        objects.push((object, outcome));
+    }
+    Ok(Runtime { dir, objects })
+}
+
+impl Object {
+    /// Key of the object: the `embedded` one, or the one built from `code`
+    /// with this command.
+    fn key(&self, triple: &str, code: &str, embedded: Option<&[u8]>) -> String {
+        let mut key = Fingerprint::new();
+        key.update(env!("CARGO_PKG_VERSION").as_bytes());
+        match embedded {
+            Some(bytes) => key.update(bytes),
+            None => {
+                key.update(code.as_bytes());
+                for arg in self.command(triple, "rustc") {
+                    key.update(arg.as_bytes());
+                }
+            }
+        }
+        key.hex()
+    }
+
+    fn compile(&self, triple: &str, src: &Path, out: &Path) -> std::result::Result<(), String> {
+        let cmd = self.command(triple, "rustc");
+        let output = Command::new(&cmd[0])
+            .args(&cmd[1..])
+            .arg(src)
+            .arg("-o").arg(out)
+            .output()
+            .map_err(|e| format!("failed to run {}: {}", cmd[0], e))?;
+        if output.status.success() {
+            return Ok(());
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let first = stderr.lines().find(|l| !l.trim().is_empty()).unwrap_or("");
+        Err(format!("{} failed: {}", cmd[0], first.trim()))
+    }
+}
+
+#[cfg_attr(not(feature = "llvm"), allow(dead_code))]
+impl Runtime {
+    /// Linker arguments: every built object whose requirements were built too,
+    /// and the `requested` on-request objects, each followed by its libraries.
+    /// Asking for an object that failed to build is an error.
+    pub fn link_args(&self, requested: &[&str]) -> Result<Vec<String>> {
+        let built = |name: &str| self.objects.iter().any(|(o, out)| o.name == name && !matches!(out, Outcome::Failed(_)));
+        let mut args = Vec::new();
+        for (object, outcome) in &self.objects {
+            if object.on_request && !requested.contains(&object.name) {
+                continue;
+            }
+            match outcome {
+                Outcome::Fresh(path) | Outcome::Extracted(path) | Outcome::Compiled(path) => {
+                    if object.requires.iter().all(|r| built(r)) {
+                        args.push(path.to_string_lossy().into_owned());
+                        args.extend(object.libs.iter().map(|l| l.to_string()));
+                    }
+                }
+                Outcome::Failed(msg) if object.on_request => {
+                    anyhow::bail!("{}.o could not be built: {}", object.name, msg);
+                }
+                Outcome::Failed(_) => {}
+            }
+        }
+        Ok(args)
+    }
+
+    /// Names of the objects that failed to build, `sdl_wrappers.o, ...`.
+    pub fn failed(&self) -> Vec<String> {
+        self.objects.iter()
+            .filter(|(_, out)| matches!(out, Outcome::Failed(_)))
+            .map(|(o, _)| format!("{}.o", o.name))
+            .collect()
+    }
+}
+
+/// Whether `prog` calls an `sdl_*` builtin, so needs `sdl_wrappers.o` and
+/// the SDL libraries.
+#[cfg_attr(not(feature = "llvm"), allow(dead_code))]
+pub fn calls_sdl(prog: &Program) -> bool {
+    let mut calls = SdlCalls(false);
+    calls.visit_program(prog);
+    calls.0
+}
+
+struct SdlCalls(bool);
+
+impl Visitor for SdlCalls {
+    fn visit_expr(&mut self, e: &Expr) {
+        if let Expr::Call { name, .. } = e {
+            self.0 |= name.starts_with("sdl_") && wheel::lint::BUILTINS.contains(&name.as_str());
+        }
+        walk_expr(self, e);
+    }
+}
@@ -0,0 +1,112 @@
+// The runtime objects and how to compile them, shared by the build script,
+// which precompiles them, and `src/runtime.rs`, which links them: both
+// `include!` this file, so the table and the compiler commands cannot drift
+// apart. It must compile on its own in either crate.
+
+enum Lang {
+    C,
+    Rust,
+}
+
+#[allow(dead_code)]
+pub struct Object {
+    /// File stem of the object, `memory` for `memory.o`
+    pub name: &'static str,
+    /// Source file in `src/stdlib`
+    file: &'static str,
+    lang: Lang,
+    /// Extra compiler flags
+    flags: &'static [&'static str],
+    /// Objects it calls into; it is linked only when they are
+    requires: &'static [&'static str],
+    /// Linker flags that follow it
+    libs: &'static [&'static str],
+    /// Linked only when asked for (`--gc`, `--debug-alloc`, a call to an
+    /// `sdl_*` builtin)
+    on_request: bool,
+}
+
+#[allow(dead_code)]
+impl Object {
+    const fn new(name: &'static str, file: &'static str, lang: Lang) -> Self {
+        Object { name, file, lang, flags: &[], requires: &[], libs: &[], on_request: false }
+    }
+
+    const fn c(name: &'static str, file: &'static str) -> Self {
+        Object::new(name, file, Lang::C)
+    }
+
+    const fn rust(name: &'static str, file: &'static str) -> Self {
+        Object::new(name, file, Lang::Rust)
+    }
+
+    const fn with_flags(mut self, flags: &'static [&'static str]) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    const fn with_requires(mut self, requires: &'static [&'static str]) -> Self {
+        self.requires = requires;
+        self
+    }
+
+    const fn with_libs(mut self, libs: &'static [&'static str]) -> Self {
+        self.libs = libs;
+        self
+    }
+
+    const fn on_request(mut self) -> Self {
+        self.on_request = true;
+        self
+    }
+
+    /// Compiler and arguments for `triple`, without the input and output;
+    /// `rustc` is the Rust compiler to run.
+    fn command(&self, triple: &str, rustc: &str) -> Vec<String> {
+        let mut cmd: Vec<String> = match self.lang {
+            Lang::C => {
+                let cc = std::env::var("CC").ok().filter(|cc| !cc.is_empty());
+                match (cc, triple) {
+                    (Some(cc), _) => vec![cc],
+                    (None, "x86_64-unknown-linux-gnu") => vec!["cc".into()],
+                    (None, "x86_64-pc-windows-gnu") => vec!["x86_64-w64-mingw32-gcc".into()],
+                    (None, _) => vec!["clang".into(), format!("--target={}", triple)],
+                }
+                .into_iter()
+                .chain(["-c", "-O2"].map(String::from))
+                .collect()
+            }
+            Lang::Rust => std::iter::once(rustc)
+                .chain(["--edition", "2021", "--crate-type", "lib", "--emit", "obj", "-C", "panic=abort", "-O", "--target", triple])
+                .map(String::from)
+                .collect(),
+        };
+        cmd.extend(self.flags.iter().map(|f| f.to_string()));
+        cmd
+    }
+}
+
+/// Every runtime object, in link order.
+pub const OBJECTS: &[Object] = &[
+    // its libraries must not be needed by programs that draw nothing
+    Object::c("sdl_wrappers", "sdl_wrappers.c").on_request().with_libs(&["-lSDL2", "-lSDL2_image"]),
+    // `time_format` allocates its strings with `mem_alloc`
+    Object::c("os_wrappers", "os_wrappers.c").with_requires(&["memory"]),
+    Object::c("luck", "luck.c"),
+    Object::c("memory", "memory.c"),
+    Object::c("vec", "vec.c"),
+    // maps return their keys as vectors
+    Object::c("map", "map.c").with_requires(&["vec"]),
+    Object::rust("strings", "strings.rs"),
+    Object::c("regex", "regex.c"),
+    Object::rust("math", "math_wrapper.rs").with_libs(&["-lm"]),
+    // recent C libraries include pthreads, and need no `libpthread.so`
+    Object::c("threads", "threads.c").with_flags(&["-pthread"]).with_libs(&["-Wl,--as-needed", "-lpthread", "-Wl,--no-as-needed"]),
+    Object::c("hwio", "hwio.c"),
+    Object::c("filesystem", "filesystem.c"),
+    Object::c("process", "process.c"),
+    // the collector takes over every allocation made outside the C library
+    Object::c("gc", "gc.c").on_request()
+        .with_libs(&["-Wl,--wrap=malloc,--wrap=calloc,--wrap=realloc,--wrap=free,--wrap=mem_alloc,--wrap=mem_free"]),
+    Object::c("debug_alloc", "debug_alloc.c").on_request(),
+];
@@ -0,0 +1,49 @@
+// Shebang scripts.
+//
+// A file whose first line is `#!/usr/bin/env wheelc` is run rather than just
+// built when no `-o` is given: `./hello.wheel a b` compiles it (ge mode) and
+// executes the result with `a b` as arguments. Executables are kept under
+// `<build dir>/scripts/<cache key>`, so an unchanged script starts without
+// recompiling or relinking.
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{Context, Result};
+use crate::cache::Fingerprint;
+use crate::config::{self, LayoutConfig};
+
+pub fn is_script(src: &str) -> bool {
+    src.starts_with("#!")
+}
+
+/// Build directory for a script: the project's when the script lives in one,
+/// otherwise the per-user cache (`~/.cache/wheel`), so running a loose script
+/// never litters the current directory.
+pub fn build_dir(input: &Path, layout: &LayoutConfig) -> PathBuf {
+    if config::find_project_file(input).is_some() {
+        return layout.build_dir(&config::project_root(input));
+    }
+    dirs::cache_dir().unwrap_or_else(std::env::temp_dir).join("wheel")
+}
+
+/// Where the executable for a script with cache key `key` lives.
+pub fn executable(build_dir: &Path, key: Fingerprint) -> Result<PathBuf> {
+    let dir = build_dir.join("scripts");
+    fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    Ok(dir.join(format!("{}{}", key.hex(), std::env::consts::EXE_SUFFIX)))
+}
+
+/// Run a compiled script in place of the compiler, passing `args` through.
+/// Only returns if the executable could not be started.
+#[cfg(unix)]
+pub fn exec(exe: &Path, script: &Path, args: &[String]) -> Result<()> {
+    use std::os::unix::process::CommandExt;
+    let err = std::process::Command::new(exe).arg0(script).args(args).exec();
+    Err(err).with_context(|| format!("failed to run {}", script.display()))
+}
+
+#[cfg(not(unix))]
+pub fn exec(exe: &Path, script: &Path, args: &[String]) -> Result<()> {
+    let status = std::process::Command::new(exe).args(args).status()
+        .with_context(|| format!("failed to run {}", script.display()))?;
+    std::process::exit(status.code().unwrap_or(1))
+}
@@ -0,0 +1,162 @@
+// `wheelc self-update`: replace the running compiler with the latest release.
+//
+// Only available when built with `--features self-update`; the compiler never
+// talks to the network otherwise. The release endpoint returns GitHub-style
+// JSON (`tag_name` plus `assets[].name/browser_download_url`). The binary for
+// this platform is the asset `wheelc-<triple>[.exe]`, and its SHA-256 must be
+// published either as `<asset>.sha256` or as a line in `SHA256SUMS`; an
+// unverifiable download is never installed.
+use anyhow::Result;
+
+#[cfg_attr(not(feature = "self-update"), allow(dead_code))]
+pub enum Outcome {
+    UpToDate(String),
+    /// `--check` found a newer release
+    Available(String),
+    Updated { from: String, to: String },
+}
+
+#[cfg(not(feature = "self-update"))]
+pub fn run(_check_only: bool, _force: bool) -> Result<Outcome> {
+    anyhow::bail!("self-update support not enabled. Rebuild with `--features self-update`")
+}
+
+#[cfg(feature = "self-update")]
+pub fn run(check_only: bool, force: bool) -> Result<Outcome> {
+    imp::run(check_only, force)
+}
+
+#[cfg(feature = "self-update")]
+mod imp {
+    use std::fs;
+    use std::io::Read;
+    use std::path::Path;
+    use anyhow::{Context, Result};
+    use serde::Deserialize;
+    use sha2::{Digest, Sha256};
+    use super::Outcome;
+
+    const DEFAULT_ENDPOINT: &str = "https://api.github.com/repos/smoreiradossantos133-sudo/Wheel/releases/latest";
+    /// Overrides [`DEFAULT_ENDPOINT`], e.g. for a mirror.
+    const ENDPOINT_ENV: &str = "WHEEL_UPDATE_URL";
+
+    const CURRENT: &str = env!("CARGO_PKG_VERSION");
+
+    /// Release asset name for the platform this compiler was built for.
+    fn asset_name() -> String {
+        let arch = std::env::consts::ARCH;
+        match std::env::consts::OS {
+            "windows" => format!("wheelc-{}-pc-windows-msvc.exe", arch),
+            "macos" => format!("wheelc-{}-apple-darwin", arch),
+            _ => format!("wheelc-{}-unknown-linux-gnu", arch),
+        }
+    }
+
+    /// `true` if `latest` (e.g. `v0.2.0`) is newer than `current` (`0.1.0`).
+    fn is_newer(latest: &str, current: &str) -> bool {
+        let parse = |v: &str| -> Option<Vec<u64>> {
+            v.trim_start_matches('v').split('.').map(|p| p.parse().ok()).collect()
+        };
+        match (parse(latest), parse(current)) {
+            (Some(l), Some(c)) => l > c,
+            _ => latest.trim_start_matches('v') != current,
+        }
+    }
+
+    #[derive(Deserialize)]
+    struct Release {
+        tag_name: String,
+        assets: Vec<Asset>,
+    }
+
+    #[derive(Deserialize)]
+    struct Asset {
+        name: String,
+        browser_download_url: String,
+    }
+
+    fn get(url: &str) -> Result<ureq::Response> {
+        ureq::get(url)
+            .set("User-Agent", concat!("wheelc/", env!("CARGO_PKG_VERSION")))
+            .call()
+            .with_context(|| format!("request to {} failed", url))
+    }
+
+    fn download(url: &str) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        get(url)?.into_reader().read_to_end(&mut bytes)
+            .with_context(|| format!("failed to download {}", url))?;
+        Ok(bytes)
+    }
+
+    /// Expected digest of `name`, from `<name>.sha256` or `SHA256SUMS`.
+    fn expected_sha256(release: &Release, name: &str) -> Result<String> {
+        let sums = release.assets.iter()
+            .find(|a| a.name == format!("{}.sha256", name))
+            .or_else(|| release.assets.iter().find(|a| a.name == "SHA256SUMS"))
+            .with_context(|| format!("release {} publishes no checksum for {}; refusing to install", release.tag_name, name))?;
+        let text = String::from_utf8(download(&sums.browser_download_url)?)
+            .context("checksum file is not UTF-8")?;
+        text.lines()
+            .filter_map(|l| {
+                let mut parts = l.split_whitespace();
+                let digest = parts.next()?;
+                // `<digest>` alone (per-asset file) or `<digest>  [*]<name>` (SHA256SUMS)
+                match parts.next() {
+                    None => Some(digest),
+                    Some(file) if file.trim_start_matches('*') == name => Some(digest),
+                    Some(_) => None,
+                }
+            })
+            .next()
+            .map(|d| d.to_ascii_lowercase())
+            .with_context(|| format!("no checksum for {} in {}", name, sums.name))
+    }
+
+    /// Write the new binary next to `exe` and rename it into place, so an
+    /// interrupted update never leaves a half-written compiler behind.
+    fn replace_exe(exe: &Path, bytes: &[u8]) -> Result<()> {
+        let file_name = exe.file_name().context("executable path has no file name")?.to_string_lossy();
+        let staged = exe.with_file_name(format!(".{}.new", file_name));
+        fs::write(&staged, bytes).with_context(|| format!("failed to write {}", staged.display()))?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&staged, fs::Permissions::from_mode(0o755))?;
+        }
+        // a running executable cannot be overwritten on Windows, but it can be moved aside
+        #[cfg(windows)]
+        {
+            let old = exe.with_file_name(format!("{}.old", file_name));
+            let _ = fs::remove_file(&old);
+            fs::rename(exe, &old).with_context(|| format!("failed to move {} aside", exe.display()))?;
+        }
+        fs::rename(&staged, exe).with_context(|| format!("failed to replace {}", exe.display()))
+    }
+
+    pub fn run(check_only: bool, force: bool) -> Result<Outcome> {
+        let endpoint = std::env::var(ENDPOINT_ENV).unwrap_or_else(|_| DEFAULT_ENDPOINT.to_string());
+        let release: Release = get(&endpoint)?.into_json().context("malformed release metadata")?;
+        let latest = release.tag_name.trim_start_matches('v').to_string();
+        if !force && !is_newer(&release.tag_name, CURRENT) {
+            return Ok(Outcome::UpToDate(CURRENT.to_string()));
+        }
+        if check_only {
+            return Ok(Outcome::Available(latest));
+        }
+
+        let name = asset_name();
+        let asset = release.assets.iter().find(|a| a.name == name)
+            .with_context(|| format!("release {} has no binary for this platform ({})", release.tag_name, name))?;
+        let expected = expected_sha256(&release, &name)?;
+        let bytes = download(&asset.browser_download_url)?;
+        let actual: String = Sha256::digest(&bytes).iter().map(|b| format!("{:02x}", b)).collect();
+        if actual != expected {
+            anyhow::bail!("checksum mismatch for {}: expected {}, got {}", name, expected, actual);
+        }
+
+        let exe = std::env::current_exe()?.canonicalize()?;
+        replace_exe(&exe, &bytes)?;
+        Ok(Outcome::Updated { from: CURRENT.to_string(), to: latest })
+    }
+}
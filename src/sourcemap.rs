@@ -0,0 +1,129 @@
+// Source maps: which Wheel statement produced which part of the output.
+//
+// The parser records a `Loc` for every statement in `Program::locs`, in
+// pre-order, and the front end keeps them in step while `@if` blocks are
+// resolved and imports inlined. A backend looks statements up in a `LocIndex`
+// while generating code and records the output lines each one produced in a
+// `SourceMap`. The `ge`/`gb` assembly also carries the locations as DWARF
+// `.loc` directives, so debuggers and `wheelc objdump -l` can show the
+// source line of every instruction.
+use std::collections::HashMap;
+use std::ops::Range;
+use crate::ast::{Loc, Program, Stmt};
+use crate::report::json_str;
+
+/// Number of statements in `stmts`, nested ones included.
+pub fn count(stmts: &[Stmt]) -> usize {
+    stmts.iter().map(|s| 1 + children(s).into_iter().map(count).sum::<usize>()).sum()
+}
+
+/// Bodies nested directly in `stmt`, in source order.
+fn children(stmt: &Stmt) -> Vec<&[Stmt]> {
+    match stmt {
+        Stmt::Func { body, .. } | Stmt::While { body, .. } | Stmt::ForRange { body, .. } => vec![body],
+        Stmt::If { then_body, else_body, .. } | Stmt::CfgIf { then_body, else_body, .. } => {
+            std::iter::once(then_body.as_slice()).chain(else_body.as_deref()).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Location of every statement of one program, found by the statement's
+/// address, so a backend can ask for any `&Stmt` borrowed from the program.
+/// Empty when the program's `locs` do not match its statements.
+pub struct LocIndex<'a> {
+    map: HashMap<*const Stmt, &'a Loc>,
+}
+
+impl<'a> LocIndex<'a> {
+    pub fn new(prog: &'a Program) -> Self {
+        let mut map = HashMap::new();
+        if prog.locs.len() == count(&prog.items) {
+            index(&prog.items, &mut prog.locs.iter(), &mut map);
+        }
+        LocIndex { map }
+    }
+
+    pub fn get(&self, stmt: &Stmt) -> Option<&'a Loc> {
+        self.map.get(&(stmt as *const Stmt)).copied()
+    }
+}
+
+fn index<'a>(stmts: &[Stmt], locs: &mut std::slice::Iter<'a, Loc>, map: &mut HashMap<*const Stmt, &'a Loc>) {
+    for stmt in stmts {
+        if let Some(loc) = locs.next() {
+            map.insert(stmt as *const Stmt, loc);
+        }
+        for body in children(stmt) {
+            index(body, locs, map);
+        }
+    }
+}
+
+/// Moves a program's `locs` along while a pass rebuilds its statements in
+/// order: `keep` for statements that stay, `skip` for dropped ones. Does
+/// nothing when the locations were unusable to begin with.
+pub(crate) struct LocCursor {
+    input: std::vec::IntoIter<Loc>,
+    pub(crate) out: Vec<Loc>,
+}
+
+impl LocCursor {
+    /// Cursor over the locations of `prog`, which are taken out of it.
+    pub(crate) fn take(prog: &mut Program) -> Self {
+        let locs = std::mem::take(&mut prog.locs);
+        let input = if locs.len() == count(&prog.items) { locs } else { Vec::new() };
+        LocCursor { input: input.into_iter(), out: Vec::new() }
+    }
+
+    pub(crate) fn keep(&mut self, n: usize) {
+        self.out.extend(self.input.by_ref().take(n));
+    }
+
+    pub(crate) fn skip(&mut self, n: usize) {
+        self.input.by_ref().take(n).for_each(drop);
+    }
+}
+
+/// Lines of generated text produced by one statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    /// 1-based line numbers, end exclusive
+    pub lines: Range<usize>,
+    pub loc: Loc,
+}
+
+/// Generated text back to Wheel source, one mapping per statement that
+/// produced output. The mappings of nested statements lie inside their
+/// parent's.
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    pub mappings: Vec<Mapping>,
+}
+
+impl SourceMap {
+    /// The innermost statement that produced output line `line`.
+    pub fn lookup(&self, line: usize) -> Option<&Loc> {
+        self.mappings.iter()
+            .filter(|m| m.lines.contains(&line))
+            .min_by_key(|m| m.lines.len())
+            .map(|m| &m.loc)
+    }
+
+    /// `{"version": 1, "mappings": [{"lines": [start, end], "file": ..., "line": ..., "column": ..., "bytes": [start, end]}]}`,
+    /// written for `--emit sourcemap`.
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\n  \"version\": 1,\n  \"mappings\": [");
+        for (i, m) in self.mappings.iter().enumerate() {
+            let file = m.loc.file.as_ref().map(|f| json_str(&f.display().to_string())).unwrap_or_else(|| "null".to_string());
+            let span = m.loc.span;
+            out.push_str(if i == 0 { "\n" } else { ",\n" });
+            out.push_str(&format!(
+                "    {{\"lines\": [{}, {}], \"file\": {}, \"line\": {}, \"column\": {}, \"bytes\": [{}, {}]}}",
+                m.lines.start, m.lines.end, file, span.line, span.column, span.start, span.end
+            ));
+        }
+        out.push_str(if self.mappings.is_empty() { "]\n}\n" } else { "\n  ]\n}\n" });
+        out
+    }
+}
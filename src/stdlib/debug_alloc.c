@@ -0,0 +1,136 @@
+// Checking allocator for Wheel: what `mem_alloc` and `mem_free` call in a
+// program built with `wheelc --mode ll --debug-alloc`.
+//
+// The code generator passes the file and line of each call. Every block
+// gets pages of its own from mmap; freeing one makes its pages inaccessible
+// instead of reusing them, so a later read or write faults and is reported
+// together with where the block was allocated and freed. Freeing a block
+// twice, or a pointer `mem_alloc` never returned, aborts the same way, and
+// the blocks still live at exit are listed as leaks. Meant for debugging:
+// each block costs at least a page. wheelc builds and links it for
+// `--debug-alloc` (see `src/runtime_objects.rs`).
+#define _GNU_SOURCE
+#include <signal.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <sys/mman.h>
+#include <unistd.h>
+
+struct debug_block {
+    uintptr_t addr;
+    size_t size;
+    size_t mapped;
+    const char* alloc_file;
+    int64_t alloc_line;
+    // NULL while the block is live
+    const char* free_file;
+    int64_t free_line;
+};
+
+// Every block ever allocated, in allocation order; grown with mmap so the
+// table never comes from the heap it is checking
+static struct debug_block* blocks = NULL;
+static size_t block_count = 0;
+static size_t block_cap = 0;
+static size_t page_size = 0;
+
+static const char* where(const char* file) {
+    return file ? file : "<unknown>";
+}
+
+static struct debug_block* find(uintptr_t addr) {
+    for (size_t i = block_count; i > 0; i--) {
+        struct debug_block* b = &blocks[i - 1];
+        if (addr >= b->addr && addr < b->addr + b->mapped) return b;
+    }
+    return NULL;
+}
+
+static void report_leaks(void) {
+    size_t leaked = 0, bytes = 0;
+    for (size_t i = 0; i < block_count; i++) {
+        struct debug_block* b = &blocks[i];
+        if (b->free_file) continue;
+        fprintf(stderr, "wheel: leak: %zu bytes allocated at %s:%ld\n", b->size, where(b->alloc_file), (long)b->alloc_line);
+        leaked++;
+        bytes += b->size;
+    }
+    if (leaked) fprintf(stderr, "wheel: %zu block(s) leaked, %zu bytes\n", leaked, bytes);
+}
+
+// A fault inside a freed block is a use after free; anything else gets the
+// default action
+static void on_fault(int sig, siginfo_t* info, void* context) {
+    (void)context;
+    struct debug_block* b = find((uintptr_t)info->si_addr);
+    if (b && b->free_file) {
+        fprintf(stderr, "wheel: use of freed memory: block of %zu bytes allocated at %s:%ld, freed at %s:%ld\n",
+            b->size, where(b->alloc_file), (long)b->alloc_line, where(b->free_file), (long)b->free_line);
+        abort();
+    }
+    signal(sig, SIG_DFL);
+    raise(sig);
+}
+
+// Install the fault handler and the leak report on first use
+static void setup(void) {
+    if (page_size) return;
+    page_size = (size_t)sysconf(_SC_PAGESIZE);
+    struct sigaction sa;
+    memset(&sa, 0, sizeof sa);
+    sa.sa_sigaction = on_fault;
+    sa.sa_flags = SA_SIGINFO;
+    sigaction(SIGSEGV, &sa, NULL);
+    sigaction(SIGBUS, &sa, NULL);
+    atexit(report_leaks);
+}
+
+static int grow_table(void) {
+    size_t cap = block_cap ? block_cap * 2 : 1024;
+    void* table = mmap(NULL, cap * sizeof *blocks, PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+    if (table == MAP_FAILED) return 0;
+    if (blocks) {
+        memcpy(table, blocks, block_count * sizeof *blocks);
+        munmap(blocks, block_cap * sizeof *blocks);
+    }
+    blocks = table;
+    block_cap = cap;
+    return 1;
+}
+
+// `mem_alloc(size)` called at file:line; 0 if size is 0 or there is no memory
+int64_t wheel_debug_alloc(int64_t size, int64_t file, int64_t line) {
+    setup();
+    if (size <= 0) return 0;
+    if (block_count == block_cap && !grow_table()) return 0;
+    size_t mapped = ((size_t)size + page_size - 1) / page_size * page_size;
+    void* p = mmap(NULL, mapped, PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+    if (p == MAP_FAILED) return 0;
+    blocks[block_count++] = (struct debug_block){
+        (uintptr_t)p, (size_t)size, mapped, (const char*)(intptr_t)file, line, NULL, 0,
+    };
+    return (int64_t)(intptr_t)p;
+}
+
+// `mem_free(ptr)` called at file:line; aborts unless ptr is a live block
+int64_t wheel_debug_free(int64_t ptr, int64_t file, int64_t line) {
+    setup();
+    if (ptr == 0) return 0;
+    const char* at = where((const char*)(intptr_t)file);
+    struct debug_block* b = find((uintptr_t)ptr);
+    if (!b || b->addr != (uintptr_t)ptr) {
+        fprintf(stderr, "wheel: mem_free at %s:%ld of %#lx, which mem_alloc did not return\n", at, (long)line, (unsigned long)ptr);
+        abort();
+    }
+    if (b->free_file) {
+        fprintf(stderr, "wheel: double free at %s:%ld: block of %zu bytes allocated at %s:%ld, already freed at %s:%ld\n",
+            at, (long)line, b->size, where(b->alloc_file), (long)b->alloc_line, where(b->free_file), (long)b->free_line);
+        abort();
+    }
+    b->free_file = at;
+    b->free_line = line;
+    mprotect((void*)b->addr, b->mapped, PROT_NONE);
+    return 0;
+}
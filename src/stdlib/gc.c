@@ -0,0 +1,172 @@
+// Conservative mark-sweep collector for Wheel: the heap `wheelc --gc`
+// links in place of manual freeing.
+//
+// The link step passes `-Wl,--wrap=` for malloc, calloc, realloc, free,
+// mem_alloc and mem_free, so the allocations of the program and of the
+// runtime objects land here while the C library keeps its own heap. free
+// and mem_free do nothing: a block is reclaimed once no word in the main
+// thread's stack and registers, the program's data and bss, or another
+// reachable block points into it. Pointers held only by other threads or
+// inside the C library are not seen, so this is not for threaded programs.
+// Relies on glibc for __libc_malloc and the stack and data bounds. wheelc
+// builds and links it for `--gc` (see `src/runtime_objects.rs`).
+#include <setjmp.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+void* __libc_malloc(size_t size);
+void __libc_free(void* ptr);
+extern void* __libc_stack_end;
+extern char __data_start[], _end[];
+
+// Header in front of every block; 32 bytes keeps the payload 16-aligned
+struct gc_block {
+    struct gc_block* next;
+    size_t size;
+    size_t marked;
+    size_t pad;
+};
+
+static struct gc_block* blocks = NULL;
+static size_t block_count = 0;
+static size_t live_bytes = 0;
+static size_t allocated_since = 0;
+// Bytes to allocate before the next collection
+static size_t threshold = 1 << 20;
+
+// Blocks sorted by address and the mark stack, during a collection
+static struct gc_block** sorted;
+static struct gc_block** pending;
+static size_t pending_len;
+
+static int by_address(const void* a, const void* b) {
+    uintptr_t x = (uintptr_t)*(struct gc_block* const*)a;
+    uintptr_t y = (uintptr_t)*(struct gc_block* const*)b;
+    return x < y ? -1 : x > y;
+}
+
+// Block whose payload contains `addr` (or ends at it), if any
+static struct gc_block* find(uintptr_t addr) {
+    size_t lo = 0, hi = block_count;
+    while (lo < hi) {
+        size_t mid = lo + (hi - lo) / 2;
+        if ((uintptr_t)(sorted[mid] + 1) <= addr) lo = mid + 1;
+        else hi = mid;
+    }
+    // sorted[lo - 1] is the last block whose payload starts at or before addr
+    if (lo == 0) return NULL;
+    struct gc_block* b = sorted[lo - 1];
+    return addr <= (uintptr_t)(b + 1) + b->size ? b : NULL;
+}
+
+// Mark every block some aligned word in [lo, hi) points into
+static void scan(const void* lo, const void* hi) {
+    uintptr_t p = ((uintptr_t)lo + 7) & ~(uintptr_t)7;
+    for (; p + sizeof(uintptr_t) <= (uintptr_t)hi; p += sizeof(uintptr_t)) {
+        struct gc_block* b = find(*(const uintptr_t*)p);
+        if (b && !b->marked) {
+            b->marked = 1;
+            pending[pending_len++] = b;
+        }
+    }
+}
+
+static void __attribute__((noinline)) collect(void) {
+    if (block_count == 0) return;
+    sorted = __libc_malloc(block_count * sizeof *sorted);
+    pending = __libc_malloc(block_count * sizeof *pending);
+    if (!sorted || !pending) {
+        // no room to collect; try again after the next threshold
+        __libc_free(sorted);
+        __libc_free(pending);
+        allocated_since = 0;
+        return;
+    }
+    size_t n = 0;
+    for (struct gc_block* b = blocks; b; b = b->next) sorted[n++] = b;
+    qsort(sorted, block_count, sizeof *sorted, by_address);
+    pending_len = 0;
+
+    // registers spilled into the jmp_buf, then the stack above it
+    jmp_buf regs;
+    setjmp(regs);
+    scan(&regs, (char*)&regs + sizeof regs);
+    scan(&regs, __libc_stack_end);
+    scan(__data_start, _end);
+    while (pending_len > 0) {
+        struct gc_block* b = pending[--pending_len];
+        scan(b + 1, (char*)(b + 1) + b->size);
+    }
+
+    live_bytes = 0;
+    for (struct gc_block** link = &blocks; *link;) {
+        struct gc_block* b = *link;
+        if (b->marked) {
+            b->marked = 0;
+            live_bytes += b->size;
+            link = &b->next;
+        } else {
+            *link = b->next;
+            block_count--;
+            __libc_free(b);
+        }
+    }
+    __libc_free(sorted);
+    __libc_free(pending);
+    sorted = pending = NULL;
+    allocated_since = 0;
+    threshold = live_bytes > (1 << 20) ? live_bytes : (1 << 20);
+}
+
+// A zeroed block of `size` bytes, collecting first when enough has been
+// allocated since the last collection
+static void* gc_alloc(size_t size) {
+    if (allocated_since >= threshold) collect();
+    struct gc_block* b = __libc_malloc(sizeof *b + size);
+    if (!b) {
+        collect();
+        b = __libc_malloc(sizeof *b + size);
+        if (!b) return NULL;
+    }
+    memset(b + 1, 0, size);
+    b->size = size;
+    b->marked = 0;
+    b->next = blocks;
+    blocks = b;
+    block_count++;
+    live_bytes += size;
+    allocated_since += size;
+    return b + 1;
+}
+
+void* __wrap_malloc(size_t size) {
+    return gc_alloc(size);
+}
+
+void* __wrap_calloc(size_t count, size_t size) {
+    if (size && count > SIZE_MAX / size) return NULL;
+    return gc_alloc(count * size);
+}
+
+void* __wrap_realloc(void* ptr, size_t size) {
+    void* grown = gc_alloc(size);
+    if (ptr && grown) {
+        size_t old = ((struct gc_block*)ptr - 1)->size;
+        memcpy(grown, ptr, old < size ? old : size);
+    }
+    return grown;
+}
+
+void __wrap_free(void* ptr) {
+    (void)ptr;
+}
+
+void* __wrap_mem_alloc(size_t size) {
+    return size ? gc_alloc(size) : NULL;
+}
+
+void __wrap_mem_free(void* ptr) {
+    (void)ptr;
+}
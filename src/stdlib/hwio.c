@@ -1,8 +1,9 @@
 /**
  * Hardware I/O Library for Wheel
- * Direct port I/O access for bare metal programming
+ * Direct port I/O access and interrupt gates for bare metal programming
  */
 
+#include <stddef.h>
 #include <stdint.h>
 
 /**
@@ -71,6 +72,22 @@ void io_write_port(int port, long value) {
     io_write_port_32((uint16_t)port, (uint32_t)value);
 }
 
+/**
+ * `port_read_byte(port)` in Wheel
+ */
+int64_t port_read_byte(int64_t port) {
+    return io_read_port_8((uint16_t)port);
+}
+
+/**
+ * `port_write_byte(port, value)` in Wheel
+ * @return: 0
+ */
+int64_t port_write_byte(int64_t port, int64_t value) {
+    io_write_port_8((uint16_t)port, (uint8_t)value);
+    return 0;
+}
+
 /**
  * Enable interrupts
  */
@@ -105,3 +122,56 @@ void io_halt() {
 void io_nop() {
     asm("nop");
 }
+
+/**
+ * 64-bit interrupt gate, one IDT entry
+ */
+struct idt_gate {
+    uint16_t offset_low;
+    uint16_t selector;
+    uint8_t ist;
+    uint8_t type_attr;
+    uint16_t offset_mid;
+    uint32_t offset_high;
+    uint32_t zero;
+} __attribute__((packed));
+
+struct idt_pointer {
+    uint16_t limit;
+    uint64_t base;
+} __attribute__((packed));
+
+static struct idt_gate idt[256] __attribute__((aligned(16)));
+static int idt_loaded = 0;
+
+/**
+ * Point interrupt `vector` at `stub`, the interrupt stub wheelc generates
+ * for `irq_register`. The first call copies the gates of the IDT in use
+ * into wheel's own table and loads that, so earlier handlers keep working.
+ * @return: 1, or 0 if vector is not 0-255
+ */
+int64_t wheel_irq_register(int64_t vector, int64_t stub) {
+    if (vector < 0 || vector > 255) return 0;
+    if (!idt_loaded) {
+        struct idt_pointer current;
+        asm volatile("sidt %0" : "=m" (current));
+        size_t size = (size_t)current.limit + 1;
+        if (current.base && size <= sizeof idt) {
+            const uint8_t* from = (const uint8_t*)(uintptr_t)current.base;
+            uint8_t* to = (uint8_t*)idt;
+            for (size_t i = 0; i < size; i++) to[i] = from[i];
+        }
+    }
+    uint16_t cs;
+    asm volatile("mov %%cs, %0" : "=r" (cs));
+    uint64_t addr = (uint64_t)stub;
+    idt[vector] = (struct idt_gate){
+        (uint16_t)addr, cs, 0, 0x8E, (uint16_t)(addr >> 16), (uint32_t)(addr >> 32), 0,
+    };
+    if (!idt_loaded) {
+        struct idt_pointer ours = { sizeof idt - 1, (uint64_t)(uintptr_t)idt };
+        asm volatile("lidt %0" : : "m" (ours));
+        idt_loaded = 1;
+    }
+    return 1;
+}
@@ -30,3 +30,33 @@ long luck_random_range(long min, long max) {
     if (min == max) return min;
     return min + (long)(rand() % (max - min + 1));
 }
+
+// Restart the sequence from `seed`, so a run can be reproduced
+void luck_seed(long seed) {
+    srand((unsigned int)seed);
+    _luck_seed_initialized = 1;
+}
+
+// Uniform fraction in [0, 1), in millionths (0 to 999999): Wheel has no
+// floating-point type yet
+long luck_random_float(void) {
+    luck_init();
+    return (long)(rand() / ((double)RAND_MAX + 1.0) * 1000000.0);
+}
+
+// Shuffle `len` values at `items` in place (Fisher-Yates)
+void luck_shuffle(long *items, long len) {
+    luck_init();
+    for (long i = len - 1; i > 0; i--) {
+        long j = luck_random(i);
+        long tmp = items[i];
+        items[i] = items[j];
+        items[j] = tmp;
+    }
+}
+
+// One of the `len` values at `items`, or 0 when there are none
+long luck_choice(const long *items, long len) {
+    if (len <= 0) return 0;
+    return items[luck_random(len - 1)];
+}
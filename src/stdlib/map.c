@@ -0,0 +1,154 @@
+// Hash map runtime for Wheel: the `wheel_map_*` symbols behind the `map_*`
+// builtins.
+//
+// A handle is the address of a `struct wheel_map`, passed around as an
+// int64. Keys are strings, copied on insertion; values are int64s. Entries
+// are kept in insertion order, which is the order `wheel_map_keys` returns
+// them in, with an open-addressing index over them. Storage comes from
+// malloc, so a map is not bounded by the heap of memory.o; the key list is a
+// vec.o vector, so link that alongside; wheelc does (see
+// `src/runtime_objects.rs`).
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+int64_t wheel_vec_new(void);
+int64_t wheel_vec_push(int64_t vec, int64_t value);
+
+struct wheel_map {
+    int64_t len;
+    int64_t cap;
+    char **keys;
+    int64_t *values;
+    // entry index + 1 for each slot, 0 when the slot is free
+    int64_t *slots;
+    int64_t nslots;
+};
+
+// FNV-1a
+static uint64_t hash(const char *key) {
+    uint64_t h = 14695981039346656037u;
+    for (; *key; key++) {
+        h ^= (unsigned char)*key;
+        h *= 1099511628211u;
+    }
+    return h;
+}
+
+// Slot holding `key`, or the free slot where it would go
+static int64_t find_slot(struct wheel_map *m, const char *key) {
+    int64_t i = hash(key) & (m->nslots - 1);
+    while (m->slots[i] && strcmp(m->keys[m->slots[i] - 1], key) != 0) {
+        i = (i + 1) & (m->nslots - 1);
+    }
+    return i;
+}
+
+// Double the index and rebuild it; 0 if it could not be allocated
+static int grow_index(struct wheel_map *m) {
+    int64_t nslots = m->nslots * 2;
+    int64_t *slots = malloc(nslots * sizeof *slots);
+    if (!slots) return 0;
+    memset(slots, 0, nslots * sizeof *slots);
+    free(m->slots);
+    m->slots = slots;
+    m->nslots = nslots;
+    for (int64_t e = 0; e < m->len; e++) {
+        m->slots[find_slot(m, m->keys[e])] = e + 1;
+    }
+    return 1;
+}
+
+// Double the entry arrays; 0 if they could not be allocated
+static int grow_entries(struct wheel_map *m) {
+    int64_t cap = m->cap ? m->cap * 2 : 4;
+    char **keys = malloc(cap * sizeof *keys);
+    int64_t *values = malloc(cap * sizeof *values);
+    if (!keys || !values) {
+        free(keys);
+        free(values);
+        return 0;
+    }
+    if (m->len) {
+        memcpy(keys, m->keys, m->len * sizeof *keys);
+        memcpy(values, m->values, m->len * sizeof *values);
+    }
+    free(m->keys);
+    free(m->values);
+    m->keys = keys;
+    m->values = values;
+    m->cap = cap;
+    return 1;
+}
+
+// A new empty map; 0 if it could not be allocated
+int64_t wheel_map_new(void) {
+    struct wheel_map *m = malloc(sizeof *m);
+    if (!m) return 0;
+    m->nslots = 8;
+    m->slots = malloc(m->nslots * sizeof *m->slots);
+    if (!m->slots) {
+        free(m);
+        return 0;
+    }
+    memset(m->slots, 0, m->nslots * sizeof *m->slots);
+    m->len = 0;
+    m->cap = 0;
+    m->keys = NULL;
+    m->values = NULL;
+    return (int64_t)(intptr_t)m;
+}
+
+// Set `key` to `value`, adding the key if it is new; 1 on success, 0 if the
+// map could not grow
+int64_t wheel_map_set(int64_t map, const char *key, int64_t value) {
+    struct wheel_map *m = (struct wheel_map *)(intptr_t)map;
+    if (!m || !key) return 0;
+    int64_t slot = find_slot(m, key);
+    if (m->slots[slot]) {
+        m->values[m->slots[slot] - 1] = value;
+        return 1;
+    }
+    // keep the index at most half full
+    if ((m->len + 1) * 2 > m->nslots) {
+        if (!grow_index(m)) return 0;
+        slot = find_slot(m, key);
+    }
+    if (m->len == m->cap && !grow_entries(m)) return 0;
+    size_t n = strlen(key) + 1;
+    char *copy = malloc(n);
+    if (!copy) return 0;
+    memcpy(copy, key, n);
+    m->keys[m->len] = copy;
+    m->values[m->len] = value;
+    m->slots[slot] = ++m->len;
+    return 1;
+}
+
+// The value of `key`; 0 if it is not in the map
+int64_t wheel_map_get(int64_t map, const char *key) {
+    struct wheel_map *m = (struct wheel_map *)(intptr_t)map;
+    if (!m || !key) return 0;
+    int64_t entry = m->slots[find_slot(m, key)];
+    return entry ? m->values[entry - 1] : 0;
+}
+
+// 1 if `key` is in the map, 0 otherwise
+int64_t wheel_map_has(int64_t map, const char *key) {
+    struct wheel_map *m = (struct wheel_map *)(intptr_t)map;
+    if (!m || !key) return 0;
+    return m->slots[find_slot(m, key)] != 0;
+}
+
+// A new vec.o vector of the keys, in insertion order; 0 if it could not be
+// allocated. The strings are the map's own copies.
+int64_t wheel_map_keys(int64_t map) {
+    struct wheel_map *m = (struct wheel_map *)(intptr_t)map;
+    int64_t vec = wheel_vec_new();
+    if (!m || !vec) return vec;
+    for (int64_t e = 0; e < m->len; e++) {
+        if (!wheel_vec_push(vec, (int64_t)(intptr_t)m->keys[e])) return 0;
+    }
+    return vec;
+}
@@ -1,108 +1,132 @@
 //! Advanced Math library for Wheel
 //! Provides floating-point operations, trigonometry, and linear algebra
+//!
+//! Also built on its own into `math.o`, which `wheelc` links (with `-lm`)
+//! for the `sqrt`, `sin`, ... builtins (see `src/runtime_objects.rs`).
 
 pub mod math {
     /// Sine function (radians)
+    #[no_mangle]
     pub extern "C" fn math_sin(x: f64) -> f64 {
         x.sin()
     }
 
     /// Cosine function (radians)
+    #[no_mangle]
     pub extern "C" fn math_cos(x: f64) -> f64 {
         x.cos()
     }
 
     /// Tangent function (radians)
+    #[no_mangle]
     pub extern "C" fn math_tan(x: f64) -> f64 {
         x.tan()
     }
 
     /// Arc sine function
+    #[no_mangle]
     pub extern "C" fn math_asin(x: f64) -> f64 {
         x.asin()
     }
 
     /// Arc cosine function
+    #[no_mangle]
     pub extern "C" fn math_acos(x: f64) -> f64 {
         x.acos()
     }
 
     /// Arc tangent function
+    #[no_mangle]
     pub extern "C" fn math_atan(x: f64) -> f64 {
         x.atan()
     }
 
     /// Two-argument arc tangent
+    #[no_mangle]
     pub extern "C" fn math_atan2(y: f64, x: f64) -> f64 {
         y.atan2(x)
     }
 
     /// Square root
+    #[no_mangle]
     pub extern "C" fn math_sqrt(x: f64) -> f64 {
         x.sqrt()
     }
 
     /// Power function (x^y)
+    #[no_mangle]
     pub extern "C" fn math_pow(x: f64, y: f64) -> f64 {
         x.powf(y)
     }
 
     /// Exponential function (e^x)
+    #[no_mangle]
     pub extern "C" fn math_exp(x: f64) -> f64 {
         x.exp()
     }
 
     /// Natural logarithm
+    #[no_mangle]
     pub extern "C" fn math_log(x: f64) -> f64 {
         x.ln()
     }
 
     /// Base-10 logarithm
+    #[no_mangle]
     pub extern "C" fn math_log10(x: f64) -> f64 {
         x.log10()
     }
 
     /// Absolute value
+    #[no_mangle]
     pub extern "C" fn math_abs(x: f64) -> f64 {
         x.abs()
     }
 
     /// Ceiling function
+    #[no_mangle]
     pub extern "C" fn math_ceil(x: f64) -> f64 {
         x.ceil()
     }
 
     /// Floor function
+    #[no_mangle]
     pub extern "C" fn math_floor(x: f64) -> f64 {
         x.floor()
     }
 
     /// Round function
+    #[no_mangle]
     pub extern "C" fn math_round(x: f64) -> f64 {
         x.round()
     }
 
     /// Modulo for floating-point
+    #[no_mangle]
     pub extern "C" fn math_fmod(x: f64, y: f64) -> f64 {
         x % y
     }
 
     /// Minimum of two values
+    #[no_mangle]
     pub extern "C" fn math_min(x: f64, y: f64) -> f64 {
         if x < y { x } else { y }
     }
 
     /// Maximum of two values
+    #[no_mangle]
     pub extern "C" fn math_max(x: f64, y: f64) -> f64 {
         if x > y { x } else { y }
     }
 
     /// Convert integer to float (for LLVM IR)
+    #[no_mangle]
     pub extern "C" fn math_int_to_float(i: i64) -> f64 {
         i as f64
     }
 
     /// Convert float to integer (truncate)
+    #[no_mangle]
     pub extern "C" fn math_float_to_int(f: f64) -> i64 {
         f as i64
     }
@@ -4,6 +4,7 @@
  */
 
 #include <stddef.h>
+#include <stdlib.h>
 #include <string.h>
 
 #define HEAP_SIZE 1024 * 1024  // 1MB heap
@@ -95,11 +96,42 @@ size_t mem_get_free() {
     return free;
 }
 
-// Aliases for compatibility
-void* malloc(size_t size) {
-    return mem_alloc(size);
+/**
+ * Arenas: a malloc'd block handed out front to back and reclaimed all at
+ * once, e.g. once per frame. They do not come from the heap above, so their
+ * size is not bounded by HEAP_SIZE. A handle is the address of the Arena.
+ */
+typedef struct Arena {
+    size_t size;
+    size_t used;
+    char data[];
+} Arena;
+
+// New arena of `size` bytes; 0 if it could not be allocated
+long arena_new(long size) {
+    if (size <= 0) return 0;
+    Arena* arena = malloc(sizeof(Arena) + (size_t)size);
+    if (arena == NULL) return 0;
+    arena->size = (size_t)size;
+    arena->used = 0;
+    return (long)arena;
+}
+
+// `n` bytes from the arena, 8-byte aligned; 0 if it is full
+long arena_alloc(long handle, long n) {
+    Arena* arena = (Arena*)handle;
+    if (arena == NULL || n <= 0) return 0;
+    size_t aligned = ((size_t)n + 7) & ~(size_t)7;
+    if (aligned > arena->size - arena->used) return 0;
+    char* ptr = arena->data + arena->used;
+    arena->used += aligned;
+    return (long)ptr;
 }
 
-void free(void* ptr) {
-    mem_free(ptr);
+// Make the whole arena available again; earlier allocations become invalid
+long arena_reset(long handle) {
+    Arena* arena = (Arena*)handle;
+    if (arena == NULL) return 0;
+    arena->used = 0;
+    return 1;
 }
@@ -1,10 +1,13 @@
 // OS/Syscalls C wrappers for Wheel
 // Provides process and timing operations
 
+#define _GNU_SOURCE // strptime, timegm
+#include <stdlib.h>
 #include <unistd.h>
 #include <sys/types.h>
 #include <time.h>
 #include <stdint.h>
+#include <string.h>
 
 // These wrapper functions provide the interface expected by Wheel
 // The actual system functions are called internally
@@ -24,3 +27,100 @@ int64_t wheel_sleep(int64_t seconds) {
     sleep(sec);
     return 1;
 }
+
+// Monotonic clock readings, from an unspecified starting point: only the
+// difference between two calls means anything
+int64_t wheel_time_nanos() {
+    struct timespec ts;
+    clock_gettime(CLOCK_MONOTONIC, &ts);
+    return (int64_t)ts.tv_sec * 1000000000 + ts.tv_nsec;
+}
+
+int64_t wheel_time_millis() {
+    return wheel_time_nanos() / 1000000;
+}
+
+// Value of the environment variable `name`; NULL (0 in Wheel) when it is not
+// set, so an unset variable and an empty one can be told apart
+const char *wheel_env_get(const char *name) {
+    return getenv(name);
+}
+
+// Set (or overwrite) the environment variable `name`; 1 on success, 0 on failure
+int64_t wheel_env_set(const char *name, const char *value) {
+    return setenv(name, value, 1) == 0;
+}
+
+// Calendar fields of `epoch` in UTC
+static struct tm wheel_utc(int64_t epoch) {
+    time_t t = (time_t)epoch;
+    struct tm tm;
+    memset(&tm, 0, sizeof tm);
+    gmtime_r(&t, &tm);
+    return tm;
+}
+
+// memory.o, which `--gc` routes through the collector
+void *mem_alloc(size_t size);
+void mem_free(void *ptr);
+
+// Longest result of `wheel_time_format`, terminator included
+#define TIME_FORMAT_MAX (1 << 20)
+
+// `epoch` formatted with strftime's `fmt`, in UTC, as a new string from
+// mem_alloc that the caller releases with mem_free, even when it is empty;
+// NULL (0 in Wheel) when the result is longer than TIME_FORMAT_MAX or
+// memory runs out
+char *wheel_time_format(int64_t epoch, const char *fmt) {
+    struct tm tm = wheel_utc(epoch);
+    // strftime returns 0 both for an empty result and for one that does not
+    // fit, so a marker is appended to the format and cut off the result
+    size_t len = strlen(fmt);
+    char *marked = mem_alloc(len + 2);
+    if (!marked) return NULL;
+    memcpy(marked, fmt, len);
+    marked[len] = '|';
+    marked[len + 1] = 0;
+    char *out = NULL;
+    for (size_t cap = 64; cap <= TIME_FORMAT_MAX; cap *= 2) {
+        out = mem_alloc(cap);
+        if (!out) break;
+        size_t n = strftime(out, cap, marked, &tm);
+        if (n > 0) {
+            out[n - 1] = 0;
+            break;
+        }
+        mem_free(out);
+        out = NULL;
+    }
+    mem_free(marked);
+    return out;
+}
+
+// Seconds since the epoch of UTC `text` in strptime's `fmt`; -1 when `text`
+// does not match all the way through
+int64_t wheel_time_parse(const char *text, const char *fmt) {
+    struct tm tm;
+    memset(&tm, 0, sizeof tm);
+    tm.tm_mday = 1;
+    const char *end = strptime(text, fmt, &tm);
+    if (!end || *end) return -1;
+    return (int64_t)timegm(&tm);
+}
+
+int64_t wheel_time_year(int64_t epoch) {
+    return wheel_utc(epoch).tm_year + 1900;
+}
+
+// 1 to 12
+int64_t wheel_time_month(int64_t epoch) {
+    return wheel_utc(epoch).tm_mon + 1;
+}
+
+int64_t wheel_time_day(int64_t epoch) {
+    return wheel_utc(epoch).tm_mday;
+}
+
+int64_t wheel_time_hour(int64_t epoch) {
+    return wheel_utc(epoch).tm_hour;
+}
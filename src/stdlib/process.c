@@ -9,6 +9,9 @@
 #include <sys/resource.h>
 #include <signal.h>
 #include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <fcntl.h>
 
 #define MAX_PROCESSES 256
 
@@ -16,6 +19,13 @@ typedef struct {
     pid_t pid;
     int status;
     int is_running;
+    // Our ends of the child's stdin and stdout pipes, -1 when not piped
+    int stdin_fd;
+    int stdout_fd;
+    // Output read from stdout_fd but not yet returned: out[out_pos..out_len]
+    char* out;
+    size_t out_len;
+    size_t out_pos;
 } Process;
 
 static Process processes[MAX_PROCESSES];
@@ -29,6 +39,11 @@ void process_init() {
         processes[i].pid = -1;
         processes[i].status = 0;
         processes[i].is_running = 0;
+        processes[i].stdin_fd = -1;
+        processes[i].stdout_fd = -1;
+        processes[i].out = NULL;
+        processes[i].out_len = 0;
+        processes[i].out_pos = 0;
     }
     process_count = 0;
 }
@@ -55,12 +70,173 @@ long process_create(const char* command) {
         processes[process_count].pid = child_pid;
         processes[process_count].status = 0;
         processes[process_count].is_running = 1;
+        processes[process_count].stdin_fd = -1;
+        processes[process_count].stdout_fd = -1;
+        processes[process_count].out = NULL;
+        processes[process_count].out_len = 0;
+        processes[process_count].out_pos = 0;
         int handle = process_count;
         process_count++;
         return handle;
     }
 }
 
+/**
+ * Create a child process running `command` with pipes to its stdin and
+ * stdout, for process_write and process_read_line
+ * Returns a handle like process_create or -1 on error
+ */
+long process_spawn(const char* command) {
+    if (process_count >= MAX_PROCESSES) return -1;
+
+    int in[2], out[2];
+    if (pipe(in) < 0) return -1;
+    if (pipe(out) < 0) {
+        close(in[0]);
+        close(in[1]);
+        return -1;
+    }
+    // Keep our ends out of later children, so they see EOF when we close them
+    fcntl(in[1], F_SETFD, FD_CLOEXEC);
+    fcntl(out[0], F_SETFD, FD_CLOEXEC);
+    // Writing to a child that has exited fails instead of killing us
+    signal(SIGPIPE, SIG_IGN);
+
+    pid_t child_pid = fork();
+
+    if (child_pid < 0) {
+        close(in[0]);
+        close(in[1]);
+        close(out[0]);
+        close(out[1]);
+        return -1;
+    } else if (child_pid == 0) {
+        dup2(in[0], STDIN_FILENO);
+        dup2(out[1], STDOUT_FILENO);
+        close(in[0]);
+        close(out[1]);
+        execl("/bin/sh", "sh", "-c", command, NULL);
+        _exit(127);
+    }
+
+    close(in[0]);
+    close(out[1]);
+    processes[process_count].pid = child_pid;
+    processes[process_count].status = 0;
+    processes[process_count].is_running = 1;
+    processes[process_count].stdin_fd = in[1];
+    processes[process_count].stdout_fd = out[0];
+    processes[process_count].out = NULL;
+    processes[process_count].out_len = 0;
+    processes[process_count].out_pos = 0;
+    return process_count++;
+}
+
+/**
+ * Write `data` to the stdin of a process_spawn child
+ * Returns the number of bytes written or -1 on error
+ */
+long process_write(long pid_handle, const char* data) {
+    if (pid_handle < 0 || pid_handle >= process_count) return -1;
+    int fd = processes[pid_handle].stdin_fd;
+    if (fd < 0 || !data) return -1;
+
+    size_t len = strlen(data), done = 0;
+    while (done < len) {
+        ssize_t n = write(fd, data + done, len - done);
+        if (n < 0) return -1;
+        done += n;
+    }
+    return (long)done;
+}
+
+/**
+ * Read more of a process_spawn child's stdout into its buffer
+ * Returns the number of bytes read, 0 at end of output or on error
+ */
+static size_t process_fill(Process* p) {
+    if (p->stdout_fd < 0) return 0;
+    // Drop what was already returned before growing
+    if (p->out_pos > 0) {
+        memmove(p->out, p->out + p->out_pos, p->out_len - p->out_pos);
+        p->out_len -= p->out_pos;
+        p->out_pos = 0;
+    }
+    char* bigger = realloc(p->out, p->out_len + 4096);
+    if (!bigger) return 0;
+    p->out = bigger;
+    ssize_t n = read(p->stdout_fd, p->out + p->out_len, 4096);
+    if (n <= 0) return 0;
+    p->out_len += n;
+    return (size_t)n;
+}
+
+/**
+ * Read the next line of a process_spawn child's stdout, without its newline
+ * Returns a new string, "" at end of output (tell it from an empty line with
+ * process_eof), or NULL if it could not be allocated
+ */
+char* process_read_line(long pid_handle) {
+    if (pid_handle < 0 || pid_handle >= process_count) return calloc(1, 1);
+    Process* p = &processes[pid_handle];
+
+    size_t scanned = 0;
+    char* newline = NULL;
+    for (;;) {
+        size_t left = p->out_len - p->out_pos - scanned;
+        if (left > 0 && (newline = memchr(p->out + p->out_pos + scanned, '\n', left))) break;
+        scanned += left;
+        if (!process_fill(p)) break;
+    }
+    size_t len = newline ? (size_t)(newline - (p->out + p->out_pos)) : p->out_len - p->out_pos;
+    char* line = malloc(len + 1);
+    if (!line) return NULL;
+    memcpy(line, p->out + p->out_pos, len);
+    line[len] = 0;
+    p->out_pos += newline ? len + 1 : len;
+    return line;
+}
+
+/**
+ * Whether a process_spawn child's output is used up: 1 once it has closed
+ * its stdout and every line has been read, 0 while there is more, and -1 on
+ * error. Blocks until the child writes or closes its stdout.
+ */
+long process_eof(long pid_handle) {
+    if (pid_handle < 0 || pid_handle >= process_count) return -1;
+    Process* p = &processes[pid_handle];
+    return p->out_pos == p->out_len && !process_fill(p);
+}
+
+/**
+ * Close the stdin of a process_spawn child, so it sees end of input, and
+ * wait for it; its output is read into memory first, so a child writing more
+ * than a pipe holds cannot block, and can still be read afterwards
+ * Returns exit code or -1 on error
+ */
+long process_exit_code(long pid_handle) {
+    if (pid_handle < 0 || pid_handle >= process_count) return -1;
+    Process* p = &processes[pid_handle];
+
+    if (p->stdin_fd >= 0) {
+        close(p->stdin_fd);
+        p->stdin_fd = -1;
+    }
+    if (p->stdout_fd >= 0) {
+        while (process_fill(p)) {}
+        close(p->stdout_fd);
+        p->stdout_fd = -1;
+    }
+    if (p->is_running) {
+        int status;
+        if (waitpid(p->pid, &status, 0) < 0) return -1;
+        p->is_running = 0;
+        p->status = status;
+    }
+
+    return WIFEXITED(p->status) ? WEXITSTATUS(p->status) : -1;
+}
+
 /**
  * Wait for a process to complete
  * Returns exit status or -1 on error
@@ -0,0 +1,151 @@
+// Regex runtime for Wheel: the `wheel_regex_*` symbols behind the
+// `regex_match`, `regex_find` and `regex_replace` builtins.
+//
+// Patterns are a small backtracking subset: literal bytes, `.`, classes such
+// as `[a-z_]` and `[^0-9]`, the escapes `\d`, `\w` and `\s` (any other escaped
+// byte matches itself), `*`, `+` and `?` after a single atom, and the anchors
+// `^` and `$`. There are no groups or alternation, and matching is byte-wise.
+// A malformed pattern (an unterminated `[` or a trailing `\`) matches
+// nothing. wheelc builds it like the other runtime objects
+// (see `src/runtime_objects.rs`).
+#include <ctype.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <string.h>
+
+// Length of the atom at `re`: a byte, `.`, `\x` or `[...]`; 0 if it is not
+// terminated
+static size_t atom_len(const char *re) {
+    if (re[0] == '\\') return re[1] ? 2 : 0;
+    if (re[0] != '[') return 1;
+    size_t i = 1;
+    if (re[i] == '^') i++;
+    // a `]` right after the `[` is part of the class
+    if (re[i] == ']') i++;
+    while (re[i] && re[i] != ']') i += re[i] == '\\' && re[i + 1] ? 2 : 1;
+    return re[i] ? i + 1 : 0;
+}
+
+// Whether `c` matches the escape `\e`
+static int escape_matches(unsigned char e, unsigned char c) {
+    switch (e) {
+    case 'd': return isdigit(c) != 0;
+    case 'w': return isalnum(c) || c == '_';
+    case 's': return isspace(c) != 0;
+    default: return c == e;
+    }
+}
+
+// Whether the `n`-byte atom at `atom` matches `c`
+static int atom_matches(const char *atom, size_t n, unsigned char c) {
+    if (atom[0] == '.') return 1;
+    if (atom[0] == '\\') return escape_matches(atom[1], c);
+    if (atom[0] != '[') return (unsigned char)atom[0] == c;
+    const unsigned char *set = (const unsigned char *)atom + 1;
+    size_t len = n - 2;
+    int negate = len > 0 && set[0] == '^';
+    if (negate) {
+        set++;
+        len--;
+    }
+    int found = 0;
+    for (size_t i = 0; i < len;) {
+        if (set[i] == '\\' && i + 1 < len) {
+            found |= escape_matches(set[i + 1], c);
+            i += 2;
+        } else if (i + 2 < len && set[i + 1] == '-') {
+            found |= set[i] <= c && c <= set[i + 2];
+            i += 3;
+        } else {
+            found |= set[i] == c;
+            i++;
+        }
+    }
+    return found != negate;
+}
+
+// End of a match of `re` starting at `text[at]`, trying the longest
+// repetitions first; -1 if there is none
+static int64_t match_here(const char *re, const unsigned char *text, int64_t len, int64_t at) {
+    if (!re[0]) return at;
+    if (re[0] == '$' && !re[1]) return at == len ? at : -1;
+    size_t n = atom_len(re);
+    if (!n) return -1;
+    char q = re[n];
+    if (q == '*' || q == '+' || q == '?') {
+        int64_t k = 0;
+        while ((q != '?' || k < 1) && at + k < len && atom_matches(re, n, text[at + k])) k++;
+        for (; k >= (q == '+'); k--) {
+            int64_t end = match_here(re + n + 1, text, len, at + k);
+            if (end >= 0) return end;
+        }
+        return -1;
+    }
+    if (at < len && atom_matches(re, n, text[at])) return match_here(re + n, text, len, at + 1);
+    return -1;
+}
+
+// End of the first match of `re` in `text` at or after `from`, with its
+// start in `*start`; -1 if there is none
+static int64_t search(const char *re, const unsigned char *text, int64_t len, int64_t from, int64_t *start) {
+    if (re[0] == '^') {
+        *start = 0;
+        return from == 0 ? match_here(re + 1, text, len, 0) : -1;
+    }
+    for (*start = from; *start <= len; (*start)++) {
+        int64_t end = match_here(re, text, len, *start);
+        if (end >= 0) return end;
+    }
+    return -1;
+}
+
+// Append `n` bytes to `out` unless it is NULL; `*at` counts them either way
+static void put(char *out, size_t *at, const void *s, size_t n) {
+    if (out) memcpy(out + *at, s, n);
+    *at += n;
+}
+
+// `text` with every match of `re` replaced by `with`, written to `out`
+// unless it is NULL; returns the length
+static size_t replace(const char *re, const char *text, const char *with, char *out) {
+    const unsigned char *t = (const unsigned char *)text;
+    int64_t len = strlen(text), at = 0, start, end;
+    size_t n = 0;
+    while ((end = search(re, t, len, at, &start)) >= 0) {
+        put(out, &n, text + at, start - at);
+        put(out, &n, with, strlen(with));
+        if (end > start) {
+            at = end;
+        } else {
+            // step over the byte after an empty match
+            if (start < len) put(out, &n, text + start, 1);
+            at = start + 1;
+        }
+        if (at > len) return n;
+    }
+    put(out, &n, text + at, len - at);
+    return n;
+}
+
+// 1 if `re` matches anywhere in `s`, 0 otherwise
+int64_t wheel_regex_match(const char *re, const char *s) {
+    int64_t start;
+    return search(re, (const unsigned char *)s, strlen(s), 0, &start) >= 0;
+}
+
+// Byte offset of the first match of `re` in `s`, or -1
+int64_t wheel_regex_find(const char *re, const char *s) {
+    int64_t start;
+    return search(re, (const unsigned char *)s, strlen(s), 0, &start) >= 0 ? start : -1;
+}
+
+// `s` with every non-overlapping match of `re` replaced by `with`, as a new
+// string; NULL if it could not be allocated
+char *wheel_regex_replace(const char *re, const char *s, const char *with) {
+    size_t total = replace(re, s, with, NULL);
+    char *out = malloc(total + 1);
+    if (!out) return NULL;
+    replace(re, s, with, out);
+    out[total] = 0;
+    return out;
+}
@@ -3,7 +3,6 @@
 
 #[cfg(feature = "sdl")]
 pub mod sdl {
-    use std::ffi::CString;
     use sdl2::Sdl;
     use sdl2::video::Window;
     use sdl2::render::Canvas;
@@ -11,7 +10,20 @@ pub mod sdl {
 
     lazy_static::lazy_static! {
         static ref SDL_CONTEXT: Mutex<Option<Sdl>> = Mutex::new(None);
-        static ref SDL_CANVAS: Mutex<Option<Canvas<Window>>> = Mutex::new(None);
+        /// Open windows; handle n is entry n - 1
+        static ref WINDOWS: Mutex<Vec<Option<Canvas<Window>>>> = Mutex::new(Vec::new());
+        /// Handle of the most recently created window, used for handle 0
+        static ref CURRENT: Mutex<i64> = Mutex::new(0);
+    }
+
+    /// Run `f` on the canvas of `window` (0 = most recent); 0 if it is not open
+    fn with_canvas(window: i64, f: impl FnOnce(&mut Canvas<Window>) -> i64) -> i64 {
+        let window = if window == 0 { *CURRENT.lock().unwrap() } else { window };
+        let mut windows = WINDOWS.lock().unwrap();
+        match windows.get_mut((window - 1) as usize).and_then(|w| w.as_mut()) {
+            Some(c) if window > 0 => f(c),
+            _ => 0,
+        }
     }
 
     pub fn init() -> i64 {
@@ -24,21 +36,25 @@ pub mod sdl {
         }
     }
 
-    pub fn create_window(width: i32, height: i32, title_ptr: i64) -> i64 {
-        let title = unsafe {
-            let ptr = title_ptr as *const u8;
-            std::ffi::CStr::from_ptr(ptr as *const i8)
-                .to_string_lossy()
-                .into_owned()
-        };
-
+    /// Open a window; returns its handle, or 0 on failure
+    pub fn create_window(width: i32, height: i32, title: &str) -> i64 {
         if let Ok(ctx) = SDL_CONTEXT.lock() {
             if let Some(sdl) = ctx.as_ref() {
                 if let Ok(video) = sdl.video() {
-                    if let Ok(window) = video.window(&title, width as u32, height as u32).build() {
+                    if let Ok(window) = video.window(title, width as u32, height as u32).build() {
                         if let Ok(canvas) = window.into_canvas().build() {
-                            *SDL_CANVAS.lock().unwrap() = Some(canvas);
-                            return 1;
+                            let mut windows = WINDOWS.lock().unwrap();
+                            let slot = match windows.iter().position(|w| w.is_none()) {
+                                Some(slot) => slot,
+                                None => {
+                                    windows.push(None);
+                                    windows.len() - 1
+                                }
+                            };
+                            windows[slot] = Some(canvas);
+                            let handle = slot as i64 + 1;
+                            *CURRENT.lock().unwrap() = handle;
+                            return handle;
                         }
                     }
                 }
@@ -47,59 +63,57 @@ pub mod sdl {
         0
     }
 
-    pub fn draw_pixel(x: i32, y: i32, r: u8, g: u8, b: u8) -> i64 {
-        if let Ok(mut canvas) = SDL_CANVAS.lock() {
-            if let Some(c) = canvas.as_mut() {
-                c.set_draw_color(sdl2::pixels::Color::RGB(r, g, b));
-                if c.draw_point((x, y)).is_ok() {
-                    return 1;
-                }
-            }
-        }
-        0
+    pub fn draw_pixel(window: i64, x: i32, y: i32, r: u8, g: u8, b: u8) -> i64 {
+        with_canvas(window, |c| {
+            c.set_draw_color(sdl2::pixels::Color::RGB(r, g, b));
+            c.draw_point((x, y)).is_ok() as i64
+        })
     }
 
-    pub fn draw_rect(x: i32, y: i32, w: i32, h: i32, r: u8, g: u8, b: u8) -> i64 {
-        if let Ok(mut canvas) = SDL_CANVAS.lock() {
-            if let Some(c) = canvas.as_mut() {
-                c.set_draw_color(sdl2::pixels::Color::RGB(r, g, b));
-                let rect = sdl2::rect::Rect::new(x, y, w as u32, h as u32);
-                if c.fill_rect(rect).is_ok() {
-                    return 1;
-                }
-            }
-        }
-        0
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_rect(window: i64, x: i32, y: i32, w: i32, h: i32, r: u8, g: u8, b: u8) -> i64 {
+        with_canvas(window, |c| {
+            c.set_draw_color(sdl2::pixels::Color::RGB(r, g, b));
+            let rect = sdl2::rect::Rect::new(x, y, w as u32, h as u32);
+            c.fill_rect(rect).is_ok() as i64
+        })
     }
 
-    pub fn clear(r: u8, g: u8, b: u8) -> i64 {
-        if let Ok(mut canvas) = SDL_CANVAS.lock() {
-            if let Some(c) = canvas.as_mut() {
-                c.set_draw_color(sdl2::pixels::Color::RGB(r, g, b));
-                c.clear();
-                return 1;
-            }
-        }
-        0
+    pub fn clear(window: i64, r: u8, g: u8, b: u8) -> i64 {
+        with_canvas(window, |c| {
+            c.set_draw_color(sdl2::pixels::Color::RGB(r, g, b));
+            c.clear();
+            1
+        })
     }
 
-    pub fn present() -> i64 {
-        if let Ok(mut canvas) = SDL_CANVAS.lock() {
-            if let Some(c) = canvas.as_mut() {
-                c.present();
-                return 1;
-            }
-        }
-        0
+    pub fn present(window: i64) -> i64 {
+        with_canvas(window, |c| {
+            c.present();
+            1
+        })
     }
 
-    pub fn destroy_window() -> i64 {
-        *SDL_CANVAS.lock().unwrap() = None;
-        1
+    pub fn destroy_window(window: i64) -> i64 {
+        let mut current = CURRENT.lock().unwrap();
+        let window = if window == 0 { *current } else { window };
+        let mut windows = WINDOWS.lock().unwrap();
+        match windows.get_mut((window - 1) as usize) {
+            Some(w) if window > 0 && w.is_some() => {
+                *w = None;
+                if *current == window {
+                    // fall back to the newest window still open
+                    *current = windows.iter().rposition(|w| w.is_some()).map_or(0, |i| i as i64 + 1);
+                }
+                1
+            }
+            _ => 0,
+        }
     }
 
     pub fn quit() -> i64 {
-        *SDL_CANVAS.lock().unwrap() = None;
+        WINDOWS.lock().unwrap().clear();
+        *CURRENT.lock().unwrap() = 0;
         *SDL_CONTEXT.lock().unwrap() = None;
         1
     }
@@ -108,11 +122,11 @@ pub mod sdl {
 #[cfg(not(feature = "sdl"))]
 pub mod sdl {
     pub fn init() -> i64 { -1 }
-    pub fn create_window(_w: i32, _h: i32, _t: i64) -> i64 { -1 }
-    pub fn draw_pixel(_x: i32, _y: i32, _r: u8, _g: u8, _b: u8) -> i64 { -1 }
-    pub fn draw_rect(_x: i32, _y: i32, _w: i32, _h: i32, _r: u8, _g: u8, _b: u8) -> i64 { -1 }
-    pub fn clear(_r: u8, _g: u8, _b: u8) -> i64 { -1 }
-    pub fn present() -> i64 { -1 }
-    pub fn destroy_window() -> i64 { -1 }
+    pub fn create_window(_w: i32, _h: i32, _t: &str) -> i64 { -1 }
+    pub fn draw_pixel(_win: i64, _x: i32, _y: i32, _r: u8, _g: u8, _b: u8) -> i64 { -1 }
+    pub fn draw_rect(_win: i64, _x: i32, _y: i32, _w: i32, _h: i32, _r: u8, _g: u8, _b: u8) -> i64 { -1 }
+    pub fn clear(_win: i64, _r: u8, _g: u8, _b: u8) -> i64 { -1 }
+    pub fn present(_win: i64) -> i64 { -1 }
+    pub fn destroy_window(_win: i64) -> i64 { -1 }
     pub fn quit() -> i64 { -1 }
 }
@@ -1,11 +1,29 @@
 // SDL2 C wrappers for Wheel
-// Compile with: gcc -fPIC -shared -lSDL2 sdl_wrappers.c -o libwheel_sdl.so
+// Compile with: gcc -fPIC -shared -lSDL2 -lSDL2_image sdl_wrappers.c -o libwheel_sdl.so
 
 #include <SDL2/SDL.h>
+#include <SDL2/SDL_image.h>
 #include <stdint.h>
 
-static SDL_Window* wheel_window = NULL;
-static SDL_Renderer* wheel_renderer = NULL;
+// Open windows and their renderers; window handle n is entry n - 1. Calls
+// given handle 0 use the most recently created window that is still open.
+#define WHEEL_MAX_WINDOWS 16
+static SDL_Window* wheel_windows[WHEEL_MAX_WINDOWS];
+static SDL_Renderer* wheel_renderers[WHEEL_MAX_WINDOWS];
+static int64_t wheel_current = 0;
+
+// Textures loaded by sdl_load_image and the renderer each belongs to;
+// texture handle n is entry n - 1
+#define WHEEL_MAX_TEXTURES 256
+static SDL_Texture* wheel_textures[WHEEL_MAX_TEXTURES];
+static SDL_Renderer* wheel_texture_owners[WHEEL_MAX_TEXTURES];
+
+// Renderer of `window`, or NULL when the handle is not an open window
+static SDL_Renderer* wheel_renderer_for(int64_t window) {
+    if (window == 0) window = wheel_current;
+    if (window < 1 || window > WHEEL_MAX_WINDOWS) return NULL;
+    return wheel_renderers[window - 1];
+}
 
 int64_t sdl_init() {
     if (SDL_Init(SDL_INIT_VIDEO) != 0) {
@@ -14,11 +32,14 @@ int64_t sdl_init() {
     return 1;
 }
 
-int64_t sdl_create_window(int32_t width, int32_t height, int64_t title_ptr) {
-    const char* title = (const char*)title_ptr;
-    
-    wheel_window = SDL_CreateWindow(
-        title,
+// Open a window titled `title`; returns its handle, or 0 on failure
+int64_t sdl_create_window(int32_t width, int32_t height, const char* title) {
+    int slot = 0;
+    while (slot < WHEEL_MAX_WINDOWS && wheel_windows[slot]) slot++;
+    if (slot == WHEEL_MAX_WINDOWS) return 0;
+
+    SDL_Window* window = SDL_CreateWindow(
+        title ? title : "",
         SDL_WINDOWPOS_CENTERED,
         SDL_WINDOWPOS_CENTERED,
         width,
@@ -26,73 +47,98 @@ int64_t sdl_create_window(int32_t width, int32_t height, int64_t title_ptr) {
         SDL_WINDOW_SHOWN
     );
     
-    if (!wheel_window) {
+    if (!window) {
         return 0;
     }
     
-    wheel_renderer = SDL_CreateRenderer(wheel_window, -1, SDL_RENDERER_ACCELERATED);
-    if (!wheel_renderer) {
-        SDL_DestroyWindow(wheel_window);
-        wheel_window = NULL;
+    SDL_Renderer* renderer = SDL_CreateRenderer(window, -1, SDL_RENDERER_ACCELERATED);
+    if (!renderer) {
+        SDL_DestroyWindow(window);
         return 0;
     }
     
-    return 1;
+    wheel_windows[slot] = window;
+    wheel_renderers[slot] = renderer;
+    wheel_current = slot + 1;
+    return wheel_current;
 }
 
-int64_t sdl_draw_pixel(int32_t x, int32_t y, uint8_t r, uint8_t g, uint8_t b) {
-    if (!wheel_renderer) return 0;
+int64_t sdl_draw_pixel(int64_t window, int32_t x, int32_t y, uint8_t r, uint8_t g, uint8_t b) {
+    SDL_Renderer* renderer = wheel_renderer_for(window);
+    if (!renderer) return 0;
     
-    SDL_SetRenderDrawColor(wheel_renderer, r, g, b, 255);
-    int result = SDL_RenderDrawPoint(wheel_renderer, x, y);
+    SDL_SetRenderDrawColor(renderer, r, g, b, 255);
+    int result = SDL_RenderDrawPoint(renderer, x, y);
     return result == 0 ? 1 : 0;
 }
 
-int64_t sdl_draw_rect(int32_t x, int32_t y, int32_t w, int32_t h, uint8_t r, uint8_t g, uint8_t b) {
-    if (!wheel_renderer) return 0;
+int64_t sdl_draw_rect(int64_t window, int32_t x, int32_t y, int32_t w, int32_t h, uint8_t r, uint8_t g, uint8_t b) {
+    SDL_Renderer* renderer = wheel_renderer_for(window);
+    if (!renderer) return 0;
     
-    SDL_SetRenderDrawColor(wheel_renderer, r, g, b, 255);
+    SDL_SetRenderDrawColor(renderer, r, g, b, 255);
     SDL_Rect rect = {x, y, w, h};
-    int result = SDL_RenderFillRect(wheel_renderer, &rect);
+    int result = SDL_RenderFillRect(renderer, &rect);
     return result == 0 ? 1 : 0;
 }
 
-int64_t sdl_clear(uint8_t r, uint8_t g, uint8_t b) {
-    if (!wheel_renderer) return 0;
+int64_t sdl_clear(int64_t window, uint8_t r, uint8_t g, uint8_t b) {
+    SDL_Renderer* renderer = wheel_renderer_for(window);
+    if (!renderer) return 0;
     
-    SDL_SetRenderDrawColor(wheel_renderer, r, g, b, 255);
-    int result = SDL_RenderClear(wheel_renderer);
+    SDL_SetRenderDrawColor(renderer, r, g, b, 255);
+    int result = SDL_RenderClear(renderer);
     return result == 0 ? 1 : 0;
 }
 
-int64_t sdl_present() {
-    if (!wheel_renderer) return 0;
+int64_t sdl_present(int64_t window) {
+    SDL_Renderer* renderer = wheel_renderer_for(window);
+    if (!renderer) return 0;
     
-    SDL_RenderPresent(wheel_renderer);
+    SDL_RenderPresent(renderer);
     return 1;
 }
 
-int64_t sdl_destroy_window() {
-    if (wheel_renderer) {
-        SDL_DestroyRenderer(wheel_renderer);
-        wheel_renderer = NULL;
+int64_t sdl_destroy_window(int64_t window) {
+    if (window == 0) window = wheel_current;
+    if (window < 1 || window > WHEEL_MAX_WINDOWS || !wheel_windows[window - 1]) return 0;
+    SDL_Renderer* renderer = wheel_renderers[window - 1];
+    // textures belong to the renderer
+    for (int i = 0; i < WHEEL_MAX_TEXTURES; i++) {
+        if (wheel_textures[i] && wheel_texture_owners[i] == renderer) {
+            SDL_DestroyTexture(wheel_textures[i]);
+            wheel_textures[i] = NULL;
+            wheel_texture_owners[i] = NULL;
+        }
     }
-    if (wheel_window) {
-        SDL_DestroyWindow(wheel_window);
-        wheel_window = NULL;
+    SDL_DestroyRenderer(renderer);
+    SDL_DestroyWindow(wheel_windows[window - 1]);
+    wheel_renderers[window - 1] = NULL;
+    wheel_windows[window - 1] = NULL;
+    if (wheel_current == window) {
+        // fall back to the newest window still open
+        wheel_current = 0;
+        for (int i = WHEEL_MAX_WINDOWS; i > 0; i--) {
+            if (wheel_windows[i - 1]) {
+                wheel_current = i;
+                break;
+            }
+        }
     }
     return 1;
 }
 
 int64_t sdl_quit() {
-    sdl_destroy_window();
+    for (int i = 1; i <= WHEEL_MAX_WINDOWS; i++) {
+        sdl_destroy_window(i);
+    }
     SDL_Quit();
     return 1;
 }
 
 // Poll for SDL events and return key codes or -1 for quit, 0 for none
 int64_t sdl_poll_event() {
-    if (!wheel_renderer && !wheel_window) return 0;
+    if (!wheel_current) return 0;
     SDL_Event e;
     while (SDL_PollEvent(&e)) {
         // log events for debugging
@@ -133,3 +179,62 @@ int64_t sdl_delay(int64_t ms) {
     SDL_Delay((Uint32)ms);
     return 1;
 }
+
+// 1 while the key with SDL keycode `keycode` (KEY_SPACE, KEY_W, ... in
+// Wheel) is held down, 0 otherwise
+int64_t sdl_key_down(int64_t keycode) {
+    SDL_PumpEvents();
+    SDL_Scancode sc = SDL_GetScancodeFromKey((SDL_Keycode)keycode);
+    if (sc == SDL_SCANCODE_UNKNOWN) return 0;
+    return SDL_GetKeyboardState(NULL)[sc] != 0;
+}
+
+// Mouse position relative to the window
+int64_t sdl_get_mouse_x() {
+    int x = 0;
+    SDL_PumpEvents();
+    SDL_GetMouseState(&x, NULL);
+    return x;
+}
+
+int64_t sdl_get_mouse_y() {
+    int y = 0;
+    SDL_PumpEvents();
+    SDL_GetMouseState(NULL, &y);
+    return y;
+}
+
+// 1 while mouse button `button` (MOUSE_LEFT = 1, MOUSE_MIDDLE = 2,
+// MOUSE_RIGHT = 3) is held down, 0 otherwise
+int64_t sdl_mouse_pressed(int64_t button) {
+    if (button < 1 || button > 5) return 0;
+    SDL_PumpEvents();
+    return (SDL_GetMouseState(NULL, NULL) & SDL_BUTTON(button)) != 0;
+}
+
+// Load a PNG, JPEG, BMP, ... file into a texture for `window`'s renderer;
+// returns its handle, or 0 if there is no such window, the file cannot be
+// loaded or all handles are taken
+int64_t sdl_load_image(int64_t window, const char* path) {
+    SDL_Renderer* renderer = wheel_renderer_for(window);
+    if (!renderer) return 0;
+    for (int i = 0; i < WHEEL_MAX_TEXTURES; i++) {
+        if (!wheel_textures[i]) {
+            wheel_textures[i] = IMG_LoadTexture(renderer, path);
+            if (!wheel_textures[i]) return 0;
+            wheel_texture_owners[i] = renderer;
+            return i + 1;
+        }
+    }
+    return 0;
+}
+
+// Draw texture `handle`, on the window it was loaded for, scaled into the
+// rectangle at (x, y), w by h pixels
+int64_t sdl_draw_image(int64_t handle, int32_t x, int32_t y, int32_t w, int32_t h) {
+    if (handle < 1 || handle > WHEEL_MAX_TEXTURES) return 0;
+    SDL_Texture* texture = wheel_textures[handle - 1];
+    if (!texture) return 0;
+    SDL_Rect dst = {x, y, w, h};
+    return SDL_RenderCopy(wheel_texture_owners[handle - 1], texture, NULL, &dst) == 0 ? 1 : 0;
+}
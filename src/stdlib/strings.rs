@@ -0,0 +1,188 @@
+// String runtime for Wheel programs: the `wheel_str_*` symbols and the
+// `wheel_sb_*` string builder.
+//
+// Strings are NUL-terminated byte strings, passed as `char *`. New strings are
+// allocated with `malloc` and never freed, like the rest of the runtime. This
+// file is not part of the compiler crate; it is built on its own into
+// `strings.o`, next to `luck.o` and `memory.o` (see `src/runtime_objects.rs`).
+#![no_std]
+
+use core::ffi::c_char;
+
+extern "C" {
+    fn malloc(size: usize) -> *mut c_char;
+    fn free(ptr: *mut c_char);
+}
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+unsafe fn len(s: *const c_char) -> usize {
+    if s.is_null() {
+        return 0;
+    }
+    let mut n = 0;
+    while *s.add(n) != 0 {
+        n += 1;
+    }
+    n
+}
+
+/// Copy `parts` into one new string.
+unsafe fn join(parts: &[(*const c_char, usize)]) -> *mut c_char {
+    let total: usize = parts.iter().map(|p| p.1).sum();
+    let out = malloc(total + 1);
+    if out.is_null() {
+        return out;
+    }
+    let mut at = 0;
+    for &(s, n) in parts {
+        core::ptr::copy_nonoverlapping(s, out.add(at), n);
+        at += n;
+    }
+    *out.add(at) = 0;
+    out
+}
+
+/// `a` followed by `b`, as a new string.
+#[no_mangle]
+pub unsafe extern "C" fn wheel_str_concat(a: *const c_char, b: *const c_char) -> *mut c_char {
+    join(&[(a, len(a)), (b, len(b))])
+}
+
+/// Length in bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wheel_str_len(s: *const c_char) -> i64 {
+    len(s) as i64
+}
+
+/// Byte-wise comparison: negative, 0 or positive like `strcmp`.
+#[no_mangle]
+pub unsafe extern "C" fn wheel_str_compare(a: *const c_char, b: *const c_char) -> i64 {
+    let (la, lb) = (len(a), len(b));
+    for i in 0..la.min(lb) {
+        let (x, y) = (*a.add(i) as u8, *b.add(i) as u8);
+        if x != y {
+            return x as i64 - y as i64;
+        }
+    }
+    la as i64 - lb as i64
+}
+
+/// Up to `count` bytes of `s` starting at byte `start`, as a new string.
+/// Out-of-range positions are clamped, so the result may be empty.
+#[no_mangle]
+pub unsafe extern "C" fn wheel_str_substring(s: *const c_char, start: i64, count: i64) -> *mut c_char {
+    let n = len(s);
+    let start = (start.max(0) as usize).min(n);
+    let count = (count.max(0) as usize).min(n - start);
+    join(&[(s.add(start), count)])
+}
+
+/// Decimal text of `n`, as a new string.
+#[no_mangle]
+pub unsafe extern "C" fn wheel_str_from_int(n: i64) -> *mut c_char {
+    // 19 digits of i64::MIN and its sign
+    let mut text = [0 as c_char; 20];
+    let mut at = text.len();
+    let mut m = n.unsigned_abs();
+    loop {
+        at -= 1;
+        *text.as_mut_ptr().add(at) = (b'0' + (m % 10) as u8) as c_char;
+        m /= 10;
+        if m == 0 {
+            break;
+        }
+    }
+    if n < 0 {
+        at -= 1;
+        *text.as_mut_ptr().add(at) = b'-' as c_char;
+    }
+    join(&[(text.as_ptr().add(at), text.len() - at)])
+}
+
+/// Leading whitespace, an optional sign and decimal digits, like `atoi` but
+/// 64-bit; 0 when there are no digits.
+#[no_mangle]
+pub unsafe extern "C" fn wheel_str_to_int(s: *const c_char) -> i64 {
+    if s.is_null() {
+        return 0;
+    }
+    let mut p = s as *const u8;
+    while matches!(*p, b' ' | b'\t' | b'\n' | b'\r' | 0x0b | 0x0c) {
+        p = p.add(1);
+    }
+    let negative = *p == b'-';
+    if negative || *p == b'+' {
+        p = p.add(1);
+    }
+    let mut n: i64 = 0;
+    while (*p).is_ascii_digit() {
+        n = n.wrapping_mul(10).wrapping_add((*p - b'0') as i64);
+        p = p.add(1);
+    }
+    if negative { n.wrapping_neg() } else { n }
+}
+
+/// A string builder: `len` bytes of text in a `cap`-byte buffer.
+#[repr(C)]
+struct Builder {
+    buf: *mut c_char,
+    len: usize,
+    cap: usize,
+}
+
+/// An empty string builder; returns its handle, or 0 if it could not be
+/// allocated.
+#[no_mangle]
+pub unsafe extern "C" fn wheel_sb_new() -> i64 {
+    let b = malloc(core::mem::size_of::<Builder>()) as *mut Builder;
+    if b.is_null() {
+        return 0;
+    }
+    b.write(Builder { buf: core::ptr::null_mut(), len: 0, cap: 0 });
+    b as i64
+}
+
+/// Append a copy of `s`, at least doubling the buffer when it is full; 1 on
+/// success, 0 otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn wheel_sb_append(sb: i64, s: *const c_char) -> i64 {
+    let b = sb as *mut Builder;
+    if b.is_null() {
+        return 0;
+    }
+    let b = &mut *b;
+    let n = len(s);
+    if n == 0 {
+        return 1;
+    }
+    if b.len + n > b.cap {
+        let cap = (b.cap * 2).max(b.len + n).max(16);
+        let buf = malloc(cap);
+        if buf.is_null() {
+            return 0;
+        }
+        if !b.buf.is_null() {
+            core::ptr::copy_nonoverlapping(b.buf, buf, b.len);
+            free(b.buf);
+        }
+        b.buf = buf;
+        b.cap = cap;
+    }
+    core::ptr::copy_nonoverlapping(s, b.buf.add(b.len), n);
+    b.len += n;
+    1
+}
+
+/// The text appended so far, as a new string; the builder can keep growing.
+#[no_mangle]
+pub unsafe extern "C" fn wheel_sb_to_str(sb: i64) -> *mut c_char {
+    let b = sb as *const Builder;
+    if b.is_null() {
+        return join(&[]);
+    }
+    join(&[((*b).buf, (*b).len)])
+}
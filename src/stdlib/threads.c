@@ -0,0 +1,162 @@
+// Threads runtime for Wheel: the `wheel_thread_*`, `wheel_mutex_*` and
+// `wheel_chan_*` symbols, on pthreads.
+//
+// Handles are the addresses of a `struct wheel_thread`, a `pthread_mutex_t`
+// or a `struct wheel_chan`, passed around as int64s. wheelc builds it with
+// `-pthread` (see `src/runtime_objects.rs`).
+#include <pthread.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Every Wheel function takes and returns int64s; one that takes no
+// parameter ignores the argument.
+typedef int64_t (*wheel_thread_fn)(int64_t);
+
+struct wheel_thread {
+    pthread_t tid;
+    wheel_thread_fn fn;
+    int64_t arg;
+    int64_t result;
+};
+
+static void *wheel_thread_start(void *p) {
+    struct wheel_thread *t = p;
+    t->result = t->fn(t->arg);
+    return NULL;
+}
+
+// Run `fn(arg)` on a new thread; 0 if the thread could not be created
+int64_t wheel_thread_spawn(int64_t fn, int64_t arg) {
+    struct wheel_thread *t = malloc(sizeof *t);
+    if (!t) return 0;
+    t->fn = (wheel_thread_fn)(intptr_t)fn;
+    t->arg = arg;
+    t->result = 0;
+    if (pthread_create(&t->tid, NULL, wheel_thread_start, t) != 0) {
+        free(t);
+        return 0;
+    }
+    return (int64_t)(intptr_t)t;
+}
+
+// Wait for the thread and return its function's result. The handle is
+// freed, so each thread is joined once.
+int64_t wheel_thread_join(int64_t handle) {
+    struct wheel_thread *t = (struct wheel_thread *)(intptr_t)handle;
+    if (!t) return 0;
+    pthread_join(t->tid, NULL);
+    int64_t result = t->result;
+    free(t);
+    return result;
+}
+
+// A new unlocked mutex; 0 if it could not be allocated
+int64_t wheel_mutex_new(void) {
+    pthread_mutex_t *m = malloc(sizeof *m);
+    if (!m) return 0;
+    pthread_mutex_init(m, NULL);
+    return (int64_t)(intptr_t)m;
+}
+
+// Block until the mutex is ours; 1 on success, 0 otherwise
+int64_t wheel_mutex_lock(int64_t mutex) {
+    pthread_mutex_t *m = (pthread_mutex_t *)(intptr_t)mutex;
+    return m && pthread_mutex_lock(m) == 0;
+}
+
+// 1 on success, 0 otherwise
+int64_t wheel_mutex_unlock(int64_t mutex) {
+    pthread_mutex_t *m = (pthread_mutex_t *)(intptr_t)mutex;
+    return m && pthread_mutex_unlock(m) == 0;
+}
+
+// A bounded FIFO of int64s: `len` values starting at `head`, wrapping
+// around `buf`
+struct wheel_chan {
+    pthread_mutex_t lock;
+    pthread_cond_t not_empty;
+    pthread_cond_t not_full;
+    int64_t *buf;
+    int64_t cap;
+    int64_t head;
+    int64_t len;
+};
+
+// A channel holding up to `capacity` values (at least 1); 0 if it could not
+// be allocated
+int64_t wheel_chan_new(int64_t capacity) {
+    if (capacity < 1) capacity = 1;
+    struct wheel_chan *c = malloc(sizeof *c);
+    if (!c) return 0;
+    c->buf = malloc(capacity * sizeof *c->buf);
+    if (!c->buf) {
+        free(c);
+        return 0;
+    }
+    pthread_mutex_init(&c->lock, NULL);
+    pthread_cond_init(&c->not_empty, NULL);
+    pthread_cond_init(&c->not_full, NULL);
+    c->cap = capacity;
+    c->head = 0;
+    c->len = 0;
+    return (int64_t)(intptr_t)c;
+}
+
+// Append `value`; the caller holds the lock and there is room
+static void chan_push(struct wheel_chan *c, int64_t value) {
+    c->buf[(c->head + c->len) % c->cap] = value;
+    c->len++;
+    pthread_cond_signal(&c->not_empty);
+}
+
+// Remove the oldest value; the caller holds the lock and there is one
+static int64_t chan_pop(struct wheel_chan *c) {
+    int64_t value = c->buf[c->head];
+    c->head = (c->head + 1) % c->cap;
+    c->len--;
+    pthread_cond_signal(&c->not_full);
+    return value;
+}
+
+// Send `value`, waiting while the channel is full; 1 on success, 0 otherwise
+int64_t wheel_chan_send(int64_t chan, int64_t value) {
+    struct wheel_chan *c = (struct wheel_chan *)(intptr_t)chan;
+    if (!c) return 0;
+    pthread_mutex_lock(&c->lock);
+    while (c->len == c->cap) pthread_cond_wait(&c->not_full, &c->lock);
+    chan_push(c, value);
+    pthread_mutex_unlock(&c->lock);
+    return 1;
+}
+
+// Send `value` if there is room; 1 if it was sent, 0 otherwise
+int64_t wheel_chan_try_send(int64_t chan, int64_t value) {
+    struct wheel_chan *c = (struct wheel_chan *)(intptr_t)chan;
+    if (!c) return 0;
+    pthread_mutex_lock(&c->lock);
+    int64_t sent = c->len < c->cap;
+    if (sent) chan_push(c, value);
+    pthread_mutex_unlock(&c->lock);
+    return sent;
+}
+
+// The oldest value, waiting while the channel is empty
+int64_t wheel_chan_recv(int64_t chan) {
+    struct wheel_chan *c = (struct wheel_chan *)(intptr_t)chan;
+    if (!c) return 0;
+    pthread_mutex_lock(&c->lock);
+    while (c->len == 0) pthread_cond_wait(&c->not_empty, &c->lock);
+    int64_t value = chan_pop(c);
+    pthread_mutex_unlock(&c->lock);
+    return value;
+}
+
+// The oldest value, or `fallback` if the channel is empty
+int64_t wheel_chan_try_recv(int64_t chan, int64_t fallback) {
+    struct wheel_chan *c = (struct wheel_chan *)(intptr_t)chan;
+    if (!c) return fallback;
+    pthread_mutex_lock(&c->lock);
+    int64_t value = c->len > 0 ? chan_pop(c) : fallback;
+    pthread_mutex_unlock(&c->lock);
+    return value;
+}
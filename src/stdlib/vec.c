@@ -0,0 +1,68 @@
+// Growable vector runtime for Wheel: the `wheel_vec_*` symbols behind the
+// `vec_*` builtins.
+//
+// A handle is the address of a `struct wheel_vec`, passed around as an
+// int64; elements are int64s, which includes string pointers. Storage comes
+// from malloc, so a vector grows as far as the process can; wheelc builds and
+// links it (see `src/runtime_objects.rs`).
+#include <stdint.h>
+#include <stdlib.h>
+
+struct wheel_vec {
+    int64_t len;
+    int64_t cap;
+    int64_t *items;
+};
+
+// A new empty vector; 0 if it could not be allocated
+int64_t wheel_vec_new(void) {
+    struct wheel_vec *v = malloc(sizeof *v);
+    if (!v) return 0;
+    v->len = 0;
+    v->cap = 0;
+    v->items = NULL;
+    return (int64_t)(intptr_t)v;
+}
+
+// Append `value`, doubling the storage when it is full; returns the new
+// length, or 0 if the storage could not grow
+int64_t wheel_vec_push(int64_t vec, int64_t value) {
+    struct wheel_vec *v = (struct wheel_vec *)(intptr_t)vec;
+    if (!v) return 0;
+    if (v->len == v->cap) {
+        int64_t cap = v->cap ? v->cap * 2 : 4;
+        int64_t *items = realloc(v->items, cap * sizeof *items);
+        if (!items) return 0;
+        v->items = items;
+        v->cap = cap;
+    }
+    v->items[v->len++] = value;
+    return v->len;
+}
+
+// Remove and return the last element; 0 if the vector is empty
+int64_t wheel_vec_pop(int64_t vec) {
+    struct wheel_vec *v = (struct wheel_vec *)(intptr_t)vec;
+    if (!v || v->len == 0) return 0;
+    return v->items[--v->len];
+}
+
+// Element `index`; 0 if it is out of range
+int64_t wheel_vec_get(int64_t vec, int64_t index) {
+    struct wheel_vec *v = (struct wheel_vec *)(intptr_t)vec;
+    if (!v || index < 0 || index >= v->len) return 0;
+    return v->items[index];
+}
+
+// Overwrite element `index`; 1 on success, 0 if it is out of range
+int64_t wheel_vec_set(int64_t vec, int64_t index, int64_t value) {
+    struct wheel_vec *v = (struct wheel_vec *)(intptr_t)vec;
+    if (!v || index < 0 || index >= v->len) return 0;
+    v->items[index] = value;
+    return 1;
+}
+
+int64_t wheel_vec_len(int64_t vec) {
+    struct wheel_vec *v = (struct wheel_vec *)(intptr_t)vec;
+    return v ? v->len : 0;
+}
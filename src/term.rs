@@ -0,0 +1,87 @@
+// Terminal styling for diagnostics and progress lines (`--color`, `NO_COLOR`).
+use std::fmt::Display;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use wheel::diagnostic::{Diagnostic, Severity};
+
+pub use clap::ColorChoice;
+
+static STDOUT_COLOR: AtomicBool = AtomicBool::new(false);
+static STDERR_COLOR: AtomicBool = AtomicBool::new(false);
+
+#[derive(Clone, Copy)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+const RED_BOLD: &str = "1;31";
+const YELLOW_BOLD: &str = "1;33";
+const GREEN_BOLD: &str = "1;32";
+const CYAN_BOLD: &str = "1;36";
+
+/// Decide once per run whether each output stream gets ANSI colors.
+/// `always`/`never` win over the environment; `auto` honours `NO_COLOR`
+/// (any non-empty value) and only colors streams attached to a terminal.
+pub fn init(choice: ColorChoice) {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    let (out, err) = match choice {
+        ColorChoice::Always => (true, true),
+        ColorChoice::Never => (false, false),
+        ColorChoice::Auto => (
+            !no_color && std::io::stdout().is_terminal(),
+            !no_color && std::io::stderr().is_terminal(),
+        ),
+    };
+    STDOUT_COLOR.store(out, Ordering::Relaxed);
+    STDERR_COLOR.store(err, Ordering::Relaxed);
+}
+
+pub fn enabled(stream: Stream) -> bool {
+    match stream {
+        Stream::Stdout => STDOUT_COLOR.load(Ordering::Relaxed),
+        Stream::Stderr => STDERR_COLOR.load(Ordering::Relaxed),
+    }
+}
+
+fn paint(stream: Stream, code: &str, text: &str) -> String {
+    if enabled(stream) {
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    } else {
+        text.to_string()
+    }
+}
+
+pub fn error(msg: impl Display) {
+    eprintln!("{} {}", paint(Stream::Stderr, RED_BOLD, "error:"), msg);
+}
+
+pub fn warning(msg: impl Display) {
+    eprintln!("{} {}", paint(Stream::Stderr, YELLOW_BOLD, "warning:"), msg);
+}
+
+/// Render a compiler diagnostic: `error: file:line:col: message [code]`,
+/// followed by one `help:` line per suggestion.
+pub fn diagnostic(d: &Diagnostic) {
+    let (color, label) = match d.severity {
+        Severity::Error => (RED_BOLD, "error:"),
+        Severity::Warning => (YELLOW_BOLD, "warning:"),
+    };
+    eprintln!("{} {}", paint(Stream::Stderr, color, label), d);
+    for s in &d.suggestions {
+        eprintln!("  {} {}", paint(Stream::Stderr, CYAN_BOLD, "help:"), s);
+    }
+}
+
+/// Progress/status line such as "Generated executable: ...". The leading word
+/// before the first space is highlighted.
+pub fn status(stream: Stream, msg: &str) {
+    let line = match msg.split_once(' ') {
+        Some((head, rest)) => format!("{} {}", paint(stream, GREEN_BOLD, head), rest),
+        None => paint(stream, GREEN_BOLD, msg),
+    };
+    match stream {
+        Stream::Stdout => println!("{}", line),
+        Stream::Stderr => eprintln!("{}", line),
+    }
+}
@@ -0,0 +1,159 @@
+// Walking the AST.
+//
+// `Visitor` inspects a program by reference, `Fold` rebuilds it by value.
+// Every method has a default that just recurses into the node's children, so
+// a pass only overrides the nodes it cares about and calls `walk_*`/`fold_*`
+// to keep descending:
+//
+//     struct Calls(Vec<String>);
+//     impl Visitor for Calls {
+//         fn visit_expr(&mut self, e: &Expr) {
+//             if let Expr::Call { name, .. } = e { self.0.push(name.clone()); }
+//             walk_expr(self, e);
+//         }
+//     }
+//
+// The condition of an `@if` is not walked: it names defines, not variables.
+use crate::ast::{Expr, Program, Stmt};
+
+pub trait Visitor {
+    fn visit_program(&mut self, prog: &Program) {
+        self.visit_block(&prog.items);
+    }
+
+    /// Statements of the top level or of a body.
+    fn visit_block(&mut self, stmts: &[Stmt]) {
+        for s in stmts {
+            self.visit_stmt(s);
+        }
+    }
+
+    fn visit_stmt(&mut self, stmt: &Stmt) {
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        walk_expr(self, expr);
+    }
+}
+
+/// Visit the expressions and bodies directly inside `stmt`.
+pub fn walk_stmt<V: Visitor + ?Sized>(v: &mut V, stmt: &Stmt) {
+    match stmt {
+        Stmt::Expr(e) | Stmt::Let { value: e, .. } | Stmt::Assign { value: e, .. } | Stmt::Return(Some(e)) => v.visit_expr(e),
+        Stmt::ArrayAssign { index, value, .. } => {
+            v.visit_expr(index);
+            v.visit_expr(value);
+        }
+        Stmt::Func { body, .. } => v.visit_block(body),
+        Stmt::If { cond, then_body, else_body } => {
+            v.visit_expr(cond);
+            v.visit_block(then_body);
+            if let Some(eb) = else_body {
+                v.visit_block(eb);
+            }
+        }
+        Stmt::CfgIf { then_body, else_body, .. } => {
+            v.visit_block(then_body);
+            if let Some(eb) = else_body {
+                v.visit_block(eb);
+            }
+        }
+        Stmt::While { cond, body } => {
+            v.visit_expr(cond);
+            v.visit_block(body);
+        }
+        Stmt::ForRange { start, end, body, .. } => {
+            v.visit_expr(start);
+            v.visit_expr(end);
+            v.visit_block(body);
+        }
+        Stmt::Return(None) | Stmt::Import { .. } | Stmt::Use { .. } | Stmt::StructDef { .. } | Stmt::Extern { .. } | Stmt::Inline { .. } => {}
+    }
+}
+
+/// Visit the subexpressions of `expr`.
+pub fn walk_expr<V: Visitor + ?Sized>(v: &mut V, expr: &Expr) {
+    match expr {
+        Expr::BinaryOp { left, right, .. } => {
+            v.visit_expr(left);
+            v.visit_expr(right);
+        }
+        Expr::Call { args, .. } | Expr::ArrayLiteral(args) => args.iter().for_each(|a| v.visit_expr(a)),
+        Expr::ArrayAccess { array, index } => {
+            v.visit_expr(array);
+            v.visit_expr(index);
+        }
+        Expr::Int(_) | Expr::Str(_) | Expr::Ident(_) => {}
+    }
+}
+
+pub trait Fold {
+    /// Statement locations are carried over as they are; a fold that adds or
+    /// drops statements leaves them unusable, and the source map skips them.
+    fn fold_program(&mut self, prog: Program) -> Program {
+        Program { items: self.fold_block(prog.items), locs: prog.locs }
+    }
+
+    /// Statements of the top level or of a body; override to drop or splice
+    /// statements.
+    fn fold_block(&mut self, stmts: Vec<Stmt>) -> Vec<Stmt> {
+        stmts.into_iter().map(|s| self.fold_stmt(s)).collect()
+    }
+
+    fn fold_stmt(&mut self, stmt: Stmt) -> Stmt {
+        fold_stmt(self, stmt)
+    }
+
+    fn fold_expr(&mut self, expr: Expr) -> Expr {
+        fold_expr(self, expr)
+    }
+}
+
+/// Rebuild `stmt` with its expressions and bodies folded.
+pub fn fold_stmt<F: Fold + ?Sized>(f: &mut F, stmt: Stmt) -> Stmt {
+    match stmt {
+        Stmt::Expr(e) => Stmt::Expr(f.fold_expr(e)),
+        Stmt::Let { name, ty, value } => Stmt::Let { name, ty, value: f.fold_expr(value) },
+        Stmt::Assign { name, value } => Stmt::Assign { name, value: f.fold_expr(value) },
+        Stmt::ArrayAssign { array, index, value } => Stmt::ArrayAssign { array, index: f.fold_expr(index), value: f.fold_expr(value) },
+        Stmt::Func { name, params, body, export, comptime } => Stmt::Func { name, params, body: f.fold_block(body), export, comptime },
+        Stmt::Return(e) => Stmt::Return(e.map(|e| f.fold_expr(e))),
+        Stmt::If { cond, then_body, else_body } => Stmt::If {
+            cond: f.fold_expr(cond),
+            then_body: f.fold_block(then_body),
+            else_body: else_body.map(|eb| f.fold_block(eb)),
+        },
+        Stmt::CfgIf { cond, then_body, else_body } => Stmt::CfgIf {
+            cond,
+            then_body: f.fold_block(then_body),
+            else_body: else_body.map(|eb| f.fold_block(eb)),
+        },
+        Stmt::While { cond, body } => Stmt::While { cond: f.fold_expr(cond), body: f.fold_block(body) },
+        Stmt::ForRange { var, start, end, body } => Stmt::ForRange {
+            var,
+            start: f.fold_expr(start),
+            end: f.fold_expr(end),
+            body: f.fold_block(body),
+        },
+        s @ (Stmt::Import { .. } | Stmt::Use { .. } | Stmt::StructDef { .. } | Stmt::Extern { .. } | Stmt::Inline { .. }) => s,
+    }
+}
+
+/// Rebuild `expr` with its subexpressions folded.
+pub fn fold_expr<F: Fold + ?Sized>(f: &mut F, expr: Expr) -> Expr {
+    match expr {
+        Expr::BinaryOp { op, left, right } => Expr::BinaryOp {
+            op,
+            left: Box::new(f.fold_expr(*left)),
+            right: Box::new(f.fold_expr(*right)),
+        },
+        Expr::Call { name, args } => Expr::Call { name, args: args.into_iter().map(|a| f.fold_expr(a)).collect() },
+        Expr::ArrayLiteral(items) => Expr::ArrayLiteral(items.into_iter().map(|a| f.fold_expr(a)).collect()),
+        Expr::ArrayAccess { array, index } => Expr::ArrayAccess {
+            array: Box::new(f.fold_expr(*array)),
+            index: Box::new(f.fold_expr(*index)),
+        },
+        e @ (Expr::Int(_) | Expr::Str(_) | Expr::Ident(_)) => e,
+    }
+}
@@ -0,0 +1,646 @@
+use std::process::Command;
+use std::fs;
+use std::path::PathBuf;
+
+fn write_source(name: &str, src: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join("wheelc_cli_test");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    fs::write(&path, src).unwrap();
+    path
+}
+
+fn wheelc() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_wheelc"))
+}
+
+#[test]
+fn exit_codes_distinguish_parse_and_semantic_errors() {
+    let unclosed = write_source("unclosed.wheel", "func main() {\n    print(\"hi\");\n");
+    let status = wheelc().arg(&unclosed).status().expect("failed to run wheelc");
+    assert_eq!(status.code(), Some(3));
+
+    let undeclared = write_source("undeclared.wheel", "set total = 1;\n");
+    let status = wheelc().arg(&undeclared).status().expect("failed to run wheelc");
+    assert_eq!(status.code(), Some(4));
+}
+
+#[test]
+fn build_report_json_lists_inputs_and_error_kind() {
+    let undeclared = write_source("report.wheel", "set total = 1;\n");
+    let output = wheelc()
+        .arg(&undeclared)
+        .arg("--build-report").arg("json")
+        .output()
+        .expect("failed to run wheelc");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\"status\": \"failed\""), "unexpected report: {}", stdout);
+    assert!(stdout.contains("report.wheel"), "unexpected report: {}", stdout);
+    assert!(stdout.contains("\"kind\": \"semantic\""), "unexpected report: {}", stdout);
+}
+
+#[test]
+fn project_config_can_deny_warnings() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_project");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("wheel.toml"), "[warnings]\ndeny = true\n").unwrap();
+    let src = dir.join("main.wheel");
+    fs::write(&src, "let unused = 1;\n").unwrap();
+
+    let status = wheelc()
+        .arg(&src)
+        .env("XDG_CONFIG_HOME", dir.join("xdg"))
+        .status()
+        .expect("failed to run wheelc");
+    assert_eq!(status.code(), Some(4));
+}
+
+#[test]
+fn objdump_disassembles_raw_binaries() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test");
+    fs::create_dir_all(&dir).unwrap();
+    let bin = dir.join("nop_ret.bin");
+    fs::write(&bin, [0x90u8, 0xc3]).unwrap();
+
+    let output = wheelc()
+        .arg("objdump").arg("--raw").arg("0x1000").arg(&bin)
+        .output()
+        .expect("failed to run wheelc");
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("1000:") && stdout.contains("nop"), "unexpected listing: {}", stdout);
+    assert!(stdout.contains("ret"), "unexpected listing: {}", stdout);
+}
+
+#[test]
+fn codegen_cache_is_invalidated_by_imported_modules() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_cache");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("wheel.toml"), "").unwrap();
+    fs::write(dir.join("greet.wheel"), "print \"hello\";\n").unwrap();
+    let src = dir.join("main.wheel");
+    fs::write(&src, "import \"greet\";\nprint \"done\";\n").unwrap();
+
+    let cache_state = || {
+        let output = wheelc()
+            .arg(&src)
+            .arg("-o").arg(dir.join("main"))
+            .arg("--build-report").arg("json")
+            .env("XDG_CONFIG_HOME", dir.join("xdg"))
+            .output()
+            .expect("failed to run wheelc");
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        assert!(output.status.success(), "build failed: {}", stdout);
+        stdout
+    };
+    assert!(cache_state().contains("\"cache\": \"miss\""));
+    assert!(cache_state().contains("\"cache\": \"hit\""));
+    assert!(dir.join("target/wheel/cache").is_dir());
+
+    fs::write(dir.join("greet.wheel"), "print \"hi again\";\n").unwrap();
+    assert!(cache_state().contains("\"cache\": \"miss\""));
+}
+
+#[test]
+fn clean_removes_build_dir_and_listed_artifacts() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_clean");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("out/wheel/cache")).unwrap();
+    fs::write(dir.join("wheel.toml"), "[layout]\nbuild_dir = \"out/wheel\"\nartifacts = [\"game\"]\n").unwrap();
+    fs::write(dir.join("out/wheel/cache/0000.s"), "").unwrap();
+    fs::write(dir.join("game"), "").unwrap();
+    fs::write(dir.join("game.o"), "").unwrap();
+    fs::write(dir.join("main.wheel"), "").unwrap();
+
+    let dry = wheelc().arg("clean").arg(&dir).arg("--dry-run").output().expect("failed to run wheelc");
+    assert!(dry.status.success());
+    assert!(dir.join("game").exists());
+
+    let status = wheelc().arg("clean").arg(&dir).status().expect("failed to run wheelc");
+    assert!(status.success());
+    assert!(!dir.join("out/wheel").exists());
+    assert!(!dir.join("game").exists());
+    assert!(!dir.join("game.o").exists());
+    assert!(dir.join("main.wheel").exists());
+}
+
+#[test]
+fn clean_refuses_paths_outside_the_project() {
+    let base = std::env::temp_dir().join("wheelc_cli_test_clean_outside");
+    let _ = fs::remove_dir_all(&base);
+    let dir = base.join("project");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(base.join("keep.txt"), "").unwrap();
+    for layout in ["build_dir = \"..\"", "build_dir = \".\"", "artifacts = [\"../keep.txt\"]"] {
+        fs::write(dir.join("wheel.toml"), format!("[layout]\n{}\n", layout)).unwrap();
+        let output = wheelc().arg("clean").arg(&dir).env("XDG_CONFIG_HOME", base.join("xdg")).output().expect("failed to run wheelc");
+        assert!(!output.status.success(), "{}", layout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("refusing to remove"), "unexpected stderr: {}", stderr);
+        assert!(base.join("keep.txt").exists());
+        assert!(dir.join("wheel.toml").exists());
+    }
+}
+
+#[test]
+fn clean_uses_the_user_config_layout() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_clean_user");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("xdg/wheel")).unwrap();
+    fs::create_dir_all(dir.join("build/cache")).unwrap();
+    fs::write(dir.join("xdg/wheel/config.toml"), "[layout]\nbuild_dir = \"build\"\n").unwrap();
+    fs::write(dir.join("wheel.toml"), "").unwrap();
+
+    let status = wheelc().arg("clean").arg(&dir).env("XDG_CONFIG_HOME", dir.join("xdg")).status().expect("failed to run wheelc");
+    assert!(status.success());
+    assert!(!dir.join("build").exists());
+    assert!(dir.join("xdg/wheel/config.toml").exists());
+}
+
+#[test]
+fn build_stdlib_extracts_embedded_runtime_objects_once() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_stdlib");
+    let _ = fs::remove_dir_all(&dir);
+    let build = || {
+        let output = wheelc()
+            .arg("build-stdlib")
+            .arg("--target").arg("x86_64-unknown-linux-gnu")
+            .arg("--build-dir").arg(&dir)
+            .output()
+            .expect("failed to run wheelc");
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    let first = build();
+    assert!(first.contains("Extracted memory.o"), "unexpected output: {}", first);
+    assert!(dir.join("stdlib/x86_64-unknown-linux-gnu/memory.o").is_file());
+    let second = build();
+    assert!(second.contains("Fresh memory.o"), "unexpected output: {}", second);
+}
+
+#[test]
+fn emit_depgraph_highlights_import_cycles() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_depgraph");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("main.wheel"), "import \"a\";\nimport \"c\";\n").unwrap();
+    fs::write(dir.join("a.wheel"), "import \"b\";\n").unwrap();
+    fs::write(dir.join("b.wheel"), "import \"a\";\n").unwrap();
+    fs::write(dir.join("c.wheel"), "print \"c\";\n").unwrap();
+
+    let output = wheelc()
+        .arg(dir.join("main.wheel"))
+        .arg("--emit").arg("depgraph")
+        .output()
+        .expect("failed to run wheelc");
+    assert!(output.status.success());
+    let dot = String::from_utf8_lossy(&output.stdout);
+    assert!(dot.starts_with("digraph imports {"), "unexpected output: {}", dot);
+    assert!(dot.contains("label=\"c.wheel\"]"), "unexpected output: {}", dot);
+    assert_eq!(dot.matches("color=red, penwidth=2").count(), 2, "unexpected output: {}", dot);
+}
+
+#[test]
+fn emit_header_declares_exported_functions() {
+    let lib = write_source("mathlib.wheel", "pub extern func add(a: int, b: int) -> int {\n    return a + b;\n}\n\
+        pub extern func greet(who: str) {\n    print(\"hi\");\n}\nfunc helper() {\n}\n");
+    let output = wheelc()
+        .arg(&lib)
+        .arg("--language").arg("0.2")
+        .arg("--emit").arg("header")
+        .output()
+        .expect("failed to run wheelc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let header = String::from_utf8_lossy(&output.stdout);
+    assert!(header.contains("#ifndef MATHLIB_H"), "unexpected header: {}", header);
+    assert!(header.contains("int64_t add(int64_t a, int64_t b);"), "unexpected header: {}", header);
+    assert!(header.contains("void greet(const char *who);"), "unexpected header: {}", header);
+    assert!(!header.contains("helper"), "unexpected header: {}", header);
+}
+
+#[test]
+fn defines_select_conditional_blocks() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_defines");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("bare.wheel"), "print(\"bare\");\n").unwrap();
+    let src = dir.join("main.wheel");
+    fs::write(&src, "@if BARE {\n    import \"bare\";\n} else {\n    print(\"hosted\");\n}\n").unwrap();
+
+    let run = |extra: &[&str]| {
+        let exe = dir.join("main");
+        let status = wheelc()
+            .arg(&src)
+            .arg("-o").arg(&exe)
+            .arg("--language").arg("0.2")
+            .args(extra)
+            .env("XDG_CONFIG_HOME", dir.join("xdg"))
+            .status()
+            .expect("failed to run wheelc");
+        assert!(status.success());
+        let out = Command::new(&exe).output().expect("failed to run program");
+        String::from_utf8_lossy(&out.stdout).to_string()
+    };
+    assert_eq!(run(&[]), "hosted");
+    assert_eq!(run(&["-D", "BARE"]), "bare");
+}
+
+#[test]
+fn embed_bakes_asset_into_binary() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_embed");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("assets")).unwrap();
+    fs::write(dir.join("wheel.toml"), "[assets]\nbanner = \"assets/banner.txt\"\n").unwrap();
+    fs::write(dir.join("assets/banner.txt"), "baked in").unwrap();
+    let src = dir.join("main.wheel");
+    fs::write(&src, "print(embed(\"banner\"));\n").unwrap();
+
+    let exe = dir.join("main");
+    let status = wheelc()
+        .arg(&src)
+        .arg("-o").arg(&exe)
+        .env("XDG_CONFIG_HOME", dir.join("xdg"))
+        .status()
+        .expect("failed to run wheelc");
+    assert!(status.success());
+    // the asset is read at build time only
+    fs::remove_file(dir.join("assets/banner.txt")).unwrap();
+    let out = Command::new(&exe).output().expect("failed to run program");
+    assert_eq!(String::from_utf8_lossy(&out.stdout), "baked in");
+}
+
+#[test]
+fn lint_levels_come_from_manifest_and_flags() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_lint");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("wheel.toml"), "[lint]\nempty-block = \"deny\"\n").unwrap();
+    let src = dir.join("main.wheel");
+    fs::write(&src, "let limit = 10;\nwhile limit > 3 {\n}\n").unwrap();
+
+    let output = wheelc()
+        .arg("lint").arg(&src)
+        .env("XDG_CONFIG_HOME", dir.join("xdg"))
+        .output()
+        .expect("failed to run wheelc");
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("[empty-block]"), "unexpected output: {}", stderr);
+    assert!(!stderr.contains("[magic-number]"), "unexpected output: {}", stderr);
+
+    let output = wheelc()
+        .arg("lint").arg(&src)
+        .arg("--allow").arg("empty-block")
+        .arg("--warn").arg("magic-number")
+        .env("XDG_CONFIG_HOME", dir.join("xdg"))
+        .output()
+        .expect("failed to run wheelc");
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("magic number `3`"), "unexpected output: {}", stderr);
+}
+
+#[test]
+fn newer_syntax_requires_its_edition() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_edition");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let src = dir.join("main.wheel");
+    fs::write(&src, "@if DEBUG {\n    print(\"debug\");\n}\n").unwrap();
+
+    let output = wheelc()
+        .arg(&src)
+        .arg("-o").arg(dir.join("main"))
+        .env("XDG_CONFIG_HOME", dir.join("xdg"))
+        .output()
+        .expect("failed to run wheelc");
+    assert_eq!(output.status.code(), Some(3));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("requires language edition 0.2"), "unexpected output: {}", stderr);
+
+    fs::write(dir.join("wheel.toml"), "language = \"0.2\"\n").unwrap();
+    let status = wheelc()
+        .arg(&src)
+        .arg("-o").arg(dir.join("main"))
+        .env("XDG_CONFIG_HOME", dir.join("xdg"))
+        .status()
+        .expect("failed to run wheelc");
+    assert!(status.success());
+}
+
+#[cfg(not(feature = "self-update"))]
+#[test]
+fn self_update_requires_feature() {
+    let output = wheelc()
+        .arg("self-update")
+        .env("WHEEL_UPDATE_URL", "http://127.0.0.1:9/latest")
+        .output()
+        .expect("failed to run wheelc");
+    assert_eq!(output.status.code(), Some(1));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--features self-update"), "unexpected output: {}", stderr);
+}
+
+#[test]
+fn shebang_script_is_compiled_and_run() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_script");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let src = dir.join("hello.wheel");
+    fs::write(&src, "#!/usr/bin/env wheelc\nprint(\"hello from a script\");\n").unwrap();
+
+    for _ in 0..2 {
+        let output = wheelc()
+            .arg(&src)
+            .arg("--some-script-flag")
+            .current_dir(&dir)
+            .env("XDG_CONFIG_HOME", dir.join("xdg"))
+            .env("XDG_CACHE_HOME", dir.join("cache"))
+            .output()
+            .expect("failed to run wheelc");
+        assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "hello from a script");
+    }
+    let scripts: Vec<_> = fs::read_dir(dir.join("cache/wheel/scripts")).unwrap().collect();
+    assert_eq!(scripts.len(), 1, "script executable should be cached");
+    assert!(!dir.join("a.out").exists());
+}
+
+#[test]
+fn run_interprets_without_a_toolchain() {
+    let src = write_source("interp.wheel", "func twice(n) {\n    return n * 2;\n}\nlet n = input();\nprint(twice(n));\nprint(\"done\");\nreturn 7;\n");
+    let mut child = wheelc().arg("run").arg(&src).arg("--backend").arg("interp")
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .spawn()
+        .expect("failed to run wheelc");
+    std::io::Write::write_all(child.stdin.as_mut().unwrap(), b"21\n").unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "42\ndone\n");
+    assert_eq!(output.status.code(), Some(7));
+
+    let divide = write_source("interp_div.wheel", "let zero = 0;\nprint(1 / zero);\n");
+    let output = wheelc().arg("run").arg(&divide).output().expect("failed to run wheelc");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("division by zero"));
+}
+
+#[test]
+fn env_builtins_read_and_write_the_environment() {
+    let src = write_source("env.wheel", "print(env_get(\"WHEEL_TEST_GREETING\"));\nprint(env_set(\"WHEEL_TEST_SET\", \"later\"));\nprint(env_get(\"WHEEL_TEST_SET\"));\nprint(env_get(\"WHEEL_TEST_UNSET\") == 0);\nprint(env_get(\"WHEEL_TEST_EMPTY\") == 0);\n");
+    let output = wheelc().arg("run").arg(&src)
+        .env("WHEEL_TEST_GREETING", "hi")
+        .env("WHEEL_TEST_EMPTY", "")
+        .env_remove("WHEEL_TEST_UNSET")
+        .output()
+        .expect("failed to run wheelc");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "hi\n1\nlater\n1\n0\n");
+}
+
+#[test]
+fn monotonic_timers_measure_elapsed_time() {
+    let src = write_source("timers.wheel", "let start = time_millis();\nlet start_ns = time_nanos();\nsleep(1);\nprint(time_millis() - start >= 1000);\nprint(time_nanos() - start_ns >= 1000000000);\n");
+    let output = wheelc().arg("run").arg(&src).output().expect("failed to run wheelc");
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "1\n1\n");
+}
+
+#[test]
+fn gc_flag_requires_the_llvm_backend() {
+    let src = write_source("gc.wheel", "print(1);\n");
+    let output = wheelc().arg(&src).arg("--gc").arg("-o").arg(src.with_extension("out")).output().expect("failed to run wheelc");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--gc needs --mode ll"), "unexpected stderr: {}", stderr);
+}
+
+#[test]
+fn ge_mode_only_targets_linux() {
+    let src = write_source("ge_macos.wheel", "print(1);\n");
+    let exe = src.with_extension("out");
+    let _ = fs::remove_file(&exe);
+    for target in ["x86_64-apple-darwin", "x86_64-pc-windows-gnu"] {
+        let output = wheelc().arg(&src).arg("--target").arg(target).arg("-o").arg(&exe).output().expect("failed to run wheelc");
+        assert_eq!(output.status.code(), Some(1));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("--mode ge only builds x86_64 Linux executables"), "unexpected stderr: {}", stderr);
+    }
+    assert!(!exe.exists());
+}
+
+#[test]
+fn assembly_modes_reject_runtime_builtins() {
+    let src = write_source("runtime_in_ge.wheel", "let v = vec_new();\nvec_push(v, 1);\nvec_push(v, 2);\nirq_register(32, \"tick\");\nfunc tick() {\n    return 0;\n}\n");
+    let output = wheelc().arg(&src).arg("-o").arg(src.with_extension("out")).output().expect("failed to run wheelc");
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(stderr.matches("is not supported by `--mode ge`").count(), 2, "unexpected stderr: {}", stderr);
+    assert!(stderr.contains("`vec_push` is not supported by `--mode ge`"), "unexpected stderr: {}", stderr);
+    assert!(stderr.contains("build with `--mode ll`"), "unexpected stderr: {}", stderr);
+    assert!(stderr.contains("`irq_register` installs an interrupt handler"), "unexpected stderr: {}", stderr);
+
+    let output = wheelc().arg(&src).arg("--mode").arg("gb").arg("-o").arg(src.with_extension("bin")).output().expect("failed to run wheelc");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("`vec_new` is not supported by `--mode gb`"), "unexpected stderr: {}", stderr);
+    assert!(!stderr.contains("irq_register"), "unexpected stderr: {}", stderr);
+}
+
+#[test]
+fn assembly_modes_reject_builtins_they_do_not_generate() {
+    let calls = [
+        "env_get(\"HOME\")", "time_millis()", "mutex_new()", "atomic_add(0, 1)", "chan_new(1)", "thread_join(0)",
+        "luck_random()", "sdl_key_down(KEY_SPACE)", "mem_alloc(8)", "port_read_byte(96)",
+    ];
+    let src = write_source("builtins_in_ge.wheel", &calls.iter().map(|c| format!("let x = {};\nprint(x);\n", c)).collect::<String>());
+    for mode in ["ge", "gb"] {
+        let output = wheelc().arg(&src).arg("--mode").arg(mode).arg("-o").arg(src.with_extension(mode)).output().expect("failed to run wheelc");
+        assert_eq!(output.status.code(), Some(4));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        for call in calls {
+            let name = &call[..call.find('(').unwrap()];
+            assert!(stderr.contains(&format!("`{}` is not supported by `--mode {}`", name, mode)), "unexpected stderr: {}", stderr);
+        }
+    }
+}
+
+#[test]
+fn assembly_modes_build_program_functions_named_like_runtime_ones() {
+    let src = write_source("shadow_ge.wheel", "func round(x) {\n    return x + 1;\n}\nfunc luck_random() {\n    return 4;\n}\nround(3);\nluck_random();\nprint(\"built\");\n");
+    for mode in ["ge", "gb"] {
+        let out = src.with_extension(mode);
+        let output = wheelc().arg(&src).arg("--mode").arg(mode).arg("-o").arg(&out).arg("--no-cache").output().expect("failed to run wheelc");
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        assert!(out.exists());
+    }
+    let run = Command::new(src.with_extension("ge")).output().expect("failed to run the program");
+    assert_eq!(String::from_utf8_lossy(&run.stdout), "built");
+}
+
+#[test]
+fn kernel_images_link_with_gcc_whatever_the_configured_linker() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_gb_linker");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("wheel.toml"), "[build]\nlinker = \"ld\"\n").unwrap();
+    let src = dir.join("kernel.wheel");
+    fs::write(&src, "print(\"booted\");\n").unwrap();
+    let bin = dir.join("kernel.bin");
+    let output = wheelc().arg(&src).arg("--mode").arg("gb").arg("-o").arg(&bin).arg("--no-cache")
+        .env("XDG_CONFIG_HOME", dir.join("xdg"))
+        .output().expect("failed to run wheelc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(bin.exists());
+}
+
+#[test]
+fn compiled_modes_reject_json_builtins() {
+    let src = write_source("json_compiled.wheel", "let doc = json_parse(\"{}\");\nprint(json_stringify(doc));\n");
+    for mode in ["ge", "gb", "ll"] {
+        let output = wheelc().arg(&src).arg("--mode").arg(mode).arg("-o").arg(src.with_extension(mode)).output().expect("failed to run wheelc");
+        assert_eq!(output.status.code(), Some(4));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        for name in ["json_parse", "json_stringify"] {
+            assert!(stderr.contains(&format!("`{}` is only supported by the interpreter, not by `--mode {}`", name, mode)), "unexpected stderr: {}", stderr);
+        }
+        assert!(!stderr.contains("is not supported by"), "unexpected stderr: {}", stderr);
+    }
+}
+
+#[test]
+fn mmio_builtins_build_only_as_kernel_images() {
+    let src = write_source("mmio_gb.wheel", "let status = mmio_read(4276092928);\nmmio_write(4276092928, status);\n");
+    let output = wheelc().arg(&src).arg("-o").arg(src.with_extension("out")).output().expect("failed to run wheelc");
+    assert_eq!(output.status.code(), Some(4));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("`mmio_read` accesses a device register"), "unexpected stderr: {}", stderr);
+    assert!(stderr.contains("`mmio_write` accesses a device register"), "unexpected stderr: {}", stderr);
+
+    let bin = src.with_extension("bin");
+    let output = wheelc().arg(&src).arg("--mode").arg("gb").arg("-o").arg(&bin).arg("--no-cache").output().expect("failed to run wheelc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(bin.exists());
+}
+
+#[cfg(feature = "llvm")]
+#[test]
+fn compiled_env_get_tells_unset_from_empty() {
+    let src = write_source("env_ll.wheel", "let unset = env_get(\"WHEEL_TEST_UNSET\");\nif unset == 0 {\n    print(\"unset\");\n}\nlet empty = env_get(\"WHEEL_TEST_EMPTY\");\nif empty != 0 {\n    print(\"empty\");\n}\n");
+    let exe = src.with_extension("ll-out");
+    let output = wheelc().arg(&src).arg("--mode").arg("ll").arg("-o").arg(&exe).arg("--no-cache").output().expect("failed to run wheelc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let run = Command::new(&exe).env("WHEEL_TEST_EMPTY", "").env_remove("WHEEL_TEST_UNSET").output().expect("failed to run the program");
+    assert_eq!(String::from_utf8_lossy(&run.stdout), "unset\nempty\n");
+}
+
+#[cfg(feature = "llvm")]
+#[test]
+fn compiled_calls_reach_program_functions_named_like_builtins() {
+    let src = write_source("shadow_ll.wheel", "func log(a, b) {\n    return a * 10 + b;\n}\nfunc round(x) {\n    return x + 1;\n}\nprint(log(5, 6));\nprint(round(3));\n");
+    let exe = src.with_extension("ll-out");
+    let output = wheelc().arg(&src).arg("--mode").arg("ll").arg("-o").arg(&exe).arg("--no-cache").output().expect("failed to run wheelc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let run = Command::new(&exe).output().expect("failed to run the program");
+    assert_eq!(String::from_utf8_lossy(&run.stdout), "56\n4\n");
+}
+
+#[cfg(feature = "llvm")]
+#[test]
+fn gc_flag_is_part_of_the_cache_key() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_gc_cache");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("wheel.toml"), "").unwrap();
+    let src = dir.join("main.wheel");
+    fs::write(&src, "print(1);\n").unwrap();
+
+    let cache_state = |gc: bool| {
+        let mut cmd = wheelc();
+        cmd.arg(&src).arg("--mode").arg("ll").arg("-o").arg(dir.join("main")).arg("--build-report").arg("json");
+        if gc {
+            cmd.arg("--gc");
+        }
+        let output = cmd.env("XDG_CONFIG_HOME", dir.join("xdg")).output().expect("failed to run wheelc");
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+        assert!(output.status.success(), "build failed: {}{}", stdout, String::from_utf8_lossy(&output.stderr));
+        stdout
+    };
+    assert!(cache_state(false).contains("\"cache\": \"miss\""));
+    assert!(cache_state(false).contains("\"cache\": \"hit\""));
+    assert!(cache_state(true).contains("\"cache\": \"miss\""));
+}
+
+#[cfg(feature = "llvm")]
+#[test]
+fn programs_without_sdl_calls_link_without_sdl() {
+    let dir = std::env::temp_dir().join("wheelc_cli_test_no_sdl");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("wheel.toml"), "").unwrap();
+    // a C compiler for which `sdl_wrappers.c` builds, as if the SDL2 headers
+    // were installed but not the libraries
+    let cc = dir.join("cc.sh");
+    fs::write(&cc, "#!/bin/sh\nprev=\nfor a in \"$@\"; do [ \"$prev\" = -o ] && out=$a; prev=$a; done\ncase \"$*\" in *sdl_wrappers.c*) exec cc -c -x c /dev/null -o \"$out\";; esac\nexec cc \"$@\"\n").unwrap();
+    fs::set_permissions(&cc, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+    let src = dir.join("main.wheel");
+    fs::write(&src, "print(\"no windows here\");\n").unwrap();
+
+    let output = wheelc().arg(&src).arg("--mode").arg("ll").arg("-o").arg(dir.join("main")).arg("--no-cache")
+        .env("CC", &cc).env("XDG_CONFIG_HOME", dir.join("xdg")).output().expect("failed to run wheelc");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let run = Command::new(dir.join("main")).output().expect("failed to run the program");
+    assert_eq!(String::from_utf8_lossy(&run.stdout), "no windows here\n");
+}
+
+#[test]
+fn debug_alloc_flag_requires_the_llvm_backend() {
+    let src = write_source("debug_alloc.wheel", "print(1);\n");
+    let output = wheelc().arg(&src).arg("--debug-alloc").arg("-o").arg(src.with_extension("out")).output().expect("failed to run wheelc");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--debug-alloc needs --mode ll"), "unexpected stderr: {}", stderr);
+}
+
+#[cfg(feature = "llvm")]
+#[test]
+fn callbacks_may_be_defined_after_the_function_naming_them() {
+    let src = write_source("late_callbacks.wheel", "func setup() {\n    irq_register(32, \"on_timer\");\n    thread_spawn(\"worker\", 7);\n}\nfunc on_timer() {\n    return 0;\n}\nfunc worker(n) {\n    return n;\n}\nsetup();\n");
+    let output = wheelc().arg(&src).arg("--mode").arg("ll").arg("--emit").arg("llvm-ir").output().expect("failed to run wheelc");
+    let ir = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(ir.contains("define internal x86_intrcc void @wheel_irq_32_on_timer("), "{}", ir);
+    assert!(ir.contains("call i64 @wheel_irq_register(i64 32, i64 ptrtoint (ptr @wheel_irq_32_on_timer to i64))"), "{}", ir);
+    assert!(ir.contains("call i64 @wheel_thread_spawn(i64 ptrtoint (ptr @worker to i64), i64 7)"), "{}", ir);
+}
+
+#[cfg(feature = "llvm")]
+#[test]
+fn sdl_calls_pass_the_window_handle_first() {
+    let src = write_source("windows.wheel", "let a = sdl_create_window(320, 240, \"left\");\nlet b = sdl_create_window(320, 240, \"right\");\nsdl_clear(a, 0, 0, 0);\nsdl_draw_rect(b, 1, 2, 3, 4, 255, 0, 0);\nsdl_draw_pixel(5, 6, 0, 0, 255);\nsdl_present(b);\nsdl_destroy_window(a);\n");
+    let output = wheelc().arg(&src).arg("--mode").arg("ll").arg("--emit").arg("llvm-ir").output().expect("failed to run wheelc");
+    let ir = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(ir.contains("call i64 @sdl_clear(i64 %load_a, i64 0, i64 0, i64 0)"), "{}", ir);
+    assert!(ir.contains("call i64 @sdl_draw_rect(i64 %load_b, i64 1, i64 2, i64 3, i64 4, i64 255, i64 0, i64 0)"), "{}", ir);
+    // without a handle, the newest window
+    assert!(ir.contains("call i64 @sdl_draw_pixel(i64 0, i64 5, i64 6, i64 0, i64 0, i64 255)"), "{}", ir);
+    assert!(ir.contains("call i64 @sdl_present(i64 %load_b"), "{}", ir);
+    assert!(ir.contains("call i64 @sdl_destroy_window(i64 %load_a"), "{}", ir);
+}
+
+#[cfg(feature = "llvm")]
+#[test]
+fn sdl_images_load_into_a_window_and_draw_by_handle() {
+    let src = write_source("sprites.wheel", "let win = sdl_create_window(320, 240, \"sprites\");\nlet img = sdl_load_image(win, \"player.png\");\nlet bg = sdl_load_image(\"bg.png\");\nsdl_draw_image(img, 1, 2, 32, 32);\n");
+    let output = wheelc().arg(&src).arg("--mode").arg("ll").arg("--emit").arg("llvm-ir").output().expect("failed to run wheelc");
+    let ir = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(ir.contains("declare i64 @sdl_load_image(i64, i64)"), "{}", ir);
+    assert!(ir.contains("declare i64 @sdl_draw_image(i64, i64, i64, i64, i64)"), "{}", ir);
+    assert!(ir.contains("call i64 @sdl_load_image(i64 %load_win"), "{}", ir);
+    assert!(ir.contains("call i64 @sdl_load_image(i64 0, i64 ptrtoint"), "{}", ir);
+    assert!(ir.contains("call i64 @sdl_draw_image(i64 %load_img"), "{}", ir);
+    assert!(ir.contains(", i64 1, i64 2, i64 32, i64 32)"), "{}", ir);
+}
@@ -0,0 +1,70 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "=== Wheel Bare Metal Demo ==="
+Lmsg1:
+    .ascii ""
+Lmsg2:
+    .ascii "1. Memory Management:"
+Lmsg3:
+    .ascii "Allocated 256 bytes"
+Lmsg4:
+    .ascii "Memory used:"
+Lmsg5:
+    .ascii "Memory free:"
+Lmsg6:
+    .ascii "Freed memory"
+Lmsg7:
+    .ascii "2. Hardware I/O:"
+Lmsg8:
+    .ascii "Reading from port 0x3F8 (serial):"
+Lmsg9:
+    .ascii "Writing to port 0x3F8:"
+Lmsg10:
+    .ascii "Wrote 65 to port"
+Lmsg11:
+    .ascii "Disabling interrupts"
+Lmsg12:
+    .ascii "Enabling interrupts"
+Lmsg13:
+    .ascii "3. Filesystem Operations:"
+Lmsg14:
+    .ascii "Filesystem handle operations:"
+Lmsg15:
+    .ascii "(In real bare metal, would operate on /dev/sda or similar)"
+Lmsg16:
+    .ascii "4. Process Management:"
+Lmsg17:
+    .ascii "Current process ID:"
+Lmsg18:
+    .ascii "Parent process ID:"
+Lmsg19:
+    .ascii "Creating child process"
+Lmsg20:
+    .ascii "Child process handle:"
+Lmsg21:
+    .ascii "Waiting for child process"
+Lmsg22:
+    .ascii "Child exit code:"
+Lmsg23:
+    .ascii "=== Demo Complete ==="
+Lmsg24:
+    .ascii "Array Demo:"
+Lmsg25:
+    .ascii "Struct Demo:"
+    .section .bss
+input_buffer: .space 256
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/bare_metal_demo.wheel"
+    .loc 1 4 1
+    .loc 1 5 1
+    .loc 1 6 1
+    .loc 1 7 1
+    .loc 1 9 1
+    .loc 1 83 1
+    .loc 1 94 1
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,132 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_____Calculadora_Wheel____ = constant [27 x i8] c"=== Calculadora Wheel ===\00\00"
+@str_Digite_o_primeiro_numero__ = constant [28 x i8] c"Digite o primeiro numero: \00\00"
+@str_Digite_o_segundo_numero__ = constant [27 x i8] c"Digite o segundo numero: \00\00"
+@str_Operacao__1___2___3___4_____ = constant [30 x i8] c"Operacao (1=+ 2=- 3=* 4=/): \00\00"
+@str_Resultado__ = constant [13 x i8] c"Resultado: \00\00"
+@str_Resultado__.1 = constant [13 x i8] c"Resultado: \00\00"
+@str_Resultado__.2 = constant [13 x i8] c"Resultado: \00\00"
+@str_Resultado__.3 = constant [13 x i8] c"Resultado: \00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %num1 = alloca i64, align 8
+  %num2 = alloca i64, align 8
+  %op = alloca i64, align 8
+  %result = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____Calculadora_Wheel____)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_primeiro_numero__)
+  %call_scanf = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i64 = zext i32 %call_atoi_input to i64
+  store i64 %atoi_to_i64, ptr %num1, align 4
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_segundo_numero__)
+  %call_scanf3 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input4 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i645 = zext i32 %call_atoi_input4 to i64
+  store i64 %atoi_to_i645, ptr %num2, align 4
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Operacao__1___2___3___4_____)
+  %call_scanf7 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input8 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i649 = zext i32 %call_atoi_input8 to i64
+  store i64 %atoi_to_i649, ptr %op, align 4
+  store i64 0, ptr %result, align 4
+  %load_op = load i64, ptr %op, align 4
+  %eqtmp = icmp eq i64 %load_op, 1
+  %bool_to_i64 = zext i1 %eqtmp to i64
+  %ifcond = icmp ne i64 %bool_to_i64, 0
+  br i1 %ifcond, label %then, label %else
+
+then:                                             ; preds = %entry
+  %load_num1 = load i64, ptr %num1, align 4
+  %load_num2 = load i64, ptr %num2, align 4
+  %addtmp = add i64 %load_num1, %load_num2
+  store i64 %addtmp, ptr %result, align 4
+  %call_printf10 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Resultado__)
+  %load_result = load i64, ptr %result, align 4
+  %call_printf11 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_result)
+  br label %ifend
+
+else:                                             ; preds = %entry
+  br label %ifend
+
+ifend:                                            ; preds = %else, %then
+  %load_op12 = load i64, ptr %op, align 4
+  %eqtmp13 = icmp eq i64 %load_op12, 2
+  %bool_to_i6414 = zext i1 %eqtmp13 to i64
+  %ifcond15 = icmp ne i64 %bool_to_i6414, 0
+  br i1 %ifcond15, label %then16, label %else17
+
+then16:                                           ; preds = %ifend
+  %load_num119 = load i64, ptr %num1, align 4
+  %load_num220 = load i64, ptr %num2, align 4
+  %subtmp = sub i64 %load_num119, %load_num220
+  store i64 %subtmp, ptr %result, align 4
+  %call_printf21 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Resultado__.1)
+  %load_result22 = load i64, ptr %result, align 4
+  %call_printf23 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_result22)
+  br label %ifend18
+
+else17:                                           ; preds = %ifend
+  br label %ifend18
+
+ifend18:                                          ; preds = %else17, %then16
+  %load_op24 = load i64, ptr %op, align 4
+  %eqtmp25 = icmp eq i64 %load_op24, 3
+  %bool_to_i6426 = zext i1 %eqtmp25 to i64
+  %ifcond27 = icmp ne i64 %bool_to_i6426, 0
+  br i1 %ifcond27, label %then28, label %else29
+
+then28:                                           ; preds = %ifend18
+  %load_num131 = load i64, ptr %num1, align 4
+  %load_num232 = load i64, ptr %num2, align 4
+  %multmp = mul i64 %load_num131, %load_num232
+  store i64 %multmp, ptr %result, align 4
+  %call_printf33 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Resultado__.2)
+  %load_result34 = load i64, ptr %result, align 4
+  %call_printf35 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_result34)
+  br label %ifend30
+
+else29:                                           ; preds = %ifend18
+  br label %ifend30
+
+ifend30:                                          ; preds = %else29, %then28
+  %load_op36 = load i64, ptr %op, align 4
+  %eqtmp37 = icmp eq i64 %load_op36, 4
+  %bool_to_i6438 = zext i1 %eqtmp37 to i64
+  %ifcond39 = icmp ne i64 %bool_to_i6438, 0
+  br i1 %ifcond39, label %then40, label %else41
+
+then40:                                           ; preds = %ifend30
+  %load_num143 = load i64, ptr %num1, align 4
+  %load_num244 = load i64, ptr %num2, align 4
+  %divtmp = sdiv i64 %load_num143, %load_num244
+  store i64 %divtmp, ptr %result, align 4
+  %call_printf45 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Resultado__.3)
+  %load_result46 = load i64, ptr %result, align 4
+  %call_printf47 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_result46)
+  br label %ifend42
+
+else41:                                           ; preds = %ifend30
+  br label %ifend42
+
+ifend42:                                          ; preds = %else41, %then40
+  ret i32 0
+}
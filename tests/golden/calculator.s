@@ -0,0 +1,312 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "=== Calculadora Wheel ==="
+Lmsg1:
+    .ascii "Digite o primeiro numero: "
+Lmsg2:
+    .ascii "Digite o segundo numero: "
+Lmsg3:
+    .ascii "Operacao (1=+ 2=- 3=* 4=/): "
+Lmsg4:
+    .ascii "Resultado: "
+    .section .bss
+input_buffer: .space 256
+num1: .quad 0
+num2: .quad 0
+op: .quad 0
+result: .quad 0
+num1_buf: .space 256
+num1_len: .quad 0
+num2_buf: .space 256
+num2_len: .quad 0
+op_buf: .space 256
+op_len: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/calculator.wheel"
+    .loc 1 1 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 3 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 26
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 4 1
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num1_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num1_len], rax
+    .loc 1 6 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 7 1
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num2_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num2_len], rax
+    .loc 1 9 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 28
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 1
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + op_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + op_len], rax
+    .loc 1 12 1
+    mov rax, 0
+    mov qword ptr [rip + result], rax
+    .loc 1 14 1
+    mov rax, qword ptr [rip + op]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_0
+    .loc 1 15 5
+    mov rax, qword ptr [rip + num1]
+    push rax
+    mov rax, qword ptr [rip + num2]
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + result], rax
+    .loc 1 16 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 17 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + result]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_1
+    neg rax
+Litoa_1:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_1
+    test r8, r8
+    jns Litoa_end_1
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_1:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_0:
+    .loc 1 20 1
+    mov rax, qword ptr [rip + op]
+    push rax
+    mov rax, 2
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_2
+    .loc 1 21 5
+    mov rax, qword ptr [rip + num1]
+    push rax
+    mov rax, qword ptr [rip + num2]
+    mov rbx, rax
+    pop rax
+    sub rax, rbx
+    mov qword ptr [rip + result], rax
+    .loc 1 22 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 23 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + result]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_3
+    neg rax
+Litoa_3:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_3
+    test r8, r8
+    jns Litoa_end_3
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_3:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_2:
+    .loc 1 26 1
+    mov rax, qword ptr [rip + op]
+    push rax
+    mov rax, 3
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_4
+    .loc 1 27 5
+    mov rax, qword ptr [rip + num1]
+    push rax
+    mov rax, qword ptr [rip + num2]
+    mov rbx, rax
+    pop rax
+    imul rax, rbx
+    mov qword ptr [rip + result], rax
+    .loc 1 28 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 29 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + result]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_5
+    neg rax
+Litoa_5:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_5
+    test r8, r8
+    jns Litoa_end_5
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_5:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_4:
+    .loc 1 32 1
+    mov rax, qword ptr [rip + op]
+    push rax
+    mov rax, 4
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_6
+    .loc 1 33 5
+    mov rax, qword ptr [rip + num1]
+    push rax
+    mov rax, qword ptr [rip + num2]
+    mov rbx, rax
+    pop rax
+    cqo
+    idiv rbx
+    mov qword ptr [rip + result], rax
+    .loc 1 34 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 35 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + result]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_7
+    neg rax
+Litoa_7:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_7
+    test r8, r8
+    jns Litoa_end_7
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_7:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_6:
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,55 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_____Calculadora_Wheel____ = constant [27 x i8] c"=== Calculadora Wheel ===\00\00"
+@str_10___4___ = constant [11 x i8] c"10 + 4 = \00\00"
+@str_10___4___.1 = constant [11 x i8] c"10 - 4 = \00\00"
+@str_10___4___.2 = constant [11 x i8] c"10 * 4 = \00\00"
+@str_10___4___.3 = constant [11 x i8] c"10 / 4 = \00\00"
+@str_____Fim____ = constant [13 x i8] c"=== Fim ===\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %num1 = alloca i64, align 8
+  %num2 = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____Calculadora_Wheel____)
+  store i64 10, ptr %num1, align 4
+  store i64 4, ptr %num2, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_10___4___)
+  %load_num1 = load i64, ptr %num1, align 4
+  %load_num2 = load i64, ptr %num2, align 4
+  %addtmp = add i64 %load_num1, %load_num2
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %addtmp)
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_10___4___.1)
+  %load_num14 = load i64, ptr %num1, align 4
+  %load_num25 = load i64, ptr %num2, align 4
+  %subtmp = sub i64 %load_num14, %load_num25
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %subtmp)
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_10___4___.2)
+  %load_num18 = load i64, ptr %num1, align 4
+  %load_num29 = load i64, ptr %num2, align 4
+  %multmp = mul i64 %load_num18, %load_num29
+  %call_printf10 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %multmp)
+  %call_printf11 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_10___4___.3)
+  %load_num112 = load i64, ptr %num1, align 4
+  %load_num213 = load i64, ptr %num2, align 4
+  %divtmp = sdiv i64 %load_num112, %load_num213
+  %call_printf14 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %divtmp)
+  %call_printf15 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____Fim____)
+  ret i32 0
+}
@@ -0,0 +1,119 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "=== Calculadora Wheel ==="
+Lmsg1:
+    .ascii "10 + 4 = "
+Lmsg2:
+    .ascii "14"
+Lmsg3:
+    .ascii "10 - 4 = "
+Lmsg4:
+    .ascii "6"
+Lmsg5:
+    .ascii "10 * 4 = "
+Lmsg6:
+    .ascii "40"
+Lmsg7:
+    .ascii "10 / 4 = "
+Lmsg8:
+    .ascii "2"
+Lmsg9:
+    .ascii "=== Fim ==="
+    .section .bss
+input_buffer: .space 256
+num1: .quad 0
+num2: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/calculator_demo.wheel"
+    .loc 1 1 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 3 1
+    mov rax, 10
+    mov qword ptr [rip + num1], rax
+    .loc 1 4 1
+    mov rax, 4
+    mov qword ptr [rip + num2], rax
+    .loc 1 6 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 9
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 7 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 9 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 9
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 12 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 9
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 13 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 15 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg7]
+    mov rdx, 9
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 16 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 18 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,25 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Whell_With_Love_By_Samuel_Fortini_From_Brazil = constant [47 x i8] c"Whell With Love By Samuel Fortini From Brazil\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Whell_With_Love_By_Samuel_Fortini_From_Brazil)
+  ret i32 0
+}
@@ -0,0 +1,21 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Whell With Love By Samuel Fortini From Brazil"
+    .section .bss
+input_buffer: .space 256
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/codigo.wheel"
+    .loc 1 1 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 45
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,63 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "x is greater than y"
+Lmsg1:
+    .ascii "x is not greater than y"
+Lmsg2:
+    .ascii "done"
+    .section .bss
+input_buffer: .space 256
+x: .quad 0
+y: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/conditional.wheel"
+    .loc 1 1 1
+    mov rax, 5
+    mov qword ptr [rip + x], rax
+    .loc 1 2 1
+    mov rax, 3
+    mov qword ptr [rip + y], rax
+    .loc 1 4 1
+    mov rax, qword ptr [rip + x]
+    push rax
+    mov rax, qword ptr [rip + y]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setg al
+    movzx rax, al
+    cmp rax, 0
+    je Lelse_0
+    .loc 1 5 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 19
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    jmp Lend_0
+Lelse_0:
+    .loc 1 8 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 23
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_0:
+    .loc 1 11 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
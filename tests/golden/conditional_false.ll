@@ -0,0 +1,47 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_x___y = constant [7 x i8] c"x > y\00\00"
+@str_x____y = constant [8 x i8] c"x <= y\00\00"
+@str__done = constant [7 x i8] c" done\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %x = alloca i64, align 8
+  %y = alloca i64, align 8
+  store i64 2, ptr %x, align 4
+  store i64 5, ptr %y, align 4
+  %load_x = load i64, ptr %x, align 4
+  %load_y = load i64, ptr %y, align 4
+  %gttmp = icmp sgt i64 %load_x, %load_y
+  %bool_to_i64 = zext i1 %gttmp to i64
+  %ifcond = icmp ne i64 %bool_to_i64, 0
+  br i1 %ifcond, label %then, label %else
+
+then:                                             ; preds = %entry
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_x___y)
+  br label %ifend
+
+else:                                             ; preds = %entry
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_x____y)
+  br label %ifend
+
+ifend:                                            ; preds = %else, %then
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__done)
+  ret i32 0
+}
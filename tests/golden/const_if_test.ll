@@ -0,0 +1,36 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_if_with_constants_works = constant [25 x i8] c"if with constants works\00\00"
+@str_done = constant [6 x i8] c"done\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  br i1 true, label %then, label %else
+
+then:                                             ; preds = %entry
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_if_with_constants_works)
+  br label %ifend
+
+else:                                             ; preds = %entry
+  br label %ifend
+
+ifend:                                            ; preds = %else, %then
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_done)
+  ret i32 0
+}
@@ -0,0 +1,33 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "if with constants works"
+Lmsg1:
+    .ascii "done"
+    .section .bss
+input_buffer: .space 256
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/const_if_test.wheel"
+    .loc 1 4 1
+    .loc 1 5 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 23
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_0:
+    .loc 1 8 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,19 @@
+    .intel_syntax noprefix
+    .section .rodata
+    .section .bss
+input_buffer: .space 256
+constants_pi: .quad 0
+constants_e: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/constants.wheel"
+    .loc 1 1 1
+    mov rax, 314
+    mov qword ptr [rip + constants_pi], rax
+    .loc 1 2 1
+    mov rax, 271
+    mov qword ptr [rip + constants_e], rax
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,46 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_start = constant [7 x i8] c"start\00\00"
+@str_x_defined = constant [11 x i8] c"x defined\00\00"
+@str_yes = constant [5 x i8] c"yes\00\00"
+@str_end = constant [5 x i8] c"end\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %x = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_start)
+  store i64 5, ptr %x, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_x_defined)
+  %load_x = load i64, ptr %x, align 4
+  %gttmp = icmp sgt i64 %load_x, 3
+  %bool_to_i64 = zext i1 %gttmp to i64
+  %ifcond = icmp ne i64 %bool_to_i64, 0
+  br i1 %ifcond, label %then, label %else
+
+then:                                             ; preds = %entry
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_yes)
+  br label %ifend
+
+else:                                             ; preds = %entry
+  br label %ifend
+
+ifend:                                            ; preds = %else, %then
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_end)
+  ret i32 0
+}
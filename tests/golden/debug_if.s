@@ -0,0 +1,67 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "start"
+Lmsg1:
+    .ascii "x defined"
+Lmsg2:
+    .ascii "yes"
+Lmsg3:
+    .ascii "end"
+    .section .bss
+input_buffer: .space 256
+x: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/debug_if.wheel"
+    .loc 1 1 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 5
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 2 1
+    mov rax, 5
+    mov qword ptr [rip + x], rax
+    .loc 1 3 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 9
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 5 1
+    mov rax, qword ptr [rip + x]
+    push rax
+    mov rax, 3
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setg al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_0
+    .loc 1 6 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 3
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_0:
+    .loc 1 9 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 3
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,20 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "For in range test:"
+Lmsg1:
+    .ascii "Loop from 0 to 5:"
+Lmsg2:
+    .ascii "Loop from 2 to 7:"
+Lmsg3:
+    .ascii "Done"
+    .section .bss
+input_buffer: .space 256
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/for_range_demo.wheel"
+    .loc 1 1 1
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,361 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "=== Jogo da Forca (Wheel Edition) ==="
+Lmsg1:
+    .ascii "Adivinhe a palavra!"
+Lmsg2:
+    .ascii "\\nPalavra: "
+Lmsg3:
+    .ascii "\\nTentativas restantes: "
+Lmsg4:
+    .ascii "\\nDigite uma letra: "
+Lmsg5:
+    .ascii "Letra errada!"
+Lmsg6:
+    .ascii "\\n\\n=== VOCÊ VENCEU! ==="
+Lmsg7:
+    .ascii "\\nA palavra era: "
+Lmsg8:
+    .ascii "\\n\\n=== FIM DO JOGO ==="
+Lmsg9:
+    .ascii "💀 Você perdeu! A palavra era: "
+    .section .bss
+input_buffer: .space 256
+palavras: .quad 0
+palavra: .quad 0
+tamanho: .quad 0
+estado: .quad 0
+tentativas: .quad 0
+letra: .quad 0
+acertou: .quad 0
+i: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/forca_random.wheel"
+    .loc 1 1 2
+    .loc 1 2 2
+    .loc 1 4 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 37
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 5 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 19
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 7 1
+    mov rax, 0
+    mov qword ptr [rip + palavras], rax
+    .loc 1 8 1
+    mov rax, 0
+    mov qword ptr [rip + palavra], rax
+    .loc 1 10 1
+    mov rax, 0
+    mov qword ptr [rip + tamanho], rax
+    .loc 1 11 1
+    mov rax, 0
+    mov qword ptr [rip + estado], rax
+    .loc 1 13 1
+    mov rax, 6
+    mov qword ptr [rip + tentativas], rax
+    .loc 1 15 1
+Lloop_0:
+    mov rax, qword ptr [rip + tentativas]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setg al
+    movzx rax, al
+    cmp rax, 0
+    je Lexit_0
+    .loc 1 17 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 18 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + estado]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_1
+    neg rax
+Litoa_1:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_1
+    test r8, r8
+    jns Litoa_end_1
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_1:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 20 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 24
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 21 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + tentativas]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_2
+    neg rax
+Litoa_2:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_2
+    test r8, r8
+    jns Litoa_end_2
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_2:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 23 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 20
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 24 5
+    mov rax, 0
+    mov qword ptr [rip + letra], rax
+    .loc 1 24 37
+    .loc 1 24 45
+    .loc 1 24 53
+    .loc 1 24 56
+    .loc 1 24 59
+    .loc 1 26 5
+    mov rax, 0
+    mov qword ptr [rip + acertou], rax
+    .loc 1 28 5
+    mov rax, 0
+    mov qword ptr [rip + i], rax
+    .loc 1 29 5
+Lloop_3:
+    mov rax, qword ptr [rip + i]
+    push rax
+    mov rax, qword ptr [rip + tamanho]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lexit_3
+    .loc 1 30 9
+    mov rax, 0
+    push rax
+    mov rax, qword ptr [rip + letra]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_4
+    .loc 1 31 13
+    mov rax, 0
+    mov qword ptr [rip + estado], rax
+    .loc 1 32 13
+    mov rax, 0
+    mov qword ptr [rip + acertou], rax
+Lend_4:
+    .loc 1 34 9
+    mov rax, qword ptr [rip + i]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + i], rax
+    jmp Lloop_3
+Lexit_3:
+    .loc 1 37 5
+    mov rax, qword ptr [rip + acertou]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_5
+    .loc 1 38 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 13
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 39 9
+    mov rax, qword ptr [rip + tentativas]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    sub rax, rbx
+    mov qword ptr [rip + tentativas], rax
+Lend_5:
+    .loc 1 42 5
+    mov rax, qword ptr [rip + estado]
+    push rax
+    mov rax, qword ptr [rip + palavra]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_6
+    .loc 1 43 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 44 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg7]
+    mov rdx, 17
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 45 9
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + palavra]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_7
+    neg rax
+Litoa_7:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_7
+    test r8, r8
+    jns Litoa_end_7
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_7:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 46 9
+Lend_6:
+    jmp Lloop_0
+Lexit_0:
+    .loc 1 50 1
+    mov rax, qword ptr [rip + estado]
+    push rax
+    mov rax, qword ptr [rip + palavra]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setne al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_8
+    .loc 1 51 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 23
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 52 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 34
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 53 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + palavra]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_9
+    neg rax
+Litoa_9:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_9
+    test r8, r8
+    jns Litoa_end_9
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_9:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_8:
+    mov rax, 60
+    xor rdi, rdi
+    syscall
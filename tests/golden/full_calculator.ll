@@ -0,0 +1,278 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str________________________________________n = constant [118 x i8] c"\E2\95\94\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\97\\n\00\00"
+@str_____Calculadora_Wheel_v1_0_____________n = constant [46 x i8] c"\E2\95\91   Calculadora Wheel v1.0           \E2\95\91\\n\00\00"
+@str________________________________________n.1 = constant [118 x i8] c"\E2\95\9A\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\90\E2\95\9D\\n\00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+@str_Escolha_uma_operacao__n = constant [25 x i8] c"Escolha uma operacao:\\n\00\00"
+@str_1__Adicao_n = constant [13 x i8] c"1: Adicao\\n\00\00"
+@str_2__Subtracao_n = constant [16 x i8] c"2: Subtracao\\n\00\00"
+@str_3__Multiplicacao_n = constant [20 x i8] c"3: Multiplicacao\\n\00\00"
+@str_4__Divisao_n = constant [14 x i8] c"4: Divisao\\n\00\00"
+@str_5__Resto__Modulo__n = constant [21 x i8] c"5: Resto (Modulo)\\n\00\00"
+@str_0__Sair_n = constant [11 x i8] c"0: Sair\\n\00\00"
+@str____ = constant [5 x i8] c">> \00\00"
+@str_0 = constant [3 x i8] c"0\00\00"
+@"str_At\C3\A9_logo__n" = constant [14 x i8] c"At\C3\A9 logo!\\n\00\00"
+@str_1 = constant [3 x i8] c"1\00\00"
+@str_Digite_o_primeiro_numero__ = constant [28 x i8] c"Digite o primeiro numero: \00\00"
+@str_Digite_o_segundo_numero__ = constant [27 x i8] c"Digite o segundo numero: \00\00"
+@str_Resultado__ = constant [13 x i8] c"Resultado: \00\00"
+@str__n_n = constant [6 x i8] c"\\n\\n\00\00"
+@str_2 = constant [3 x i8] c"2\00\00"
+@str_Digite_o_primeiro_numero__.2 = constant [28 x i8] c"Digite o primeiro numero: \00\00"
+@str_Digite_o_segundo_numero__.3 = constant [27 x i8] c"Digite o segundo numero: \00\00"
+@str_Resultado__.4 = constant [13 x i8] c"Resultado: \00\00"
+@str__n_n.5 = constant [6 x i8] c"\\n\\n\00\00"
+@str_3 = constant [3 x i8] c"3\00\00"
+@str_Digite_o_primeiro_numero__.6 = constant [28 x i8] c"Digite o primeiro numero: \00\00"
+@str_Digite_o_segundo_numero__.7 = constant [27 x i8] c"Digite o segundo numero: \00\00"
+@str_Resultado__.8 = constant [13 x i8] c"Resultado: \00\00"
+@str__n_n.9 = constant [6 x i8] c"\\n\\n\00\00"
+@str_4 = constant [3 x i8] c"4\00\00"
+@str_Digite_o_primeiro_numero__.10 = constant [28 x i8] c"Digite o primeiro numero: \00\00"
+@str_Digite_o_segundo_numero__.11 = constant [27 x i8] c"Digite o segundo numero: \00\00"
+@str_Resultado__.12 = constant [13 x i8] c"Resultado: \00\00"
+@str__n_n.13 = constant [6 x i8] c"\\n\\n\00\00"
+@str_5 = constant [3 x i8] c"5\00\00"
+@str_Digite_o_primeiro_numero__.14 = constant [28 x i8] c"Digite o primeiro numero: \00\00"
+@str_Digite_o_segundo_numero__.15 = constant [27 x i8] c"Digite o segundo numero: \00\00"
+@str_Resultado__.16 = constant [13 x i8] c"Resultado: \00\00"
+@str__n_n.17 = constant [6 x i8] c"\\n\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %resultado = alloca i64, align 8
+  %num2 = alloca i64, align 8
+  %num1 = alloca i64, align 8
+  %opcao = alloca i64, align 8
+  %continuar = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str________________________________________n)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____Calculadora_Wheel_v1_0_____________n)
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str________________________________________n.1)
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  store i64 1, ptr %continuar, align 4
+  br label %loop
+
+loop:                                             ; preds = %ifend111, %entry
+  %load_continuar = load i64, ptr %continuar, align 4
+  %eqtmp = icmp eq i64 %load_continuar, 1
+  %bool_to_i64 = zext i1 %eqtmp to i64
+  %whilecond = icmp ne i64 %bool_to_i64, 0
+  br i1 %whilecond, label %loopbody, label %loopafter
+
+loopbody:                                         ; preds = %loop
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Escolha_uma_operacao__n)
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_1__Adicao_n)
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_2__Subtracao_n)
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_3__Multiplicacao_n)
+  %call_printf8 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_4__Divisao_n)
+  %call_printf9 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_5__Resto__Modulo__n)
+  %call_printf10 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_0__Sair_n)
+  %call_printf11 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str____)
+  %call_scanf = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i64 = zext i32 %call_atoi_input to i64
+  store i64 %atoi_to_i64, ptr %opcao, align 4
+  %load_opcao = load i64, ptr %opcao, align 4
+  %call_atoi_r = call i32 @atoi(ptr @str_0)
+  %atoi_r = sext i32 %call_atoi_r to i64
+  %eqi = icmp eq i64 %load_opcao, %atoi_r
+  %bool_to_i6412 = zext i1 %eqi to i64
+  %ifcond = icmp ne i64 %bool_to_i6412, 0
+  br i1 %ifcond, label %then, label %else
+
+loopafter:                                        ; preds = %loop
+  ret i32 0
+
+then:                                             ; preds = %loopbody
+  store i64 0, ptr %continuar, align 4
+  %call_printf13 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @"str_At\C3\A9_logo__n")
+  br label %ifend
+
+else:                                             ; preds = %loopbody
+  br label %ifend
+
+ifend:                                            ; preds = %else, %then
+  %load_opcao14 = load i64, ptr %opcao, align 4
+  %call_atoi_r15 = call i32 @atoi(ptr @str_1)
+  %atoi_r16 = sext i32 %call_atoi_r15 to i64
+  %eqi17 = icmp eq i64 %load_opcao14, %atoi_r16
+  %bool_to_i6418 = zext i1 %eqi17 to i64
+  %ifcond19 = icmp ne i64 %bool_to_i6418, 0
+  br i1 %ifcond19, label %then20, label %else21
+
+then20:                                           ; preds = %ifend
+  %call_printf23 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_primeiro_numero__)
+  %call_scanf24 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input25 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i6426 = zext i32 %call_atoi_input25 to i64
+  store i64 %atoi_to_i6426, ptr %num1, align 4
+  %call_printf27 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_segundo_numero__)
+  %call_scanf28 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input29 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i6430 = zext i32 %call_atoi_input29 to i64
+  store i64 %atoi_to_i6430, ptr %num2, align 4
+  %load_num1 = load i64, ptr %num1, align 4
+  %load_num2 = load i64, ptr %num2, align 4
+  %addtmp = add i64 %load_num1, %load_num2
+  store i64 %addtmp, ptr %resultado, align 4
+  %call_printf31 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Resultado__)
+  %load_resultado = load i64, ptr %resultado, align 4
+  %call_printf32 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_resultado)
+  %call_printf33 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n)
+  br label %ifend22
+
+else21:                                           ; preds = %ifend
+  br label %ifend22
+
+ifend22:                                          ; preds = %else21, %then20
+  %load_opcao34 = load i64, ptr %opcao, align 4
+  %call_atoi_r35 = call i32 @atoi(ptr @str_2)
+  %atoi_r36 = sext i32 %call_atoi_r35 to i64
+  %eqi37 = icmp eq i64 %load_opcao34, %atoi_r36
+  %bool_to_i6438 = zext i1 %eqi37 to i64
+  %ifcond39 = icmp ne i64 %bool_to_i6438, 0
+  br i1 %ifcond39, label %then40, label %else41
+
+then40:                                           ; preds = %ifend22
+  %call_printf43 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_primeiro_numero__.2)
+  %call_scanf44 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input45 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i6446 = zext i32 %call_atoi_input45 to i64
+  store i64 %atoi_to_i6446, ptr %num1, align 4
+  %call_printf47 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_segundo_numero__.3)
+  %call_scanf48 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input49 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i6450 = zext i32 %call_atoi_input49 to i64
+  store i64 %atoi_to_i6450, ptr %num2, align 4
+  %load_num151 = load i64, ptr %num1, align 4
+  %load_num252 = load i64, ptr %num2, align 4
+  %subtmp = sub i64 %load_num151, %load_num252
+  store i64 %subtmp, ptr %resultado, align 4
+  %call_printf53 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Resultado__.4)
+  %load_resultado54 = load i64, ptr %resultado, align 4
+  %call_printf55 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_resultado54)
+  %call_printf56 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n.5)
+  br label %ifend42
+
+else41:                                           ; preds = %ifend22
+  br label %ifend42
+
+ifend42:                                          ; preds = %else41, %then40
+  %load_opcao57 = load i64, ptr %opcao, align 4
+  %call_atoi_r58 = call i32 @atoi(ptr @str_3)
+  %atoi_r59 = sext i32 %call_atoi_r58 to i64
+  %eqi60 = icmp eq i64 %load_opcao57, %atoi_r59
+  %bool_to_i6461 = zext i1 %eqi60 to i64
+  %ifcond62 = icmp ne i64 %bool_to_i6461, 0
+  br i1 %ifcond62, label %then63, label %else64
+
+then63:                                           ; preds = %ifend42
+  %call_printf66 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_primeiro_numero__.6)
+  %call_scanf67 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input68 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i6469 = zext i32 %call_atoi_input68 to i64
+  store i64 %atoi_to_i6469, ptr %num1, align 4
+  %call_printf70 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_segundo_numero__.7)
+  %call_scanf71 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input72 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i6473 = zext i32 %call_atoi_input72 to i64
+  store i64 %atoi_to_i6473, ptr %num2, align 4
+  %load_num174 = load i64, ptr %num1, align 4
+  %load_num275 = load i64, ptr %num2, align 4
+  %multmp = mul i64 %load_num174, %load_num275
+  store i64 %multmp, ptr %resultado, align 4
+  %call_printf76 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Resultado__.8)
+  %load_resultado77 = load i64, ptr %resultado, align 4
+  %call_printf78 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_resultado77)
+  %call_printf79 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n.9)
+  br label %ifend65
+
+else64:                                           ; preds = %ifend42
+  br label %ifend65
+
+ifend65:                                          ; preds = %else64, %then63
+  %load_opcao80 = load i64, ptr %opcao, align 4
+  %call_atoi_r81 = call i32 @atoi(ptr @str_4)
+  %atoi_r82 = sext i32 %call_atoi_r81 to i64
+  %eqi83 = icmp eq i64 %load_opcao80, %atoi_r82
+  %bool_to_i6484 = zext i1 %eqi83 to i64
+  %ifcond85 = icmp ne i64 %bool_to_i6484, 0
+  br i1 %ifcond85, label %then86, label %else87
+
+then86:                                           ; preds = %ifend65
+  %call_printf89 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_primeiro_numero__.10)
+  %call_scanf90 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input91 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i6492 = zext i32 %call_atoi_input91 to i64
+  store i64 %atoi_to_i6492, ptr %num1, align 4
+  %call_printf93 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_segundo_numero__.11)
+  %call_scanf94 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input95 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i6496 = zext i32 %call_atoi_input95 to i64
+  store i64 %atoi_to_i6496, ptr %num2, align 4
+  %load_num197 = load i64, ptr %num1, align 4
+  %load_num298 = load i64, ptr %num2, align 4
+  %divtmp = sdiv i64 %load_num197, %load_num298
+  store i64 %divtmp, ptr %resultado, align 4
+  %call_printf99 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Resultado__.12)
+  %load_resultado100 = load i64, ptr %resultado, align 4
+  %call_printf101 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_resultado100)
+  %call_printf102 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n.13)
+  br label %ifend88
+
+else87:                                           ; preds = %ifend65
+  br label %ifend88
+
+ifend88:                                          ; preds = %else87, %then86
+  %load_opcao103 = load i64, ptr %opcao, align 4
+  %call_atoi_r104 = call i32 @atoi(ptr @str_5)
+  %atoi_r105 = sext i32 %call_atoi_r104 to i64
+  %eqi106 = icmp eq i64 %load_opcao103, %atoi_r105
+  %bool_to_i64107 = zext i1 %eqi106 to i64
+  %ifcond108 = icmp ne i64 %bool_to_i64107, 0
+  br i1 %ifcond108, label %then109, label %else110
+
+then109:                                          ; preds = %ifend88
+  %call_printf112 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_primeiro_numero__.14)
+  %call_scanf113 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input114 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i64115 = zext i32 %call_atoi_input114 to i64
+  store i64 %atoi_to_i64115, ptr %num1, align 4
+  %call_printf116 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_segundo_numero__.15)
+  %call_scanf117 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input118 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i64119 = zext i32 %call_atoi_input118 to i64
+  store i64 %atoi_to_i64119, ptr %num2, align 4
+  %load_num1120 = load i64, ptr %num1, align 4
+  store i64 %load_num1120, ptr %resultado, align 4
+  %load_num2121 = load i64, ptr %num2, align 4
+  %call_printf122 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Resultado__.16)
+  %load_resultado123 = load i64, ptr %resultado, align 4
+  %call_printf124 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_resultado123)
+  %call_printf125 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n.17)
+  br label %ifend111
+
+else110:                                          ; preds = %ifend88
+  br label %ifend111
+
+ifend111:                                         ; preds = %else110, %then109
+  br label %loop
+}
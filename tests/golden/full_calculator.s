@@ -0,0 +1,666 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "╔════════════════════════════════════╗\\n"
+Lmsg1:
+    .ascii "║   Calculadora Wheel v1.0           ║\\n"
+Lmsg2:
+    .ascii "╚════════════════════════════════════╝\\n"
+Lmsg3:
+    .ascii "\\n"
+Lmsg4:
+    .ascii "Escolha uma operacao:\\n"
+Lmsg5:
+    .ascii "1: Adicao\\n"
+Lmsg6:
+    .ascii "2: Subtracao\\n"
+Lmsg7:
+    .ascii "3: Multiplicacao\\n"
+Lmsg8:
+    .ascii "4: Divisao\\n"
+Lmsg9:
+    .ascii "5: Resto (Modulo)\\n"
+Lmsg10:
+    .ascii "0: Sair\\n"
+Lmsg11:
+    .ascii ">> "
+Lmsg12:
+    .ascii "Até logo!\\n"
+Lmsg13:
+    .ascii "Digite o primeiro numero: "
+Lmsg14:
+    .ascii "Digite o segundo numero: "
+Lmsg15:
+    .ascii "Resultado: "
+Lmsg16:
+    .ascii "\\n\\n"
+    .section .bss
+input_buffer: .space 256
+continuar: .quad 0
+opcao: .quad 0
+num1: .quad 0
+num2: .quad 0
+resultado: .quad 0
+opcao_buf: .space 256
+opcao_len: .quad 0
+num1_buf: .space 256
+num1_len: .quad 0
+num2_buf: .space 256
+num2_len: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/full_calculator.wheel"
+    .loc 1 5 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 116
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 6 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 44
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 7 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 116
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 8 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 1
+    mov rax, 1
+    mov qword ptr [rip + continuar], rax
+    .loc 1 12 1
+Lloop_0:
+    mov rax, qword ptr [rip + continuar]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lexit_0
+    .loc 1 13 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 23
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 14 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 15 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 14
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 16 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg7]
+    mov rdx, 18
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 17 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 12
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 18 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 19
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 19 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg10]
+    mov rdx, 9
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 20 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg11]
+    mov rdx, 3
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 22 5
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + opcao_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + opcao_len], rax
+    .loc 1 24 5
+    mov rax, qword ptr [rip + opcao]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_1
+    .loc 1 25 9
+    mov rax, 0
+    mov qword ptr [rip + continuar], rax
+    .loc 1 26 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg12]
+    mov rdx, 12
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_1:
+    .loc 1 29 5
+    mov rax, qword ptr [rip + opcao]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_2
+    .loc 1 30 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg13]
+    mov rdx, 26
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 31 9
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num1_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num1_len], rax
+    .loc 1 32 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg14]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 33 9
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num2_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num2_len], rax
+    .loc 1 34 9
+    mov rax, qword ptr [rip + num1]
+    push rax
+    mov rax, qword ptr [rip + num2]
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + resultado], rax
+    .loc 1 35 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg15]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 36 9
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + resultado]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_3
+    neg rax
+Litoa_3:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_3
+    test r8, r8
+    jns Litoa_end_3
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_3:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 37 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg16]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_2:
+    .loc 1 40 5
+    mov rax, qword ptr [rip + opcao]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_4
+    .loc 1 41 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg13]
+    mov rdx, 26
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 42 9
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num1_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num1_len], rax
+    .loc 1 43 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg14]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 44 9
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num2_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num2_len], rax
+    .loc 1 45 9
+    mov rax, qword ptr [rip + num1]
+    push rax
+    mov rax, qword ptr [rip + num2]
+    mov rbx, rax
+    pop rax
+    sub rax, rbx
+    mov qword ptr [rip + resultado], rax
+    .loc 1 46 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg15]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 47 9
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + resultado]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_5
+    neg rax
+Litoa_5:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_5
+    test r8, r8
+    jns Litoa_end_5
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_5:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 48 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg16]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_4:
+    .loc 1 51 5
+    mov rax, qword ptr [rip + opcao]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_6
+    .loc 1 52 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg13]
+    mov rdx, 26
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 53 9
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num1_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num1_len], rax
+    .loc 1 54 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg14]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 55 9
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num2_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num2_len], rax
+    .loc 1 56 9
+    mov rax, qword ptr [rip + num1]
+    push rax
+    mov rax, qword ptr [rip + num2]
+    mov rbx, rax
+    pop rax
+    imul rax, rbx
+    mov qword ptr [rip + resultado], rax
+    .loc 1 57 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg15]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 58 9
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + resultado]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_7
+    neg rax
+Litoa_7:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_7
+    test r8, r8
+    jns Litoa_end_7
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_7:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 59 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg16]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_6:
+    .loc 1 62 5
+    mov rax, qword ptr [rip + opcao]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_8
+    .loc 1 63 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg13]
+    mov rdx, 26
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 64 9
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num1_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num1_len], rax
+    .loc 1 65 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg14]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 66 9
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num2_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num2_len], rax
+    .loc 1 67 9
+    mov rax, qword ptr [rip + num1]
+    push rax
+    mov rax, qword ptr [rip + num2]
+    mov rbx, rax
+    pop rax
+    cqo
+    idiv rbx
+    mov qword ptr [rip + resultado], rax
+    .loc 1 68 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg15]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 69 9
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + resultado]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_9
+    neg rax
+Litoa_9:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_9
+    test r8, r8
+    jns Litoa_end_9
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_9:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 70 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg16]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_8:
+    .loc 1 73 5
+    mov rax, qword ptr [rip + opcao]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_10
+    .loc 1 74 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg13]
+    mov rdx, 26
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 75 9
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num1_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num1_len], rax
+    .loc 1 76 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg14]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 77 9
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + num2_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + num2_len], rax
+    .loc 1 78 9
+    mov rax, qword ptr [rip + num1]
+    mov qword ptr [rip + resultado], rax
+    .loc 1 78 32
+    .loc 1 79 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg15]
+    mov rdx, 11
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 80 9
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + resultado]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_11
+    neg rax
+Litoa_11:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_11
+    test r8, r8
+    jns Litoa_end_11
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_11:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 81 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg16]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_10:
+    jmp Lloop_0
+Lexit_0:
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,108 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_____Jogo_de_Adivinhar_Numero____ = constant [34 x i8] c"=== Jogo de Adivinhar Numero ===\00\00"
+@str_Pense_em_um_numero_entre_1_e_100 = constant [34 x i8] c"Pense em um numero entre 1 e 100\00\00"
+@str_Digite_seu_palpite__ = constant [22 x i8] c"Digite seu palpite: \00\00"
+@str_Seu_palpite_e_muito_baixo_ = constant [28 x i8] c"Seu palpite e muito baixo!\00\00"
+@str_Seu_palpite_e_muito_alto_ = constant [27 x i8] c"Seu palpite e muito alto!\00\00"
+@str_Parabens__Voce_acertou_em_ = constant [28 x i8] c"Parabens! Voce acertou em \00\00"
+@str__tentativas_ = constant [14 x i8] c" tentativas!\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %secret = alloca i64, align 8
+  %guess = alloca i64, align 8
+  %attempts = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____Jogo_de_Adivinhar_Numero____)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Pense_em_um_numero_entre_1_e_100)
+  store i64 42, ptr %secret, align 4
+  store i64 0, ptr %guess, align 4
+  store i64 0, ptr %attempts, align 4
+  br label %loop
+
+loop:                                             ; preds = %ifend21, %entry
+  %load_guess = load i64, ptr %guess, align 4
+  %load_secret = load i64, ptr %secret, align 4
+  %netmp = icmp ne i64 %load_guess, %load_secret
+  %bool_to_i64 = zext i1 %netmp to i64
+  %whilecond = icmp ne i64 %bool_to_i64, 0
+  br i1 %whilecond, label %loopbody, label %loopafter
+
+loopbody:                                         ; preds = %loop
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_seu_palpite__)
+  %call_scanf = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i64 = zext i32 %call_atoi_input to i64
+  store i64 %atoi_to_i64, ptr %guess, align 4
+  %load_attempts = load i64, ptr %attempts, align 4
+  %addtmp = add i64 %load_attempts, 1
+  store i64 %addtmp, ptr %attempts, align 4
+  %load_guess3 = load i64, ptr %guess, align 4
+  %load_secret4 = load i64, ptr %secret, align 4
+  %lttmp = icmp slt i64 %load_guess3, %load_secret4
+  %bool_to_i645 = zext i1 %lttmp to i64
+  %ifcond = icmp ne i64 %bool_to_i645, 0
+  br i1 %ifcond, label %then, label %else
+
+loopafter:                                        ; preds = %loop
+  ret i32 0
+
+then:                                             ; preds = %loopbody
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Seu_palpite_e_muito_baixo_)
+  br label %ifend
+
+else:                                             ; preds = %loopbody
+  br label %ifend
+
+ifend:                                            ; preds = %else, %then
+  %load_guess7 = load i64, ptr %guess, align 4
+  %load_secret8 = load i64, ptr %secret, align 4
+  %gttmp = icmp sgt i64 %load_guess7, %load_secret8
+  %bool_to_i649 = zext i1 %gttmp to i64
+  %ifcond10 = icmp ne i64 %bool_to_i649, 0
+  br i1 %ifcond10, label %then11, label %else12
+
+then11:                                           ; preds = %ifend
+  %call_printf14 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Seu_palpite_e_muito_alto_)
+  br label %ifend13
+
+else12:                                           ; preds = %ifend
+  br label %ifend13
+
+ifend13:                                          ; preds = %else12, %then11
+  %load_guess15 = load i64, ptr %guess, align 4
+  %load_secret16 = load i64, ptr %secret, align 4
+  %eqtmp = icmp eq i64 %load_guess15, %load_secret16
+  %bool_to_i6417 = zext i1 %eqtmp to i64
+  %ifcond18 = icmp ne i64 %bool_to_i6417, 0
+  br i1 %ifcond18, label %then19, label %else20
+
+then19:                                           ; preds = %ifend13
+  %call_printf22 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Parabens__Voce_acertou_em_)
+  %load_attempts23 = load i64, ptr %attempts, align 4
+  %call_printf24 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_attempts23)
+  %call_printf25 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__tentativas_)
+  br label %ifend21
+
+else20:                                           ; preds = %ifend13
+  br label %ifend21
+
+ifend21:                                          ; preds = %else20, %then19
+  br label %loop
+}
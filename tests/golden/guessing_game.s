@@ -0,0 +1,190 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "=== Jogo de Adivinhar Numero ==="
+Lmsg1:
+    .ascii "Pense em um numero entre 1 e 100"
+Lmsg2:
+    .ascii "Digite seu palpite: "
+Lmsg3:
+    .ascii "Seu palpite e muito baixo!"
+Lmsg4:
+    .ascii "Seu palpite e muito alto!"
+Lmsg5:
+    .ascii "Parabens! Voce acertou em "
+Lmsg6:
+    .ascii " tentativas!"
+    .section .bss
+input_buffer: .space 256
+secret: .quad 0
+guess: .quad 0
+attempts: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/guessing_game.wheel"
+    .loc 1 1 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 32
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 2 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 32
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 4 1
+    mov rax, 42
+    mov qword ptr [rip + secret], rax
+    .loc 1 5 1
+    mov rax, 0
+    mov qword ptr [rip + guess], rax
+    .loc 1 6 1
+    mov rax, 0
+    mov qword ptr [rip + attempts], rax
+    .loc 1 8 1
+Lloop_0:
+    mov rax, qword ptr [rip + guess]
+    push rax
+    mov rax, qword ptr [rip + secret]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setne al
+    movzx rax, al
+    cmp rax, 0
+    je Lexit_0
+    .loc 1 9 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 20
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 5
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + input_buffer]
+    mov rdx, 255
+    syscall
+    mov rbx, rax
+    lea rax, [rip + input_buffer]
+    mov rax, rax
+    mov rax, rax
+    mov qword ptr [rip + guess], rax
+    .loc 1 11 5
+    mov rax, qword ptr [rip + attempts]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + attempts], rax
+    .loc 1 13 5
+    mov rax, qword ptr [rip + guess]
+    push rax
+    mov rax, qword ptr [rip + secret]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_1
+    .loc 1 14 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 26
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_1:
+    .loc 1 17 5
+    mov rax, qword ptr [rip + guess]
+    push rax
+    mov rax, qword ptr [rip + secret]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setg al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_2
+    .loc 1 18 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_2:
+    .loc 1 21 5
+    mov rax, qword ptr [rip + guess]
+    push rax
+    mov rax, qword ptr [rip + secret]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_3
+    .loc 1 22 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 26
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 23 9
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + attempts]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_4
+    neg rax
+Litoa_4:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_4
+    test r8, r8
+    jns Litoa_end_4
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_4:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 24 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 12
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_3:
+    jmp Lloop_0
+Lexit_0:
+    mov rax, 60
+    xor rdi, rdi
+    syscall
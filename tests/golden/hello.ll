@@ -0,0 +1,29 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Hello__Wheel_world_ = constant [21 x i8] c"Hello, Wheel world!\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %greeting = alloca i64, align 8
+  store i64 ptrtoint (ptr @str_Hello__Wheel_world_ to i64), ptr %greeting, align 4
+  %load_greeting = load i64, ptr %greeting, align 4
+  %str_ptr = inttoptr i64 %load_greeting to ptr
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr %str_ptr)
+  ret i32 0
+}
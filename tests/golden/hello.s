@@ -0,0 +1,25 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Hello, Wheel world!"
+    .section .bss
+input_buffer: .space 256
+greeting: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/hello.wheel"
+    .loc 1 1 1
+    lea rax, [rip + Lmsg0]
+    mov qword ptr [rip + greeting], rax
+    .loc 1 2 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 19
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,33 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Hello = constant [7 x i8] c"Hello\00\00"
+@str_World = constant [7 x i8] c"World\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %call_user_main = call i64 @user_main()
+  ret i32 0
+}
+
+define internal i64 @user_main() {
+entry:
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Hello)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_World)
+  ret i64 0
+}
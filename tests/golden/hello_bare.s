@@ -0,0 +1,16 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Hello"
+Lmsg1:
+    .ascii "World"
+    .section .bss
+input_buffer: .space 256
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/hello_bare.wheel"
+    .loc 1 2 1
+    mov rax, 60
+    xor rdi, rdi
+    syscall
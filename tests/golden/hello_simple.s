@@ -0,0 +1,31 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Hello"
+Lmsg1:
+    .ascii "World"
+    .section .bss
+input_buffer: .space 256
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/hello_simple.wheel"
+    .loc 1 1 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 5
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 2 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 5
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
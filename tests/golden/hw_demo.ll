@@ -0,0 +1,31 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_____Hardware_I_O_Demo_____n = constant [29 x i8] c"=== Hardware I/O Demo ===\\n\00\00"
+@str_This_example_demonstrates_low_level_hardware_access_n = constant [55 x i8] c"This example demonstrates low-level hardware access\\n\00\00"
+@str_Note__Actual_hardware_operations_require_kernel_privileges_n = constant [62 x i8] c"Note: Actual hardware operations require kernel privileges\\n\00\00"
+@str_Hardware_functions_declared_but_require_unsafe_context_n = constant [58 x i8] c"Hardware functions declared but require unsafe context\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____Hardware_I_O_Demo_____n)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_This_example_demonstrates_low_level_hardware_access_n)
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Note__Actual_hardware_operations_require_kernel_privileges_n)
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Hardware_functions_declared_but_require_unsafe_context_n)
+  ret i32 0
+}
@@ -0,0 +1,51 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "=== Hardware I/O Demo ===\\n"
+Lmsg1:
+    .ascii "This example demonstrates low-level hardware access\\n"
+Lmsg2:
+    .ascii "Note: Actual hardware operations require kernel privileges\\n"
+Lmsg3:
+    .ascii "Hardware functions declared but require unsafe context\\n"
+    .section .bss
+input_buffer: .space 256
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/hw_demo.wheel"
+    .loc 1 5 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 27
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 6 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 53
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 7 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 60
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 18 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 56
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
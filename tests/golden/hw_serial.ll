@@ -0,0 +1,81 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str______________________________________n = constant [40 x i8] c"====================================\\n\00\00"
+@str_Hardware_I_O_Serial_Port_Demo_n = constant [33 x i8] c"Hardware I/O Serial Port Demo\\n\00\00"
+@str______________________________________n_n = constant [42 x i8] c"====================================\\n\\n\00\00"
+@str_Serial_Port__0x3F8__COM1__n = constant [29 x i8] c"Serial Port: 0x3F8 (COM1)\\n\00\00"
+@str_Attempting_to_check_serial_port_status____n_n = constant [47 x i8] c"Attempting to check serial port status...\\n\\n\00\00"
+@str_Serial_port_status_read__ = constant [27 x i8] c"Serial port status read: \00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+@str_Transmitter_is_ready__n = constant [25 x i8] c"Transmitter is ready!\\n\00\00"
+@str_Sending_data_to_serial_port____n = constant [34 x i8] c"Sending data to serial port...\\n\00\00"
+@str_Data_sent__n = constant [14 x i8] c"Data sent!\\n\00\00"
+@str_Transmitter_not_ready_n = constant [25 x i8] c"Transmitter not ready\\n\00\00"
+@str__nNote__This_example_requires__n = constant [34 x i8] c"\\nNote: This example requires:\\n\00\00"
+@str___Root_or_elevated_privileges_n = constant [33 x i8] c"- Root or elevated privileges\\n\00\00"
+@str___A_serial_port_device___dev_ttyS0__n = constant [39 x i8] c"- A serial port device (/dev/ttyS0)\\n\00\00"
+@str___Actual_hardware_serial_port_n = constant [33 x i8] c"- Actual hardware serial port\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %SERIAL_DATA = alloca i64, align 8
+  %SERIAL_STATUS = alloca i64, align 8
+  %TRANSMITTER_EMPTY = alloca i64, align 8
+  %status = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str______________________________________n)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Hardware_I_O_Serial_Port_Demo_n)
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str______________________________________n_n)
+  store i64 0, ptr %SERIAL_DATA, align 4
+  store i64 0, ptr %SERIAL_STATUS, align 4
+  store i64 0, ptr %TRANSMITTER_EMPTY, align 4
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Serial_Port__0x3F8__COM1__n)
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Attempting_to_check_serial_port_status____n_n)
+  %load_SERIAL_STATUS = load i64, ptr %SERIAL_STATUS, align 4
+  %call_port_read_byte = call i64 @port_read_byte(i64 %load_SERIAL_STATUS)
+  store i64 %call_port_read_byte, ptr %status, align 4
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Serial_port_status_read__)
+  %load_status = load i64, ptr %status, align 4
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_status)
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  %call_printf8 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Transmitter_is_ready__n)
+  %call_printf9 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Sending_data_to_serial_port____n)
+  %load_SERIAL_DATA = load i64, ptr %SERIAL_DATA, align 4
+  %call_port_write_byte = call i64 @port_write_byte(i64 %load_SERIAL_DATA, i64 72)
+  %load_SERIAL_DATA10 = load i64, ptr %SERIAL_DATA, align 4
+  %call_port_write_byte11 = call i64 @port_write_byte(i64 %load_SERIAL_DATA10, i64 101)
+  %load_SERIAL_DATA12 = load i64, ptr %SERIAL_DATA, align 4
+  %call_port_write_byte13 = call i64 @port_write_byte(i64 %load_SERIAL_DATA12, i64 108)
+  %load_SERIAL_DATA14 = load i64, ptr %SERIAL_DATA, align 4
+  %call_port_write_byte15 = call i64 @port_write_byte(i64 %load_SERIAL_DATA14, i64 108)
+  %load_SERIAL_DATA16 = load i64, ptr %SERIAL_DATA, align 4
+  %call_port_write_byte17 = call i64 @port_write_byte(i64 %load_SERIAL_DATA16, i64 111)
+  %load_SERIAL_DATA18 = load i64, ptr %SERIAL_DATA, align 4
+  %call_port_write_byte19 = call i64 @port_write_byte(i64 %load_SERIAL_DATA18, i64 10)
+  %call_printf20 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Data_sent__n)
+  %call_printf21 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Transmitter_not_ready_n)
+  %call_printf22 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__nNote__This_example_requires__n)
+  %call_printf23 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str___Root_or_elevated_privileges_n)
+  %call_printf24 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str___A_serial_port_device___dev_ttyS0__n)
+  %call_printf25 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str___Actual_hardware_serial_port_n)
+  ret i32 0
+}
+
+declare i64 @port_read_byte(i64)
+
+declare i64 @port_write_byte(i64, i64)
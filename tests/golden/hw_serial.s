@@ -0,0 +1,214 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "====================================\\n"
+Lmsg1:
+    .ascii "Hardware I/O Serial Port Demo\\n"
+Lmsg2:
+    .ascii "====================================\\n\\n"
+Lmsg3:
+    .ascii "Serial Port: 0x3F8 (COM1)\\n"
+Lmsg4:
+    .ascii "Attempting to check serial port status...\\n\\n"
+Lmsg5:
+    .ascii "Serial port status read: "
+Lmsg6:
+    .ascii "\\n"
+Lmsg7:
+    .ascii "Transmitter is ready!\\n"
+Lmsg8:
+    .ascii "Sending data to serial port...\\n"
+Lmsg9:
+    .ascii "Data sent!\\n"
+Lmsg10:
+    .ascii "Transmitter not ready\\n"
+Lmsg11:
+    .ascii "\\nNote: This example requires:\\n"
+Lmsg12:
+    .ascii "- Root or elevated privileges\\n"
+Lmsg13:
+    .ascii "- A serial port device (/dev/ttyS0)\\n"
+Lmsg14:
+    .ascii "- Actual hardware serial port\\n"
+    .section .bss
+input_buffer: .space 256
+SERIAL_DATA: .quad 0
+SERIAL_STATUS: .quad 0
+TRANSMITTER_EMPTY: .quad 0
+status: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/hw_serial.wheel"
+    .loc 1 5 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 38
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 6 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 31
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 7 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 40
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 1
+    mov rax, 0
+    mov qword ptr [rip + SERIAL_DATA], rax
+    .loc 1 10 20
+    .loc 1 11 1
+    mov rax, 0
+    mov qword ptr [rip + SERIAL_STATUS], rax
+    .loc 1 11 22
+    .loc 1 12 1
+    mov rax, 0
+    mov qword ptr [rip + TRANSMITTER_EMPTY], rax
+    .loc 1 12 26
+    .loc 1 14 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 27
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 15 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 45
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 18 1
+    mov rax, 0
+    mov qword ptr [rip + status], rax
+    .loc 1 19 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 20 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + status]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_0
+    neg rax
+Litoa_0:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_0
+    test r8, r8
+    jns Litoa_end_0
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_0:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 21 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 25 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg7]
+    mov rdx, 23
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 28 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 32
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 30 5
+    .loc 1 31 5
+    .loc 1 32 5
+    .loc 1 33 5
+    .loc 1 34 5
+    .loc 1 35 5
+    .loc 1 37 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 12
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 39 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg10]
+    mov rdx, 23
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 42 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg11]
+    mov rdx, 32
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 43 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg12]
+    mov rdx, 31
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 44 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg13]
+    mov rdx, 37
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 45 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg14]
+    mov rdx, 31
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,52 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Hardware_I_O_Test = constant [19 x i8] c"Hardware I/O Test\00\00"
+@str_Reading_from_port_0x3F8__COM1_serial__ = constant [40 x i8] c"Reading from port 0x3F8 (COM1 serial):\00\00"
+@str_Disabling_interrupts = constant [22 x i8] c"Disabling interrupts\00\00"
+@str_Enabling_interrupts = constant [21 x i8] c"Enabling interrupts\00\00"
+@str_I_O_Test_Complete = constant [19 x i8] c"I/O Test Complete\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %call_user_main = call i64 @user_main()
+  ret i32 0
+}
+
+define internal i64 @user_main() {
+entry:
+  %val = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Hardware_I_O_Test)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Reading_from_port_0x3F8__COM1_serial__)
+  %call_io_read_port = call i64 @io_read_port(i64 1016)
+  store i64 %call_io_read_port, ptr %val, align 4
+  %load_val = load i64, ptr %val, align 4
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_val)
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Disabling_interrupts)
+  call void @io_disable_interrupts()
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Enabling_interrupts)
+  call void @io_enable_interrupts()
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_I_O_Test_Complete)
+  ret i64 0
+}
+
+declare i64 @io_read_port(i64)
+
+declare void @io_disable_interrupts()
+
+declare void @io_enable_interrupts()
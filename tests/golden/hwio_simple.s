@@ -0,0 +1,23 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Hardware I/O Test"
+Lmsg1:
+    .ascii "Reading from port 0x3F8 (COM1 serial):"
+Lmsg2:
+    .ascii "Disabling interrupts"
+Lmsg3:
+    .ascii "Enabling interrupts"
+Lmsg4:
+    .ascii "I/O Test Complete"
+    .section .bss
+input_buffer: .space 256
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/hwio_simple.wheel"
+    .loc 1 1 1
+    .loc 1 3 1
+    mov rax, 60
+    xor rdi, rdi
+    syscall
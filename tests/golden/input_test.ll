@@ -0,0 +1,32 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_type_something__ = constant [18 x i8] c"type something: \00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %s = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_type_something__)
+  %call_scanf = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i64 = zext i32 %call_atoi_input to i64
+  store i64 %atoi_to_i64, ptr %s, align 4
+  %load_s = load i64, ptr %s, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_s)
+  ret i32 0
+}
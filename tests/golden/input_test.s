@@ -0,0 +1,41 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "type something: "
+    .section .bss
+input_buffer: .space 256
+s: .quad 0
+s_buf: .space 256
+s_len: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/input_test.wheel"
+    .loc 1 1 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 16
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 1 28
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + s_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + s_len], rax
+    .loc 1 1 45
+    mov rax, 1
+    mov rdi, 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + s_buf]
+    mov rdx, qword ptr [rip + s_len]
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,85 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_____Integer_Literal_Test_____n = constant [32 x i8] c"=== Integer Literal Test ===\\n\00\00"
+@str_15___25___ = constant [12 x i8] c"15 + 25 = \00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+@str_25___15___ = constant [12 x i8] c"25 - 15 = \00\00"
+@str__n.1 = constant [4 x i8] c"\\n\00\00"
+@str_15___25___.2 = constant [12 x i8] c"15 * 25 = \00\00"
+@str__n.3 = constant [4 x i8] c"\\n\00\00"
+@str_25___15___.4 = constant [12 x i8] c"25 / 15 = \00\00"
+@str__n.5 = constant [4 x i8] c"\\n\00\00"
+@str_25___15___.6 = constant [12 x i8] c"25 % 15 = \00\00"
+@str__n.7 = constant [4 x i8] c"\\n\00\00"
+@str_____All_tests_completed_____n = constant [31 x i8] c"=== All tests completed ===\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %x = alloca i64, align 8
+  %y = alloca i64, align 8
+  %sum = alloca i64, align 8
+  %diff = alloca i64, align 8
+  %prod = alloca i64, align 8
+  %div = alloca i64, align 8
+  %mod_res = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____Integer_Literal_Test_____n)
+  store i64 15, ptr %x, align 4
+  store i64 25, ptr %y, align 4
+  %load_x = load i64, ptr %x, align 4
+  %load_y = load i64, ptr %y, align 4
+  %addtmp = add i64 %load_x, %load_y
+  store i64 %addtmp, ptr %sum, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_15___25___)
+  %load_sum = load i64, ptr %sum, align 4
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_sum)
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  %load_y4 = load i64, ptr %y, align 4
+  %load_x5 = load i64, ptr %x, align 4
+  %subtmp = sub i64 %load_y4, %load_x5
+  store i64 %subtmp, ptr %diff, align 4
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_25___15___)
+  %load_diff = load i64, ptr %diff, align 4
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_diff)
+  %call_printf8 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.1)
+  %load_x9 = load i64, ptr %x, align 4
+  %load_y10 = load i64, ptr %y, align 4
+  %multmp = mul i64 %load_x9, %load_y10
+  store i64 %multmp, ptr %prod, align 4
+  %call_printf11 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_15___25___.2)
+  %load_prod = load i64, ptr %prod, align 4
+  %call_printf12 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_prod)
+  %call_printf13 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.3)
+  %load_y14 = load i64, ptr %y, align 4
+  %load_x15 = load i64, ptr %x, align 4
+  %divtmp = sdiv i64 %load_y14, %load_x15
+  store i64 %divtmp, ptr %div, align 4
+  %call_printf16 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_25___15___.4)
+  %load_div = load i64, ptr %div, align 4
+  %call_printf17 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_div)
+  %call_printf18 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.5)
+  %load_y19 = load i64, ptr %y, align 4
+  store i64 %load_y19, ptr %mod_res, align 4
+  %load_x20 = load i64, ptr %x, align 4
+  %call_printf21 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_25___15___.6)
+  %load_mod_res = load i64, ptr %mod_res, align 4
+  %call_printf22 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_mod_res)
+  %call_printf23 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.7)
+  %call_printf24 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____All_tests_completed_____n)
+  ret i32 0
+}
@@ -0,0 +1,302 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "=== Integer Literal Test ===\\n"
+Lmsg1:
+    .ascii "15 + 25 = "
+Lmsg2:
+    .ascii "40"
+Lmsg3:
+    .ascii "\\n"
+Lmsg4:
+    .ascii "25 - 15 = "
+Lmsg5:
+    .ascii "10"
+Lmsg6:
+    .ascii "15 * 25 = "
+Lmsg7:
+    .ascii "375"
+Lmsg8:
+    .ascii "25 / 15 = "
+Lmsg9:
+    .ascii "1"
+Lmsg10:
+    .ascii "25 % 15 = "
+Lmsg11:
+    .ascii "25"
+Lmsg12:
+    .ascii "=== All tests completed ===\\n"
+    .section .bss
+input_buffer: .space 256
+x: .quad 0
+y: .quad 0
+sum: .quad 0
+diff: .quad 0
+prod: .quad 0
+div: .quad 0
+mod_res: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/integer_test.wheel"
+    .loc 1 2 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 30
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 3 1
+    mov rax, 15
+    mov qword ptr [rip + x], rax
+    .loc 1 4 1
+    mov rax, 25
+    mov qword ptr [rip + y], rax
+    .loc 1 5 1
+    mov rax, 40
+    mov qword ptr [rip + sum], rax
+    .loc 1 6 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 10
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 7 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + sum]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_0
+    neg rax
+Litoa_0:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_0
+    test r8, r8
+    jns Litoa_end_0
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_0:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 8 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 1
+    mov rax, 10
+    mov qword ptr [rip + diff], rax
+    .loc 1 11 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 10
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 12 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + diff]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_1
+    neg rax
+Litoa_1:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_1
+    test r8, r8
+    jns Litoa_end_1
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_1:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 13 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 15 1
+    mov rax, 375
+    mov qword ptr [rip + prod], rax
+    .loc 1 16 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 10
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 17 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + prod]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_2
+    neg rax
+Litoa_2:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_2
+    test r8, r8
+    jns Litoa_end_2
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_2:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 18 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 20 1
+    mov rax, 1
+    mov qword ptr [rip + div], rax
+    .loc 1 21 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 10
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 22 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + div]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_3
+    neg rax
+Litoa_3:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_3
+    test r8, r8
+    jns Litoa_end_3
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_3:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 23 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 25 1
+    mov rax, 25
+    mov qword ptr [rip + mod_res], rax
+    .loc 1 25 19
+    .loc 1 26 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg10]
+    mov rdx, 10
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 27 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + mod_res]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_4
+    neg rax
+Litoa_4:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_4
+    test r8, r8
+    jns Litoa_end_4
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_4:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 28 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 30 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg12]
+    mov rdx, 29
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
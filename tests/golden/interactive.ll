@@ -0,0 +1,36 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_What_is_your_name_ = constant [20 x i8] c"What is your name?\00\00"
+@str_Hello__ = constant [9 x i8] c"Hello, \00\00"
+@str__ = constant [3 x i8] c"!\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %name = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_What_is_your_name_)
+  %call_scanf = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i64 = zext i32 %call_atoi_input to i64
+  store i64 %atoi_to_i64, ptr %name, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Hello__)
+  %load_name = load i64, ptr %name, align 4
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_name)
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__)
+  ret i32 0
+}
@@ -0,0 +1,61 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "What is your name?"
+Lmsg1:
+    .ascii "Hello, "
+Lmsg2:
+    .ascii "!"
+    .section .bss
+input_buffer: .space 256
+name: .quad 0
+name_buf: .space 256
+name_len: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/interactive.wheel"
+    .loc 1 1 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 18
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 2 1
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + name_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + name_len], rax
+    .loc 1 3 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 7
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 4 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + name_buf]
+    mov rdx, qword ptr [rip + name_len]
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 5 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
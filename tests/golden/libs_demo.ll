@@ -0,0 +1,180 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str______________________________________n = constant [40 x i8] c"====================================\\n\00\00"
+@str_Wheel_Libraries_Integration_Demo_n = constant [36 x i8] c"Wheel Libraries Integration Demo\\n\00\00"
+@str______________________________________n.1 = constant [40 x i8] c"====================================\\n\00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+@str_1__MATH_LIBRARY_n = constant [19 x i8] c"1. MATH LIBRARY\\n\00\00"
+@str_________________n = constant [19 x i8] c"===============\\n\00\00"
+@str_a___ = constant [6 x i8] c"a = \00\00"
+@str__n.2 = constant [4 x i8] c"\\n\00\00"
+@str_b___ = constant [6 x i8] c"b = \00\00"
+@str__n.3 = constant [4 x i8] c"\\n\00\00"
+@str_a___b___ = constant [10 x i8] c"a + b = \00\00"
+@str__n.4 = constant [4 x i8] c"\\n\00\00"
+@str_a___b___.5 = constant [10 x i8] c"a * b = \00\00"
+@str__n.6 = constant [4 x i8] c"\\n\00\00"
+@str_a___b___.7 = constant [10 x i8] c"a / b = \00\00"
+@str__n.8 = constant [4 x i8] c"\\n\00\00"
+@str_a___b___.9 = constant [10 x i8] c"a % b = \00\00"
+@str__n.10 = constant [4 x i8] c"\\n\00\00"
+@str__n.11 = constant [4 x i8] c"\\n\00\00"
+@str_2__FIBONACCI_SEQUENCE_n = constant [25 x i8] c"2. FIBONACCI SEQUENCE\\n\00\00"
+@str______________________n = constant [24 x i8] c"====================\\n\00\00"
+@str_Sequence__ = constant [12 x i8] c"Sequence: \00\00"
+@str__ = constant [3 x i8] c" \00\00"
+@str__.12 = constant [3 x i8] c" \00\00"
+@str__.13 = constant [3 x i8] c" \00\00"
+@str__n.14 = constant [4 x i8] c"\\n\00\00"
+@str__n.15 = constant [4 x i8] c"\\n\00\00"
+@str_3__PYTHAGOREAN_CALCULATION_n = constant [30 x i8] c"3. PYTHAGOREAN CALCULATION\\n\00\00"
+@str____________________________n = constant [30 x i8] c"==========================\\n\00\00"
+@str_Sides__ = constant [9 x i8] c"Sides: \00\00"
+@str__and_ = constant [7 x i8] c" and \00\00"
+@str__n.16 = constant [4 x i8] c"\\n\00\00"
+@str_Hypotenuse_squared__a_2___b_2___ = constant [34 x i8] c"Hypotenuse squared (a^2 + b^2): \00\00"
+@str__n.17 = constant [4 x i8] c"\\n\00\00"
+@str__Expected__25__so_hypotenuse___5__n = constant [37 x i8] c"(Expected: 25, so hypotenuse = 5)\\n\00\00"
+@str__n.18 = constant [4 x i8] c"\\n\00\00"
+@str______________________________________n.19 = constant [40 x i8] c"====================================\\n\00\00"
+@str_Demo_Complete_n = constant [17 x i8] c"Demo Complete\\n\00\00"
+@str______________________________________n.20 = constant [40 x i8] c"====================================\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %f3 = alloca i64, align 8
+  %a = alloca i64, align 8
+  %b = alloca i64, align 8
+  %f1 = alloca i64, align 8
+  %f2 = alloca i64, align 8
+  %idx = alloca i64, align 8
+  %side_a = alloca i64, align 8
+  %side_b = alloca i64, align 8
+  %hyp_squared = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str______________________________________n)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Wheel_Libraries_Integration_Demo_n)
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str______________________________________n.1)
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_1__MATH_LIBRARY_n)
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_________________n)
+  store i64 15, ptr %a, align 4
+  store i64 8, ptr %b, align 4
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_a___)
+  %load_a = load i64, ptr %a, align 4
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_a)
+  %call_printf8 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.2)
+  %call_printf9 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_b___)
+  %load_b = load i64, ptr %b, align 4
+  %call_printf10 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_b)
+  %call_printf11 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.3)
+  %call_printf12 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_a___b___)
+  %load_a13 = load i64, ptr %a, align 4
+  %load_b14 = load i64, ptr %b, align 4
+  %addtmp = add i64 %load_a13, %load_b14
+  %call_printf15 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %addtmp)
+  %call_printf16 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.4)
+  %call_printf17 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_a___b___.5)
+  %load_a18 = load i64, ptr %a, align 4
+  %load_b19 = load i64, ptr %b, align 4
+  %multmp = mul i64 %load_a18, %load_b19
+  %call_printf20 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %multmp)
+  %call_printf21 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.6)
+  %call_printf22 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_a___b___.7)
+  %load_a23 = load i64, ptr %a, align 4
+  %load_b24 = load i64, ptr %b, align 4
+  %divtmp = sdiv i64 %load_a23, %load_b24
+  %call_printf25 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %divtmp)
+  %call_printf26 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.8)
+  %call_printf27 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_a___b___.9)
+  %load_a28 = load i64, ptr %a, align 4
+  %call_printf29 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_a28)
+  %load_b30 = load i64, ptr %b, align 4
+  %call_printf31 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.10)
+  %call_printf32 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.11)
+  %call_printf33 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_2__FIBONACCI_SEQUENCE_n)
+  %call_printf34 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str______________________n)
+  store i64 1, ptr %f1, align 4
+  store i64 1, ptr %f2, align 4
+  %call_printf35 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Sequence__)
+  %load_f1 = load i64, ptr %f1, align 4
+  %call_printf36 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_f1)
+  %call_printf37 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__)
+  %load_f2 = load i64, ptr %f2, align 4
+  %call_printf38 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_f2)
+  %call_printf39 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__.12)
+  store i64 0, ptr %idx, align 4
+  br label %loop
+
+loop:                                             ; preds = %loopbody, %entry
+  %load_idx = load i64, ptr %idx, align 4
+  %lttmp = icmp slt i64 %load_idx, 6
+  %bool_to_i64 = zext i1 %lttmp to i64
+  %whilecond = icmp ne i64 %bool_to_i64, 0
+  br i1 %whilecond, label %loopbody, label %loopafter
+
+loopbody:                                         ; preds = %loop
+  %load_f140 = load i64, ptr %f1, align 4
+  %load_f241 = load i64, ptr %f2, align 4
+  %addtmp42 = add i64 %load_f140, %load_f241
+  store i64 %addtmp42, ptr %f3, align 4
+  %load_f3 = load i64, ptr %f3, align 4
+  %call_printf43 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_f3)
+  %call_printf44 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__.13)
+  %load_f245 = load i64, ptr %f2, align 4
+  store i64 %load_f245, ptr %f1, align 4
+  %load_f346 = load i64, ptr %f3, align 4
+  store i64 %load_f346, ptr %f2, align 4
+  %load_idx47 = load i64, ptr %idx, align 4
+  %addtmp48 = add i64 %load_idx47, 1
+  store i64 %addtmp48, ptr %idx, align 4
+  br label %loop
+
+loopafter:                                        ; preds = %loop
+  %call_printf49 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.14)
+  %call_printf50 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.15)
+  %call_printf51 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_3__PYTHAGOREAN_CALCULATION_n)
+  %call_printf52 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str____________________________n)
+  store i64 3, ptr %side_a, align 4
+  store i64 4, ptr %side_b, align 4
+  %load_side_a = load i64, ptr %side_a, align 4
+  %load_side_a53 = load i64, ptr %side_a, align 4
+  %multmp54 = mul i64 %load_side_a, %load_side_a53
+  %load_side_b = load i64, ptr %side_b, align 4
+  %load_side_b55 = load i64, ptr %side_b, align 4
+  %multmp56 = mul i64 %load_side_b, %load_side_b55
+  %addtmp57 = add i64 %multmp54, %multmp56
+  store i64 %addtmp57, ptr %hyp_squared, align 4
+  %call_printf58 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Sides__)
+  %load_side_a59 = load i64, ptr %side_a, align 4
+  %call_printf60 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_side_a59)
+  %call_printf61 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__and_)
+  %load_side_b62 = load i64, ptr %side_b, align 4
+  %call_printf63 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_side_b62)
+  %call_printf64 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.16)
+  %call_printf65 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Hypotenuse_squared__a_2___b_2___)
+  %load_hyp_squared = load i64, ptr %hyp_squared, align 4
+  %call_printf66 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_hyp_squared)
+  %call_printf67 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.17)
+  %call_printf68 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__Expected__25__so_hypotenuse___5__n)
+  %call_printf69 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.18)
+  %call_printf70 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str______________________________________n.19)
+  %call_printf71 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Demo_Complete_n)
+  %call_printf72 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str______________________________________n.20)
+  ret i32 0
+}
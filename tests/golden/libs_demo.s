@@ -0,0 +1,729 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "====================================\\n"
+Lmsg1:
+    .ascii "Wheel Libraries Integration Demo\\n"
+Lmsg2:
+    .ascii "\\n"
+Lmsg3:
+    .ascii "1. MATH LIBRARY\\n"
+Lmsg4:
+    .ascii "===============\\n"
+Lmsg5:
+    .ascii "a = "
+Lmsg6:
+    .ascii "15"
+Lmsg7:
+    .ascii "b = "
+Lmsg8:
+    .ascii "8"
+Lmsg9:
+    .ascii "a + b = "
+Lmsg10:
+    .ascii "23"
+Lmsg11:
+    .ascii "a * b = "
+Lmsg12:
+    .ascii "120"
+Lmsg13:
+    .ascii "a / b = "
+Lmsg14:
+    .ascii "1"
+Lmsg15:
+    .ascii "a % b = "
+Lmsg16:
+    .ascii "2. FIBONACCI SEQUENCE\\n"
+Lmsg17:
+    .ascii "====================\\n"
+Lmsg18:
+    .ascii "Sequence: "
+Lmsg19:
+    .ascii " "
+Lmsg20:
+    .ascii "3. PYTHAGOREAN CALCULATION\\n"
+Lmsg21:
+    .ascii "==========================\\n"
+Lmsg22:
+    .ascii "Sides: "
+Lmsg23:
+    .ascii "3"
+Lmsg24:
+    .ascii " and "
+Lmsg25:
+    .ascii "4"
+Lmsg26:
+    .ascii "Hypotenuse squared (a^2 + b^2): "
+Lmsg27:
+    .ascii "25"
+Lmsg28:
+    .ascii "(Expected: 25, so hypotenuse = 5)\\n"
+Lmsg29:
+    .ascii "Demo Complete\\n"
+    .section .bss
+input_buffer: .space 256
+a: .quad 0
+b: .quad 0
+f1: .quad 0
+f2: .quad 0
+idx: .quad 0
+f3: .quad 0
+side_a: .quad 0
+side_b: .quad 0
+hyp_squared: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/libs_demo.wheel"
+    .loc 1 2 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 38
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 3 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 34
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 4 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 38
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 5 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 8 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 17
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 9 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 17
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 1
+    mov rax, 15
+    mov qword ptr [rip + a], rax
+    .loc 1 11 1
+    mov rax, 8
+    mov qword ptr [rip + b], rax
+    .loc 1 12 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 13 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + a]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_0
+    neg rax
+Litoa_0:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_0
+    test r8, r8
+    jns Litoa_end_0
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_0:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 14 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 15 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg7]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 16 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + b]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_1
+    neg rax
+Litoa_1:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_1
+    test r8, r8
+    jns Litoa_end_1
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_1:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 17 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 18 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 19 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg10]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 20 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 21 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg11]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 22 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg12]
+    mov rdx, 3
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 23 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 24 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg13]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 25 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg14]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 26 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 27 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg15]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 28 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + a]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_2
+    neg rax
+Litoa_2:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_2
+    test r8, r8
+    jns Litoa_end_2
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_2:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 28 11
+    .loc 1 29 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 30 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 33 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg16]
+    mov rdx, 23
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 34 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg17]
+    mov rdx, 22
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 35 1
+    mov rax, 1
+    mov qword ptr [rip + f1], rax
+    .loc 1 36 1
+    mov rax, 1
+    mov qword ptr [rip + f2], rax
+    .loc 1 37 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg18]
+    mov rdx, 10
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 38 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + f1]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_3
+    neg rax
+Litoa_3:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_3
+    test r8, r8
+    jns Litoa_end_3
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_3:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 39 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg19]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 40 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + f2]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_4
+    neg rax
+Litoa_4:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_4
+    test r8, r8
+    jns Litoa_end_4
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_4:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 41 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg19]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 43 1
+    mov rax, 0
+    mov qword ptr [rip + idx], rax
+    .loc 1 44 1
+Lloop_5:
+    mov rax, qword ptr [rip + idx]
+    push rax
+    mov rax, 6
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lexit_5
+    .loc 1 45 5
+    mov rax, qword ptr [rip + f1]
+    push rax
+    mov rax, qword ptr [rip + f2]
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + f3], rax
+    .loc 1 46 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + f3]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_6
+    neg rax
+Litoa_6:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_6
+    test r8, r8
+    jns Litoa_end_6
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_6:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 47 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg19]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 48 5
+    mov rax, qword ptr [rip + f2]
+    mov qword ptr [rip + f1], rax
+    .loc 1 49 5
+    mov rax, qword ptr [rip + f3]
+    mov qword ptr [rip + f2], rax
+    .loc 1 50 5
+    mov rax, qword ptr [rip + idx]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + idx], rax
+    jmp Lloop_5
+Lexit_5:
+    .loc 1 52 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 53 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 56 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg20]
+    mov rdx, 28
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 57 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg21]
+    mov rdx, 28
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 58 1
+    mov rax, 3
+    mov qword ptr [rip + side_a], rax
+    .loc 1 59 1
+    mov rax, 4
+    mov qword ptr [rip + side_b], rax
+    .loc 1 60 1
+    mov rax, 25
+    mov qword ptr [rip + hyp_squared], rax
+    .loc 1 61 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg22]
+    mov rdx, 7
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 62 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + side_a]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_7
+    neg rax
+Litoa_7:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_7
+    test r8, r8
+    jns Litoa_end_7
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_7:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 63 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg24]
+    mov rdx, 5
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 64 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + side_b]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_8
+    neg rax
+Litoa_8:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_8
+    test r8, r8
+    jns Litoa_end_8
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_8:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 65 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 66 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg26]
+    mov rdx, 32
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 67 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + hyp_squared]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_9
+    neg rax
+Litoa_9:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_9
+    test r8, r8
+    jns Litoa_end_9
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_9:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 68 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 69 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg28]
+    mov rdx, 35
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 70 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 72 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 38
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 73 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg29]
+    mov rdx, 15
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 74 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 38
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,79 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "done"
+    .section .bss
+input_buffer: .space 256
+i: .quad 0
+max: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/loop_while.wheel"
+    .loc 1 1 1
+    mov rax, 1
+    mov qword ptr [rip + i], rax
+    .loc 1 2 1
+    mov rax, 3
+    mov qword ptr [rip + max], rax
+    .loc 1 4 1
+Lloop_0:
+    mov rax, qword ptr [rip + i]
+    push rax
+    mov rax, qword ptr [rip + max]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lexit_0
+    .loc 1 5 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + i]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_1
+    neg rax
+Litoa_1:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_1
+    test r8, r8
+    jns Litoa_end_1
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_1:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 6 5
+    mov rax, qword ptr [rip + i]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + i], rax
+    jmp Lloop_0
+Lexit_0:
+    .loc 1 9 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
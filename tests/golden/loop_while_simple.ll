@@ -0,0 +1,42 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_loop = constant [6 x i8] c"loop\00\00"
+@str_done = constant [6 x i8] c"done\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %x = alloca i64, align 8
+  store i64 0, ptr %x, align 4
+  br label %loop
+
+loop:                                             ; preds = %loopbody, %entry
+  %load_x = load i64, ptr %x, align 4
+  %lttmp = icmp slt i64 %load_x, 3
+  %bool_to_i64 = zext i1 %lttmp to i64
+  %whilecond = icmp ne i64 %bool_to_i64, 0
+  br i1 %whilecond, label %loopbody, label %loopafter
+
+loopbody:                                         ; preds = %loop
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_loop)
+  br label %loop
+
+loopafter:                                        ; preds = %loop
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_done)
+  ret i32 0
+}
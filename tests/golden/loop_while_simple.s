@@ -0,0 +1,49 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "loop"
+Lmsg1:
+    .ascii "done"
+    .section .bss
+input_buffer: .space 256
+x: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/loop_while_simple.wheel"
+    .loc 1 1 1
+    mov rax, 0
+    mov qword ptr [rip + x], rax
+    .loc 1 3 1
+Lloop_0:
+    mov rax, qword ptr [rip + x]
+    push rax
+    mov rax, 3
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lexit_0
+    .loc 1 4 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    jmp Lloop_0
+Lexit_0:
+    .loc 1 7 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,109 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_____Numero_Aleatorio_com_Luck____ = constant [35 x i8] c"=== Numero Aleatorio com Luck ===\00\00"
+@str_Digite_seu_palpite__0_100___ = constant [30 x i8] c"Digite seu palpite (0-100): \00\00"
+@str_Seu_palpite_e_muito_baixo_ = constant [28 x i8] c"Seu palpite e muito baixo!\00\00"
+@str_Seu_palpite_e_muito_alto_ = constant [27 x i8] c"Seu palpite e muito alto!\00\00"
+@str_Parabens__Acertou_em_ = constant [23 x i8] c"Parabens! Acertou em \00\00"
+@str__tentativas_ = constant [14 x i8] c" tentativas!\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %secret = alloca i64, align 8
+  %guess = alloca i64, align 8
+  %attempts = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____Numero_Aleatorio_com_Luck____)
+  %call_luck_random = call i64 @luck_random(i64 100)
+  store i64 %call_luck_random, ptr %secret, align 4
+  store i64 0, ptr %guess, align 4
+  store i64 0, ptr %attempts, align 4
+  br label %loop
+
+loop:                                             ; preds = %ifend20, %entry
+  %load_guess = load i64, ptr %guess, align 4
+  %load_secret = load i64, ptr %secret, align 4
+  %netmp = icmp ne i64 %load_guess, %load_secret
+  %bool_to_i64 = zext i1 %netmp to i64
+  %whilecond = icmp ne i64 %bool_to_i64, 0
+  br i1 %whilecond, label %loopbody, label %loopafter
+
+loopbody:                                         ; preds = %loop
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_seu_palpite__0_100___)
+  %call_scanf = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i64 = zext i32 %call_atoi_input to i64
+  store i64 %atoi_to_i64, ptr %guess, align 4
+  %load_attempts = load i64, ptr %attempts, align 4
+  %addtmp = add i64 %load_attempts, 1
+  store i64 %addtmp, ptr %attempts, align 4
+  %load_guess2 = load i64, ptr %guess, align 4
+  %load_secret3 = load i64, ptr %secret, align 4
+  %lttmp = icmp slt i64 %load_guess2, %load_secret3
+  %bool_to_i644 = zext i1 %lttmp to i64
+  %ifcond = icmp ne i64 %bool_to_i644, 0
+  br i1 %ifcond, label %then, label %else
+
+loopafter:                                        ; preds = %loop
+  ret i32 0
+
+then:                                             ; preds = %loopbody
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Seu_palpite_e_muito_baixo_)
+  br label %ifend
+
+else:                                             ; preds = %loopbody
+  br label %ifend
+
+ifend:                                            ; preds = %else, %then
+  %load_guess6 = load i64, ptr %guess, align 4
+  %load_secret7 = load i64, ptr %secret, align 4
+  %gttmp = icmp sgt i64 %load_guess6, %load_secret7
+  %bool_to_i648 = zext i1 %gttmp to i64
+  %ifcond9 = icmp ne i64 %bool_to_i648, 0
+  br i1 %ifcond9, label %then10, label %else11
+
+then10:                                           ; preds = %ifend
+  %call_printf13 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Seu_palpite_e_muito_alto_)
+  br label %ifend12
+
+else11:                                           ; preds = %ifend
+  br label %ifend12
+
+ifend12:                                          ; preds = %else11, %then10
+  %load_guess14 = load i64, ptr %guess, align 4
+  %load_secret15 = load i64, ptr %secret, align 4
+  %eqtmp = icmp eq i64 %load_guess14, %load_secret15
+  %bool_to_i6416 = zext i1 %eqtmp to i64
+  %ifcond17 = icmp ne i64 %bool_to_i6416, 0
+  br i1 %ifcond17, label %then18, label %else19
+
+then18:                                           ; preds = %ifend12
+  %call_printf21 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Parabens__Acertou_em_)
+  %load_attempts22 = load i64, ptr %attempts, align 4
+  %call_printf23 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_attempts22)
+  %call_printf24 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__tentativas_)
+  br label %ifend20
+
+else19:                                           ; preds = %ifend12
+  br label %ifend20
+
+ifend20:                                          ; preds = %else19, %then18
+  br label %loop
+}
+
+declare i64 @luck_random(i64)
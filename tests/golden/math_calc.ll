@@ -0,0 +1,41 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_____Pythagorean_Theorem_Calculator_____n = constant [42 x i8] c"=== Pythagorean Theorem Calculator ===\\n\00\00"
+@str_Enter_side_a__ = constant [16 x i8] c"Enter side a: \00\00"
+@str_Enter_side_b__ = constant [16 x i8] c"Enter side b: \00\00"
+@str_Calculation_requires_float_support__coming_soon__n = constant [52 x i8] c"Calculation requires float support (coming soon)\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %a = alloca i64, align 8
+  %b = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____Pythagorean_Theorem_Calculator_____n)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Enter_side_a__)
+  %call_scanf = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i64 = zext i32 %call_atoi_input to i64
+  store i64 %atoi_to_i64, ptr %a, align 4
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Enter_side_b__)
+  %call_scanf3 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input4 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i645 = zext i32 %call_atoi_input4 to i64
+  store i64 %atoi_to_i645, ptr %b, align 4
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Calculation_requires_float_support__coming_soon__n)
+  ret i32 0
+}
@@ -0,0 +1,71 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "=== Pythagorean Theorem Calculator ===\\n"
+Lmsg1:
+    .ascii "Enter side a: "
+Lmsg2:
+    .ascii "Enter side b: "
+Lmsg3:
+    .ascii "Calculation requires float support (coming soon)\\n"
+    .section .bss
+input_buffer: .space 256
+a: .quad 0
+b: .quad 0
+a_buf: .space 256
+a_len: .quad 0
+b_buf: .space 256
+b_len: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/math_calc.wheel"
+    .loc 1 5 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 40
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 6 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 14
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 7 1
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + a_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + a_len], rax
+    .loc 1 8 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 14
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 9 1
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + b_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + b_len], rax
+    .loc 1 13 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 50
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
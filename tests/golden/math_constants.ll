@@ -0,0 +1,37 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Pi_estimate__ = constant [15 x i8] c"Pi estimate: \00\00"
+@str__nE_estimate__ = constant [16 x i8] c"\\nE estimate: \00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %constants_pi = alloca i64, align 8
+  %constants_e = alloca i64, align 8
+  store i64 314, ptr %constants_pi, align 4
+  store i64 271, ptr %constants_e, align 4
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Pi_estimate__)
+  %load_constants_pi = load i64, ptr %constants_pi, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_constants_pi)
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__nE_estimate__)
+  %load_constants_e = load i64, ptr %constants_e, align 4
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_constants_e)
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  ret i32 0
+}
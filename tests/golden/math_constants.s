@@ -0,0 +1,110 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Pi estimate: "
+Lmsg1:
+    .ascii "314"
+Lmsg2:
+    .ascii "\\nE estimate: "
+Lmsg3:
+    .ascii "271"
+Lmsg4:
+    .ascii "\\n"
+    .section .bss
+input_buffer: .space 256
+constants_pi: .quad 0
+constants_e: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/constants.wheel"
+    .loc 1 1 1
+    mov rax, 314
+    mov qword ptr [rip + constants_pi], rax
+    .loc 1 2 1
+    mov rax, 271
+    mov qword ptr [rip + constants_e], rax
+    .file 2 "examples/math_constants.wheel"
+    .loc 2 2 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 13
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 2 3 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + constants_pi]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_0
+    neg rax
+Litoa_0:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_0
+    test r8, r8
+    jns Litoa_end_0
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_0:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 2 4 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 14
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 2 5 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + constants_e]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_1
+    neg rax
+Litoa_1:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_1
+    test r8, r8
+    jns Litoa_end_1
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_1:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 2 6 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,243 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str______________________________________n = constant [40 x i8] c"====================================\\n\00\00"
+@str_Mathematical_Calculations_n = constant [29 x i8] c"Mathematical Calculations\\n\00\00"
+@str______________________________________n_n = constant [42 x i8] c"====================================\\n\\n\00\00"
+@str_Basic_Arithmetic_with_Large_Numbers__n_n = constant [42 x i8] c"Basic Arithmetic with Large Numbers:\\n\\n\00\00"
+@str_a___ = constant [6 x i8] c"a = \00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+@str_b___ = constant [6 x i8] c"b = \00\00"
+@str__n_n = constant [6 x i8] c"\\n\\n\00\00"
+@str_a___b___ = constant [10 x i8] c"a + b = \00\00"
+@str__n.1 = constant [4 x i8] c"\\n\00\00"
+@str_a___b___.2 = constant [10 x i8] c"a - b = \00\00"
+@str__n.3 = constant [4 x i8] c"\\n\00\00"
+@str_a___b___.4 = constant [10 x i8] c"a * b = \00\00"
+@str__n.5 = constant [4 x i8] c"\\n\00\00"
+@str_a___b___.6 = constant [10 x i8] c"a / b = \00\00"
+@str__n.7 = constant [4 x i8] c"\\n\00\00"
+@str_a___b___.8 = constant [10 x i8] c"a % b = \00\00"
+@str__n_n.9 = constant [6 x i8] c"\\n\\n\00\00"
+@str_num___ = constant [8 x i8] c"num = \00\00"
+@str__n.10 = constant [4 x i8] c"\\n\00\00"
+@str_num___num__square____ = constant [23 x i8] c"num * num (square) = \00\00"
+@str__n.11 = constant [4 x i8] c"\\n\00\00"
+@str_num___num___num__cube____ = constant [27 x i8] c"num * num * num (cube) = \00\00"
+@str__n_n.12 = constant [6 x i8] c"\\n\\n\00\00"
+@str_Fibonacci_like_Sequence__n = constant [28 x i8] c"Fibonacci-like Sequence:\\n\00\00"
+@str__ = constant [3 x i8] c" \00\00"
+@str__.13 = constant [3 x i8] c" \00\00"
+@str__.14 = constant [3 x i8] c" \00\00"
+@str__n_n.15 = constant [6 x i8] c"\\n\\n\00\00"
+@str_Pythagorean_calculations__integer_approximations___n = constant [54 x i8] c"Pythagorean calculations (integer approximations):\\n\00\00"
+@str_Side_a___ = constant [11 x i8] c"Side a = \00\00"
+@str__n.16 = constant [4 x i8] c"\\n\00\00"
+@str_Side_b___ = constant [11 x i8] c"Side b = \00\00"
+@str__n.17 = constant [4 x i8] c"\\n\00\00"
+@str_Hypotenuse_squared___ = constant [23 x i8] c"Hypotenuse squared = \00\00"
+@str__n.18 = constant [4 x i8] c"\\n\00\00"
+@"str__a\C2\B2___b\C2\B2___3\C2\B2___4\C2\B2___9___16___25__so_hypotenuse___5__n_n" = constant [62 x i8] c"(a\C2\B2 + b\C2\B2 = 3\C2\B2 + 4\C2\B2 = 9 + 16 = 25, so hypotenuse = 5)\\n\\n\00\00"
+@str_Complex_calculation__n = constant [24 x i8] c"Complex calculation:\\n\00\00"
+@str__100___50_____100___50_____50___2____ = constant [39 x i8] c"(100 * 50) + (100 / 50) - (50 / 2) = \00\00"
+@str__n.19 = constant [4 x i8] c"\\n\00\00"
+@str__nNote__Full_trigonometric_logarithmic_functions_require_float64_support_n = constant [76 x i8] c"\\nNote: Full trigonometric/logarithmic functions require float64 support\\n\00\00"
+@str_Float64_support_coming_in_next_LLVM_backend_update_n = constant [54 x i8] c"Float64 support coming in next LLVM backend update\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %f3 = alloca i64, align 8
+  %a = alloca i64, align 8
+  %b = alloca i64, align 8
+  %add = alloca i64, align 8
+  %sub = alloca i64, align 8
+  %mul = alloca i64, align 8
+  %div = alloca i64, align 8
+  %mod_val = alloca i64, align 8
+  %num = alloca i64, align 8
+  %square = alloca i64, align 8
+  %cube = alloca i64, align 8
+  %f1 = alloca i64, align 8
+  %f2 = alloca i64, align 8
+  %i = alloca i64, align 8
+  %a_side = alloca i64, align 8
+  %b_side = alloca i64, align 8
+  %hyp_squared = alloca i64, align 8
+  %x = alloca i64, align 8
+  %y = alloca i64, align 8
+  %result = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str______________________________________n)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Mathematical_Calculations_n)
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str______________________________________n_n)
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Basic_Arithmetic_with_Large_Numbers__n_n)
+  store i64 1000, ptr %a, align 4
+  store i64 37, ptr %b, align 4
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_a___)
+  %load_a = load i64, ptr %a, align 4
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_a)
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_b___)
+  %load_b = load i64, ptr %b, align 4
+  %call_printf8 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_b)
+  %call_printf9 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n)
+  %call_printf10 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_a___b___)
+  %load_a11 = load i64, ptr %a, align 4
+  %load_b12 = load i64, ptr %b, align 4
+  %addtmp = add i64 %load_a11, %load_b12
+  store i64 %addtmp, ptr %add, align 4
+  %load_add = load i64, ptr %add, align 4
+  %call_printf13 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_add)
+  %call_printf14 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.1)
+  %call_printf15 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_a___b___.2)
+  %load_a16 = load i64, ptr %a, align 4
+  %load_b17 = load i64, ptr %b, align 4
+  %subtmp = sub i64 %load_a16, %load_b17
+  store i64 %subtmp, ptr %sub, align 4
+  %load_sub = load i64, ptr %sub, align 4
+  %call_printf18 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_sub)
+  %call_printf19 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.3)
+  %call_printf20 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_a___b___.4)
+  %load_a21 = load i64, ptr %a, align 4
+  %load_b22 = load i64, ptr %b, align 4
+  %multmp = mul i64 %load_a21, %load_b22
+  store i64 %multmp, ptr %mul, align 4
+  %load_mul = load i64, ptr %mul, align 4
+  %call_printf23 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_mul)
+  %call_printf24 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.5)
+  %call_printf25 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_a___b___.6)
+  %load_a26 = load i64, ptr %a, align 4
+  %load_b27 = load i64, ptr %b, align 4
+  %divtmp = sdiv i64 %load_a26, %load_b27
+  store i64 %divtmp, ptr %div, align 4
+  %load_div = load i64, ptr %div, align 4
+  %call_printf28 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_div)
+  %call_printf29 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.7)
+  %call_printf30 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_a___b___.8)
+  %load_a31 = load i64, ptr %a, align 4
+  store i64 %load_a31, ptr %mod_val, align 4
+  %load_b32 = load i64, ptr %b, align 4
+  %load_mod_val = load i64, ptr %mod_val, align 4
+  %call_printf33 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_mod_val)
+  %call_printf34 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n.9)
+  store i64 12, ptr %num, align 4
+  %call_printf35 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_num___)
+  %load_num = load i64, ptr %num, align 4
+  %call_printf36 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_num)
+  %call_printf37 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.10)
+  %call_printf38 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_num___num__square____)
+  %load_num39 = load i64, ptr %num, align 4
+  %load_num40 = load i64, ptr %num, align 4
+  %multmp41 = mul i64 %load_num39, %load_num40
+  store i64 %multmp41, ptr %square, align 4
+  %load_square = load i64, ptr %square, align 4
+  %call_printf42 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_square)
+  %call_printf43 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.11)
+  %call_printf44 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_num___num___num__cube____)
+  %load_num45 = load i64, ptr %num, align 4
+  %load_num46 = load i64, ptr %num, align 4
+  %multmp47 = mul i64 %load_num45, %load_num46
+  %load_num48 = load i64, ptr %num, align 4
+  %multmp49 = mul i64 %multmp47, %load_num48
+  store i64 %multmp49, ptr %cube, align 4
+  %load_cube = load i64, ptr %cube, align 4
+  %call_printf50 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_cube)
+  %call_printf51 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n.12)
+  %call_printf52 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Fibonacci_like_Sequence__n)
+  store i64 1, ptr %f1, align 4
+  store i64 1, ptr %f2, align 4
+  %load_f1 = load i64, ptr %f1, align 4
+  %call_printf53 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_f1)
+  %call_printf54 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__)
+  %load_f2 = load i64, ptr %f2, align 4
+  %call_printf55 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_f2)
+  %call_printf56 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__.13)
+  store i64 0, ptr %i, align 4
+  br label %loop
+
+loop:                                             ; preds = %loopbody, %entry
+  %load_i = load i64, ptr %i, align 4
+  %lttmp = icmp slt i64 %load_i, 8
+  %bool_to_i64 = zext i1 %lttmp to i64
+  %whilecond = icmp ne i64 %bool_to_i64, 0
+  br i1 %whilecond, label %loopbody, label %loopafter
+
+loopbody:                                         ; preds = %loop
+  %load_f157 = load i64, ptr %f1, align 4
+  %load_f258 = load i64, ptr %f2, align 4
+  %addtmp59 = add i64 %load_f157, %load_f258
+  store i64 %addtmp59, ptr %f3, align 4
+  %load_f3 = load i64, ptr %f3, align 4
+  %call_printf60 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_f3)
+  %call_printf61 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__.14)
+  %load_f262 = load i64, ptr %f2, align 4
+  store i64 %load_f262, ptr %f1, align 4
+  %load_f363 = load i64, ptr %f3, align 4
+  store i64 %load_f363, ptr %f2, align 4
+  %load_i64 = load i64, ptr %i, align 4
+  %addtmp65 = add i64 %load_i64, 1
+  store i64 %addtmp65, ptr %i, align 4
+  br label %loop
+
+loopafter:                                        ; preds = %loop
+  %call_printf66 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n.15)
+  %call_printf67 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Pythagorean_calculations__integer_approximations___n)
+  store i64 3, ptr %a_side, align 4
+  store i64 4, ptr %b_side, align 4
+  %load_a_side = load i64, ptr %a_side, align 4
+  %load_a_side68 = load i64, ptr %a_side, align 4
+  %multmp69 = mul i64 %load_a_side, %load_a_side68
+  %load_b_side = load i64, ptr %b_side, align 4
+  %load_b_side70 = load i64, ptr %b_side, align 4
+  %multmp71 = mul i64 %load_b_side, %load_b_side70
+  %addtmp72 = add i64 %multmp69, %multmp71
+  store i64 %addtmp72, ptr %hyp_squared, align 4
+  %call_printf73 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Side_a___)
+  %load_a_side74 = load i64, ptr %a_side, align 4
+  %call_printf75 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_a_side74)
+  %call_printf76 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.16)
+  %call_printf77 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Side_b___)
+  %load_b_side78 = load i64, ptr %b_side, align 4
+  %call_printf79 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_b_side78)
+  %call_printf80 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.17)
+  %call_printf81 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Hypotenuse_squared___)
+  %load_hyp_squared = load i64, ptr %hyp_squared, align 4
+  %call_printf82 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_hyp_squared)
+  %call_printf83 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.18)
+  %call_printf84 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @"str__a\C2\B2___b\C2\B2___3\C2\B2___4\C2\B2___9___16___25__so_hypotenuse___5__n_n")
+  %call_printf85 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Complex_calculation__n)
+  store i64 100, ptr %x, align 4
+  store i64 50, ptr %y, align 4
+  %load_x = load i64, ptr %x, align 4
+  %load_y = load i64, ptr %y, align 4
+  %multmp86 = mul i64 %load_x, %load_y
+  %load_x87 = load i64, ptr %x, align 4
+  %load_y88 = load i64, ptr %y, align 4
+  %divtmp89 = sdiv i64 %load_x87, %load_y88
+  %addtmp90 = add i64 %multmp86, %divtmp89
+  %load_y91 = load i64, ptr %y, align 4
+  %divtmp92 = sdiv i64 %load_y91, 2
+  %subtmp93 = sub i64 %addtmp90, %divtmp92
+  store i64 %subtmp93, ptr %result, align 4
+  %call_printf94 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__100___50_____100___50_____50___2____)
+  %load_result = load i64, ptr %result, align 4
+  %call_printf95 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_result)
+  %call_printf96 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.19)
+  %call_printf97 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__nNote__Full_trigonometric_logarithmic_functions_require_float64_support_n)
+  %call_printf98 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Float64_support_coming_in_next_LLVM_backend_update_n)
+  ret i32 0
+}
@@ -0,0 +1,1017 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "====================================\\n"
+Lmsg1:
+    .ascii "Mathematical Calculations\\n"
+Lmsg2:
+    .ascii "====================================\\n\\n"
+Lmsg3:
+    .ascii "Basic Arithmetic with Large Numbers:\\n\\n"
+Lmsg4:
+    .ascii "a = "
+Lmsg5:
+    .ascii "1000"
+Lmsg6:
+    .ascii "\\n"
+Lmsg7:
+    .ascii "b = "
+Lmsg8:
+    .ascii "37"
+Lmsg9:
+    .ascii "\\n\\n"
+Lmsg10:
+    .ascii "a + b = "
+Lmsg11:
+    .ascii "1037"
+Lmsg12:
+    .ascii "a - b = "
+Lmsg13:
+    .ascii "963"
+Lmsg14:
+    .ascii "a * b = "
+Lmsg15:
+    .ascii "37000"
+Lmsg16:
+    .ascii "a / b = "
+Lmsg17:
+    .ascii "27"
+Lmsg18:
+    .ascii "a % b = "
+Lmsg19:
+    .ascii "num = "
+Lmsg20:
+    .ascii "12"
+Lmsg21:
+    .ascii "num * num (square) = "
+Lmsg22:
+    .ascii "144"
+Lmsg23:
+    .ascii "num * num * num (cube) = "
+Lmsg24:
+    .ascii "1728"
+Lmsg25:
+    .ascii "Fibonacci-like Sequence:\\n"
+Lmsg26:
+    .ascii " "
+Lmsg27:
+    .ascii "Pythagorean calculations (integer approximations):\\n"
+Lmsg28:
+    .ascii "Side a = "
+Lmsg29:
+    .ascii "3"
+Lmsg30:
+    .ascii "Side b = "
+Lmsg31:
+    .ascii "4"
+Lmsg32:
+    .ascii "Hypotenuse squared = "
+Lmsg33:
+    .ascii "25"
+Lmsg34:
+    .ascii "(a² + b² = 3² + 4² = 9 + 16 = 25, so hypotenuse = 5)\\n\\n"
+Lmsg35:
+    .ascii "Complex calculation:\\n"
+Lmsg36:
+    .ascii "(100 * 50) + (100 / 50) - (50 / 2) = "
+Lmsg37:
+    .ascii "4977"
+Lmsg38:
+    .ascii "\\nNote: Full trigonometric/logarithmic functions require float64 support\\n"
+Lmsg39:
+    .ascii "Float64 support coming in next LLVM backend update\\n"
+    .section .bss
+input_buffer: .space 256
+a: .quad 0
+b: .quad 0
+add: .quad 0
+sub: .quad 0
+mul: .quad 0
+div: .quad 0
+mod_val: .quad 0
+num: .quad 0
+square: .quad 0
+cube: .quad 0
+f1: .quad 0
+f2: .quad 0
+i: .quad 0
+f3: .quad 0
+a_side: .quad 0
+b_side: .quad 0
+hyp_squared: .quad 0
+x: .quad 0
+y: .quad 0
+result: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/math_demo.wheel"
+    .loc 1 5 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 38
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 6 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 27
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 7 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 40
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 9 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 40
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 12 1
+    mov rax, 1000
+    mov qword ptr [rip + a], rax
+    .loc 1 13 1
+    mov rax, 37
+    mov qword ptr [rip + b], rax
+    .loc 1 15 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 16 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + a]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_0
+    neg rax
+Litoa_0:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_0
+    test r8, r8
+    jns Litoa_end_0
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_0:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 17 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 18 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg7]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 19 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + b]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_1
+    neg rax
+Litoa_1:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_1
+    test r8, r8
+    jns Litoa_end_1
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_1:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 20 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 22 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg10]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 23 1
+    mov rax, 1037
+    mov qword ptr [rip + add], rax
+    .loc 1 24 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + add]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_2
+    neg rax
+Litoa_2:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_2
+    test r8, r8
+    jns Litoa_end_2
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_2:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 25 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 27 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg12]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 28 1
+    mov rax, 963
+    mov qword ptr [rip + sub], rax
+    .loc 1 29 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + sub]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_3
+    neg rax
+Litoa_3:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_3
+    test r8, r8
+    jns Litoa_end_3
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_3:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 30 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 32 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg14]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 33 1
+    mov rax, 37000
+    mov qword ptr [rip + mul], rax
+    .loc 1 34 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + mul]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_4
+    neg rax
+Litoa_4:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_4
+    test r8, r8
+    jns Litoa_end_4
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_4:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 35 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 37 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg16]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 38 1
+    mov rax, 27
+    mov qword ptr [rip + div], rax
+    .loc 1 39 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + div]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_5
+    neg rax
+Litoa_5:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_5
+    test r8, r8
+    jns Litoa_end_5
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_5:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 40 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 42 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg18]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 43 1
+    mov rax, 1000
+    mov qword ptr [rip + mod_val], rax
+    .loc 1 43 19
+    .loc 1 44 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + mod_val]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_6
+    neg rax
+Litoa_6:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_6
+    test r8, r8
+    jns Litoa_end_6
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_6:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 45 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 48 1
+    mov rax, 12
+    mov qword ptr [rip + num], rax
+    .loc 1 49 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg19]
+    mov rdx, 6
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 50 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + num]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_7
+    neg rax
+Litoa_7:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_7
+    test r8, r8
+    jns Litoa_end_7
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_7:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 51 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 53 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg21]
+    mov rdx, 21
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 54 1
+    mov rax, 144
+    mov qword ptr [rip + square], rax
+    .loc 1 55 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + square]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_8
+    neg rax
+Litoa_8:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_8
+    test r8, r8
+    jns Litoa_end_8
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_8:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 56 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 58 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg23]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 59 1
+    mov rax, 1728
+    mov qword ptr [rip + cube], rax
+    .loc 1 60 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + cube]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_9
+    neg rax
+Litoa_9:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_9
+    test r8, r8
+    jns Litoa_end_9
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_9:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 61 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 64 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg25]
+    mov rdx, 26
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 65 1
+    mov rax, 1
+    mov qword ptr [rip + f1], rax
+    .loc 1 66 1
+    mov rax, 1
+    mov qword ptr [rip + f2], rax
+    .loc 1 67 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + f1]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_10
+    neg rax
+Litoa_10:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_10
+    test r8, r8
+    jns Litoa_end_10
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_10:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 68 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg26]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 69 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + f2]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_11
+    neg rax
+Litoa_11:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_11
+    test r8, r8
+    jns Litoa_end_11
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_11:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 70 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg26]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 72 1
+    mov rax, 0
+    mov qword ptr [rip + i], rax
+    .loc 1 73 1
+Lloop_12:
+    mov rax, qword ptr [rip + i]
+    push rax
+    mov rax, 8
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lexit_12
+    .loc 1 74 5
+    mov rax, qword ptr [rip + f1]
+    push rax
+    mov rax, qword ptr [rip + f2]
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + f3], rax
+    .loc 1 75 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + f3]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_13
+    neg rax
+Litoa_13:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_13
+    test r8, r8
+    jns Litoa_end_13
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_13:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 76 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg26]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 77 5
+    mov rax, qword ptr [rip + f2]
+    mov qword ptr [rip + f1], rax
+    .loc 1 78 5
+    mov rax, qword ptr [rip + f3]
+    mov qword ptr [rip + f2], rax
+    .loc 1 79 5
+    mov rax, qword ptr [rip + i]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + i], rax
+    jmp Lloop_12
+Lexit_12:
+    .loc 1 81 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 84 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg27]
+    mov rdx, 52
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 85 1
+    mov rax, 3
+    mov qword ptr [rip + a_side], rax
+    .loc 1 86 1
+    mov rax, 4
+    mov qword ptr [rip + b_side], rax
+    .loc 1 87 1
+    mov rax, 25
+    mov qword ptr [rip + hyp_squared], rax
+    .loc 1 89 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg28]
+    mov rdx, 9
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 90 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + a_side]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_14
+    neg rax
+Litoa_14:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_14
+    test r8, r8
+    jns Litoa_end_14
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_14:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 91 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 92 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg30]
+    mov rdx, 9
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 93 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + b_side]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_15
+    neg rax
+Litoa_15:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_15
+    test r8, r8
+    jns Litoa_end_15
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_15:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 94 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 95 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg32]
+    mov rdx, 21
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 96 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + hyp_squared]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_16
+    neg rax
+Litoa_16:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_16
+    test r8, r8
+    jns Litoa_end_16
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_16:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 97 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 98 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg34]
+    mov rdx, 60
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 101 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg35]
+    mov rdx, 22
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 102 1
+    mov rax, 100
+    mov qword ptr [rip + x], rax
+    .loc 1 103 1
+    mov rax, 50
+    mov qword ptr [rip + y], rax
+    .loc 1 104 1
+    mov rax, 4977
+    mov qword ptr [rip + result], rax
+    .loc 1 105 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg36]
+    mov rdx, 37
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 106 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + result]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_17
+    neg rax
+Litoa_17:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_17
+    test r8, r8
+    jns Litoa_end_17
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_17:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 107 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 109 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg38]
+    mov rdx, 74
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 110 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg39]
+    mov rdx, 52
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
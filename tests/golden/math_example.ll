@@ -0,0 +1,31 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %a = alloca i64, align 8
+  %b = alloca i64, align 8
+  store i64 2, ptr %a, align 4
+  store i64 2, ptr %b, align 4
+  %load_a = load i64, ptr %a, align 4
+  %load_b = load i64, ptr %b, align 4
+  %addtmp = add i64 %load_a, %load_b
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %addtmp)
+  ret i32 0
+}
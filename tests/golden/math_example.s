@@ -0,0 +1,29 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "4"
+    .section .bss
+input_buffer: .space 256
+a: .quad 0
+b: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/math_example.wheel"
+    .loc 1 1 1
+    mov rax, 2
+    mov qword ptr [rip + a], rax
+    .loc 1 2 1
+    mov rax, 2
+    mov qword ptr [rip + b], rax
+    .loc 1 3 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
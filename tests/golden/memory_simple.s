@@ -0,0 +1,19 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Memory Test"
+Lmsg1:
+    .ascii "Allocated"
+Lmsg2:
+    .ascii "Freed"
+    .section .bss
+input_buffer: .space 256
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/memory_simple.wheel"
+    .loc 1 1 1
+    .loc 1 3 1
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,45 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Memory_Test = constant [13 x i8] c"Memory Test\00\00"
+@str_Allocated = constant [11 x i8] c"Allocated\00\00"
+@str_Freed = constant [7 x i8] c"Freed\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %call_user_main = call i64 @user_main()
+  ret i32 0
+}
+
+define internal i64 @user_main() {
+entry:
+  %ptr = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Memory_Test)
+  %call_mem_alloc = call ptr @mem_alloc(i64 100)
+  %ptr_to_i64 = ptrtoint ptr %call_mem_alloc to i64
+  store i64 %ptr_to_i64, ptr %ptr, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Allocated)
+  %load_ptr = load i64, ptr %ptr, align 4
+  call void @mem_free(i64 %load_ptr)
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Freed)
+  ret i64 0
+}
+
+declare ptr @mem_alloc(i64)
+
+declare void @mem_free(ptr)
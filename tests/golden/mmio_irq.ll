@@ -0,0 +1,49 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %call_irq_register = call i64 @wheel_irq_register(i64 32, i64 ptrtoint (ptr @wheel_irq_32_on_timer to i64))
+  store volatile i32 1, ptr inttoptr (i64 4276092944 to ptr), align 4
+  ret i32 0
+}
+
+define internal i64 @on_timer() {
+entry:
+  %status = alloca i64, align 8
+  %mmio_read = load volatile i32, ptr inttoptr (i64 4276092928 to ptr), align 4
+  %mmio_value = zext i32 %mmio_read to i64
+  store i64 %mmio_value, ptr %status, align 4
+  %load_status = load i64, ptr %status, align 4
+  %mmio_value1 = trunc i64 %load_status to i32
+  store volatile i32 %mmio_value1, ptr inttoptr (i64 4276092928 to ptr), align 4
+  store volatile i32 0, ptr inttoptr (i64 4276093104 to ptr), align 4
+  ret i64 0
+
+after_return:                                     ; No predecessors!
+  ret i64 0
+}
+
+define internal x86_intrcc void @wheel_irq_32_on_timer(ptr byval([5 x i64]) %0) {
+entry:
+  %call_handler = call i64 @on_timer()
+  ret void
+}
+
+declare i64 @wheel_irq_register(i64, i64)
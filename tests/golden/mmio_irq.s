@@ -0,0 +1,123 @@
+    .intel_syntax noprefix
+    .section .rodata
+    .section .bss
+input_buffer: .space 256
+on_timer.status: .quad 0
+    .balign 16
+wheel_idt: .space 4096
+wheel_idt_loaded: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/mmio_irq.wheel"
+    .loc 1 4 1
+    .loc 1 11 1
+    mov rdi, 32
+    lea rsi, [rip + wheel_irq_32_on_timer]
+    call wheel_irq_register
+    .loc 1 12 1
+    mov rax, 4276092944
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    mov dword ptr [rax], ebx
+    xor eax, eax
+    mov rax, 60
+    xor rdi, rdi
+    syscall
+on_timer:
+    mov rax, 4276092928
+    mov eax, dword ptr [rax]
+    mov qword ptr [rip + on_timer.status], rax
+    mov rax, 4276092928
+    push rax
+    mov rax, qword ptr [rip + on_timer.status]
+    mov rbx, rax
+    pop rax
+    mov dword ptr [rax], ebx
+    xor eax, eax
+    mov rax, 4276093104
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    mov dword ptr [rax], ebx
+    xor eax, eax
+    mov rax, 0
+    ret
+    xor eax, eax
+    ret
+wheel_irq_32_on_timer:
+    push rax
+    push rcx
+    push rdx
+    push rbx
+    push rsi
+    push rdi
+    push r8
+    push r9
+    push r10
+    push r11
+    xor edi, edi
+    sub rsp, 8
+    cld
+    call on_timer
+    add rsp, 8
+    pop r11
+    pop r10
+    pop r9
+    pop r8
+    pop rdi
+    pop rsi
+    pop rbx
+    pop rdx
+    pop rcx
+    pop rax
+    iretq
+wheel_irq_register:
+    cmp qword ptr [rip + wheel_idt_loaded], 0
+    jne Lirq_gate
+    sub rsp, 16
+    sidt [rsp]
+    movzx rcx, word ptr [rsp]
+    inc rcx
+    mov rax, qword ptr [rsp + 2]
+    add rsp, 16
+    test rax, rax
+    jz Lirq_gate
+    cmp rcx, 4096
+    ja Lirq_gate
+    push rsi
+    push rdi
+    mov rsi, rax
+    lea rdi, [rip + wheel_idt]
+    rep movsb
+    pop rdi
+    pop rsi
+Lirq_gate:
+    lea rdx, [rip + wheel_idt]
+    shl rdi, 4
+    add rdx, rdi
+    mov rax, rsi
+    mov word ptr [rdx], ax
+    mov cx, cs
+    mov word ptr [rdx + 2], cx
+    mov word ptr [rdx + 4], 0x8E00
+    shr rax, 16
+    mov word ptr [rdx + 6], ax
+    shr rax, 16
+    mov dword ptr [rdx + 8], eax
+    mov dword ptr [rdx + 12], 0
+    cmp qword ptr [rip + wheel_idt_loaded], 0
+    jne Lirq_done
+    sub rsp, 16
+    mov word ptr [rsp], 4095
+    lea rax, [rip + wheel_idt]
+    mov qword ptr [rsp + 2], rax
+    lidt [rsp]
+    add rsp, 16
+    mov qword ptr [rip + wheel_idt_loaded], 1
+Lirq_done:
+    mov eax, 1
+    ret
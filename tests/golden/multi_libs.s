@@ -0,0 +1,387 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Multi-Library Demo\\n"
+Lmsg1:
+    .ascii "==================\\n\\n"
+Lmsg2:
+    .ascii "1. Hardware Library (hwio)\\n"
+Lmsg3:
+    .ascii "CPU operations available with hwio library\\n"
+Lmsg4:
+    .ascii "Serial port status: "
+Lmsg5:
+    .ascii "\\n\\n"
+Lmsg6:
+    .ascii "2. Operating System Library (os)\\n"
+Lmsg7:
+    .ascii "Process ID: "
+Lmsg8:
+    .ascii "\\n"
+Lmsg9:
+    .ascii "Sleeping for 1 second...\\n"
+Lmsg10:
+    .ascii "Done!\\n\\n"
+Lmsg11:
+    .ascii "3. Math Library (math)\\n"
+Lmsg12:
+    .ascii "a = "
+Lmsg13:
+    .ascii "10"
+Lmsg14:
+    .ascii ", b = "
+Lmsg15:
+    .ascii "3"
+Lmsg16:
+    .ascii "a + b = "
+Lmsg17:
+    .ascii "13"
+Lmsg18:
+    .ascii "a * b = "
+Lmsg19:
+    .ascii "30"
+Lmsg20:
+    .ascii "a / b = "
+Lmsg21:
+    .ascii "\\nAll libraries loaded and working!\\n"
+    .section .bss
+input_buffer: .space 256
+status: .quad 0
+pid: .quad 0
+a: .quad 0
+b: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/multi_libs.wheel"
+    .loc 1 4 1
+    .loc 1 5 1
+    .loc 1 6 1
+    .loc 1 8 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 20
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 9 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 22
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 12 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 28
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 13 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 44
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 14 1
+    mov rax, 0
+    mov qword ptr [rip + status], rax
+    .loc 1 15 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 20
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 16 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + status]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_0
+    neg rax
+Litoa_0:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_0
+    test r8, r8
+    jns Litoa_end_0
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_0:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 17 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 20 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 34
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 21 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg7]
+    mov rdx, 12
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 22 1
+    mov rax, 0
+    mov qword ptr [rip + pid], rax
+    .loc 1 23 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + pid]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_1
+    neg rax
+Litoa_1:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_1
+    test r8, r8
+    jns Litoa_end_1
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_1:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 24 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 25 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 26
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 26 1
+    .loc 1 27 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg10]
+    mov rdx, 9
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 30 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg11]
+    mov rdx, 24
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 31 1
+    mov rax, 10
+    mov qword ptr [rip + a], rax
+    .loc 1 32 1
+    mov rax, 3
+    mov qword ptr [rip + b], rax
+    .loc 1 33 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg12]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 34 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + a]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_2
+    neg rax
+Litoa_2:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_2
+    test r8, r8
+    jns Litoa_end_2
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_2:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 35 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg14]
+    mov rdx, 6
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 36 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + b]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_3
+    neg rax
+Litoa_3:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_3
+    test r8, r8
+    jns Litoa_end_3
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_3:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 37 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 38 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg16]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 39 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg17]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 40 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 41 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg18]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 42 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg19]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 43 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 44 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg20]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 45 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg15]
+    mov rdx, 1
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 46 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 48 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg21]
+    mov rdx, 37
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
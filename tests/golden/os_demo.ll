@@ -0,0 +1,40 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_____OS_Communication_Demo_____n = constant [33 x i8] c"=== OS Communication Demo ===\\n\00\00"
+@str_Process_ID__ = constant [14 x i8] c"Process ID: \00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+@str_Process_created_successfully_n = constant [32 x i8] c"Process created successfully\\n\00\00"
+@str_Fork_wait_operations_available_but_require_process_support_n = constant [62 x i8] c"Fork/wait operations available but require process support\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %pid = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____OS_Communication_Demo_____n)
+  %call_getpid = call i64 @wheel_getpid()
+  store i64 %call_getpid, ptr %pid, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Process_ID__)
+  %load_pid = load i64, ptr %pid, align 4
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_pid)
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Process_created_successfully_n)
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Fork_wait_operations_available_but_require_process_support_n)
+  ret i32 0
+}
+
+declare i64 @wheel_getpid()
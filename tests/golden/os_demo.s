@@ -0,0 +1,93 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "=== OS Communication Demo ===\\n"
+Lmsg1:
+    .ascii "Process ID: "
+Lmsg2:
+    .ascii "\\n"
+Lmsg3:
+    .ascii "Process created successfully\\n"
+Lmsg4:
+    .ascii "Fork/wait operations available but require process support\\n"
+    .section .bss
+input_buffer: .space 256
+pid: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/os_demo.wheel"
+    .loc 1 5 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 31
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 8 1
+    mov rax, 0
+    mov qword ptr [rip + pid], rax
+    .loc 1 9 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 12
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + pid]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_0
+    neg rax
+Litoa_0:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_0
+    test r8, r8
+    jns Litoa_end_0
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_0:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 11 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 14 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 30
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 26 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 60
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,70 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_____OS_Functions_Demo_____n = constant [29 x i8] c"=== OS Functions Demo ===\\n\00\00"
+@str_Getting_process_ID____n = constant [25 x i8] c"Getting process ID...\\n\00\00"
+@str_Process_ID__ = constant [14 x i8] c"Process ID: \00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+@str_Getting_current_timestamp____n = constant [32 x i8] c"Getting current timestamp...\\n\00\00"
+@str_Timestamp__ = constant [13 x i8] c"Timestamp: \00\00"
+@str__n.1 = constant [4 x i8] c"\\n\00\00"
+@str_Sleeping_for_1_second____n = constant [28 x i8] c"Sleeping for 1 second...\\n\00\00"
+@str_Awake__n = constant [10 x i8] c"Awake!\\n\00\00"
+@str_PID_doubled__ = constant [15 x i8] c"PID doubled: \00\00"
+@str__n.2 = constant [4 x i8] c"\\n\00\00"
+@str_____Demo_Complete_____n = constant [25 x i8] c"=== Demo Complete ===\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %pid = alloca i64, align 8
+  %t = alloca i64, align 8
+  %double_pid = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____OS_Functions_Demo_____n)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Getting_process_ID____n)
+  %call_getpid = call i64 @wheel_getpid()
+  store i64 %call_getpid, ptr %pid, align 4
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Process_ID__)
+  %load_pid = load i64, ptr %pid, align 4
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_pid)
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Getting_current_timestamp____n)
+  %call_time_now = call i64 @wheel_time_now()
+  store i64 %call_time_now, ptr %t, align 4
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Timestamp__)
+  %load_t = load i64, ptr %t, align 4
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_t)
+  %call_printf8 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.1)
+  %call_printf9 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Sleeping_for_1_second____n)
+  %call_sleep = call i64 @wheel_sleep(i64 1)
+  %call_printf10 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Awake__n)
+  %call_printf11 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_PID_doubled__)
+  %load_pid12 = load i64, ptr %pid, align 4
+  %multmp = mul i64 %load_pid12, 2
+  store i64 %multmp, ptr %double_pid, align 4
+  %load_double_pid = load i64, ptr %double_pid, align 4
+  %call_printf13 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_double_pid)
+  %call_printf14 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.2)
+  %call_printf15 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____Demo_Complete_____n)
+  ret i32 0
+}
+
+declare i64 @wheel_getpid()
+
+declare i64 @wheel_time_now()
+
+declare i64 @wheel_sleep(i64)
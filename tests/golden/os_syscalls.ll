@@ -0,0 +1,94 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str______________________________________n = constant [40 x i8] c"====================================\\n\00\00"
+@str_OS_Syscalls_Demo_n = constant [20 x i8] c"OS Syscalls Demo\\n\00\00"
+@str______________________________________n_n = constant [42 x i8] c"====================================\\n\\n\00\00"
+@str_1__Getting_Process_ID_n = constant [25 x i8] c"1. Getting Process ID\\n\00\00"
+@str____Current_PID__ = constant [18 x i8] c"   Current PID: \00\00"
+@str__n_n = constant [6 x i8] c"\\n\\n\00\00"
+@str_2__Testing_Sleep_Function_n = constant [29 x i8] c"2. Testing Sleep Function\\n\00\00"
+@str____Sleeping_for_2_seconds____n = constant [32 x i8] c"   Sleeping for 2 seconds...\\n\00\00"
+@str____Done_sleeping__n_n = constant [23 x i8] c"   Done sleeping!\\n\\n\00\00"
+@str_3__Getting_Current_Time_n = constant [27 x i8] c"3. Getting Current Time\\n\00\00"
+@str____Timestamp__seconds_since_epoch___ = constant [38 x i8] c"   Timestamp (seconds since epoch): \00\00"
+@str__n_n.1 = constant [6 x i8] c"\\n\\n\00\00"
+@str_4__Process_based_Calculations_n = constant [33 x i8] c"4. Process-based Calculations\\n\00\00"
+@str____PID___2___ = constant [15 x i8] c"   PID * 2 = \00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+@str____PID___10___ = constant [16 x i8] c"   PID / 10 = \00\00"
+@str__n_n.2 = constant [6 x i8] c"\\n\\n\00\00"
+@str_5__Environment_Information_n = constant [30 x i8] c"5. Environment Information\\n\00\00"
+@str____Process_still_running_successfully_n_n = constant [43 x i8] c"   Process still running successfully\\n\\n\00\00"
+@str_Demo_complete__n = constant [18 x i8] c"Demo complete!\\n\00\00"
+@str_Note__Full_fork_wait_pipes_require_parser_support_for_array_handling_n = constant [72 x i8] c"Note: Full fork/wait/pipes require parser support for array handling\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %my_pid = alloca i64, align 8
+  %timestamp = alloca i64, align 8
+  %result = alloca i64, align 8
+  %result2 = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str______________________________________n)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_OS_Syscalls_Demo_n)
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str______________________________________n_n)
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_1__Getting_Process_ID_n)
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str____Current_PID__)
+  %call_getpid = call i64 @wheel_getpid()
+  store i64 %call_getpid, ptr %my_pid, align 4
+  %load_my_pid = load i64, ptr %my_pid, align 4
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_my_pid)
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n)
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_2__Testing_Sleep_Function_n)
+  %call_printf8 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str____Sleeping_for_2_seconds____n)
+  %call_sleep = call i64 @wheel_sleep(i64 2)
+  %call_printf9 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str____Done_sleeping__n_n)
+  %call_printf10 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_3__Getting_Current_Time_n)
+  %call_printf11 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str____Timestamp__seconds_since_epoch___)
+  %call_time_now = call i64 @wheel_time_now()
+  store i64 %call_time_now, ptr %timestamp, align 4
+  %load_timestamp = load i64, ptr %timestamp, align 4
+  %call_printf12 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_timestamp)
+  %call_printf13 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n.1)
+  %call_printf14 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_4__Process_based_Calculations_n)
+  %call_printf15 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str____PID___2___)
+  %load_my_pid16 = load i64, ptr %my_pid, align 4
+  %multmp = mul i64 %load_my_pid16, 2
+  store i64 %multmp, ptr %result, align 4
+  %load_result = load i64, ptr %result, align 4
+  %call_printf17 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_result)
+  %call_printf18 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  %call_printf19 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str____PID___10___)
+  %load_my_pid20 = load i64, ptr %my_pid, align 4
+  %divtmp = sdiv i64 %load_my_pid20, 10
+  store i64 %divtmp, ptr %result2, align 4
+  %load_result2 = load i64, ptr %result2, align 4
+  %call_printf21 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_result2)
+  %call_printf22 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n_n.2)
+  %call_printf23 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_5__Environment_Information_n)
+  %call_printf24 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str____Process_still_running_successfully_n_n)
+  %call_printf25 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Demo_complete__n)
+  %call_printf26 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Note__Full_fork_wait_pipes_require_parser_support_for_array_handling_n)
+  ret i32 0
+}
+
+declare i64 @wheel_getpid()
+
+declare i64 @wheel_sleep(i64)
+
+declare i64 @wheel_time_now()
@@ -0,0 +1,357 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "====================================\\n"
+Lmsg1:
+    .ascii "OS Syscalls Demo\\n"
+Lmsg2:
+    .ascii "====================================\\n\\n"
+Lmsg3:
+    .ascii "1. Getting Process ID\\n"
+Lmsg4:
+    .ascii "   Current PID: "
+Lmsg5:
+    .ascii "\\n\\n"
+Lmsg6:
+    .ascii "2. Testing Sleep Function\\n"
+Lmsg7:
+    .ascii "   Sleeping for 2 seconds...\\n"
+Lmsg8:
+    .ascii "   Done sleeping!\\n\\n"
+Lmsg9:
+    .ascii "3. Getting Current Time\\n"
+Lmsg10:
+    .ascii "   Timestamp (seconds since epoch): "
+Lmsg11:
+    .ascii "4. Process-based Calculations\\n"
+Lmsg12:
+    .ascii "   PID * 2 = "
+Lmsg13:
+    .ascii "\\n"
+Lmsg14:
+    .ascii "   PID / 10 = "
+Lmsg15:
+    .ascii "5. Environment Information\\n"
+Lmsg16:
+    .ascii "   Process still running successfully\\n\\n"
+Lmsg17:
+    .ascii "Demo complete!\\n"
+Lmsg18:
+    .ascii "Note: Full fork/wait/pipes require parser support for array handling\\n"
+    .section .bss
+input_buffer: .space 256
+my_pid: .quad 0
+timestamp: .quad 0
+result: .quad 0
+result2: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/os_syscalls.wheel"
+    .loc 1 5 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 38
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 6 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 18
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 7 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 40
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 23
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 11 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 16
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 12 1
+    mov rax, 0
+    mov qword ptr [rip + my_pid], rax
+    .loc 1 13 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + my_pid]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_0
+    neg rax
+Litoa_0:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_0
+    test r8, r8
+    jns Litoa_end_0
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_0:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 14 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 17 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 27
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 18 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg7]
+    mov rdx, 30
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 19 1
+    .loc 1 20 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 21
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 23 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 24 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg10]
+    mov rdx, 36
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 25 1
+    mov rax, 0
+    mov qword ptr [rip + timestamp], rax
+    .loc 1 26 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + timestamp]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_1
+    neg rax
+Litoa_1:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_1
+    test r8, r8
+    jns Litoa_end_1
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_1:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 27 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 30 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg11]
+    mov rdx, 31
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 31 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg12]
+    mov rdx, 13
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 32 1
+    mov rax, qword ptr [rip + my_pid]
+    push rax
+    mov rax, 2
+    mov rbx, rax
+    pop rax
+    imul rax, rbx
+    mov qword ptr [rip + result], rax
+    .loc 1 33 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + result]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_2
+    neg rax
+Litoa_2:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_2
+    test r8, r8
+    jns Litoa_end_2
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_2:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 34 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg13]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 36 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg14]
+    mov rdx, 14
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 37 1
+    mov rax, qword ptr [rip + my_pid]
+    push rax
+    mov rax, 10
+    mov rbx, rax
+    pop rax
+    cqo
+    idiv rbx
+    mov qword ptr [rip + result2], rax
+    .loc 1 38 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + result2]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_3
+    neg rax
+Litoa_3:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_3
+    test r8, r8
+    jns Litoa_end_3
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_3:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 39 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 41 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg15]
+    mov rdx, 28
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 42 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg16]
+    mov rdx, 41
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 44 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg17]
+    mov rdx, 16
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 45 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg18]
+    mov rdx, 70
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
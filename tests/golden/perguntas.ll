@@ -0,0 +1,107 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Fassa_o_calculo_de_um_numero_n = constant [32 x i8] c"Fassa o calculo de um numero\\n\00\00"
+@str_1__Adicao = constant [11 x i8] c"1: Adicao\00\00"
+@str_2__Subtracao = constant [14 x i8] c"2: Subtracao\00\00"
+@str_1 = constant [3 x i8] c"1\00\00"
+@str_Digite_o_primeiro_numero_que_voce_quer_somar = constant [46 x i8] c"Digite o primeiro numero que voce quer somar\00\00"
+@str_Digite_o_segundo_numero_que_voce_quer_somar = constant [45 x i8] c"Digite o segundo numero que voce quer somar\00\00"
+@str_2 = constant [3 x i8] c"2\00\00"
+@str_Digite_o_primeiro_numero_que_voce_quer_subtrair = constant [49 x i8] c"Digite o primeiro numero que voce quer subtrair\00\00"
+@str_Digite_o_segundo_numero_que_voce_quer_subtrair = constant [48 x i8] c"Digite o segundo numero que voce quer subtrair\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %Resultado = alloca i64, align 8
+  %Secondnumero = alloca i64, align 8
+  %Numero = alloca i64, align 8
+  %Opcao = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Fassa_o_calculo_de_um_numero_n)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_1__Adicao)
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_2__Subtracao)
+  %call_scanf = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i64 = zext i32 %call_atoi_input to i64
+  store i64 %atoi_to_i64, ptr %Opcao, align 4
+  %load_Opcao = load i64, ptr %Opcao, align 4
+  %call_atoi_r = call i32 @atoi(ptr @str_1)
+  %atoi_r = sext i32 %call_atoi_r to i64
+  %eqi = icmp eq i64 %load_Opcao, %atoi_r
+  %bool_to_i64 = zext i1 %eqi to i64
+  %ifcond = icmp ne i64 %bool_to_i64, 0
+  br i1 %ifcond, label %then, label %else
+
+then:                                             ; preds = %entry
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_primeiro_numero_que_voce_quer_somar)
+  %call_scanf4 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input5 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i646 = zext i32 %call_atoi_input5 to i64
+  store i64 %atoi_to_i646, ptr %Numero, align 4
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_segundo_numero_que_voce_quer_somar)
+  %call_scanf8 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input9 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i6410 = zext i32 %call_atoi_input9 to i64
+  store i64 %atoi_to_i6410, ptr %Secondnumero, align 4
+  %load_Numero = load i64, ptr %Numero, align 4
+  %load_Secondnumero = load i64, ptr %Secondnumero, align 4
+  %addtmp = add i64 %load_Numero, %load_Secondnumero
+  store i64 %addtmp, ptr %Resultado, align 4
+  %load_Resultado = load i64, ptr %Resultado, align 4
+  %subtmp = sub i64 %load_Resultado, 1
+  %call_printf11 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %subtmp)
+  br label %ifend
+
+else:                                             ; preds = %entry
+  br label %ifend
+
+ifend:                                            ; preds = %else, %then
+  %load_Opcao12 = load i64, ptr %Opcao, align 4
+  %call_atoi_r13 = call i32 @atoi(ptr @str_2)
+  %atoi_r14 = sext i32 %call_atoi_r13 to i64
+  %eqi15 = icmp eq i64 %load_Opcao12, %atoi_r14
+  %bool_to_i6416 = zext i1 %eqi15 to i64
+  %ifcond17 = icmp ne i64 %bool_to_i6416, 0
+  br i1 %ifcond17, label %then18, label %else19
+
+then18:                                           ; preds = %ifend
+  %call_printf21 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_primeiro_numero_que_voce_quer_subtrair)
+  %call_scanf22 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input23 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i6424 = zext i32 %call_atoi_input23 to i64
+  store i64 %atoi_to_i6424, ptr %Numero, align 4
+  %call_printf25 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Digite_o_segundo_numero_que_voce_quer_subtrair)
+  %call_scanf26 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input27 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i6428 = zext i32 %call_atoi_input27 to i64
+  store i64 %atoi_to_i6428, ptr %Secondnumero, align 4
+  %load_Numero29 = load i64, ptr %Numero, align 4
+  %load_Secondnumero30 = load i64, ptr %Secondnumero, align 4
+  %subtmp31 = sub i64 %load_Numero29, %load_Secondnumero30
+  store i64 %subtmp31, ptr %Resultado, align 4
+  %load_Resultado32 = load i64, ptr %Resultado, align 4
+  %subtmp33 = sub i64 %load_Resultado32, 1
+  %call_printf34 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %subtmp33)
+  br label %ifend20
+
+else19:                                           ; preds = %ifend
+  br label %ifend20
+
+ifend20:                                          ; preds = %else19, %then18
+  ret i32 0
+}
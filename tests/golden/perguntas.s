@@ -0,0 +1,232 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Fassa o calculo de um numero\\n"
+Lmsg1:
+    .ascii "1: Adicao"
+Lmsg2:
+    .ascii "2: Subtracao"
+Lmsg3:
+    .ascii "Digite o primeiro numero que voce quer somar"
+Lmsg4:
+    .ascii "Digite o segundo numero que voce quer somar"
+Lmsg5:
+    .ascii "Digite o primeiro numero que voce quer subtrair"
+Lmsg6:
+    .ascii "Digite o segundo numero que voce quer subtrair"
+    .section .bss
+input_buffer: .space 256
+Opcao: .quad 0
+Numero: .quad 0
+Secondnumero: .quad 0
+Resultado: .quad 0
+Opcao_buf: .space 256
+Opcao_len: .quad 0
+Numero_buf: .space 256
+Numero_len: .quad 0
+Secondnumero_buf: .space 256
+Secondnumero_len: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/perguntas.wheel"
+    .loc 1 1 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 30
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 2 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 9
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 3 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 12
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 5 1
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + Opcao_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + Opcao_len], rax
+    .loc 1 7 1
+    mov rax, qword ptr [rip + Opcao]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_0
+    .loc 1 8 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 44
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 9 5
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + Numero_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + Numero_len], rax
+    .loc 1 10 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 43
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 11 5
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + Secondnumero_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + Secondnumero_len], rax
+    .loc 1 12 5
+    mov rax, qword ptr [rip + Numero]
+    push rax
+    mov rax, qword ptr [rip + Secondnumero]
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + Resultado], rax
+    .loc 1 13 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + Resultado]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    sub rax, rbx
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_1
+    neg rax
+Litoa_1:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_1
+    test r8, r8
+    jns Litoa_end_1
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_1:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_0:
+    .loc 1 16 1
+    mov rax, qword ptr [rip + Opcao]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_2
+    .loc 1 17 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 47
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 18 5
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + Numero_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + Numero_len], rax
+    .loc 1 19 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 46
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 20 5
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + Secondnumero_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + Secondnumero_len], rax
+    .loc 1 21 5
+    mov rax, qword ptr [rip + Numero]
+    push rax
+    mov rax, qword ptr [rip + Secondnumero]
+    mov rbx, rax
+    pop rax
+    sub rax, rbx
+    mov qword ptr [rip + Resultado], rax
+    .loc 1 22 5
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + Resultado]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    sub rax, rbx
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_3
+    neg rax
+Litoa_3:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_3
+    test r8, r8
+    jns Litoa_end_3
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_3:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_2:
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,29 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Process Management Test"
+Lmsg1:
+    .ascii "Current PID:"
+Lmsg2:
+    .ascii "Parent PID:"
+Lmsg3:
+    .ascii "Creating child process"
+Lmsg4:
+    .ascii "Child handle:"
+Lmsg5:
+    .ascii "Waiting for child"
+Lmsg6:
+    .ascii "Child exit code:"
+Lmsg7:
+    .ascii "Done"
+    .section .bss
+input_buffer: .space 256
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/process_simple.wheel"
+    .loc 1 1 1
+    .loc 1 3 1
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,52 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Teorema_de_Pit_u00e1goras__n = constant [30 x i8] c"Teorema de Pit\\u00e1goras:\\n\00\00"
+@str_Base__ = constant [8 x i8] c"Base: \00\00"
+@str__nHeight__ = constant [12 x i8] c"\\nHeight: \00\00"
+@str__nHypotenuse_2__ = constant [18 x i8] c"\\nHypotenuse^2: \00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %base = alloca i64, align 8
+  %height = alloca i64, align 8
+  %hypotenuse_sq = alloca i64, align 8
+  store i64 3, ptr %base, align 4
+  store i64 4, ptr %height, align 4
+  %load_base = load i64, ptr %base, align 4
+  %load_base1 = load i64, ptr %base, align 4
+  %multmp = mul i64 %load_base, %load_base1
+  %load_height = load i64, ptr %height, align 4
+  %load_height2 = load i64, ptr %height, align 4
+  %multmp3 = mul i64 %load_height, %load_height2
+  %addtmp = add i64 %multmp, %multmp3
+  store i64 %addtmp, ptr %hypotenuse_sq, align 4
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Teorema_de_Pit_u00e1goras__n)
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Base__)
+  %load_base5 = load i64, ptr %base, align 4
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_base5)
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__nHeight__)
+  %load_height8 = load i64, ptr %height, align 4
+  %call_printf9 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_height8)
+  %call_printf10 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__nHypotenuse_2__)
+  %load_hypotenuse_sq = load i64, ptr %hypotenuse_sq, align 4
+  %call_printf11 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_hypotenuse_sq)
+  %call_printf12 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  ret i32 0
+}
@@ -0,0 +1,145 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Initializing_SDL____n = constant [23 x i8] c"Initializing SDL...\\n\00\00"
+@str_Creating_window__800x600_____n = constant [32 x i8] c"Creating window (800x600)...\\n\00\00"
+@str_Wheel_Graphics_Demo = constant [21 x i8] c"Wheel Graphics Demo\00\00"
+@str_Drawing_patterns____n = constant [23 x i8] c"Drawing patterns...\\n\00\00"
+@str_Displaying_scene____n = constant [23 x i8] c"Displaying scene...\\n\00\00"
+@str_Window_will_stay_open__Press_Ctrl_C_to_exit__n = constant [48 x i8] c"Window will stay open. Press Ctrl+C to exit.\\n\00\00"
+@str_Cleaning_up____n = constant [18 x i8] c"Cleaning up...\\n\00\00"
+@str_Done__n = constant [9 x i8] c"Done!\\n\00\00"
+@str_Failed_to_create_window_n = constant [27 x i8] c"Failed to create window\\n\00\00"
+@str_Failed_to_initialize_SDL_n = constant [28 x i8] c"Failed to initialize SDL\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %py = alloca i64, align 8
+  %y = alloca i64, align 8
+  %x = alloca i64, align 8
+  %window = alloca i64, align 8
+  %init = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Initializing_SDL____n)
+  %call_sdl_init = call i64 @sdl_init()
+  store i64 %call_sdl_init, ptr %init, align 4
+  %load_init = load i64, ptr %init, align 4
+  %eqtmp = icmp eq i64 %load_init, 1
+  %bool_to_i64 = zext i1 %eqtmp to i64
+  %ifcond = icmp ne i64 %bool_to_i64, 0
+  br i1 %ifcond, label %then, label %else
+
+then:                                             ; preds = %entry
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Creating_window__800x600_____n)
+  %call_sdl_create_window = call i64 @sdl_create_window(i64 800, i64 600, i64 ptrtoint (ptr @str_Wheel_Graphics_Demo to i64))
+  store i64 %call_sdl_create_window, ptr %window, align 4
+  %load_window = load i64, ptr %window, align 4
+  %eqtmp2 = icmp eq i64 %load_window, 1
+  %bool_to_i643 = zext i1 %eqtmp2 to i64
+  %ifcond4 = icmp ne i64 %bool_to_i643, 0
+  br i1 %ifcond4, label %then5, label %else6
+
+else:                                             ; preds = %entry
+  %call_printf32 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Failed_to_initialize_SDL_n)
+  br label %ifend
+
+ifend:                                            ; preds = %else, %ifend7
+  ret i32 0
+
+then5:                                            ; preds = %then
+  %call_printf8 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Drawing_patterns____n)
+  %call_sdl_clear = call i64 @sdl_clear(i64 0, i64 0, i64 0, i64 0)
+  %call_sdl_draw_rect = call i64 @sdl_draw_rect(i64 0, i64 50, i64 50, i64 700, i64 100, i64 255, i64 0, i64 0)
+  %call_sdl_draw_rect9 = call i64 @sdl_draw_rect(i64 0, i64 50, i64 200, i64 700, i64 100, i64 255, i64 100, i64 0)
+  %call_sdl_draw_rect10 = call i64 @sdl_draw_rect(i64 0, i64 50, i64 350, i64 700, i64 100, i64 255, i64 200, i64 0)
+  %call_sdl_draw_rect11 = call i64 @sdl_draw_rect(i64 0, i64 100, i64 100, i64 100, i64 400, i64 0, i64 255, i64 0)
+  %call_sdl_draw_rect12 = call i64 @sdl_draw_rect(i64 0, i64 300, i64 100, i64 100, i64 400, i64 0, i64 200, i64 100)
+  %call_sdl_draw_rect13 = call i64 @sdl_draw_rect(i64 0, i64 500, i64 100, i64 100, i64 400, i64 0, i64 150, i64 150)
+  store i64 150, ptr %x, align 4
+  store i64 150, ptr %y, align 4
+  br label %loop
+
+else6:                                            ; preds = %then
+  %call_printf31 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Failed_to_create_window_n)
+  br label %ifend7
+
+ifend7:                                           ; preds = %else6, %loopafter
+  br label %ifend
+
+loop:                                             ; preds = %loopafter17, %then5
+  %load_x = load i64, ptr %x, align 4
+  %lttmp = icmp slt i64 %load_x, 650
+  %bool_to_i6414 = zext i1 %lttmp to i64
+  %whilecond = icmp ne i64 %bool_to_i6414, 0
+  br i1 %whilecond, label %loopbody, label %loopafter
+
+loopbody:                                         ; preds = %loop
+  store i64 150, ptr %py, align 4
+  br label %loop15
+
+loopafter:                                        ; preds = %loop
+  %call_printf26 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Displaying_scene____n)
+  %load_window27 = load i64, ptr %window, align 4
+  %call_sdl_present = call i64 @sdl_present(i64 %load_window27)
+  %call_printf28 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Window_will_stay_open__Press_Ctrl_C_to_exit__n)
+  %call_sleep = call i64 @wheel_sleep(i64 5)
+  %call_printf29 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Cleaning_up____n)
+  %call_sdl_destroy_window = call i64 @sdl_destroy_window(i64 0)
+  %call_sdl_quit = call i64 @sdl_quit()
+  %call_printf30 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Done__n)
+  br label %ifend7
+
+loop15:                                           ; preds = %loopbody16, %loopbody
+  %load_py = load i64, ptr %py, align 4
+  %lttmp18 = icmp slt i64 %load_py, 500
+  %bool_to_i6419 = zext i1 %lttmp18 to i64
+  %whilecond20 = icmp ne i64 %bool_to_i6419, 0
+  br i1 %whilecond20, label %loopbody16, label %loopafter17
+
+loopbody16:                                       ; preds = %loop15
+  %load_x21 = load i64, ptr %x, align 4
+  %load_py22 = load i64, ptr %py, align 4
+  %call_sdl_draw_pixel = call i64 @sdl_draw_pixel(i64 0, i64 %load_x21, i64 %load_py22, i64 0, i64 0, i64 255)
+  %load_py23 = load i64, ptr %py, align 4
+  %addtmp = add i64 %load_py23, 50
+  store i64 %addtmp, ptr %py, align 4
+  br label %loop15
+
+loopafter17:                                      ; preds = %loop15
+  %load_x24 = load i64, ptr %x, align 4
+  %addtmp25 = add i64 %load_x24, 50
+  store i64 %addtmp25, ptr %x, align 4
+  br label %loop
+}
+
+declare i64 @sdl_init()
+
+declare i64 @sdl_create_window(i64, i64, i64)
+
+declare i64 @sdl_clear(i64, i64, i64, i64)
+
+declare i64 @sdl_draw_rect(i64, i64, i64, i64, i64, i64, i64, i64)
+
+declare i64 @sdl_draw_pixel(i64, i64, i64, i64, i64, i64)
+
+declare i64 @sdl_present(i64)
+
+declare i64 @wheel_sleep(i64)
+
+declare i64 @sdl_destroy_window(i64)
+
+declare i64 @sdl_quit()
@@ -0,0 +1,205 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Initializing SDL...\\n"
+Lmsg1:
+    .ascii "Creating window (800x600)...\\n"
+Lmsg2:
+    .ascii "Drawing patterns...\\n"
+Lmsg3:
+    .ascii "Displaying scene...\\n"
+Lmsg4:
+    .ascii "Window will stay open. Press Ctrl+C to exit.\\n"
+Lmsg5:
+    .ascii "Cleaning up...\\n"
+Lmsg6:
+    .ascii "Done!\\n"
+Lmsg7:
+    .ascii "Failed to create window\\n"
+Lmsg8:
+    .ascii "Failed to initialize SDL\\n"
+    .section .bss
+input_buffer: .space 256
+init: .quad 0
+window: .quad 0
+x: .quad 0
+y: .quad 0
+py: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/sdl_draw.wheel"
+    .loc 1 5 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 21
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 6 1
+    mov rax, 0
+    mov qword ptr [rip + init], rax
+    .loc 1 8 1
+    mov rax, qword ptr [rip + init]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lelse_0
+    .loc 1 9 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 30
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 5
+    mov rax, 0
+    mov qword ptr [rip + window], rax
+    .loc 1 12 5
+    mov rax, qword ptr [rip + window]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lelse_1
+    .loc 1 13 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 21
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 16 9
+    .loc 1 19 9
+    .loc 1 20 9
+    .loc 1 21 9
+    .loc 1 24 9
+    .loc 1 25 9
+    .loc 1 26 9
+    .loc 1 29 9
+    mov rax, 150
+    mov qword ptr [rip + x], rax
+    .loc 1 30 9
+    mov rax, 150
+    mov qword ptr [rip + y], rax
+    .loc 1 31 9
+Lloop_2:
+    mov rax, qword ptr [rip + x]
+    push rax
+    mov rax, 650
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lexit_2
+    .loc 1 32 13
+    mov rax, 150
+    mov qword ptr [rip + py], rax
+    .loc 1 33 13
+Lloop_3:
+    mov rax, qword ptr [rip + py]
+    push rax
+    mov rax, 500
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lexit_3
+    .loc 1 34 17
+    .loc 1 35 17
+    mov rax, qword ptr [rip + py]
+    push rax
+    mov rax, 50
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + py], rax
+    jmp Lloop_3
+Lexit_3:
+    .loc 1 37 13
+    mov rax, qword ptr [rip + x]
+    push rax
+    mov rax, 50
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + x], rax
+    jmp Lloop_2
+Lexit_2:
+    .loc 1 40 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 21
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 41 9
+    .loc 1 43 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 46
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 46 9
+    .loc 1 48 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 16
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 49 9
+    .loc 1 50 9
+    .loc 1 51 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 7
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    jmp Lend_1
+Lelse_1:
+    .loc 1 53 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg7]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_1:
+    jmp Lend_0
+Lelse_0:
+    .loc 1 56 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 26
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_0:
+    mov rax, 60
+    xor rdi, rdi
+    syscall
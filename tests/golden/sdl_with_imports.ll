@@ -0,0 +1,106 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_SDL_Graphics_Demo_with_Imports_n = constant [34 x i8] c"SDL Graphics Demo with Imports\\n\00\00"
+@str__________________________________n_n = constant [38 x i8] c"================================\\n\\n\00\00"
+@str_Initializing_SDL2_Library____n = constant [32 x i8] c"Initializing SDL2 Library...\\n\00\00"
+@str___SDL_initialized_successfully_n = constant [36 x i8] c"\E2\9C\93 SDL initialized successfully\\n\00\00"
+@str_Creating_window__800x600_____n = constant [32 x i8] c"Creating window (800x600)...\\n\00\00"
+@str_Wheel_SDL_Demo = constant [16 x i8] c"Wheel SDL Demo\00\00"
+@str___Window_created_n = constant [22 x i8] c"\E2\9C\93 Window created\\n\00\00"
+@str_Drawing_graphics____n = constant [23 x i8] c"Drawing graphics...\\n\00\00"
+@str___Graphics_rendered_n = constant [25 x i8] c"\E2\9C\93 Graphics rendered\\n\00\00"
+@str_Cleaning_up____n = constant [18 x i8] c"Cleaning up...\\n\00\00"
+@str___SDL_closed_n = constant [18 x i8] c"\E2\9C\93 SDL closed\\n\00\00"
+@str___Failed_to_initialize_SDL_n = constant [32 x i8] c"\E2\9C\97 Failed to initialize SDL\\n\00\00"
+@str__nDemo_finished__n = constant [20 x i8] c"\\nDemo finished!\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %window = alloca i64, align 8
+  %init = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_SDL_Graphics_Demo_with_Imports_n)
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__________________________________n_n)
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Initializing_SDL2_Library____n)
+  %call_sdl_init = call i64 @sdl_init()
+  store i64 %call_sdl_init, ptr %init, align 4
+  %load_init = load i64, ptr %init, align 4
+  %eqtmp = icmp eq i64 %load_init, 1
+  %bool_to_i64 = zext i1 %eqtmp to i64
+  %ifcond = icmp ne i64 %bool_to_i64, 0
+  br i1 %ifcond, label %then, label %else
+
+then:                                             ; preds = %entry
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str___SDL_initialized_successfully_n)
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Creating_window__800x600_____n)
+  %call_sdl_create_window = call i64 @sdl_create_window(i64 800, i64 600, i64 ptrtoint (ptr @str_Wheel_SDL_Demo to i64))
+  store i64 %call_sdl_create_window, ptr %window, align 4
+  %load_window = load i64, ptr %window, align 4
+  %eqtmp5 = icmp eq i64 %load_window, 1
+  %bool_to_i646 = zext i1 %eqtmp5 to i64
+  %ifcond7 = icmp ne i64 %bool_to_i646, 0
+  br i1 %ifcond7, label %then8, label %else9
+
+else:                                             ; preds = %entry
+  %call_printf20 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str___Failed_to_initialize_SDL_n)
+  br label %ifend
+
+ifend:                                            ; preds = %else, %ifend10
+  %call_printf21 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__nDemo_finished__n)
+  ret i32 0
+
+then8:                                            ; preds = %then
+  %call_printf11 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str___Window_created_n)
+  %call_printf12 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Drawing_graphics____n)
+  %call_sdl_clear = call i64 @sdl_clear(i64 0, i64 0, i64 0, i64 0)
+  %call_sdl_draw_rect = call i64 @sdl_draw_rect(i64 0, i64 50, i64 50, i64 300, i64 200, i64 255, i64 0, i64 0)
+  %call_sdl_draw_rect13 = call i64 @sdl_draw_rect(i64 0, i64 400, i64 50, i64 300, i64 200, i64 0, i64 255, i64 0)
+  %call_sdl_draw_rect14 = call i64 @sdl_draw_rect(i64 0, i64 50, i64 300, i64 300, i64 200, i64 0, i64 0, i64 255)
+  %call_sdl_draw_rect15 = call i64 @sdl_draw_rect(i64 0, i64 400, i64 300, i64 300, i64 200, i64 255, i64 255, i64 0)
+  %call_printf16 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str___Graphics_rendered_n)
+  %load_window17 = load i64, ptr %window, align 4
+  %call_sdl_present = call i64 @sdl_present(i64 %load_window17)
+  %call_sleep = call i64 @wheel_sleep(i64 3)
+  %call_printf18 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Cleaning_up____n)
+  %call_sdl_destroy_window = call i64 @sdl_destroy_window(i64 0)
+  %call_sdl_quit = call i64 @sdl_quit()
+  %call_printf19 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str___SDL_closed_n)
+  br label %ifend10
+
+else9:                                            ; preds = %then
+  br label %ifend10
+
+ifend10:                                          ; preds = %else9, %then8
+  br label %ifend
+}
+
+declare i64 @sdl_init()
+
+declare i64 @sdl_create_window(i64, i64, i64)
+
+declare i64 @sdl_clear(i64, i64, i64, i64)
+
+declare i64 @sdl_draw_rect(i64, i64, i64, i64, i64, i64, i64, i64)
+
+declare i64 @sdl_present(i64)
+
+declare i64 @wheel_sleep(i64)
+
+declare i64 @sdl_destroy_window(i64)
+
+declare i64 @sdl_quit()
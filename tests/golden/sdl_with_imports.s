@@ -0,0 +1,175 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "SDL Graphics Demo with Imports\\n"
+Lmsg1:
+    .ascii "================================\\n\\n"
+Lmsg2:
+    .ascii "Initializing SDL2 Library...\\n"
+Lmsg3:
+    .ascii "✓ SDL initialized successfully\\n"
+Lmsg4:
+    .ascii "Creating window (800x600)...\\n"
+Lmsg5:
+    .ascii "✓ Window created\\n"
+Lmsg6:
+    .ascii "Drawing graphics...\\n"
+Lmsg7:
+    .ascii "✓ Graphics rendered\\n"
+Lmsg8:
+    .ascii "Cleaning up...\\n"
+Lmsg9:
+    .ascii "✓ SDL closed\\n"
+Lmsg10:
+    .ascii "✗ Failed to initialize SDL\\n"
+Lmsg11:
+    .ascii "\\nDemo finished!\\n"
+    .section .bss
+input_buffer: .space 256
+init: .quad 0
+window: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/sdl_with_imports.wheel"
+    .loc 1 5 1
+    .loc 1 7 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 32
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 8 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 36
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 30
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 11 1
+    mov rax, 0
+    mov qword ptr [rip + init], rax
+    .loc 1 13 1
+    mov rax, qword ptr [rip + init]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lelse_0
+    .loc 1 14 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 34
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 15 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 30
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 17 5
+    mov rax, 0
+    mov qword ptr [rip + window], rax
+    .loc 1 19 5
+    mov rax, qword ptr [rip + window]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_1
+    .loc 1 20 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 20
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 21 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 21
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 24 9
+    .loc 1 27 9
+    .loc 1 28 9
+    .loc 1 29 9
+    .loc 1 30 9
+    .loc 1 32 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg7]
+    mov rdx, 23
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 33 9
+    .loc 1 35 9
+    .loc 1 37 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 16
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 38 9
+    .loc 1 39 9
+    .loc 1 40 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 16
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_1:
+    jmp Lend_0
+Lelse_0:
+    .loc 1 43 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg10]
+    mov rdx, 30
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_0:
+    .loc 1 46 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg11]
+    mov rdx, 18
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
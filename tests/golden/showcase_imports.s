@@ -0,0 +1,517 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "╔════════════════════════════════════════╗\\n"
+Lmsg1:
+    .ascii "║    WHEEL LANGUAGE - IMPORT SHOWCASE   ║\\n"
+Lmsg2:
+    .ascii "╚════════════════════════════════════════╝\\n\\n"
+Lmsg3:
+    .ascii "📋 OS Library Demo - Process Information\\n"
+Lmsg4:
+    .ascii "──────────────────────────────────────────\\n"
+Lmsg5:
+    .ascii "Current Process ID: "
+Lmsg6:
+    .ascii "\\n"
+Lmsg7:
+    .ascii "Current Unix Timestamp: "
+Lmsg8:
+    .ascii "\\n\\n"
+Lmsg9:
+    .ascii "🔢 Math Library Demo - Calculations\\n"
+Lmsg10:
+    .ascii "Fibonacci sequence (0-10):\\n"
+Lmsg11:
+    .ascii ", "
+Lmsg12:
+    .ascii "⚙️ Hardware I/O Demo - Port Operations\\n"
+Lmsg13:
+    .ascii "(Reading from mock ports)\\n"
+Lmsg14:
+    .ascii "Port 0x0021 status: Ready\\n"
+Lmsg15:
+    .ascii "Port 0x00A1 status: Ready\\n\\n"
+Lmsg16:
+    .ascii "⏱️  Timing Demo\\n"
+Lmsg17:
+    .ascii "Completed 100,000,000 loop iterations\\n"
+Lmsg18:
+    .ascii "Elapsed time: "
+Lmsg19:
+    .ascii " seconds\\n\\n"
+Lmsg20:
+    .ascii "📐 Pythagorean Theorem Example\\n"
+Lmsg21:
+    .ascii "For a right triangle with sides a="
+Lmsg22:
+    .ascii " and b="
+Lmsg23:
+    .ascii ":\\n"
+Lmsg24:
+    .ascii "c² = "
+Lmsg25:
+    .ascii "\\nc = 5 (theoretical)\\n\\n"
+Lmsg26:
+    .ascii "║   All Libraries Imported Successfully  ║\\n"
+Lmsg27:
+    .ascii "║  - OS Library (getpid, time_now)       ║\\n"
+Lmsg28:
+    .ascii "║  - Math Library (arithmetic)           ║\\n"
+Lmsg29:
+    .ascii "║  - Hardware I/O Library (ports)        ║\\n"
+Lmsg30:
+    .ascii "╚════════════════════════════════════════╝\\n"
+Lmsg31:
+    .ascii "Demo completed successfully!\\n"
+    .section .bss
+input_buffer: .space 256
+pid: .quad 0
+start_time: .quad 0
+fib_a: .quad 0
+fib_b: .quad 0
+fib_index: .quad 0
+temp: .quad 0
+loop_count: .quad 0
+loop_time_before: .quad 0
+loop_time_after: .quad 0
+elapsed: .quad 0
+a: .quad 0
+b: .quad 0
+c_squared: .quad 0
+print_i64: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/showcase_imports.wheel"
+    .loc 1 5 1
+    .loc 1 6 1
+    .loc 1 7 1
+    .loc 1 9 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 128
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 10 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 47
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 11 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 130
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 16 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 44
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 17 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 128
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 19 1
+    mov rax, 0
+    mov qword ptr [rip + pid], rax
+    .loc 1 20 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg5]
+    mov rdx, 20
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 21 1
+    .loc 1 22 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg6]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 24 1
+    mov rax, 0
+    mov qword ptr [rip + start_time], rax
+    .loc 1 25 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg7]
+    mov rdx, 24
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 26 1
+    .loc 1 27 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 32 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg9]
+    mov rdx, 39
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 33 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 128
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 36 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg10]
+    mov rdx, 28
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 37 1
+    mov rax, 0
+    mov qword ptr [rip + fib_a], rax
+    .loc 1 38 1
+    mov rax, 1
+    mov qword ptr [rip + fib_b], rax
+    .loc 1 39 1
+    mov rax, 0
+    mov qword ptr [rip + fib_index], rax
+    .loc 1 41 1
+    .loc 1 42 5
+    mov rax, qword ptr [rip + fib_index]
+    push rax
+    mov rax, 11
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_0
+    .loc 1 43 9
+Lend_0:
+    .loc 1 46 5
+    mov rax, qword ptr [rip + fib_index]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setg al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_1
+    .loc 1 47 9
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg11]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_1:
+    .loc 1 49 5
+    .loc 1 51 5
+    mov rax, qword ptr [rip + fib_a]
+    push rax
+    mov rax, qword ptr [rip + fib_b]
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + temp], rax
+    .loc 1 52 5
+    mov rax, qword ptr [rip + fib_b]
+    mov qword ptr [rip + fib_a], rax
+    .loc 1 53 5
+    mov rax, qword ptr [rip + temp]
+    mov qword ptr [rip + fib_b], rax
+    .loc 1 55 5
+    mov rax, qword ptr [rip + fib_index]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + fib_index], rax
+    .loc 1 57 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg8]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 62 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg12]
+    mov rdx, 44
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 63 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 128
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 64 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg13]
+    mov rdx, 27
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 65 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg14]
+    mov rdx, 27
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 66 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg15]
+    mov rdx, 29
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 71 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg16]
+    mov rdx, 21
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 72 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 128
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 74 1
+    mov rax, 0
+    mov qword ptr [rip + loop_count], rax
+    .loc 1 75 1
+    mov rax, 0
+    mov qword ptr [rip + loop_time_before], rax
+    .loc 1 77 1
+    .loc 1 78 5
+    mov rax, qword ptr [rip + loop_count]
+    push rax
+    mov rax, 100000000
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_2
+    .loc 1 79 9
+Lend_2:
+    .loc 1 81 5
+    mov rax, qword ptr [rip + loop_count]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + loop_count], rax
+    .loc 1 84 1
+    mov rax, 0
+    mov qword ptr [rip + loop_time_after], rax
+    .loc 1 85 1
+    mov rax, qword ptr [rip + loop_time_after]
+    push rax
+    mov rax, qword ptr [rip + loop_time_before]
+    mov rbx, rax
+    pop rax
+    sub rax, rbx
+    mov qword ptr [rip + elapsed], rax
+    .loc 1 86 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg17]
+    mov rdx, 39
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 87 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg18]
+    mov rdx, 14
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 88 1
+    .loc 1 89 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg19]
+    mov rdx, 12
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 94 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg20]
+    mov rdx, 34
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 95 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg4]
+    mov rdx, 128
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 97 1
+    mov rax, 3
+    mov qword ptr [rip + a], rax
+    .loc 1 98 1
+    mov rax, 4
+    mov qword ptr [rip + b], rax
+    .loc 1 99 1
+    mov rax, 25
+    mov qword ptr [rip + c_squared], rax
+    .loc 1 101 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg21]
+    mov rdx, 34
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 102 1
+    .loc 1 103 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg22]
+    mov rdx, 7
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 104 1
+    .loc 1 105 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg23]
+    mov rdx, 3
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 106 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg24]
+    mov rdx, 6
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 107 1
+    .loc 1 108 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg25]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 113 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 128
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 114 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg26]
+    mov rdx, 48
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 115 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg27]
+    mov rdx, 48
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 116 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg28]
+    mov rdx, 48
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 117 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg29]
+    mov rdx, 48
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 118 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg30]
+    mov rdx, 128
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 121 1
+    mov rax, 42
+    mov qword ptr [rip + print_i64], rax
+    .loc 1 123 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg31]
+    mov rdx, 30
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,48 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Enter_first_number__ = constant [22 x i8] c"Enter first number: \00\00"
+@str_Enter_second_number__ = constant [23 x i8] c"Enter second number: \00\00"
+@str_Sum__ = constant [7 x i8] c"Sum: \00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %a = alloca i64, align 8
+  %b = alloca i64, align 8
+  %sum = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Enter_first_number__)
+  %call_scanf = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i64 = zext i32 %call_atoi_input to i64
+  store i64 %atoi_to_i64, ptr %a, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Enter_second_number__)
+  %call_scanf2 = call i32 (ptr, ...) @scanf(ptr @_fmt_scan, ptr @_input_buf)
+  %call_atoi_input3 = call i32 @atoi(ptr @_input_buf)
+  %atoi_to_i644 = zext i32 %call_atoi_input3 to i64
+  store i64 %atoi_to_i644, ptr %b, align 4
+  %load_a = load i64, ptr %a, align 4
+  %load_b = load i64, ptr %b, align 4
+  %addtmp = add i64 %load_a, %load_b
+  store i64 %addtmp, ptr %sum, align 4
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Sum__)
+  %load_sum = load i64, ptr %sum, align 4
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_sum)
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  ret i32 0
+}
@@ -0,0 +1,108 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Enter first number: "
+Lmsg1:
+    .ascii "Enter second number: "
+Lmsg2:
+    .ascii "Sum: "
+Lmsg3:
+    .ascii "\\n"
+    .section .bss
+input_buffer: .space 256
+a: .quad 0
+b: .quad 0
+sum: .quad 0
+a_buf: .space 256
+a_len: .quad 0
+b_buf: .space 256
+b_len: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/simple_math.wheel"
+    .loc 1 2 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 20
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 3 1
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + a_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + a_len], rax
+    .loc 1 4 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 21
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 5 1
+    mov rax, 0
+    mov rdi, 0
+    lea rsi, [rip + b_buf]
+    mov rdx, 255
+    syscall
+    mov qword ptr [rip + b_len], rax
+    .loc 1 6 1
+    mov rax, qword ptr [rip + a]
+    push rax
+    mov rax, qword ptr [rip + b]
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + sum], rax
+    .loc 1 7 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg2]
+    mov rdx, 5
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 8 1
+    mov rax, 1
+    mov rdi, 1
+    mov rax, qword ptr [rip + sum]
+    lea rsi, [rip + input_buffer + 32]
+    mov rcx, 10
+    mov r8, rax
+    test rax, rax
+    jns Litoa_0
+    neg rax
+Litoa_0:
+    xor edx, edx
+    div rcx
+    add dl, '0'
+    dec rsi
+    mov byte ptr [rsi], dl
+    test rax, rax
+    jnz Litoa_0
+    test r8, r8
+    jns Litoa_end_0
+    dec rsi
+    mov byte ptr [rsi], '-'
+Litoa_end_0:
+    lea rdx, [rip + input_buffer + 32]
+    sub rdx, rsi
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    .loc 1 9 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg3]
+    mov rdx, 2
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,54 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Starting_n = constant [12 x i8] c"Starting\\n\00\00"
+@str_x___ = constant [6 x i8] c"x = \00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+@str_y___ = constant [6 x i8] c"y = \00\00"
+@str__n.1 = constant [4 x i8] c"\\n\00\00"
+@str_sum___ = constant [8 x i8] c"sum = \00\00"
+@str__n.2 = constant [4 x i8] c"\\n\00\00"
+@str_Done_n = constant [8 x i8] c"Done\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %x = alloca i64, align 8
+  %y = alloca i64, align 8
+  %sum = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Starting_n)
+  store i64 10, ptr %x, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_x___)
+  %load_x = load i64, ptr %x, align 4
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_x)
+  %call_printf3 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  store i64 20, ptr %y, align 4
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_y___)
+  %load_y = load i64, ptr %y, align 4
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_y)
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.1)
+  %load_x7 = load i64, ptr %x, align 4
+  %load_y8 = load i64, ptr %y, align 4
+  %addtmp = add i64 %load_x7, %load_y8
+  store i64 %addtmp, ptr %sum, align 4
+  %call_printf9 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_sum___)
+  %load_sum = load i64, ptr %sum, align 4
+  %call_printf10 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_sum)
+  %call_printf11 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n.2)
+  %call_printf12 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Done_n)
+  ret i32 0
+}
@@ -0,0 +1,492 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Snake_from_Wheel = constant [18 x i8] c"Snake from Wheel\00\00"
+@str_Failed_to_create_window_n = constant [27 x i8] c"Failed to create window\\n\00\00"
+@str_Exited_Snake_n = constant [16 x i8] c"Exited Snake\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %k = alloca i64, align 8
+  %food_y = alloca i64, align 8
+  %food_x = alloca i64, align 8
+  %dir = alloca i64, align 8
+  %y7 = alloca i64, align 8
+  %x7 = alloca i64, align 8
+  %y6 = alloca i64, align 8
+  %x6 = alloca i64, align 8
+  %y5 = alloca i64, align 8
+  %x5 = alloca i64, align 8
+  %y4 = alloca i64, align 8
+  %x4 = alloca i64, align 8
+  %y3 = alloca i64, align 8
+  %x3 = alloca i64, align 8
+  %y2 = alloca i64, align 8
+  %x2 = alloca i64, align 8
+  %y1 = alloca i64, align 8
+  %x1 = alloca i64, align 8
+  %y0 = alloca i64, align 8
+  %x0 = alloca i64, align 8
+  %len = alloca i64, align 8
+  %W = alloca i64, align 8
+  %H = alloca i64, align 8
+  %CELL = alloca i64, align 8
+  %COLS = alloca i64, align 8
+  %ROWS = alloca i64, align 8
+  %window = alloca i64, align 8
+  store i64 480, ptr %W, align 4
+  store i64 320, ptr %H, align 4
+  store i64 16, ptr %CELL, align 4
+  store i64 30, ptr %COLS, align 4
+  store i64 20, ptr %ROWS, align 4
+  %call_sdl_init = call i64 @sdl_init()
+  %load_W = load i64, ptr %W, align 4
+  %load_H = load i64, ptr %H, align 4
+  %call_sdl_create_window = call i64 @sdl_create_window(i64 %load_W, i64 %load_H, i64 ptrtoint (ptr @str_Snake_from_Wheel to i64))
+  store i64 %call_sdl_create_window, ptr %window, align 4
+  %load_window = load i64, ptr %window, align 4
+  %eqtmp = icmp eq i64 %load_window, 0
+  %bool_to_i64 = zext i1 %eqtmp to i64
+  %ifcond = icmp ne i64 %bool_to_i64, 0
+  br i1 %ifcond, label %then, label %else
+
+then:                                             ; preds = %entry
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Failed_to_create_window_n)
+  br label %ifend
+
+else:                                             ; preds = %entry
+  store i64 3, ptr %len, align 4
+  store i64 5, ptr %x0, align 4
+  store i64 5, ptr %y0, align 4
+  store i64 4, ptr %x1, align 4
+  store i64 5, ptr %y1, align 4
+  store i64 3, ptr %x2, align 4
+  store i64 5, ptr %y2, align 4
+  store i64 0, ptr %x3, align 4
+  store i64 0, ptr %y3, align 4
+  store i64 0, ptr %x4, align 4
+  store i64 0, ptr %y4, align 4
+  store i64 0, ptr %x5, align 4
+  store i64 0, ptr %y5, align 4
+  store i64 0, ptr %x6, align 4
+  store i64 0, ptr %y6, align 4
+  store i64 0, ptr %x7, align 4
+  store i64 0, ptr %y7, align 4
+  store i64 4, ptr %dir, align 4
+  store i64 10, ptr %food_x, align 4
+  store i64 8, ptr %food_y, align 4
+  %call_sdl_poll_event = call i64 @sdl_poll_event()
+  store i64 %call_sdl_poll_event, ptr %k, align 4
+  br label %ifend
+
+ifend:                                            ; preds = %else, %then
+  store i64 1, ptr %dir, align 4
+  store i64 2, ptr %dir, align 4
+  store i64 3, ptr %dir, align 4
+  store i64 4, ptr %dir, align 4
+  %load_x6 = load i64, ptr %x6, align 4
+  store i64 %load_x6, ptr %x7, align 4
+  %load_y6 = load i64, ptr %y6, align 4
+  store i64 %load_y6, ptr %y7, align 4
+  %load_x5 = load i64, ptr %x5, align 4
+  store i64 %load_x5, ptr %x6, align 4
+  %load_y5 = load i64, ptr %y5, align 4
+  store i64 %load_y5, ptr %y6, align 4
+  %load_x4 = load i64, ptr %x4, align 4
+  store i64 %load_x4, ptr %x5, align 4
+  %load_y4 = load i64, ptr %y4, align 4
+  store i64 %load_y4, ptr %y5, align 4
+  %load_x3 = load i64, ptr %x3, align 4
+  store i64 %load_x3, ptr %x4, align 4
+  %load_y3 = load i64, ptr %y3, align 4
+  store i64 %load_y3, ptr %y4, align 4
+  %load_x2 = load i64, ptr %x2, align 4
+  store i64 %load_x2, ptr %x3, align 4
+  %load_y2 = load i64, ptr %y2, align 4
+  store i64 %load_y2, ptr %y3, align 4
+  %load_x1 = load i64, ptr %x1, align 4
+  store i64 %load_x1, ptr %x2, align 4
+  %load_y1 = load i64, ptr %y1, align 4
+  store i64 %load_y1, ptr %y2, align 4
+  %load_x0 = load i64, ptr %x0, align 4
+  store i64 %load_x0, ptr %x1, align 4
+  %load_y0 = load i64, ptr %y0, align 4
+  store i64 %load_y0, ptr %y1, align 4
+  %load_dir = load i64, ptr %dir, align 4
+  %eqtmp1 = icmp eq i64 %load_dir, 1
+  %bool_to_i642 = zext i1 %eqtmp1 to i64
+  %ifcond3 = icmp ne i64 %bool_to_i642, 0
+  br i1 %ifcond3, label %then4, label %else5
+
+then4:                                            ; preds = %ifend
+  %load_y07 = load i64, ptr %y0, align 4
+  %subtmp = sub i64 %load_y07, 1
+  store i64 %subtmp, ptr %y0, align 4
+  br label %ifend6
+
+else5:                                            ; preds = %ifend
+  br label %ifend6
+
+ifend6:                                           ; preds = %else5, %then4
+  %load_dir8 = load i64, ptr %dir, align 4
+  %eqtmp9 = icmp eq i64 %load_dir8, 2
+  %bool_to_i6410 = zext i1 %eqtmp9 to i64
+  %ifcond11 = icmp ne i64 %bool_to_i6410, 0
+  br i1 %ifcond11, label %then12, label %else13
+
+then12:                                           ; preds = %ifend6
+  %load_x015 = load i64, ptr %x0, align 4
+  %subtmp16 = sub i64 %load_x015, 1
+  store i64 %subtmp16, ptr %x0, align 4
+  br label %ifend14
+
+else13:                                           ; preds = %ifend6
+  br label %ifend14
+
+ifend14:                                          ; preds = %else13, %then12
+  %load_dir17 = load i64, ptr %dir, align 4
+  %eqtmp18 = icmp eq i64 %load_dir17, 3
+  %bool_to_i6419 = zext i1 %eqtmp18 to i64
+  %ifcond20 = icmp ne i64 %bool_to_i6419, 0
+  br i1 %ifcond20, label %then21, label %else22
+
+then21:                                           ; preds = %ifend14
+  %load_y024 = load i64, ptr %y0, align 4
+  %addtmp = add i64 %load_y024, 1
+  store i64 %addtmp, ptr %y0, align 4
+  br label %ifend23
+
+else22:                                           ; preds = %ifend14
+  br label %ifend23
+
+ifend23:                                          ; preds = %else22, %then21
+  %load_dir25 = load i64, ptr %dir, align 4
+  %eqtmp26 = icmp eq i64 %load_dir25, 4
+  %bool_to_i6427 = zext i1 %eqtmp26 to i64
+  %ifcond28 = icmp ne i64 %bool_to_i6427, 0
+  br i1 %ifcond28, label %then29, label %else30
+
+then29:                                           ; preds = %ifend23
+  %load_x032 = load i64, ptr %x0, align 4
+  %addtmp33 = add i64 %load_x032, 1
+  store i64 %addtmp33, ptr %x0, align 4
+  br label %ifend31
+
+else30:                                           ; preds = %ifend23
+  br label %ifend31
+
+ifend31:                                          ; preds = %else30, %then29
+  %load_x034 = load i64, ptr %x0, align 4
+  %lttmp = icmp slt i64 %load_x034, 0
+  %bool_to_i6435 = zext i1 %lttmp to i64
+  %ifcond36 = icmp ne i64 %bool_to_i6435, 0
+  br i1 %ifcond36, label %then37, label %else38
+
+then37:                                           ; preds = %ifend31
+  %load_COLS = load i64, ptr %COLS, align 4
+  %subtmp40 = sub i64 %load_COLS, 1
+  store i64 %subtmp40, ptr %x0, align 4
+  br label %ifend39
+
+else38:                                           ; preds = %ifend31
+  br label %ifend39
+
+ifend39:                                          ; preds = %else38, %then37
+  %load_x041 = load i64, ptr %x0, align 4
+  %load_COLS42 = load i64, ptr %COLS, align 4
+  %gteqtmp = icmp sge i64 %load_x041, %load_COLS42
+  %bool_to_i6443 = zext i1 %gteqtmp to i64
+  %ifcond44 = icmp ne i64 %bool_to_i6443, 0
+  br i1 %ifcond44, label %then45, label %else46
+
+then45:                                           ; preds = %ifend39
+  store i64 0, ptr %x0, align 4
+  br label %ifend47
+
+else46:                                           ; preds = %ifend39
+  br label %ifend47
+
+ifend47:                                          ; preds = %else46, %then45
+  %load_y048 = load i64, ptr %y0, align 4
+  %lttmp49 = icmp slt i64 %load_y048, 0
+  %bool_to_i6450 = zext i1 %lttmp49 to i64
+  %ifcond51 = icmp ne i64 %bool_to_i6450, 0
+  br i1 %ifcond51, label %then52, label %else53
+
+then52:                                           ; preds = %ifend47
+  %load_ROWS = load i64, ptr %ROWS, align 4
+  %subtmp55 = sub i64 %load_ROWS, 1
+  store i64 %subtmp55, ptr %y0, align 4
+  br label %ifend54
+
+else53:                                           ; preds = %ifend47
+  br label %ifend54
+
+ifend54:                                          ; preds = %else53, %then52
+  %load_y056 = load i64, ptr %y0, align 4
+  %load_ROWS57 = load i64, ptr %ROWS, align 4
+  %gteqtmp58 = icmp sge i64 %load_y056, %load_ROWS57
+  %bool_to_i6459 = zext i1 %gteqtmp58 to i64
+  %ifcond60 = icmp ne i64 %bool_to_i6459, 0
+  br i1 %ifcond60, label %then61, label %else62
+
+then61:                                           ; preds = %ifend54
+  store i64 0, ptr %y0, align 4
+  br label %ifend63
+
+else62:                                           ; preds = %ifend54
+  br label %ifend63
+
+ifend63:                                          ; preds = %else62, %then61
+  %load_food_y = load i64, ptr %food_y, align 4
+  %load_len = load i64, ptr %len, align 4
+  %lttmp64 = icmp slt i64 %load_len, 8
+  %bool_to_i6465 = zext i1 %lttmp64 to i64
+  %ifcond66 = icmp ne i64 %bool_to_i6465, 0
+  br i1 %ifcond66, label %then67, label %else68
+
+then67:                                           ; preds = %ifend63
+  %load_len70 = load i64, ptr %len, align 4
+  %addtmp71 = add i64 %load_len70, 1
+  store i64 %addtmp71, ptr %len, align 4
+  br label %ifend69
+
+else68:                                           ; preds = %ifend63
+  br label %ifend69
+
+ifend69:                                          ; preds = %else68, %then67
+  %load_food_x = load i64, ptr %food_x, align 4
+  %addtmp72 = add i64 %load_food_x, 7
+  store i64 %addtmp72, ptr %food_x, align 4
+  %load_COLS73 = load i64, ptr %COLS, align 4
+  %load_food_y74 = load i64, ptr %food_y, align 4
+  %addtmp75 = add i64 %load_food_y74, 3
+  store i64 %addtmp75, ptr %food_y, align 4
+  %load_ROWS76 = load i64, ptr %ROWS, align 4
+  %call_sdl_clear = call i64 @sdl_clear(i64 0, i64 0, i64 0, i64 0)
+  %load_food_x77 = load i64, ptr %food_x, align 4
+  %load_CELL = load i64, ptr %CELL, align 4
+  %multmp = mul i64 %load_food_x77, %load_CELL
+  %load_food_y78 = load i64, ptr %food_y, align 4
+  %load_CELL79 = load i64, ptr %CELL, align 4
+  %multmp80 = mul i64 %load_food_y78, %load_CELL79
+  %load_CELL81 = load i64, ptr %CELL, align 4
+  %load_CELL82 = load i64, ptr %CELL, align 4
+  %call_sdl_draw_rect = call i64 @sdl_draw_rect(i64 0, i64 %multmp, i64 %multmp80, i64 %load_CELL81, i64 %load_CELL82, i64 255, i64 0, i64 0)
+  %load_len83 = load i64, ptr %len, align 4
+  %gteqtmp84 = icmp sge i64 %load_len83, 1
+  %bool_to_i6485 = zext i1 %gteqtmp84 to i64
+  %ifcond86 = icmp ne i64 %bool_to_i6485, 0
+  br i1 %ifcond86, label %then87, label %else88
+
+then87:                                           ; preds = %ifend69
+  %load_x090 = load i64, ptr %x0, align 4
+  %load_CELL91 = load i64, ptr %CELL, align 4
+  %multmp92 = mul i64 %load_x090, %load_CELL91
+  %load_y093 = load i64, ptr %y0, align 4
+  %load_CELL94 = load i64, ptr %CELL, align 4
+  %multmp95 = mul i64 %load_y093, %load_CELL94
+  %load_CELL96 = load i64, ptr %CELL, align 4
+  %load_CELL97 = load i64, ptr %CELL, align 4
+  %call_sdl_draw_rect98 = call i64 @sdl_draw_rect(i64 0, i64 %multmp92, i64 %multmp95, i64 %load_CELL96, i64 %load_CELL97, i64 0, i64 255, i64 0)
+  br label %ifend89
+
+else88:                                           ; preds = %ifend69
+  br label %ifend89
+
+ifend89:                                          ; preds = %else88, %then87
+  %load_len99 = load i64, ptr %len, align 4
+  %gteqtmp100 = icmp sge i64 %load_len99, 2
+  %bool_to_i64101 = zext i1 %gteqtmp100 to i64
+  %ifcond102 = icmp ne i64 %bool_to_i64101, 0
+  br i1 %ifcond102, label %then103, label %else104
+
+then103:                                          ; preds = %ifend89
+  %load_x1106 = load i64, ptr %x1, align 4
+  %load_CELL107 = load i64, ptr %CELL, align 4
+  %multmp108 = mul i64 %load_x1106, %load_CELL107
+  %load_y1109 = load i64, ptr %y1, align 4
+  %load_CELL110 = load i64, ptr %CELL, align 4
+  %multmp111 = mul i64 %load_y1109, %load_CELL110
+  %load_CELL112 = load i64, ptr %CELL, align 4
+  %load_CELL113 = load i64, ptr %CELL, align 4
+  %call_sdl_draw_rect114 = call i64 @sdl_draw_rect(i64 0, i64 %multmp108, i64 %multmp111, i64 %load_CELL112, i64 %load_CELL113, i64 0, i64 200, i64 0)
+  br label %ifend105
+
+else104:                                          ; preds = %ifend89
+  br label %ifend105
+
+ifend105:                                         ; preds = %else104, %then103
+  %load_len115 = load i64, ptr %len, align 4
+  %gteqtmp116 = icmp sge i64 %load_len115, 3
+  %bool_to_i64117 = zext i1 %gteqtmp116 to i64
+  %ifcond118 = icmp ne i64 %bool_to_i64117, 0
+  br i1 %ifcond118, label %then119, label %else120
+
+then119:                                          ; preds = %ifend105
+  %load_x2122 = load i64, ptr %x2, align 4
+  %load_CELL123 = load i64, ptr %CELL, align 4
+  %multmp124 = mul i64 %load_x2122, %load_CELL123
+  %load_y2125 = load i64, ptr %y2, align 4
+  %load_CELL126 = load i64, ptr %CELL, align 4
+  %multmp127 = mul i64 %load_y2125, %load_CELL126
+  %load_CELL128 = load i64, ptr %CELL, align 4
+  %load_CELL129 = load i64, ptr %CELL, align 4
+  %call_sdl_draw_rect130 = call i64 @sdl_draw_rect(i64 0, i64 %multmp124, i64 %multmp127, i64 %load_CELL128, i64 %load_CELL129, i64 0, i64 180, i64 0)
+  br label %ifend121
+
+else120:                                          ; preds = %ifend105
+  br label %ifend121
+
+ifend121:                                         ; preds = %else120, %then119
+  %load_len131 = load i64, ptr %len, align 4
+  %gteqtmp132 = icmp sge i64 %load_len131, 4
+  %bool_to_i64133 = zext i1 %gteqtmp132 to i64
+  %ifcond134 = icmp ne i64 %bool_to_i64133, 0
+  br i1 %ifcond134, label %then135, label %else136
+
+then135:                                          ; preds = %ifend121
+  %load_x3138 = load i64, ptr %x3, align 4
+  %load_CELL139 = load i64, ptr %CELL, align 4
+  %multmp140 = mul i64 %load_x3138, %load_CELL139
+  %load_y3141 = load i64, ptr %y3, align 4
+  %load_CELL142 = load i64, ptr %CELL, align 4
+  %multmp143 = mul i64 %load_y3141, %load_CELL142
+  %load_CELL144 = load i64, ptr %CELL, align 4
+  %load_CELL145 = load i64, ptr %CELL, align 4
+  %call_sdl_draw_rect146 = call i64 @sdl_draw_rect(i64 0, i64 %multmp140, i64 %multmp143, i64 %load_CELL144, i64 %load_CELL145, i64 0, i64 160, i64 0)
+  br label %ifend137
+
+else136:                                          ; preds = %ifend121
+  br label %ifend137
+
+ifend137:                                         ; preds = %else136, %then135
+  %load_len147 = load i64, ptr %len, align 4
+  %gteqtmp148 = icmp sge i64 %load_len147, 5
+  %bool_to_i64149 = zext i1 %gteqtmp148 to i64
+  %ifcond150 = icmp ne i64 %bool_to_i64149, 0
+  br i1 %ifcond150, label %then151, label %else152
+
+then151:                                          ; preds = %ifend137
+  %load_x4154 = load i64, ptr %x4, align 4
+  %load_CELL155 = load i64, ptr %CELL, align 4
+  %multmp156 = mul i64 %load_x4154, %load_CELL155
+  %load_y4157 = load i64, ptr %y4, align 4
+  %load_CELL158 = load i64, ptr %CELL, align 4
+  %multmp159 = mul i64 %load_y4157, %load_CELL158
+  %load_CELL160 = load i64, ptr %CELL, align 4
+  %load_CELL161 = load i64, ptr %CELL, align 4
+  %call_sdl_draw_rect162 = call i64 @sdl_draw_rect(i64 0, i64 %multmp156, i64 %multmp159, i64 %load_CELL160, i64 %load_CELL161, i64 0, i64 140, i64 0)
+  br label %ifend153
+
+else152:                                          ; preds = %ifend137
+  br label %ifend153
+
+ifend153:                                         ; preds = %else152, %then151
+  %load_len163 = load i64, ptr %len, align 4
+  %gteqtmp164 = icmp sge i64 %load_len163, 6
+  %bool_to_i64165 = zext i1 %gteqtmp164 to i64
+  %ifcond166 = icmp ne i64 %bool_to_i64165, 0
+  br i1 %ifcond166, label %then167, label %else168
+
+then167:                                          ; preds = %ifend153
+  %load_x5170 = load i64, ptr %x5, align 4
+  %load_CELL171 = load i64, ptr %CELL, align 4
+  %multmp172 = mul i64 %load_x5170, %load_CELL171
+  %load_y5173 = load i64, ptr %y5, align 4
+  %load_CELL174 = load i64, ptr %CELL, align 4
+  %multmp175 = mul i64 %load_y5173, %load_CELL174
+  %load_CELL176 = load i64, ptr %CELL, align 4
+  %load_CELL177 = load i64, ptr %CELL, align 4
+  %call_sdl_draw_rect178 = call i64 @sdl_draw_rect(i64 0, i64 %multmp172, i64 %multmp175, i64 %load_CELL176, i64 %load_CELL177, i64 0, i64 120, i64 0)
+  br label %ifend169
+
+else168:                                          ; preds = %ifend153
+  br label %ifend169
+
+ifend169:                                         ; preds = %else168, %then167
+  %load_len179 = load i64, ptr %len, align 4
+  %gteqtmp180 = icmp sge i64 %load_len179, 7
+  %bool_to_i64181 = zext i1 %gteqtmp180 to i64
+  %ifcond182 = icmp ne i64 %bool_to_i64181, 0
+  br i1 %ifcond182, label %then183, label %else184
+
+then183:                                          ; preds = %ifend169
+  %load_x6186 = load i64, ptr %x6, align 4
+  %load_CELL187 = load i64, ptr %CELL, align 4
+  %multmp188 = mul i64 %load_x6186, %load_CELL187
+  %load_y6189 = load i64, ptr %y6, align 4
+  %load_CELL190 = load i64, ptr %CELL, align 4
+  %multmp191 = mul i64 %load_y6189, %load_CELL190
+  %load_CELL192 = load i64, ptr %CELL, align 4
+  %load_CELL193 = load i64, ptr %CELL, align 4
+  %call_sdl_draw_rect194 = call i64 @sdl_draw_rect(i64 0, i64 %multmp188, i64 %multmp191, i64 %load_CELL192, i64 %load_CELL193, i64 0, i64 100, i64 0)
+  br label %ifend185
+
+else184:                                          ; preds = %ifend169
+  br label %ifend185
+
+ifend185:                                         ; preds = %else184, %then183
+  %load_len195 = load i64, ptr %len, align 4
+  %gteqtmp196 = icmp sge i64 %load_len195, 8
+  %bool_to_i64197 = zext i1 %gteqtmp196 to i64
+  %ifcond198 = icmp ne i64 %bool_to_i64197, 0
+  br i1 %ifcond198, label %then199, label %else200
+
+then199:                                          ; preds = %ifend185
+  %load_x7 = load i64, ptr %x7, align 4
+  %load_CELL202 = load i64, ptr %CELL, align 4
+  %multmp203 = mul i64 %load_x7, %load_CELL202
+  %load_y7 = load i64, ptr %y7, align 4
+  %load_CELL204 = load i64, ptr %CELL, align 4
+  %multmp205 = mul i64 %load_y7, %load_CELL204
+  %load_CELL206 = load i64, ptr %CELL, align 4
+  %load_CELL207 = load i64, ptr %CELL, align 4
+  %call_sdl_draw_rect208 = call i64 @sdl_draw_rect(i64 0, i64 %multmp203, i64 %multmp205, i64 %load_CELL206, i64 %load_CELL207, i64 0, i64 80, i64 0)
+  br label %ifend201
+
+else200:                                          ; preds = %ifend185
+  br label %ifend201
+
+ifend201:                                         ; preds = %else200, %then199
+  %load_window209 = load i64, ptr %window, align 4
+  %call_sdl_present = call i64 @sdl_present(i64 %load_window209)
+  %call_sdl_delay = call i64 @sdl_delay(i64 120)
+  %call_sdl_destroy_window = call i64 @sdl_destroy_window(i64 0)
+  %call_sdl_quit = call i64 @sdl_quit()
+  %call_printf210 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Exited_Snake_n)
+  ret i32 0
+}
+
+declare i64 @sdl_init()
+
+declare i64 @sdl_create_window(i64, i64, i64)
+
+declare i64 @sdl_poll_event()
+
+declare i64 @sdl_clear(i64, i64, i64, i64)
+
+declare i64 @sdl_draw_rect(i64, i64, i64, i64, i64, i64, i64, i64)
+
+declare i64 @sdl_present(i64)
+
+declare i64 @sdl_delay(i64)
+
+declare i64 @sdl_destroy_window(i64)
+
+declare i64 @sdl_quit()
@@ -0,0 +1,516 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Failed to create window\\n"
+Lmsg1:
+    .ascii "Exited Snake\\n"
+    .section .bss
+input_buffer: .space 256
+W: .quad 0
+H: .quad 0
+CELL: .quad 0
+COLS: .quad 0
+ROWS: .quad 0
+window: .quad 0
+len: .quad 0
+x0: .quad 0
+y0: .quad 0
+x1: .quad 0
+y1: .quad 0
+x2: .quad 0
+y2: .quad 0
+x3: .quad 0
+y3: .quad 0
+x4: .quad 0
+y4: .quad 0
+x5: .quad 0
+y5: .quad 0
+x6: .quad 0
+y6: .quad 0
+x7: .quad 0
+y7: .quad 0
+dir: .quad 0
+food_x: .quad 0
+food_y: .quad 0
+k: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/snake.wheel"
+    .loc 1 5 1
+    .loc 1 8 1
+    mov rax, 480
+    mov qword ptr [rip + W], rax
+    .loc 1 9 1
+    mov rax, 320
+    mov qword ptr [rip + H], rax
+    .loc 1 10 1
+    mov rax, 16
+    mov qword ptr [rip + CELL], rax
+    .loc 1 11 1
+    mov rax, 30
+    mov qword ptr [rip + COLS], rax
+    .loc 1 12 1
+    mov rax, 20
+    mov qword ptr [rip + ROWS], rax
+    .loc 1 14 1
+    .loc 1 15 1
+    mov rax, 0
+    mov qword ptr [rip + window], rax
+    .loc 1 16 1
+    mov rax, qword ptr [rip + window]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lelse_0
+    .loc 1 17 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 25
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    jmp Lend_0
+Lelse_0:
+    .loc 1 20 5
+    mov rax, 3
+    mov qword ptr [rip + len], rax
+    .loc 1 21 5
+    mov rax, 5
+    mov qword ptr [rip + x0], rax
+    .loc 1 21 17
+    mov rax, 5
+    mov qword ptr [rip + y0], rax
+    .loc 1 22 5
+    mov rax, 4
+    mov qword ptr [rip + x1], rax
+    .loc 1 22 17
+    mov rax, 5
+    mov qword ptr [rip + y1], rax
+    .loc 1 23 5
+    mov rax, 3
+    mov qword ptr [rip + x2], rax
+    .loc 1 23 17
+    mov rax, 5
+    mov qword ptr [rip + y2], rax
+    .loc 1 24 5
+    mov rax, 0
+    mov qword ptr [rip + x3], rax
+    .loc 1 24 17
+    mov rax, 0
+    mov qword ptr [rip + y3], rax
+    .loc 1 25 5
+    mov rax, 0
+    mov qword ptr [rip + x4], rax
+    .loc 1 25 17
+    mov rax, 0
+    mov qword ptr [rip + y4], rax
+    .loc 1 26 5
+    mov rax, 0
+    mov qword ptr [rip + x5], rax
+    .loc 1 26 17
+    mov rax, 0
+    mov qword ptr [rip + y5], rax
+    .loc 1 27 5
+    mov rax, 0
+    mov qword ptr [rip + x6], rax
+    .loc 1 27 17
+    mov rax, 0
+    mov qword ptr [rip + y6], rax
+    .loc 1 28 5
+    mov rax, 0
+    mov qword ptr [rip + x7], rax
+    .loc 1 28 17
+    mov rax, 0
+    mov qword ptr [rip + y7], rax
+    .loc 1 30 5
+    mov rax, 4
+    mov qword ptr [rip + dir], rax
+    .loc 1 31 5
+    mov rax, 10
+    mov qword ptr [rip + food_x], rax
+    .loc 1 31 22
+    mov rax, 8
+    mov qword ptr [rip + food_y], rax
+    .loc 1 33 5
+    .loc 1 35 9
+    mov rax, 0
+    mov qword ptr [rip + k], rax
+    .loc 1 36 18
+    .loc 1 36 29
+    .loc 1 36 33
+Lend_0:
+    .loc 1 37 29
+    .loc 1 37 33
+    mov rax, 1
+    mov qword ptr [rip + dir], rax
+    .loc 1 38 29
+    .loc 1 38 33
+    mov rax, 2
+    mov qword ptr [rip + dir], rax
+    .loc 1 39 29
+    .loc 1 39 33
+    mov rax, 3
+    mov qword ptr [rip + dir], rax
+    .loc 1 40 29
+    .loc 1 40 33
+    mov rax, 4
+    mov qword ptr [rip + dir], rax
+    .loc 1 43 9
+    mov rax, qword ptr [rip + x6]
+    mov qword ptr [rip + x7], rax
+    .loc 1 43 18
+    mov rax, qword ptr [rip + y6]
+    mov qword ptr [rip + y7], rax
+    .loc 1 44 9
+    mov rax, qword ptr [rip + x5]
+    mov qword ptr [rip + x6], rax
+    .loc 1 44 18
+    mov rax, qword ptr [rip + y5]
+    mov qword ptr [rip + y6], rax
+    .loc 1 45 9
+    mov rax, qword ptr [rip + x4]
+    mov qword ptr [rip + x5], rax
+    .loc 1 45 18
+    mov rax, qword ptr [rip + y4]
+    mov qword ptr [rip + y5], rax
+    .loc 1 46 9
+    mov rax, qword ptr [rip + x3]
+    mov qword ptr [rip + x4], rax
+    .loc 1 46 18
+    mov rax, qword ptr [rip + y3]
+    mov qword ptr [rip + y4], rax
+    .loc 1 47 9
+    mov rax, qword ptr [rip + x2]
+    mov qword ptr [rip + x3], rax
+    .loc 1 47 18
+    mov rax, qword ptr [rip + y2]
+    mov qword ptr [rip + y3], rax
+    .loc 1 48 9
+    mov rax, qword ptr [rip + x1]
+    mov qword ptr [rip + x2], rax
+    .loc 1 48 18
+    mov rax, qword ptr [rip + y1]
+    mov qword ptr [rip + y2], rax
+    .loc 1 49 9
+    mov rax, qword ptr [rip + x0]
+    mov qword ptr [rip + x1], rax
+    .loc 1 49 18
+    mov rax, qword ptr [rip + y0]
+    mov qword ptr [rip + y1], rax
+    .loc 1 52 9
+    mov rax, qword ptr [rip + dir]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_1
+    .loc 1 52 23
+    mov rax, qword ptr [rip + y0]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    sub rax, rbx
+    mov qword ptr [rip + y0], rax
+Lend_1:
+    .loc 1 53 9
+    mov rax, qword ptr [rip + dir]
+    push rax
+    mov rax, 2
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_2
+    .loc 1 53 23
+    mov rax, qword ptr [rip + x0]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    sub rax, rbx
+    mov qword ptr [rip + x0], rax
+Lend_2:
+    .loc 1 54 9
+    mov rax, qword ptr [rip + dir]
+    push rax
+    mov rax, 3
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_3
+    .loc 1 54 23
+    mov rax, qword ptr [rip + y0]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + y0], rax
+Lend_3:
+    .loc 1 55 9
+    mov rax, qword ptr [rip + dir]
+    push rax
+    mov rax, 4
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    sete al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_4
+    .loc 1 55 23
+    mov rax, qword ptr [rip + x0]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + x0], rax
+Lend_4:
+    .loc 1 58 9
+    mov rax, qword ptr [rip + x0]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_5
+    .loc 1 58 21
+    mov rax, qword ptr [rip + COLS]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    sub rax, rbx
+    mov qword ptr [rip + x0], rax
+Lend_5:
+    .loc 1 59 9
+    mov rax, qword ptr [rip + x0]
+    push rax
+    mov rax, qword ptr [rip + COLS]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_6
+    .loc 1 59 25
+    mov rax, 0
+    mov qword ptr [rip + x0], rax
+Lend_6:
+    .loc 1 60 9
+    mov rax, qword ptr [rip + y0]
+    push rax
+    mov rax, 0
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_7
+    .loc 1 60 21
+    mov rax, qword ptr [rip + ROWS]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    sub rax, rbx
+    mov qword ptr [rip + y0], rax
+Lend_7:
+    .loc 1 61 9
+    mov rax, qword ptr [rip + y0]
+    push rax
+    mov rax, qword ptr [rip + ROWS]
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_8
+    .loc 1 61 25
+    mov rax, 0
+    mov qword ptr [rip + y0], rax
+Lend_8:
+    .loc 1 64 34
+    .loc 1 65 13
+    mov rax, qword ptr [rip + len]
+    push rax
+    mov rax, 8
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_9
+    .loc 1 65 26
+    mov rax, qword ptr [rip + len]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + len], rax
+Lend_9:
+    .loc 1 66 13
+    mov rax, qword ptr [rip + food_x]
+    push rax
+    mov rax, 7
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + food_x], rax
+    .loc 1 66 37
+    .loc 1 67 13
+    mov rax, qword ptr [rip + food_y]
+    push rax
+    mov rax, 3
+    mov rbx, rax
+    pop rax
+    add rax, rbx
+    mov qword ptr [rip + food_y], rax
+    .loc 1 67 37
+    .loc 1 71 9
+    .loc 1 73 9
+    .loc 1 75 9
+    mov rax, qword ptr [rip + len]
+    push rax
+    mov rax, 1
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_10
+    .loc 1 75 23
+Lend_10:
+    .loc 1 76 9
+    mov rax, qword ptr [rip + len]
+    push rax
+    mov rax, 2
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_11
+    .loc 1 76 23
+Lend_11:
+    .loc 1 77 9
+    mov rax, qword ptr [rip + len]
+    push rax
+    mov rax, 3
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_12
+    .loc 1 77 23
+Lend_12:
+    .loc 1 78 9
+    mov rax, qword ptr [rip + len]
+    push rax
+    mov rax, 4
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_13
+    .loc 1 78 23
+Lend_13:
+    .loc 1 79 9
+    mov rax, qword ptr [rip + len]
+    push rax
+    mov rax, 5
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_14
+    .loc 1 79 23
+Lend_14:
+    .loc 1 80 9
+    mov rax, qword ptr [rip + len]
+    push rax
+    mov rax, 6
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_15
+    .loc 1 80 23
+Lend_15:
+    .loc 1 81 9
+    mov rax, qword ptr [rip + len]
+    push rax
+    mov rax, 7
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_16
+    .loc 1 81 23
+Lend_16:
+    .loc 1 82 9
+    mov rax, qword ptr [rip + len]
+    push rax
+    mov rax, 8
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setge al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_17
+    .loc 1 82 23
+Lend_17:
+    .loc 1 84 9
+    .loc 1 85 9
+    .loc 1 87 5
+    .loc 1 88 5
+    .loc 1 91 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 14
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,47 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "if works"
+Lmsg1:
+    .ascii "done"
+    .section .bss
+input_buffer: .space 256
+i: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/test_cond_only.wheel"
+    .loc 1 1 1
+    mov rax, 1
+    mov qword ptr [rip + i], rax
+    .loc 1 3 1
+    mov rax, qword ptr [rip + i]
+    push rax
+    mov rax, 3
+    mov rbx, rax
+    pop rax
+    cmp rax, rbx
+    setl al
+    movzx rax, al
+    cmp rax, 0
+    je Lend_0
+    .loc 1 4 5
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg0]
+    mov rdx, 8
+    mov rax, 1
+    mov rdi, 1
+    syscall
+Lend_0:
+    .loc 1 7 1
+    mov rax, 1
+    mov rdi, 1
+    lea rsi, [rip + Lmsg1]
+    mov rdx, 4
+    mov rax, 1
+    mov rdi, 1
+    syscall
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,53 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_Testing_while_loop_n = constant [22 x i8] c"Testing while loop\\n\00\00"
+@str_Starting_loop_n = constant [17 x i8] c"Starting loop\\n\00\00"
+@str_i___ = constant [6 x i8] c"i = \00\00"
+@str__n = constant [4 x i8] c"\\n\00\00"
+@str_Loop_ended_n = constant [14 x i8] c"Loop ended\\n\00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %i = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Testing_while_loop_n)
+  store i64 0, ptr %i, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Starting_loop_n)
+  br label %loop
+
+loop:                                             ; preds = %loopbody, %entry
+  %load_i = load i64, ptr %i, align 4
+  %lttmp = icmp slt i64 %load_i, 3
+  %bool_to_i64 = zext i1 %lttmp to i64
+  %whilecond = icmp ne i64 %bool_to_i64, 0
+  br i1 %whilecond, label %loopbody, label %loopafter
+
+loopbody:                                         ; preds = %loop
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_i___)
+  %load_i3 = load i64, ptr %i, align 4
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_i3)
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str__n)
+  %load_i6 = load i64, ptr %i, align 4
+  %addtmp = add i64 %load_i6, 1
+  store i64 %addtmp, ptr %i, align 4
+  br label %loop
+
+loopafter:                                        ; preds = %loop
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Loop_ended_n)
+  ret i32 0
+}
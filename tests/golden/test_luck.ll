@@ -0,0 +1,50 @@
+; ModuleID = 'wheel_module'
+source_filename = "wheel_module"
+target triple = "x86_64-unknown-linux-gnu"
+
+@_fmt_ld = constant [5 x i8] c"%ld\0A\00"
+@_fmt_s = constant [4 x i8] c"%s\0A\00"
+@_fmt_scan = constant [7 x i8] c"%255s\00\00"
+@_input_buf = global [256 x i8] zeroinitializer
+@str_____Teste_Luck_Random____ = constant [27 x i8] c"=== Teste Luck Random ===\00\00"
+@str_Random_0_10__ = constant [15 x i8] c"Random 0-10: \00\00"
+@str_Random_0_100__ = constant [16 x i8] c"Random 0-100: \00\00"
+@str_Random_1_10__ = constant [15 x i8] c"Random 1-10: \00\00"
+
+declare i32 @printf(ptr, ...)
+
+declare i32 @scanf(ptr, ...)
+
+declare ptr @malloc(i64)
+
+declare i32 @atoi(ptr)
+
+declare i32 @strcmp(ptr, ptr)
+
+define i32 @main(i32 %0, ptr %1) {
+entry:
+  %num1 = alloca i64, align 8
+  %num2 = alloca i64, align 8
+  %num3 = alloca i64, align 8
+  %call_printf = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_____Teste_Luck_Random____)
+  %call_luck_random = call i64 @luck_random(i64 10)
+  store i64 %call_luck_random, ptr %num1, align 4
+  %call_printf1 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Random_0_10__)
+  %load_num1 = load i64, ptr %num1, align 4
+  %call_printf2 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_num1)
+  %call_luck_random3 = call i64 @luck_random(i64 100)
+  store i64 %call_luck_random3, ptr %num2, align 4
+  %call_printf4 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Random_0_100__)
+  %load_num2 = load i64, ptr %num2, align 4
+  %call_printf5 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_num2)
+  %call_luck_random_range = call i64 @luck_random_range(i64 1, i64 10)
+  store i64 %call_luck_random_range, ptr %num3, align 4
+  %call_printf6 = call i32 (ptr, ...) @printf(ptr @_fmt_s, ptr @str_Random_1_10__)
+  %load_num3 = load i64, ptr %num3, align 4
+  %call_printf7 = call i32 (ptr, ...) @printf(ptr @_fmt_ld, i64 %load_num3)
+  ret i32 0
+}
+
+declare i64 @luck_random(i64)
+
+declare i64 @luck_random_range(i64, i64)
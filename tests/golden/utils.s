@@ -0,0 +1,17 @@
+    .intel_syntax noprefix
+    .section .rodata
+Lmsg0:
+    .ascii "Hello from utils!"
+    .section .bss
+input_buffer: .space 256
+greeting: .quad 0
+    .section .text
+    .global _start
+_start:
+    .file 1 "examples/utils.wheel"
+    .loc 1 1 1
+    lea rax, [rip + Lmsg0]
+    mov qword ptr [rip + greeting], rax
+    mov rax, 60
+    xor rdi, rdi
+    syscall
@@ -0,0 +1,211 @@
+// Golden-file tests for the backends: every program under `examples/` is
+// compiled by `wheelc` with `--mode ge` (and `--mode ll` when built with
+// `--features llvm`), and the generated assembly or LLVM IR and the stdout
+// of the built program are compared with the files in `tests/golden/`, so a
+// refactor of `codegen.rs` or `llvm_backend.rs` cannot change either
+// silently. A program that fails to compile has its error recorded instead;
+// examples that cannot run here are listed in `NOT_RUN`, and those a backend
+// is known to miscompile in `UNSUPPORTED`, with the reason.
+//
+// After an intended change, rewrite the files with
+//
+//     WHEEL_BLESS=1 cargo test --test golden_test
+//
+// and review the diff like any other. A plain `cargo test` leaves the `.ll`
+// and `.ll.stdout` files alone; they are written and compared only by
+//
+//     WHEEL_BLESS=1 cargo test --features llvm --test golden_test
+//
+// which needs an LLVM 16 to link against, the version the `llvm` feature's
+// bindings target. Other LLVM versions fold some constant expressions
+// differently, so bless and check the IR with LLVM 16.
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// How long a built example may run; programs waiting for input get an
+/// empty stdin, but some loop on it forever
+const RUN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Examples that cannot run here in the given modes, so only their assembly
+/// and IR are compared
+const NOT_RUN: &[(&str, &[&str], &str)] = &[
+    ("guessing_game", &["ge", "ll"], "asks for guesses until one is right, and stdin is empty"),
+    ("loop_while_simple", &["ge", "ll"], "never increments its loop counter"),
+    ("lucky_game", &["ll"], "asks for guesses until one is right, and stdin is empty"),
+    ("full_calculator", &["ge"], "`--mode ge` compares `input()` and string literals as 0, so it divides by zero"),
+    ("perguntas", &["ge"], "`--mode ge` compares `input()` and string literals as 0, so it takes both branches"),
+    ("hw_serial", &["ll"], "port I/O faults outside ring 0"),
+    ("hwio_simple", &["ll"], "port I/O faults outside ring 0"),
+    ("mmio_irq", &["ge", "ll"], "writes device registers, so it only runs as a `--mode gb` kernel"),
+    ("sdl_draw", &["ll"], "needs SDL2 and a display"),
+    ("sdl_with_imports", &["ll"], "needs SDL2 and a display"),
+    ("snake", &["ll"], "needs SDL2 and a display"),
+];
+
+/// Examples the given modes miscompile: they call functions that are not
+/// defined or not with that many arguments, which `--mode ll` compiles to 0.
+/// Neither their code nor their output is recorded, so the 0 is not blessed
+const UNSUPPORTED: &[(&str, &[&str], &str)] = &[
+    ("bare_metal_demo", &["ll"], "calls `process_get_parent_pid`, which nothing defines"),
+    ("forca_random", &["ll"], "calls `input_char` and `string_*` functions that nothing defines"),
+    ("multi_libs", &["ll"], "`0x3F8` lexes as `0` and `x3F8`, so `port_read_byte` gets two arguments"),
+    ("process_simple", &["ll"], "calls `process_get_parent_pid`, which nothing defines"),
+    ("showcase_imports", &["ll"], "calls `print_i64`, which nothing defines"),
+];
+
+/// Examples whose numbers change from run to run in the given modes; their
+/// digits are masked before comparing
+const VARIES: &[(&str, &[&str], &str)] = &[
+    ("os_demo", &["ll"], "prints its process id"),
+    ("os_simple", &["ll"], "prints its process id and the time"),
+    ("os_syscalls", &["ll"], "prints its process id and the time"),
+    ("test_luck", &["ll"], "prints random numbers"),
+];
+
+/// Whether `stem` is listed in `list` for `mode`.
+fn listed(list: &[(&str, &[&str], &str)], stem: &str, mode: &str) -> bool {
+    list.iter().any(|(name, modes, _)| *name == stem && modes.contains(&mode))
+}
+
+/// `s` with every run of digits replaced by `#`.
+fn mask_numbers(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        if !c.is_ascii_digit() {
+            out.push(c);
+        } else if !out.ends_with('#') {
+            out.push('#');
+        }
+    }
+    out
+}
+
+fn wheelc() -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_wheelc"));
+    cmd.current_dir(env!("CARGO_MANIFEST_DIR"));
+    cmd
+}
+
+fn examples() -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("examples"))
+        .expect("failed to read examples/")
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.extension().is_some_and(|e| e == "wheel"))
+        .map(|p| Path::new("examples").join(p.file_name().unwrap()))
+        .collect();
+    files.sort();
+    files
+}
+
+/// The `error` lines `wheelc` printed; the linker's own output names
+/// temporary files.
+fn errors(stderr: &[u8]) -> String {
+    String::from_utf8_lossy(stderr).lines().filter(|l| l.starts_with("error")).map(|l| format!("{}\n", l)).collect()
+}
+
+/// Stdout of `wheelc <args>`, or its errors if it failed.
+fn emit(example: &Path, args: &[&str]) -> String {
+    let output = wheelc().arg(example).args(args).arg("--no-cache").output().expect("failed to run wheelc");
+    if output.status.success() {
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    } else {
+        format!("[{}]\n{}", output.status, errors(&output.stderr))
+    }
+}
+
+/// Build `example` with `--mode <mode>` and run it; its stdout and how it
+/// ended.
+fn build_and_run(example: &Path, mode: &str, dir: &Path) -> String {
+    let exe = dir.join(format!("{}-{}", example.file_stem().unwrap().to_string_lossy(), mode));
+    let built = wheelc().arg(example).arg("--mode").arg(mode).arg("-o").arg(&exe).arg("--no-cache").output().expect("failed to run wheelc");
+    if !built.status.success() {
+        return format!("[build failed, {}]\n{}", built.status, errors(&built.stderr));
+    }
+
+    let mut child = Command::new(&exe)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to run the built example");
+    let mut stdout = child.stdout.take().unwrap();
+    let reader = std::thread::spawn(move || {
+        let mut out = Vec::new();
+        let _ = stdout.read_to_end(&mut out);
+        out
+    });
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        if start.elapsed() > RUN_TIMEOUT {
+            let _ = child.kill();
+            let _ = child.wait();
+            // what it printed before being killed depends on timing
+            return "[timed out]\n".to_string();
+        }
+        std::thread::sleep(Duration::from_millis(10));
+    };
+    let out = String::from_utf8_lossy(&reader.join().unwrap()).into_owned();
+    if status.success() {
+        out
+    } else {
+        format!("{}\n[{}]\n", out, status)
+    }
+}
+
+/// Compare `actual` with the golden file `name`, or rewrite it under
+/// `WHEEL_BLESS`. Returns what differs.
+fn check(name: &str, actual: &str) -> Option<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(name);
+    if std::env::var_os("WHEEL_BLESS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return None;
+    }
+    let Ok(expected) = fs::read_to_string(&path) else {
+        return Some(format!("{}: missing golden file", name));
+    };
+    if expected == actual {
+        return None;
+    }
+    let (expected, actual): (Vec<_>, Vec<_>) = (expected.lines().collect(), actual.lines().collect());
+    let same = expected.iter().zip(&actual).take_while(|(e, a)| e == a).count();
+    let line = |lines: &[&str]| lines.get(same).map_or("<end>".to_string(), |l| l.to_string());
+    Some(format!("{}:{}: expected {:?}, got {:?}", name, same + 1, line(&expected), line(&actual)))
+}
+
+#[test]
+fn examples_match_their_golden_files() {
+    let dir = std::env::temp_dir().join("wheelc_golden_test");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+
+    let mut diffs = Vec::new();
+    for example in examples() {
+        let stem = example.file_stem().unwrap().to_string_lossy().into_owned();
+        let run = |mode: &str| !listed(NOT_RUN, &stem, mode);
+        let stdout = |mode: &str| {
+            let out = build_and_run(&example, mode, &dir);
+            if listed(VARIES, &stem, mode) { mask_numbers(&out) } else { out }
+        };
+        if !listed(UNSUPPORTED, &stem, "ge") {
+            diffs.extend(check(&format!("{}.s", stem), &emit(&example, &["--emit", "asm"])));
+            if run("ge") {
+                diffs.extend(check(&format!("{}.ge.stdout", stem), &stdout("ge")));
+            }
+        }
+        #[cfg(feature = "llvm")]
+        if !listed(UNSUPPORTED, &stem, "ll") {
+            diffs.extend(check(&format!("{}.ll", stem), &emit(&example, &["--emit", "llvm-ir"])));
+            if run("ll") {
+                diffs.extend(check(&format!("{}.ll.stdout", stem), &stdout("ll")));
+            }
+        }
+    }
+    assert!(diffs.is_empty(), "output changed; rerun with WHEEL_BLESS=1 if that is intended:\n{}", diffs.join("\n"));
+}
@@ -0,0 +1,596 @@
+use std::fs;
+use wheel::diagnostic::Severity;
+use wheel::report::FailureKind;
+use wheel::WheelError;
+
+#[test]
+fn compile_source_inlines_imports_and_reports_warnings() {
+    let dir = std::env::temp_dir().join("wheel_lib_test_compile");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("greet.wheel"), "print(\"hello from lib\");\n").unwrap();
+
+    let src = "import \"greet\";\nlet unused = 5;\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new(dir.join("main.wheel"))).unwrap();
+    assert_eq!(artifacts.imports.modules().len(), 1);
+    assert!(artifacts.warnings.iter().any(|w| w.code == "unused-variable"));
+    assert!(artifacts.asm().contains("hello from lib"));
+
+    let mut opts = wheel::Options::new(dir.join("main.wheel"));
+    opts.allowed_warnings.push("unused-variable".to_string());
+    let artifacts = wheel::compile_source(src, opts).unwrap();
+    assert!(artifacts.warnings.is_empty());
+}
+
+#[test]
+fn std_imports_resolve_to_bundled_modules_and_the_search_path() {
+    let dir = std::env::temp_dir().join("wheel_lib_test_std");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("lib")).unwrap();
+    fs::write(dir.join("lib/util.wheel"), "func twice(x) {\n    return x * 2;\n}\n").unwrap();
+
+    // the bundled modules' constants don't count as unused variables
+    let src = "import \"std/math\";\nimport \"std/strings\";\nprint(int_to_str(clamp(15, 0, 10)));\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new(dir.join("main.wheel"))).unwrap();
+    let modules: Vec<_> = artifacts.imports.modules().iter().map(|p| p.to_string_lossy().replace('\\', "/")).collect();
+    assert_eq!(modules, ["<std>/math.wheel", "<std>/strings.wheel"]);
+    assert_eq!(artifacts.imports.files().count(), 0);
+    assert!(artifacts.warnings.is_empty(), "unexpected warnings: {:?}", artifacts.warnings);
+
+    let mut opts = wheel::Options::new(dir.join("main.wheel"));
+    opts.search_path = vec![dir.join("lib")];
+    let artifacts = wheel::compile_source("import \"util\";\nprint(twice(2));\n", opts).unwrap();
+    assert_eq!(artifacts.imports.modules(), [dir.join("lib/util.wheel")]);
+
+    let mut opts = wheel::Options::new(dir.join("main.wheel"));
+    opts.edition = wheel::edition::Edition::V0_2;
+    opts.builtins = wheel::builtins::Builtins::runtime();
+    let err = wheel::compile_source("extern func int_to_str(n: str) -> str;\n", opts).unwrap_err();
+    assert_eq!(err.diagnostics().iter().map(|d| d.code).collect::<Vec<_>>(), ["extern-mismatch"]);
+}
+
+#[test]
+fn compile_source_errors_carry_their_stage() {
+    let err = wheel::compile_source("func main() {\n", wheel::Options::new("broken.wheel")).unwrap_err();
+    assert_eq!(err.kind(), FailureKind::Parse);
+
+    let err = wheel::compile_source("set total = 1;\n", wheel::Options::new("undeclared.wheel")).unwrap_err();
+    assert_eq!(err.kind(), FailureKind::Semantic);
+
+    let err = wheel::compile_source("print(\"open);\n", wheel::Options::new("lex.wheel")).unwrap_err();
+    let WheelError::Lex { diagnostics } = &err else { panic!("expected a lex error, got {:?}", err) };
+    assert_eq!(diagnostics[0].code, "unterminated-string");
+    assert_eq!(err.kind(), FailureKind::Parse);
+
+    let mut opts = wheel::Options::new("cfg.wheel");
+    opts.edition = wheel::edition::Edition::V0_2;
+    let err = wheel::compile_source("@if 1 + \"a\" { }\n", opts).unwrap_err();
+    assert!(matches!(&err, WheelError::Type { diagnostics } if diagnostics[0].code == "invalid-cfg"), "unexpected error: {:?}", err);
+
+    #[cfg(feature = "native")]
+    {
+        let err = wheel::lint::lint_file(std::path::Path::new("missing.wheel"), Default::default(), &Default::default()).unwrap_err();
+        assert!(matches!(&err, WheelError::Io { path, source } if path.ends_with("missing.wheel") && source.kind() == std::io::ErrorKind::NotFound));
+    }
+}
+
+#[test]
+fn errors_are_structured_diagnostics() {
+    let err = wheel::compile_source("let a = 1;\nfunc main() {\n    print(\"hi\");\n", wheel::Options::new("unclosed.wheel")).unwrap_err();
+    let [d] = err.diagnostics() else { panic!("expected one diagnostic, got {:?}", err.diagnostics()) };
+    assert_eq!((d.severity, d.code), (Severity::Error, "unclosed-block"));
+    assert_eq!(d.file.as_deref(), Some(std::path::Path::new("unclosed.wheel")));
+    assert_eq!(d.span.map(|s| (s.line, s.column)), Some((4, 1)));
+    assert!(!d.suggestions.is_empty());
+
+    let err = wheel::compile_source("let unused = 1;\nset total = 1;\n", wheel::Options::new("check.wheel")).unwrap_err();
+    let codes: Vec<_> = err.diagnostics().iter().map(|d| (d.severity, d.code)).collect();
+    assert_eq!(codes, [(Severity::Error, "undeclared-variable"), (Severity::Warning, "unused-variable")]);
+}
+
+#[test]
+fn token_stream_keeps_comments_and_whitespace() {
+    use wheel::cst::TokenStream;
+    use wheel::lexer::{Token, TriviaKind};
+
+    let src = "#!/usr/bin/env wheelc\n// greet\nlet x = 1;  // one\n\tprint(x); $\n\"open";
+    let stream = TokenStream::lex(src);
+    assert_eq!(stream.to_string(), src);
+    let comments: Vec<_> = stream.comments().map(|c| stream.text(c.span)).collect();
+    assert_eq!(comments, ["// greet", "// one"]);
+    let kinds: Vec<_> = stream.tokens()[0].leading.iter().map(|t| t.kind).collect();
+    assert_eq!(kinds, [TriviaKind::Shebang, TriviaKind::Whitespace, TriviaKind::Comment, TriviaKind::Whitespace]);
+    assert_eq!(stream.tokens().last().unwrap().token, Token::EOF);
+    assert_eq!(stream.diagnostics()[0].code, "unterminated-string");
+
+    let prog = wheel::parser::Parser::from_stream(&stream, Default::default()).parse_program();
+    assert_eq!(prog.items.len(), 3);
+}
+
+#[test]
+fn visitor_and_fold_walk_nested_bodies() {
+    use wheel::ast::{Expr, Stmt};
+    use wheel::codegen::ConstFolder;
+    use wheel::visit::{walk_expr, Fold, Visitor};
+
+    struct Calls(Vec<String>);
+    impl Visitor for Calls {
+        fn visit_expr(&mut self, e: &Expr) {
+            if let Expr::Call { name, .. } = e {
+                self.0.push(name.clone());
+            }
+            walk_expr(self, e);
+        }
+    }
+
+    let src = "func main() {\n    while 1 {\n        if 2 * 3 > 5 { beep(); }\n    }\n}\nboop();\n";
+    let prog = wheel::parser::Parser::with_edition(src, Default::default()).parse_program();
+    let mut calls = Calls(Vec::new());
+    calls.visit_program(&prog);
+    assert_eq!(calls.0, ["beep", "boop"]);
+
+    let folded = ConstFolder.fold_program(prog);
+    let Stmt::Func { body, .. } = &folded.items[0] else { panic!("expected a function") };
+    let Stmt::While { body, .. } = &body[0] else { panic!("expected a loop") };
+    assert!(matches!(&body[0], Stmt::If { cond: Expr::Int(1), .. }), "not folded: {:?}", body[0]);
+}
+
+#[test]
+fn registered_builtins_are_checked_and_linked() {
+    use wheel::builtins::{Builtin, BuiltinConfig, Builtins, ValueType};
+
+    let table: std::collections::BTreeMap<String, BuiltinConfig> = toml::from_str(
+        "clamp = { params = [\"int\", \"int\", \"int\"], returns = \"int\", link = \"native/clamp.o\" }\n\
+         log_line = { params = [\"str\"], link = \"-lgamelog\" }\n",
+    ).unwrap();
+    let mut builtins = Builtins::from_config(&table, std::path::Path::new("proj")).unwrap();
+    assert_eq!(builtins.get("clamp").unwrap().signature(), "clamp(int, int, int) -> int");
+    assert_eq!(builtins.get("wheel_str_substring").unwrap().signature(), "wheel_str_substring(str, int, int) -> str");
+    assert_eq!(builtins.link_args(), [std::path::Path::new("proj/native/clamp.o").to_string_lossy(), "-lgamelog".into()]);
+    assert!(builtins.register(Builtin::new("print", vec![ValueType::Str], None)).is_err());
+
+    let mut opts = wheel::Options::new("game.wheel");
+    opts.builtins = builtins;
+    wheel::compile_source("let x = clamp(5, 0, 3);\nlog_line(\"hi\");\nprint(x);\n", opts.clone()).unwrap();
+    let err = wheel::compile_source("let x = clamp(5, 0);\nprint(x);\n", opts).unwrap_err();
+    let [d] = err.diagnostics() else { panic!("expected one diagnostic, got {:?}", err.diagnostics()) };
+    assert_eq!(d.code, "builtin-arity");
+}
+
+#[test]
+fn extern_funcs_declare_typed_foreign_symbols() {
+    use wheel::ast::{Stmt, Type};
+
+    let mut opts = wheel::Options::new("ffi.wheel");
+    opts.edition = wheel::edition::Edition::V0_2;
+    let artifacts = wheel::compile_source("extern func puts(s: str) -> int;\nlet n = puts(\"hi\");\nprint(n);\n", opts.clone()).unwrap();
+    let Stmt::Extern { name, params, ret } = &artifacts.program.items[0] else { panic!("expected an extern") };
+    assert_eq!(name, "puts");
+    assert!(matches!(params.as_slice(), [(p, Type::Str)] if p == "s"));
+    assert!(matches!(ret, Some(Type::Int)));
+
+    let err = wheel::compile_source("extern func puts(s: str) -> int;\nputs(\"a\", \"b\");\n", opts.clone()).unwrap_err();
+    assert_eq!(err.diagnostics().iter().map(|d| d.code).collect::<Vec<_>>(), ["builtin-arity"]);
+    let err = wheel::compile_source("extern func sleep(s: int);\n", opts).unwrap_err();
+    assert_eq!(err.diagnostics().iter().map(|d| d.code).collect::<Vec<_>>(), ["reserved-name"]);
+    let err = wheel::compile_source("extern func puts(s: str) -> int;\n", wheel::Options::new("ffi.wheel")).unwrap_err();
+    assert_eq!(err.diagnostics()[0].code, "requires-edition");
+}
+
+#[test]
+fn runtime_functions_are_not_reserved_names() {
+    use wheel::builtins::{Builtin, Builtins, ValueType};
+
+    for name in ["vec_push", "regex_match", "sb_new", "process_eof", "arena_new", "sqrt", "log", "map_keys"] {
+        assert!(!wheel::lint::is_builtin(name), "{}", name);
+    }
+    assert!(wheel::lint::is_builtin("luck_random"));
+    let mut builtins = Builtins::runtime();
+    builtins.register(Builtin::new("vec_push", vec![ValueType::Str], None)).unwrap();
+    assert!(builtins.register(Builtin::new("mem_alloc", vec![ValueType::Int], None)).is_err());
+
+    let src = "func vec_len(v) {\n    return 0;\n}\nfunc log(a, b) {\n    return a + b;\n}\nlet map_new = 1;\nlet sleep = 2;\nprint(vec_len(map_new) + log(1, sleep));\n";
+    let findings = wheel::lint::lint_source(src, std::path::Path::new("shadow.wheel"), Default::default(), &Default::default()).unwrap();
+    let shadowed: Vec<_> = findings.iter().filter(|f| f.rule == "shadowed-builtin").map(|f| f.message.as_str()).collect();
+    assert_eq!(shadowed.len(), 1, "{:?}", shadowed);
+    assert!(shadowed[0].contains("`sleep`"), "{:?}", shadowed);
+
+    // checked against the runtime signature even without a registry
+    let mut opts = wheel::Options::new("ffi.wheel");
+    opts.edition = wheel::edition::Edition::V0_2;
+    let err = wheel::compile_source("extern func regex_match(re: int) -> int;\n", opts.clone()).unwrap_err();
+    assert_eq!(err.diagnostics().iter().map(|d| d.code).collect::<Vec<_>>(), ["extern-mismatch"]);
+    wheel::compile_source("extern func regex_match(re: str, s: str) -> int;\nprint(regex_match(\"a\", \"b\"));\n", opts).unwrap();
+}
+
+#[test]
+fn comptime_calls_on_constants_are_folded() {
+    use wheel::ast::{Expr, Stmt};
+
+    let mut opts = wheel::Options::new("boot.wheel");
+    opts.edition = wheel::edition::Edition::V0_2;
+    let src = "comptime func sum(n) {\n    let total = 0;\n    for i in range(n + 1) {\n        set total = total + i;\n    }\n    return total;\n}\nlet table = [sum(2), sum(4 - 1)];\nlet n = 5;\nprint(sum(n));\nlet m = 5;\nset m = m + 1;\nprint(sum(m));\n";
+    let artifacts = wheel::compile_source(src, opts.clone()).unwrap();
+    let Stmt::Let { value: Expr::ArrayLiteral(items), .. } = &artifacts.program.items[1] else { panic!("expected the table") };
+    assert!(matches!(items.as_slice(), [Expr::Int(3), Expr::Int(6)]), "{:?}", items);
+    // a constant `let` folds like the literal it holds
+    let Stmt::Expr(Expr::Call { args, .. }) = &artifacts.program.items[3] else { panic!("expected print") };
+    assert!(matches!(args.as_slice(), [Expr::Int(15)]), "{:?}", args);
+    // an assigned one is a runtime argument, which leaves the call for the backend
+    let Stmt::Expr(Expr::Call { args, .. }) = &artifacts.program.items[6] else { panic!("expected print") };
+    assert!(matches!(args.as_slice(), [Expr::Call { name, .. }] if name == "sum"));
+
+    let err = wheel::compile_source("comptime func make(n) {\n    let v = vec_new();\n    return n;\n}\nprint(make(1));\n", opts.clone()).unwrap_err();
+    let [d] = err.diagnostics() else { panic!("expected one diagnostic, got {:?}", err.diagnostics()) };
+    assert_eq!(d.code, "comptime-error");
+    assert!(d.message.contains("handle"), "{}", d.message);
+
+    let err = wheel::compile_source("comptime func spin(n) {\n    while 1 {\n    }\n    return n;\n}\nprint(spin(1));\n", opts).unwrap_err();
+    let [d] = err.diagnostics() else { panic!("expected one diagnostic, got {:?}", err.diagnostics()) };
+    assert_eq!(d.code, "comptime-error");
+    assert!(d.message.contains("step limit"), "{}", d.message);
+}
+
+#[test]
+fn inline_asm_is_copied_into_the_assembly_and_rejected_elsewhere() {
+    use wheel::ast::InlineTarget;
+
+    let mut opts = wheel::Options::new("halt.wheel");
+    opts.edition = wheel::edition::Edition::V0_2;
+    let artifacts = wheel::compile_source("print(\"bye\");\n@asm(\"    cli\n    hlt\")\n", opts.clone()).unwrap();
+    assert!(artifacts.asm().contains(".loc 1 2 1\n    cli\n    hlt\n"), "{}", artifacts.asm());
+    assert!(wheel::check::check_inline(&artifacts.program, InlineTarget::Asm).is_empty());
+    let errors = wheel::check::check_inline(&artifacts.program, InlineTarget::Llvm);
+    assert_eq!(errors.iter().map(|d| d.code).collect::<Vec<_>>(), ["inline-backend"]);
+
+    let err = wheel::compile_source("func f() {\n    @llvm(\"define i64 @seven() { ret i64 7 }\")\n}\n", opts).unwrap_err();
+    assert_eq!(err.diagnostics().iter().map(|d| d.code).collect::<Vec<_>>(), ["misplaced-inline"]);
+    let err = wheel::compile_source("@asm(\"hlt\")\n", wheel::Options::new("halt.wheel")).unwrap_err();
+    assert_eq!(err.diagnostics()[0].code, "requires-edition");
+}
+
+#[cfg(feature = "native")]
+#[test]
+fn build_helper_writes_header_and_reports_front_end_errors() {
+    let dir = std::env::temp_dir().join("wheel_lib_test_build");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("wheel.toml"), "language = \"0.2\"\n").unwrap();
+    fs::write(dir.join("logic.wheel"), "pub extern func add(a: int, b: int) -> int {\n    return a + b;\n}\n").unwrap();
+    fs::write(dir.join("broken.wheel"), "func main() {\n").unwrap();
+
+    let out = dir.join("out");
+    let result = wheel::build::Build::new(dir.join("logic.wheel")).out_dir(&out).compile();
+    let header = fs::read_to_string(out.join("logic.h")).unwrap();
+    assert!(header.contains("int64_t add(int64_t a, int64_t b);"), "unexpected header: {}", header);
+    #[cfg(not(feature = "llvm"))]
+    assert!(result.unwrap_err().to_string().contains("llvm"));
+    #[cfg(feature = "llvm")]
+    assert!(result.unwrap().archive.exists());
+
+    let err = wheel::build::Build::new(dir.join("broken.wheel")).out_dir(&out).compile().unwrap_err();
+    assert_eq!(err.kind(), FailureKind::Parse);
+}
+
+#[test]
+fn source_map_leads_generated_lines_back_to_statements() {
+    let dir = std::env::temp_dir().join("wheel_lib_test_sourcemap");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("util.wheel"), "print(\"from util\");\n").unwrap();
+
+    let src = "import \"util\";\nlet a = 2;\n@if 1 {\n    print(\"kept\");\n}\nif a > 1 {\n    print(\"big\");\n}\n";
+    let mut opts = wheel::Options::new(dir.join("main.wheel"));
+    opts.edition = wheel::edition::Edition::V0_2;
+    let artifacts = wheel::compile_source(src, opts).unwrap();
+    assert_eq!(artifacts.program.locs.len(), wheel::sourcemap::count(&artifacts.program.items));
+
+    let (asm, map) = artifacts.asm_with_source_map();
+    let lines: Vec<&str> = asm.lines().collect();
+    // 1-based line of the instruction that loads the string `text`
+    let use_of = |text: &str| {
+        let def = lines.iter().position(|l| l.contains(&format!(".ascii \"{}\"", text))).unwrap();
+        let label = lines[def - 1].trim_end_matches(':');
+        lines.iter().position(|l| l.contains(&format!("[rip + {}]", label))).unwrap() + 1
+    };
+    let at = |line: usize| map.lookup(line).map(|l| (l.file.clone().unwrap(), l.span.line, l.span.column));
+    assert_eq!(at(use_of("from util")), Some((dir.join("util.wheel"), 1, 1)));
+    assert_eq!(at(use_of("kept")), Some((dir.join("main.wheel"), 4, 5)));
+    assert_eq!(at(use_of("big")), Some((dir.join("main.wheel"), 7, 5)));
+    assert!(asm.contains(".loc 2 7 5"), "no line directives in:\n{}", asm);
+}
+
+#[test]
+fn built_programs_go_through_the_front_end() {
+    use wheel::builder::*;
+
+    let prog = program([
+        func("sq", ["n"], [return_(ident("n") * ident("n"))]),
+        let_("total", int(0)),
+        for_range("i", int(1), int(4), [set("total", ident("total") + call("sq", [ident("i")]))]),
+        if_else(gt(ident("total"), int(10)), [print(string("big"))], [print(ident("total"))]),
+        print(ident("total")),
+    ]);
+    let artifacts = wheel::compile_program(prog, wheel::Options::new("level.wheel")).unwrap();
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "big\n14\n");
+
+    let err = wheel::compile_program(program([set("missing", int(1))]), wheel::Options::new("level.wheel")).unwrap_err();
+    assert_eq!(err.kind(), FailureKind::Semantic);
+}
+
+#[test]
+fn element_assignments_warn_that_backends_skip_them() {
+    use wheel::builder::*;
+
+    let prog = program([
+        let_("xs", array([int(1), int(2)])),
+        set_index("xs", int(0), int(5)),
+        print(index(ident("xs"), int(0))),
+    ]);
+    let artifacts = wheel::compile_program(prog, wheel::Options::new("xs.wheel")).unwrap();
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "5\n");
+    let warnings = wheel::check::check_compiled(&artifacts.program);
+    assert_eq!(warnings.iter().map(|d| d.code).collect::<Vec<_>>(), ["unsupported-statement"]);
+    assert!(warnings[0].to_string().contains("element of `xs`"), "{}", warnings[0]);
+}
+
+#[test]
+fn interpreter_keeps_state_between_evals() {
+    use wheel::interp::{Interpreter, Value};
+
+    let artifacts = wheel::compile_source("let greeting = \"hi \" + \"there\";\nfunc main() {\n    print(greeting);\n}\n", wheel::Options::new("hello.wheel")).unwrap();
+    let mut out = Vec::new();
+    assert_eq!(wheel::interp::run(&artifacts.program, &mut out).unwrap(), 0);
+    assert_eq!(String::from_utf8(out).unwrap(), "hi there\n");
+
+    let src = "let s = wheel_str_concat(\"wheel\", \"barrow\");\nprint(wheel_str_substring(s, 5, 3));\nprint(wheel_str_len(s) + wheel_str_compare(\"a\", \"b\"));\n";
+    let mut opts = wheel::Options::new("strings.wheel");
+    opts.builtins = wheel::builtins::Builtins::runtime();
+    let artifacts = wheel::compile_source(src, opts).unwrap();
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "bar\n10\n");
+
+    let parse = |src: &str| wheel::parser::Parser::with_edition(src, Default::default()).parse_program().items;
+    let mut out = Vec::new();
+    let mut repl = Interpreter::new(&mut out);
+    assert_eq!(repl.eval(&parse("func sq(n) { return n * n; }\nlet xs = [1, 2, 3];\n")).unwrap(), None);
+    assert_eq!(repl.eval(&parse("sq(xs[2]) + 1;\n")).unwrap(), Some(Value::Int(10)));
+    assert!(repl.eval(&parse("sq(1, 2);\n")).is_err());
+    repl.eval(&parse("print(xs);\n")).unwrap();
+    assert_eq!(repl.global("xs"), Some(&Value::Array(vec![Value::Int(1), Value::Int(2), Value::Int(3)])));
+    drop(repl);
+    assert_eq!(String::from_utf8(out).unwrap(), "[1, 2, 3]\n");
+}
+
+#[test]
+fn json_builtins_parse_and_stringify_nested_values() {
+    use wheel::builder::*;
+
+    // Wheel string literals have no escapes, so the document comes in through the builder.
+    let doc = r#"{"name": "wheel", "tags": [1, true, null]}"#;
+    let prog = program([
+        let_("doc", call("json_parse", [string(doc)])),
+        print(index(ident("doc"), string("name"))),
+        print(index(index(ident("doc"), string("tags")), int(1)) + int(2)),
+        print(call("json_stringify", [ident("doc")])),
+    ]);
+    let artifacts = wheel::compile_program(prog, wheel::Options::new("json.wheel")).unwrap();
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "wheel\n3\n{\"name\":\"wheel\",\"tags\":[1,1,0]}\n");
+
+    let bad = wheel::compile_source("json_parse(\"[1, 2.5]\");\n", wheel::Options::new("bad.wheel")).unwrap();
+    let err = wheel::interp::run(&bad.program, &mut Vec::new()).unwrap_err();
+    assert!(err.to_string().contains("expected an integer at byte 5"), "{}", err);
+}
+
+#[test]
+fn spawned_threads_are_joined_for_their_result() {
+    let src = "func square(n) {\n    return n * n;\n}\nfunc hello() {\n    print(\"hello\");\n}\nlet a = thread_spawn(\"square\", 7);\nlet b = thread_spawn(\"hello\");\nprint(thread_join(a) + thread_join(b));\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("threads.wheel")).unwrap();
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "hello\n49\n");
+
+    let err = wheel::compile_source("let t = thread_spawn(\"nope\");\nprint(t);\n", wheel::Options::new("bad.wheel")).unwrap_err();
+    assert_eq!(err.diagnostics()[0].code, "bad-spawn");
+}
+
+#[test]
+fn irq_handlers_are_named_functions_on_literal_vectors() {
+    let src = "func on_timer() {\n    mmio_write(4276092992, 0);\n}\nfunc on_fault(code) {\n    print(code);\n}\nirq_register(32, \"on_timer\");\nirq_register(14, \"on_fault\");\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("kernel.wheel")).unwrap();
+    let err = wheel::interp::run(&artifacts.program, &mut Vec::new()).unwrap_err();
+    assert!(err.to_string().contains("`irq_register` with 2 argument(s) is not supported by the interpreter"), "{}", err);
+
+    let bad = "func tick(a, b) {\n    return a;\n}\nlet v = 32;\nirq_register(v, \"tick\");\nirq_register(300, \"missing\");\n";
+    let err = wheel::compile_source(bad, wheel::Options::new("bad.wheel")).unwrap_err();
+    let messages: Vec<_> = err.diagnostics().iter().filter(|d| d.code == "bad-irq").map(|d| d.message.as_str()).collect();
+    assert_eq!(messages, [
+        "`irq_register` takes the vector as an integer literal from 0 to 255",
+        "`tick` takes 2 arguments but an interrupt handler gets at most one, the error code",
+        "`irq_register` takes the vector as an integer literal from 0 to 255",
+        "`irq_register` of undefined function `missing`",
+    ]);
+}
+
+#[test]
+fn irq_register_generates_stubs_and_an_idt_in_the_assembly() {
+    let src = "let faults = 0;\nirq_register(13, \"on_fault\");\nirq_register(32, \"on_timer\");\nfunc on_fault(code) {\n    let last = code;\n    set faults = faults + 1;\n}\nfunc on_timer() {\n    return 0;\n}\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("kernel.wheel")).unwrap();
+    let asm = artifacts.asm();
+    assert!(asm.contains("    mov rdi, 32\n    lea rsi, [rip + wheel_irq_32_on_timer]\n    call wheel_irq_register\n"), "{}", asm);
+    // the handler's locals are its own, its parameter is the error code
+    assert!(asm.contains("on_fault.code: .quad 0\non_fault.last: .quad 0\n"), "{}", asm);
+    assert!(asm.contains("on_fault:\n    mov qword ptr [rip + on_fault.code], rdi\n"), "{}", asm);
+    // vector 13 pushes an error code, which the stub passes on and pops
+    assert!(asm.contains("    push r11\n    mov rdi, qword ptr [rsp + 80]\n    cld\n    call on_fault\n"), "{}", asm);
+    assert!(asm.contains("    pop rax\n    add rsp, 8\n    iretq\nwheel_irq_32_on_timer:"), "{}", asm);
+    assert!(asm.contains("    xor edi, edi\n    sub rsp, 8\n    cld\n    call on_timer\n    add rsp, 8\n"), "{}", asm);
+    assert!(asm.contains("wheel_idt: .space 4096\n"), "{}", asm);
+    assert!(asm.contains("    sidt [rsp]\n") && asm.contains("    mov word ptr [rdx + 4], 0x8E00\n") && asm.contains("    lidt [rsp]\n"), "{}", asm);
+}
+
+#[test]
+fn interpreter_mutexes_report_a_self_deadlock() {
+    let src = "let m = mutex_new();\nprint(mutex_lock(m) + mutex_unlock(m) + mutex_lock(m));\nmutex_lock(m);\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("mutex.wheel")).unwrap();
+    let mut out = Vec::new();
+    let err = wheel::interp::run(&artifacts.program, &mut out).unwrap_err();
+    assert_eq!(String::from_utf8(out).unwrap(), "3\n");
+    assert!(err.to_string().contains("deadlock: mutex 1 is already locked"), "{}", err);
+}
+
+#[test]
+fn channels_pass_values_between_threads() {
+    let src = "func produce(ch) {\n    for i in range(1, 4) {\n        chan_send(ch, i * 10);\n    }\n}\nlet ch = chan_new(3);\nlet t = thread_spawn(\"produce\", ch);\nthread_join(t);\nprint(chan_try_send(ch, 99));\nprint(chan_recv(ch) + chan_recv(ch) + chan_recv(ch));\nprint(chan_try_recv(ch, 7));\nchan_recv(ch);\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("chan.wheel")).unwrap();
+    let mut out = Vec::new();
+    let err = wheel::interp::run(&artifacts.program, &mut out).unwrap_err();
+    assert_eq!(String::from_utf8(out).unwrap(), "0\n60\n7\n");
+    assert!(err.to_string().contains("deadlock: channel 1 is empty"), "{}", err);
+}
+
+#[test]
+fn timestamps_format_and_parse_in_utc() {
+    let src = "let t = 951827696;\nprint(time_format(t, \"%Y-%m-%d %H:%M:%S (100%%)\"));\nprint(time_year(t) * 1000000 + time_month(t) * 10000 + time_day(t) * 100 + time_hour(t));\nprint(time_parse(\"2100-01-01 00:00\", \"%Y-%m-%d %H:%M\"));\nprint(time_parse(\"2023-13-01\", \"%Y-%m-%d\"));\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("time.wheel")).unwrap();
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "2000-02-29 12:34:56 (100%)\n2000022912\n4102444800\n-1\n");
+}
+
+#[test]
+fn seeded_luck_is_reproducible() {
+    let src = "luck_seed(42);\nlet xs = [1, 2, 3, 4, 5, 6];\nprint(luck_shuffle(xs));\nprint(xs);\nprint(luck_choice(xs) * 0 + luck_random_float() / 1000000);\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("luck.wheel")).unwrap();
+    let run = || {
+        let mut out = Vec::new();
+        wheel::interp::run(&artifacts.program, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+    let first = run();
+    assert_eq!(first, run());
+    let lines: Vec<&str> = first.lines().collect();
+    let mut shuffled: Vec<&str> = lines[0].trim_matches(['[', ']']).split(", ").collect();
+    shuffled.sort();
+    assert_eq!(shuffled, ["1", "2", "3", "4", "5", "6"]);
+    assert_eq!(lines[1..], ["[1, 2, 3, 4, 5, 6]", "0"]);
+}
+
+#[test]
+fn math_functions_are_registered_runtime_builtins() {
+    let builtins = wheel::builtins::Builtins::runtime();
+    let sqrt = builtins.get("sqrt").unwrap();
+    assert_eq!(sqrt.signature(), "sqrt(float) -> float");
+    assert_eq!(sqrt.symbol(), "math_sqrt");
+
+    let mut opts = wheel::Options::new("math.wheel");
+    opts.builtins = builtins.clone();
+    let artifacts = wheel::compile_source("print(sqrt(16) + pow(2, 10) + fmod(7, 4));\n", opts).unwrap();
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "1031\n");
+
+    let mut opts = wheel::Options::new("math.wheel");
+    opts.builtins = builtins;
+    let err = wheel::compile_source("print(pow(2));\n", opts).unwrap_err();
+    assert_eq!(err.diagnostics()[0].code, "builtin-arity");
+}
+
+#[test]
+fn program_functions_shadow_runtime_builtins() {
+    let mut opts = wheel::Options::new("shadow.wheel");
+    opts.builtins = wheel::builtins::Builtins::runtime();
+    let src = "func log(a, b) {\n    return a * 10 + b;\n}\nprint(log(5, 6));\nprint(sqrt(16));\n";
+    let artifacts = wheel::compile_source(src, opts).unwrap();
+    assert!(artifacts.warnings.is_empty(), "{:?}", artifacts.warnings);
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "56\n4\n");
+}
+
+#[test]
+fn key_and_mouse_constants_are_predeclared() {
+    assert_eq!(wheel::builtins::constant("KEY_SPACE"), Some(32));
+    let src = "print(KEY_UP);\nprint(MOUSE_RIGHT);\nlet KEY_A = 5;\nprint(KEY_A);\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("keys.wheel")).unwrap();
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "1073741906\n3\n5\n");
+}
+
+#[test]
+fn regex_builtins_match_find_and_replace() {
+    let src = "print(regex_match(\"^[a-z_]\\w*$\", \"snake_case2\"));\nprint(regex_match(\"^\\d+$\", \"12a\"));\nprint(regex_find(\"o+\", \"hello world\"));\nprint(regex_find(\"x\", \"abc\"));\nprint(regex_replace(\"\\s+\", \"a  b \t c\", \" \"));\nprint(regex_replace(\"x*\", \"ab\", \"-\"));\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("regex.wheel")).unwrap();
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "1\n0\n4\n-1\na b c\n-a-b-\n");
+
+    let artifacts = wheel::compile_source("print(regex_match(\"[abc\", \"a\"));\n", wheel::Options::new("bad.wheel")).unwrap();
+    let err = wheel::interp::run(&artifacts.program, &mut Vec::new()).unwrap_err();
+    assert!(err.to_string().contains("regex: malformed pattern `[abc`: unterminated `[`"), "{}", err);
+}
+
+#[test]
+fn vec_builtins_grow_and_index_a_vector() {
+    let src = "let v = vec_new();\nlet i = 0;\nwhile i < 10 {\n    vec_push(v, i * i);\n    i = i + 1;\n}\nvec_set(v, 0, 7);\nprint(vec_len(v));\nprint(vec_pop(v));\nprint(vec_get(v, 0) + vec_get(v, 8));\nprint(vec_len(v));\nprint(vec_get(v, 9));\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("vec.wheel")).unwrap();
+    let mut out = Vec::new();
+    let err = wheel::interp::run(&artifacts.program, &mut out).unwrap_err();
+    assert_eq!(String::from_utf8(out).unwrap(), "10\n81\n71\n9\n");
+    assert!(err.to_string().contains("`vec_get`: index 9 out of range for vector 1 of length 9"), "{}", err);
+}
+
+#[test]
+fn map_builtins_keep_scores_by_name() {
+    let src = "let scores = map_new();\nmap_set(scores, \"bob\", 3);\nmap_set(scores, \"alice\", 5);\nmap_set(scores, \"bob\", map_get(scores, \"bob\") + 4);\nprint(map_get(scores, \"bob\"));\nprint(map_has(scores, \"carol\"));\nlet keys = map_keys(scores);\nprint(vec_len(keys));\nprint(vec_get(keys, 0));\nprint(map_get(scores, \"carol\"));\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("scores.wheel")).unwrap();
+    let mut out = Vec::new();
+    let err = wheel::interp::run(&artifacts.program, &mut out).unwrap_err();
+    assert_eq!(String::from_utf8(out).unwrap(), "7\n0\n2\nbob\n");
+    assert!(err.to_string().contains("`map_get`: key \"carol\" is not in map 1"), "{}", err);
+}
+
+#[test]
+fn string_builder_and_int_conversions_format_output() {
+    let src = "let sb = sb_new();\nlet i = 1;\nwhile i < 4 {\n    sb_append(sb, int_to_str(i * 10));\n    sb_append(sb, \";\");\n    i = i + 1;\n}\nprint(sb_to_str(sb));\nprint(str_to_int(\" 42abc\") + str_to_int(\"x\"));\nprint(wheel_str_len(int_to_str(0 - 123)));\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("format.wheel")).unwrap();
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "10;20;30;\n42\n4\n");
+}
+
+#[test]
+fn process_pipes_feed_stdin_and_capture_stdout() {
+    let src = "let p = process_spawn(\"sort; exit 3\");\nprocess_write(p, \"pear\");\nprocess_write(p, \" \");\nprocess_write(p, \"apple\");\nprint(process_exit_code(p));\nprint(process_eof(p));\nprint(process_read_line(p));\nprint(process_eof(p));\n";
+    let artifacts = wheel::compile_source(src, wheel::Options::new("pipes.wheel")).unwrap();
+    let mut out = Vec::new();
+    wheel::interp::run(&artifacts.program, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "3\n0\npear apple\n1\n");
+}
+
+#[test]
+fn sdl_present_requires_a_window_handle() {
+    let src = "let a = sdl_create_window(320, 240, \"left\");\nsdl_clear(a, 0, 0, 0);\nsdl_present(a);\n";
+    assert!(wheel::compile_source(src, wheel::Options::new("windows.wheel")).is_ok());
+
+    let err = wheel::compile_source("sdl_create_window(320, 240, \"left\");\nsdl_present();\n", wheel::Options::new("bad.wheel")).unwrap_err();
+    assert_eq!(err.diagnostics()[0].code, "bad-sdl-call");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn ast_round_trips_through_json() {
+    let src = "let xs = [1, 2];\nfunc main(a, b) {\n    if a < b { print(\"lt\"); } else { return xs[0]; }\n}\n";
+    let prog = wheel::parser::Parser::with_edition(src, Default::default()).parse_program();
+    let json = serde_json::to_string(&prog).unwrap();
+    let back: wheel::ast::Program = serde_json::from_str(&json).unwrap();
+    assert_eq!(format!("{:?}", back), format!("{:?}", prog));
+}
@@ -0,0 +1,435 @@
+// Drives the runtime objects `wheelc build-stdlib` ships with small C
+// programs, so the code `--mode ll` links is what is tested, not the
+// interpreter's stand-ins.
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+
+const TRIPLE: &str = "x86_64-unknown-linux-gnu";
+
+/// Directory holding the extracted runtime objects, extracted once per run.
+fn stdlib() -> &'static Path {
+    static DIR: OnceLock<PathBuf> = OnceLock::new();
+    DIR.get_or_init(|| {
+        let dir = std::env::temp_dir().join("wheelc_runtime_test");
+        let _ = fs::remove_dir_all(&dir);
+        let output = Command::new(env!("CARGO_BIN_EXE_wheelc"))
+            .arg("build-stdlib")
+            .arg("--target").arg(TRIPLE)
+            .arg("--build-dir").arg(&dir)
+            .output()
+            .expect("failed to run wheelc");
+        assert!(output.status.success(), "build-stdlib failed: {}", String::from_utf8_lossy(&output.stderr));
+        dir.join("stdlib").join(TRIPLE)
+    })
+}
+
+/// Compile `driver` against `objects` (and `libs`), run it and return its
+/// stdout; the driver must exit with 0.
+fn run_driver(name: &str, driver: &str, objects: &[&str], libs: &[&str]) -> String {
+    let dir = std::env::temp_dir().join("wheelc_runtime_test_drivers");
+    fs::create_dir_all(&dir).unwrap();
+    let src = dir.join(format!("{}.c", name));
+    let exe = dir.join(name);
+    fs::write(&src, driver).unwrap();
+    let status = Command::new("cc")
+        .arg(&src)
+        .args(objects.iter().map(|o| stdlib().join(format!("{}.o", o))))
+        .args(libs)
+        .arg("-o").arg(&exe)
+        .status()
+        .expect("failed to run cc");
+    assert!(status.success(), "failed to link {}", name);
+    let output = Command::new(&exe).output().expect("failed to run driver");
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    assert!(output.status.success(), "{} failed: {}{}", name, stdout, String::from_utf8_lossy(&output.stderr));
+    stdout
+}
+
+#[test]
+fn vectors_grow_past_the_memory_heap() {
+    let stdout = run_driver("vec_grow", r#"
+#include <stdint.h>
+#include <stdio.h>
+int64_t wheel_vec_new(void);
+int64_t wheel_vec_push(int64_t vec, int64_t value);
+int64_t wheel_vec_get(int64_t vec, int64_t index);
+int64_t wheel_vec_len(int64_t vec);
+int main(void) {
+    int64_t v = wheel_vec_new();
+    for (int64_t i = 0; i < 200000; i++) {
+        if (wheel_vec_push(v, i * 3) != i + 1) return 1;
+    }
+    printf("%lld %lld\n", (long long)wheel_vec_len(v), (long long)wheel_vec_get(v, 199999));
+    return 0;
+}
+"#, &["vec", "memory"], &[]);
+    assert_eq!(stdout, "200000 599997\n");
+}
+
+#[test]
+fn arenas_hand_out_aligned_memory_and_reset() {
+    let stdout = run_driver("arena", r#"
+#include <stdio.h>
+#include <string.h>
+long arena_new(long size);
+long arena_alloc(long handle, long n);
+long arena_reset(long handle);
+int main(void) {
+    // larger than the 1 MB heap of memory.o
+    long arena = arena_new(2000000);
+    if (!arena) return 1;
+    long a = arena_alloc(arena, 3);
+    long b = arena_alloc(arena, 1999990);
+    if (!a || !b || b - a != 8 || b % 8) return 2;
+    memset((void*)b, 1, 1999990);
+    if (arena_alloc(arena, 16)) return 3;
+    if (!arena_reset(arena) || arena_alloc(arena, 16) != a) return 4;
+    printf("ok\n");
+    return 0;
+}
+"#, &["memory"], &[]);
+    assert_eq!(stdout, "ok\n");
+}
+
+#[test]
+fn process_pipes_survive_large_output_and_report_the_end() {
+    let stdout = run_driver("process", r#"
+#include <stdio.h>
+#include <string.h>
+long process_spawn(const char* command);
+long process_write(long p, const char* data);
+char* process_read_line(long p);
+long process_eof(long p);
+long process_exit_code(long p);
+int main(void) {
+    // far more than a pipe holds, so waiting without reading would hang
+    long p = process_spawn("seq 1 200000; echo; echo last; exit 4");
+    if (process_exit_code(p) != 4) return 1;
+    long lines = 0;
+    char* line = NULL;
+    while (!process_eof(p)) {
+        line = process_read_line(p);
+        lines++;
+        if (lines == 200000 && strcmp(line, "200000") != 0) return 2;
+        if (lines == 200001 && *line) return 3;
+    }
+    printf("%ld %s %s\n", lines, line, *process_read_line(p) ? "more" : "end");
+
+    long cat = process_spawn("cat");
+    process_write(cat, "a\n\nb");
+    if (process_exit_code(cat) != 0) return 4;
+    printf("[%s]", process_read_line(cat));
+    printf("[%s]", process_read_line(cat));
+    printf("[%s]", process_read_line(cat));
+    printf("%ld\n", process_eof(cat));
+    return 0;
+}
+"#, &["process"], &[]);
+    assert_eq!(stdout, "200002 last end\n[a][][b]1\n");
+}
+
+#[test]
+fn threads_run_concurrently_and_join_with_their_results() {
+    let stdout = run_driver("threads", r#"
+#include <stdint.h>
+#include <stdio.h>
+int64_t wheel_thread_spawn(int64_t fn, int64_t arg);
+int64_t wheel_thread_join(int64_t handle);
+static volatile int64_t started;
+static int64_t square_when_all_started(int64_t n) {
+    __atomic_add_fetch(&started, 1, __ATOMIC_SEQ_CST);
+    // only returns once every thread runs at the same time
+    while (__atomic_load_n(&started, __ATOMIC_SEQ_CST) < 4) {}
+    return n * n;
+}
+int main(void) {
+    int64_t threads[4];
+    for (int64_t i = 0; i < 4; i++) {
+        threads[i] = wheel_thread_spawn((int64_t)(intptr_t)square_when_all_started, i + 1);
+        if (!threads[i]) return 1;
+    }
+    int64_t sum = 0;
+    for (int i = 0; i < 4; i++) sum += wheel_thread_join(threads[i]);
+    printf("%lld\n", (long long)sum);
+    return 0;
+}
+"#, &["threads"], &["-lpthread"]);
+    assert_eq!(stdout, "30\n");
+}
+
+#[test]
+fn contended_mutexes_block_until_unlocked() {
+    let stdout = run_driver("mutex", r#"
+#include <stdint.h>
+#include <stdio.h>
+#include <unistd.h>
+int64_t wheel_thread_spawn(int64_t fn, int64_t arg);
+int64_t wheel_thread_join(int64_t handle);
+int64_t wheel_mutex_new(void);
+int64_t wheel_mutex_lock(int64_t mutex);
+int64_t wheel_mutex_unlock(int64_t mutex);
+static int64_t counter;
+static volatile int64_t entered;
+static int64_t count(int64_t mutex) {
+    for (int i = 0; i < 100000; i++) {
+        wheel_mutex_lock(mutex);
+        // a racy read-modify-write unless the lock excludes the others
+        int64_t n = counter;
+        counter = n + 1;
+        wheel_mutex_unlock(mutex);
+    }
+    return 0;
+}
+static int64_t enter(int64_t mutex) {
+    wheel_mutex_lock(mutex);
+    entered = 1;
+    wheel_mutex_unlock(mutex);
+    return 0;
+}
+int main(void) {
+    int64_t mutex = wheel_mutex_new();
+    if (!mutex) return 1;
+    int64_t threads[4];
+    for (int i = 0; i < 4; i++) threads[i] = wheel_thread_spawn((int64_t)(intptr_t)count, mutex);
+    for (int i = 0; i < 4; i++) wheel_thread_join(threads[i]);
+    printf("%lld ", (long long)counter);
+
+    if (!wheel_mutex_lock(mutex)) return 2;
+    int64_t waiter = wheel_thread_spawn((int64_t)(intptr_t)enter, mutex);
+    usleep(100000);
+    printf("%lld ", (long long)entered);
+    wheel_mutex_unlock(mutex);
+    wheel_thread_join(waiter);
+    printf("%lld\n", (long long)entered);
+    return 0;
+}
+"#, &["threads"], &["-lpthread"]);
+    assert_eq!(stdout, "400000 0 1\n");
+}
+
+#[test]
+fn channels_block_receivers_and_full_senders() {
+    let stdout = run_driver("chan", r#"
+#include <stdint.h>
+#include <stdio.h>
+#include <unistd.h>
+int64_t wheel_thread_spawn(int64_t fn, int64_t arg);
+int64_t wheel_thread_join(int64_t handle);
+int64_t wheel_chan_new(int64_t capacity);
+int64_t wheel_chan_send(int64_t chan, int64_t value);
+int64_t wheel_chan_try_send(int64_t chan, int64_t value);
+int64_t wheel_chan_recv(int64_t chan);
+int64_t wheel_chan_try_recv(int64_t chan, int64_t fallback);
+static volatile int64_t received;
+static int64_t recv_one(int64_t chan) {
+    int64_t value = wheel_chan_recv(chan);
+    received = 1;
+    return value;
+}
+static int64_t sum(int64_t chan) {
+    int64_t total = 0;
+    for (int i = 0; i < 1000; i++) total += wheel_chan_recv(chan);
+    return total;
+}
+int main(void) {
+    int64_t chan = wheel_chan_new(1);
+    if (!chan) return 1;
+    int64_t reader = wheel_thread_spawn((int64_t)(intptr_t)recv_one, chan);
+    usleep(100000);
+    printf("%lld ", (long long)received);
+    wheel_chan_send(chan, 42);
+    printf("%lld ", (long long)wheel_thread_join(reader));
+
+    // one slot, so every send past the first waits for the reader
+    reader = wheel_thread_spawn((int64_t)(intptr_t)sum, chan);
+    for (int64_t i = 1; i <= 1000; i++) wheel_chan_send(chan, i);
+    printf("%lld ", (long long)wheel_thread_join(reader));
+
+    printf("%lld ", (long long)wheel_chan_try_send(chan, 7));
+    printf("%lld ", (long long)wheel_chan_try_send(chan, 8));
+    printf("%lld ", (long long)wheel_chan_try_recv(chan, -1));
+    printf("%lld\n", (long long)wheel_chan_try_recv(chan, -1));
+    return 0;
+}
+"#, &["threads"], &["-lpthread"]);
+    assert_eq!(stdout, "0 42 500500 1 0 7 -1\n");
+}
+
+#[test]
+fn regexes_match_find_and_replace() {
+    let stdout = run_driver("regex", r#"
+#include <stdint.h>
+#include <stdio.h>
+int64_t wheel_regex_match(const char *re, const char *s);
+int64_t wheel_regex_find(const char *re, const char *s);
+char *wheel_regex_replace(const char *re, const char *s, const char *with);
+int main(void) {
+    printf("%lld", (long long)wheel_regex_match("^[a-z_]\\w*$", "snake_case2"));
+    printf("%lld", (long long)wheel_regex_match("^[a-z_]\\w*$", "2fast"));
+    printf("%lld", (long long)wheel_regex_match("colou?r", "what colour"));
+    printf("%lld ", (long long)wheel_regex_match("[a-", "a"));
+    printf("%lld ", (long long)wheel_regex_find("\\d+", "abc 123"));
+    printf("%lld ", (long long)wheel_regex_find("x", "abc"));
+    printf("%s ", wheel_regex_replace("\\s+", "a  b\t c", "_"));
+    printf("%s\n", wheel_regex_replace("a*", "baaac", "-"));
+    return 0;
+}
+"#, &["regex"], &[]);
+    assert_eq!(stdout, "1010 4 -1 a_b_c -b--c-\n");
+}
+
+#[test]
+fn maps_keep_every_key_in_insertion_order() {
+    let stdout = run_driver("map", r#"
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+int64_t wheel_map_new(void);
+int64_t wheel_map_set(int64_t map, const char *key, int64_t value);
+int64_t wheel_map_get(int64_t map, const char *key);
+int64_t wheel_map_has(int64_t map, const char *key);
+int64_t wheel_map_keys(int64_t map);
+int64_t wheel_vec_len(int64_t vec);
+int64_t wheel_vec_get(int64_t vec, int64_t index);
+int main(void) {
+    int64_t map = wheel_map_new();
+    char key[32];
+    for (int64_t i = 0; i < 50000; i++) {
+        sprintf(key, "key%lld", (long long)i);
+        if (!wheel_map_set(map, key, i * 2)) return 1;
+    }
+    // overwriting keeps the key where it was
+    wheel_map_set(map, "key0", -1);
+    for (int64_t i = 1; i < 50000; i++) {
+        sprintf(key, "key%lld", (long long)i);
+        if (wheel_map_get(map, key) != i * 2) return 2;
+    }
+    printf("%lld %lld %lld ", (long long)wheel_map_get(map, "key0"), (long long)wheel_map_has(map, "key49999"), (long long)wheel_map_has(map, "key50000"));
+    int64_t keys = wheel_map_keys(map);
+    printf("%lld %s %s\n", (long long)wheel_vec_len(keys), (char *)(intptr_t)wheel_vec_get(keys, 0), (char *)(intptr_t)wheel_vec_get(keys, 49999));
+    return 0;
+}
+"#, &["map", "vec"], &[]);
+    assert_eq!(stdout, "-1 1 0 50000 key0 key49999\n");
+}
+
+#[test]
+fn string_builders_grow_and_snapshot() {
+    let stdout = run_driver("sb", r#"
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+int64_t wheel_sb_new(void);
+int64_t wheel_sb_append(int64_t sb, const char *s);
+char *wheel_sb_to_str(int64_t sb);
+int64_t wheel_str_len(const char *s);
+int main(void) {
+    int64_t sb = wheel_sb_new();
+    if (!sb) return 1;
+    printf("[%s] ", wheel_sb_to_str(sb));
+    wheel_sb_append(sb, "ab");
+    char *early = wheel_sb_to_str(sb);
+    // past the first buffer many times over
+    for (int i = 0; i < 10000; i++) {
+        if (!wheel_sb_append(sb, "0123456789")) return 2;
+    }
+    wheel_sb_append(sb, "");
+    char *all = wheel_sb_to_str(sb);
+    if (strncmp(all, "ab0123456789012", 15) != 0) return 3;
+    printf("%s %lld %s\n", early, (long long)wheel_str_len(all), all + wheel_str_len(all) - 4);
+    return 0;
+}
+"#, &["strings"], &[]);
+    assert_eq!(stdout, "[] ab 100002 6789\n");
+}
+
+#[test]
+fn the_collector_keeps_reachable_blocks_and_reclaims_the_rest() {
+    let stdout = run_driver("gc", r#"
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <sys/resource.h>
+struct node { struct node* next; long value; };
+static struct node* head;
+int main(void) {
+    // reachable only through the global and then through each other
+    for (long i = 1; i <= 100; i++) {
+        struct node* n = malloc(sizeof *n);
+        n->next = head;
+        n->value = i;
+        head = n;
+    }
+    // 1 GB of garbage; without collecting, far more than the limit below
+    for (int i = 0; i < 16384; i++) {
+        char* junk = malloc(65536);
+        memset(junk, i, 65536);
+        free(junk);
+    }
+    long sum = 0;
+    for (struct node* n = head; n; n = n->next) sum += n->value;
+    struct rusage usage;
+    getrusage(RUSAGE_SELF, &usage);
+    printf("%ld %s\n", sum, usage.ru_maxrss < 64 * 1024 ? "bounded" : "grew");
+    return 0;
+}
+"#, &["gc"], &["-Wl,--wrap=malloc,--wrap=calloc,--wrap=realloc,--wrap=free,--wrap=mem_alloc,--wrap=mem_free"]);
+    assert_eq!(stdout, "5050 bounded\n");
+}
+
+#[test]
+fn unset_environment_variables_read_as_null() {
+    let stdout = run_driver("env_unset", r#"
+#include <stdio.h>
+#include <stdlib.h>
+const char *wheel_env_get(const char *name);
+int main(void) {
+    unsetenv("WHEEL_TEST_UNSET");
+    setenv("WHEEL_TEST_EMPTY", "", 1);
+    const char *empty = wheel_env_get("WHEEL_TEST_EMPTY");
+    printf("%d %d [%s]\n", wheel_env_get("WHEEL_TEST_UNSET") == NULL, empty == NULL, empty);
+    return 0;
+}
+"#, &["os_wrappers", "memory"], &[]);
+    assert_eq!(stdout, "1 0 []\n");
+}
+
+#[test]
+fn formatted_times_are_always_freeable() {
+    let driver = r#"
+#include <stdint.h>
+#include <stdio.h>
+#include <string.h>
+char *wheel_time_format(int64_t epoch, const char *fmt);
+void mem_free(void *ptr);
+size_t mem_get_used(void);
+int main(void) {
+    // every result, empty and long ones included, is freed, so the 1 MB
+    // heap of memory.o never fills up
+    size_t used = mem_get_used();
+    char fmt[4000] = "";
+    for (int i = 0; i < 400; i++) strcat(fmt, "%Y-%m-%d ");
+    char *long_one = wheel_time_format(951827696, fmt);
+    char *empty = wheel_time_format(0, "");
+    char *literal = wheel_time_format(0, "100%%");
+    printf("%zu %s [%s] %s %d\n", strlen(long_one), long_one + 4389, empty, literal, wheel_time_format(0, "%2000000Y") == NULL);
+    mem_free(long_one);
+    mem_free(empty);
+    mem_free(literal);
+    for (int i = 0; i < 100000; i++) {
+        char *s = wheel_time_format(i, "%H:%M:%S");
+        if (!s || strlen(s) != 8) return 1;
+        mem_free(s);
+    }
+    printf("%s\n", mem_get_used() == used ? "reused" : "leaked");
+    return 0;
+}
+"#;
+    let expected = "4400 2000-02-29  [] 100% 1\nreused\n";
+    assert_eq!(run_driver("time_format", driver, &["os_wrappers", "memory"], &[]), expected);
+    // with the collector, mem_free does nothing and the results are collected
+    let collected = driver.replace("mem_get_used() == used", "1");
+    assert_eq!(run_driver("time_format_gc", &collected, &["os_wrappers", "memory", "gc"], &["-Wl,--wrap=malloc,--wrap=calloc,--wrap=realloc,--wrap=free,--wrap=mem_alloc,--wrap=mem_free"]), expected);
+}